@@ -108,12 +108,13 @@ pub fn query_health(
         .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
 
     // Query all limit orders for the account.
-    let limit_orders =
-        querier.query_wasm_smart(app_cfg.addresses.dex, QueryOrdersByUserRequest {
+    let limit_orders = querier
+        .query_wasm_smart(app_cfg.addresses.dex, QueryOrdersByUserRequest {
             user: account,
             start_after: None,
             limit: None,
-        })?;
+        })?
+        .orders;
 
     Ok(HealthData {
         scaled_debts,
@@ -30,6 +30,13 @@ pub fn instantiate(ctx: MutableCtx, _msg: InstantiateMsg) -> anyhow::Result<Resp
 
 #[cfg_attr(not(feature = "library"), grug::export)]
 pub fn authenticate(ctx: AuthCtx, tx: Tx) -> anyhow::Result<AuthResponse> {
+    // A multisig can only act through proposals, so it can't agree to pay the
+    // fee for someone else's transaction.
+    ensure!(
+        tx.sender == ctx.contract,
+        "a multi-signature account can't be a fee payer"
+    );
+
     let metadata: Metadata = tx.data.clone().deserialize_json()?;
     let mut has_non_voting = false;
 
@@ -371,6 +378,7 @@ mod tests {
         {
             let res = authenticate(ctx.as_auth(), Tx {
                 sender: MULTI,
+                fee_payer: None,
                 gas_limit: 1_000_000,
                 msgs: NonEmpty::new_unchecked(vec![
                     Message::transfer(Addr::mock(123), Coins::new()).unwrap(),
@@ -380,6 +388,7 @@ mod tests {
                     chain_id,
                     nonce: 0,
                     expiry: None,
+                    tip: None,
                 }
                 .to_json_value()
                 .unwrap(),
@@ -397,6 +406,7 @@ mod tests {
         {
             let res = authenticate(ctx.as_auth(), Tx {
                 sender: MULTI,
+                fee_payer: None,
                 gas_limit: 1_000_000,
                 msgs: NonEmpty::new_unchecked(vec![
                     Message::execute(
@@ -416,6 +426,7 @@ mod tests {
                     chain_id: "".to_string(),
                     nonce: 0,
                     expiry: None,
+                    tip: None,
                 }
                 .to_json_value()
                 .unwrap(),
@@ -14,8 +14,8 @@ use {
         },
     },
     grug::{
-        Addr, Api, AuthCtx, AuthMode, Inner, Item, JsonDeExt, JsonSerExt, QuerierExt, SignData,
-        StdError, StdResult, Storage, StorageQuerier, Tx,
+        Addr, Api, AuthCtx, AuthMode, Inner, Item, Json, JsonDeExt, JsonSerExt, QuerierExt,
+        SignData, StdError, StdResult, Storage, StorageQuerier, Tx,
     },
     sha2::Sha256,
     std::collections::BTreeSet,
@@ -79,12 +79,24 @@ pub fn query_seen_nonces(storage: &dyn Storage) -> StdResult<BTreeSet<Nonce>> {
         .map(|opt| opt.unwrap_or_default())
 }
 
+/// Return the metadata and credential of the given account, which is either the
+/// transaction's sender, or its fee payer.
+fn data_and_credential_of(account: Addr, tx: &Tx) -> (&Json, &Json) {
+    match &tx.fee_payer {
+        Some(fee_payer) if fee_payer.address == account => (&fee_payer.data, &fee_payer.credential),
+        _ => (&tx.data, &tx.credential),
+    }
+}
+
 /// Authenticate a transaction by ensuring:
 ///
-/// - the username is associated with the sender account;
+/// - the username is associated with the account;
 /// - the nonce is acceptible;
 /// - the signature is authentic.
 ///
+/// The account may be either the transaction's sender, or its fee payer, in
+/// which case the fee payer's metadata and credential are verified.
+///
 /// This logic is used by single-signature accounts (Spot and Margin).
 pub fn authenticate_tx(
     ctx: AuthCtx,
@@ -99,21 +111,24 @@ pub fn authenticate_tx(
     let metadata = if let Some(metadata) = maybe_metadata {
         metadata
     } else {
-        tx.data.clone().deserialize_json()?
+        data_and_credential_of(ctx.contract, &tx)
+            .0
+            .clone()
+            .deserialize_json()?
     };
 
-    // If the sender account is associated with the username, then an entry
-    // must exist in the `ACCOUNTS_BY_USER` set, and the value should be
-    // empty because we Borsh for encoding.
+    // If the account is associated with the username, then an entry must exist
+    // in the `ACCOUNTS_BY_USER` set, and the value should be empty because we
+    // Borsh for encoding.
     ensure!(
         ctx.querier
             .query_wasm_raw(
                 factory,
-                account_factory::ACCOUNTS_BY_USER.path((&metadata.username, tx.sender)),
+                account_factory::ACCOUNTS_BY_USER.path((&metadata.username, ctx.contract)),
             )?
             .is_some_and(|bytes| bytes.is_empty()),
         "account {} isn't associated with user `{}`",
-        tx.sender,
+        ctx.contract,
         metadata.username,
     );
 
@@ -141,11 +156,14 @@ pub fn verify_nonce_and_signature(
         ctx.querier.query_account_factory()?
     };
 
+    let (data, credential) = data_and_credential_of(ctx.contract, &tx);
+    let credential = credential.clone();
+
     // Deserialize the transaction metadata, if it's not already done.
     let metadata = if let Some(metadata) = maybe_metadata {
         metadata
     } else {
-        tx.data.deserialize_json()?
+        data.clone().deserialize_json()?
     };
 
     // Ensure the chain ID in metadata matches the context.
@@ -156,9 +174,12 @@ pub fn verify_nonce_and_signature(
         metadata.chain_id
     );
 
+    // The sender and the fee payer sign the same transaction, each with their
+    // own metadata.
     let sign_doc = SignDoc {
         gas_limit: tx.gas_limit,
-        sender: ctx.contract,
+        sender: tx.sender,
+        fee_payer: tx.fee_payer.map(|fee_payer| fee_payer.address),
         messages: tx.msgs,
         data: metadata.clone(),
     };
@@ -234,7 +255,7 @@ pub fn verify_nonce_and_signature(
                     signature,
                 },
                 session_credential,
-            ) = match tx.credential.deserialize_json::<Credential>()? {
+            ) = match credential.deserialize_json::<Credential>()? {
                 Credential::Session(c) => (c.authorization.clone(), Some(c)),
                 Credential::Standard(c) => (c, None),
             };
@@ -1,8 +1,14 @@
-use serde::{Deserialize, Serialize};
+use {
+    grug_db_disk::PruningStrategy,
+    serde::{Deserialize, Serialize},
+};
 
 #[derive(Serialize, Deserialize, Default)]
 pub struct Config {
     pub grug: GrugConfig,
+    // Optional, so that config files predating this section remain valid.
+    #[serde(default)]
+    pub db: DbConfig,
     pub indexer: IndexerConfig,
     pub httpd: HttpdConfig,
     pub metrics_httpd: HttpdConfig,
@@ -33,6 +39,11 @@ pub enum LogFormat {
 pub struct GrugConfig {
     pub wasm_cache_capacity: usize,
     pub query_gas_limit: u64,
+    /// For chains started before the gas costs became part of the chain
+    /// config: the block height at which to rewrite the stored config to
+    /// include them.
+    #[serde(default)]
+    pub gas_costs_upgrade_height: Option<u64>,
 }
 
 impl Default for GrugConfig {
@@ -40,6 +51,85 @@ impl Default for GrugConfig {
         Self {
             wasm_cache_capacity: 1000,
             query_gas_limit: 100_000_000,
+            gas_costs_upgrade_height: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DbConfig {
+    pub backend: DbBackend,
+    pub pruning: PruningConfig,
+    pub snapshot: SnapshotConfig,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DbBackend {
+    /// Keeps only the latest state. Doesn't support state sync snapshots.
+    #[default]
+    Lite,
+    /// Keeps historical states, and supports state sync snapshots.
+    Disk,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct PruningConfig {
+    /// Number of most recent versions to keep; zero means to keep all versions.
+    pub keep_recent: u64,
+    /// Additionally keep every this many versions; zero means to not keep any
+    /// beyond the most recent ones.
+    pub keep_every: u64,
+    /// Prune every this many versions.
+    pub interval: u64,
+}
+
+impl Default for PruningConfig {
+    fn default() -> Self {
+        Self {
+            keep_recent: 0,
+            keep_every: 0,
+            interval: 10,
+        }
+    }
+}
+
+impl PruningConfig {
+    pub fn strategy(&self) -> PruningStrategy {
+        if self.keep_recent == 0 {
+            PruningStrategy::Nothing
+        } else if self.keep_every == 0 {
+            PruningStrategy::KeepRecent {
+                keep_recent: self.keep_recent,
+                interval: self.interval,
+            }
+        } else {
+            PruningStrategy::KeepRecentAndEvery {
+                keep_recent: self.keep_recent,
+                keep_every: self.keep_every,
+                interval: self.interval,
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct SnapshotConfig {
+    /// Take a snapshot every this many blocks; zero means to not take snapshots.
+    pub interval: u64,
+    pub keep_recent: usize,
+    pub chunk_size: usize,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            interval: 0,
+            keep_recent: 2,
+            chunk_size: 10 * 1024 * 1024,
         }
     }
 }
@@ -1,4 +1,16 @@
-use {crate::home_directory::HomeDirectory, clap::Subcommand, colored::Colorize, std::fs};
+use {
+    crate::{
+        config::{Config, DbBackend},
+        home_directory::HomeDirectory,
+    },
+    anyhow::ensure,
+    clap::Subcommand,
+    colored::Colorize,
+    config_parser::parse_config,
+    grug_app::{Db, PrunableDb},
+    grug_db_disk::{DiskDb, PruningStrategy},
+    std::fs,
+};
 
 #[derive(Subcommand)]
 pub enum DbCmd {
@@ -8,6 +20,18 @@ pub enum DbCmd {
         #[arg(short, long)]
         yes: bool,
     },
+    /// Delete historical states, keeping only the most recent versions
+    ///
+    /// Only applicable to the `disk` backend. The node must not be running.
+    Prune {
+        /// Number of most recent versions to keep
+        #[arg(long)]
+        keep_recent: u64,
+
+        /// Skip confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
 }
 
 impl DbCmd {
@@ -33,6 +57,50 @@ impl DbCmd {
 
                 Ok(fs::remove_dir_all(data_dir)?)
             },
+            DbCmd::Prune { keep_recent, yes } => {
+                let cfg: Config = parse_config(dir.config_file())?;
+
+                ensure!(
+                    matches!(cfg.db.backend, DbBackend::Disk),
+                    "the lite DB backend keeps only the latest state, nothing to prune"
+                );
+
+                let db = DiskDb::open(&data_dir)?;
+
+                let Some(latest_version) = db.latest_version() else {
+                    println!("Database is empty, nothing to do.");
+                    return Ok(());
+                };
+
+                let strategy = PruningStrategy::KeepRecent {
+                    keep_recent,
+                    interval: 1,
+                };
+
+                let Some(up_to_version) = strategy.prune_up_to(latest_version, db.oldest_version())
+                else {
+                    println!("No version older than the {keep_recent} most recent, nothing to do.");
+                    return Ok(());
+                };
+
+                if !yes
+                    && !confirm(
+                        format!(
+                            "Confirm deleting versions older than {up_to_version}? This operation is irreversible."
+                        )
+                        .bold()
+                        .to_string(),
+                    )?
+                {
+                    return Ok(());
+                }
+
+                db.prune(up_to_version)?;
+
+                println!("Pruned versions older than {up_to_version}.");
+
+                Ok(())
+            },
         }
     }
 }
@@ -1,8 +1,10 @@
 use {
     crate::{config::Config, home_directory::HomeDirectory},
+    anyhow::{Context, ensure},
     clap::{Parser, Subcommand},
     colored_json::ToColoredJson,
     config_parser::parse_config,
+    grug_app::CONTRACT_NAMESPACE,
     grug_client::TendermintRpcClient,
     grug_types::{
         Addr, Binary, BlockClient, Bound, Denom, Hash, Hash256, JsonDeExt, JsonSerExt, Proof,
@@ -15,9 +17,15 @@ use {
 #[derive(Parser)]
 pub struct QueryCmd {
     /// The block height at which to perform queries [default: last finalized height]
-    #[arg(long)]
+    #[arg(long, global = true)]
     height: Option<u64>,
 
+    /// Whether to request Merkle proof; only supported for raw store queries
+    /// (`store` and `wasm-raw`), and by nodes running the `disk` DB backend
+    /// [default: false]
+    #[arg(long, global = true, default_value_t = false)]
+    prove: bool,
+
     #[command(subcommand)]
     subcmd: SubCmd,
 }
@@ -124,9 +132,6 @@ enum SubCmd {
     Store {
         /// Key in b64 encoding
         key: String,
-        /// Whether to request Merkle proof for raw store queries [default: false]
-        #[arg(long, default_value_t = false)]
-        prove: bool,
     },
 }
 
@@ -178,6 +183,14 @@ impl QueryCmd {
             SubCmd::WasmRaw { contract, key } => {
                 // We interpret the input raw key as Hex encoded
                 let key = Binary::from(hex::decode(key)?);
+
+                // Smart queries can't be Merkle proved, so if a proof is
+                // requested, query the key in the contract's substore directly.
+                if self.prove {
+                    let key = [CONTRACT_NAMESPACE, contract.as_ref(), key.as_ref()].concat();
+                    return query_store(&client, key.into(), self.height, true).await;
+                }
+
                 Query::wasm_raw(contract, key)
             },
             SubCmd::WasmScan {
@@ -215,11 +228,17 @@ impl QueryCmd {
                 let msg = msg.deserialize_json()?;
                 Query::WasmSmart(QueryWasmSmartRequest { contract, msg })
             },
-            SubCmd::Store { key, prove } => {
-                return query_store(&client, key, self.height, prove).await;
+            SubCmd::Store { key } => {
+                let key = Binary::from_str(&key)?;
+                return query_store(&client, key, self.height, self.prove).await;
             },
         };
 
+        ensure!(
+            !self.prove,
+            "Merkle proof is only supported for raw store queries (`store` and `wasm-raw`)"
+        );
+
         client
             .query_app(req, self.height)
             .await
@@ -245,23 +264,36 @@ async fn query_block(client: &TendermintRpcClient, height: Option<u64>) -> anyho
 #[derive(Serialize)]
 struct PrintableQueryStoreResponse {
     key: String,
+    height: u64,
     value: Option<String>,
     proof: Option<Proof>,
+    root_hash: Option<Hash256>,
 }
 
 async fn query_store(
     client: &TendermintRpcClient,
-    key_b64: String,
+    key: Binary,
     height: Option<u64>,
     prove: bool,
 ) -> anyhow::Result<()> {
-    let key = Binary::from_str(&key_b64)?;
-    let (value, proof) = client.query_store(key, height, prove).await?;
+    let res = client
+        .query_store(key.clone(), height, prove)
+        .await
+        .with_context(|| {
+            if prove {
+                "failed to query store; note that Merkle proofs are only served by nodes running \
+                 the `disk` DB backend"
+            } else {
+                "failed to query store"
+            }
+        })?;
 
     print_json_pretty(PrintableQueryStoreResponse {
-        key: key_b64,
-        value: value.map(hex::encode),
-        proof,
+        key: key.to_string(),
+        height: res.height,
+        value: res.value.map(hex::encode),
+        proof: res.proof,
+        root_hash: res.root_hash,
     })
 }
 
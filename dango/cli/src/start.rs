@@ -1,15 +1,19 @@
 use {
     crate::{
-        config::{Config, GrugConfig, HttpdConfig, TendermintConfig},
+        config::{Config, DbBackend, GrugConfig, HttpdConfig, TendermintConfig},
         home_directory::HomeDirectory,
     },
-    anyhow::anyhow,
+    anyhow::{anyhow, ensure},
     clap::Parser,
     config_parser::parse_config,
     dango_genesis::GenesisCodes,
     dango_proposal_preparer::ProposalPreparer,
-    grug_app::{App, Db, Indexer, NaiveProposalPreparer, NullIndexer},
+    grug_app::{
+        App, AppError, Db, Indexer, NaiveProposalPreparer, NullIndexer, SnapshotConfig,
+        UpgradeHandler,
+    },
     grug_client::TendermintRpcClient,
+    grug_db_disk::{DiskDb, DiskDbOptions},
     grug_db_disk_lite::DiskDbLite,
     grug_httpd::context::Context as HttpdContext,
     grug_types::{GIT_COMMIT, HashExt},
@@ -36,9 +40,49 @@ impl StartCmd {
         // Parse the config file.
         let cfg: Config = parse_config(app_dir.config_file())?;
 
-        // Open disk DB.
-        let db = DiskDbLite::open(app_dir.data_dir())?;
+        // Open disk DB of the chosen backend.
+        match cfg.db.backend {
+            DbBackend::Lite => {
+                ensure!(
+                    cfg.db.snapshot.interval == 0,
+                    "state sync snapshots require the `disk` DB backend"
+                );
+
+                let db = DiskDbLite::open(app_dir.data_dir())?;
 
+                self.run_with_db(app_dir, cfg, metrics_handler, db, None)
+                    .await
+            },
+            DbBackend::Disk => {
+                let db = DiskDb::open_with_opts(app_dir.data_dir(), DiskDbOptions {
+                    pruning: cfg.db.pruning.strategy(),
+                    ..Default::default()
+                })?;
+                let snapshot_cfg = (cfg.db.snapshot.interval > 0).then_some(SnapshotConfig {
+                    interval: cfg.db.snapshot.interval,
+                    keep_recent: cfg.db.snapshot.keep_recent,
+                    chunk_size: cfg.db.snapshot.chunk_size,
+                });
+
+                self.run_with_db(app_dir, cfg, metrics_handler, db, snapshot_cfg)
+                    .await
+            },
+        }
+    }
+
+    async fn run_with_db<DB>(
+        self,
+        app_dir: HomeDirectory,
+        cfg: Config,
+        metrics_handler: PrometheusHandle,
+        db: DB,
+        snapshot_cfg: Option<SnapshotConfig>,
+    ) -> anyhow::Result<()>
+    where
+        DB: Db + Clone + Send + Sync + 'static,
+        DB::Error: std::error::Error + Send + Sync + 'static,
+        AppError: From<DB::Error>,
+    {
         // Create Rust VM contract codes.
         let codes = HybridVm::genesis_codes();
 
@@ -62,15 +106,19 @@ impl StartCmd {
         ]);
 
         // Create the base app instance for HTTP server
-        let app = App::new(
+        let mut app = App::new(
             db.clone(),
             vm.clone(),
             NaiveProposalPreparer,
             NullIndexer,
             cfg.grug.query_gas_limit,
-            None, // currently there's no chain upgrade
+            None, // this instance only serves queries, so never performs upgrades
+            None, // nor takes snapshots
         );
 
+        // Allow balance overrides when simulating transactions.
+        app.balance_overrider = Some(dango_bank::issue);
+
         let sql_indexer = indexer_sql::IndexerBuilder::default()
             .with_keep_blocks(cfg.indexer.keep_blocks)
             .with_database_url(&cfg.indexer.database.url)
@@ -105,7 +153,14 @@ impl StartCmd {
                 tokio::try_join!(
                     Self::run_dango_httpd_server(&cfg.httpd, dango_httpd_context,),
                     Self::run_metrics_httpd_server(&cfg.metrics_httpd, metrics_handler),
-                    self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, hooked_indexer)
+                    self.run_with_indexer(
+                        cfg.grug,
+                        cfg.tendermint,
+                        db,
+                        vm,
+                        snapshot_cfg,
+                        hooked_indexer
+                    )
                 )?;
             },
             (true, true, false) => {
@@ -123,7 +178,14 @@ impl StartCmd {
 
                 tokio::try_join!(
                     Self::run_dango_httpd_server(&cfg.httpd, dango_httpd_context,),
-                    self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, hooked_indexer)
+                    self.run_with_indexer(
+                        cfg.grug,
+                        cfg.tendermint,
+                        db,
+                        vm,
+                        snapshot_cfg,
+                        hooked_indexer
+                    )
                 )?;
             },
             (true, false, true) => {
@@ -141,7 +203,14 @@ impl StartCmd {
 
                 tokio::try_join!(
                     Self::run_metrics_httpd_server(&cfg.metrics_httpd, metrics_handler),
-                    self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, hooked_indexer)
+                    self.run_with_indexer(
+                        cfg.grug,
+                        cfg.tendermint,
+                        db,
+                        vm,
+                        snapshot_cfg,
+                        hooked_indexer
+                    )
                 )?;
             },
             (true, false, false) => {
@@ -157,15 +226,29 @@ impl StartCmd {
                     )
                     .await?;
 
-                self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, hooked_indexer)
-                    .await?;
+                self.run_with_indexer(
+                    cfg.grug,
+                    cfg.tendermint,
+                    db,
+                    vm,
+                    snapshot_cfg,
+                    hooked_indexer,
+                )
+                .await?;
             },
             (false, true, false) => {
                 // No indexer, but HTTP server enabled (minimal mode), metrics disabled
                 let httpd_context = HttpdContext::new(Arc::new(app));
                 tokio::try_join!(
                     Self::run_minimal_httpd_server(&cfg.httpd, httpd_context),
-                    self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, NullIndexer)
+                    self.run_with_indexer(
+                        cfg.grug,
+                        cfg.tendermint,
+                        db,
+                        vm,
+                        snapshot_cfg,
+                        NullIndexer
+                    )
                 )?;
             },
             (false, true, true) => {
@@ -173,13 +256,20 @@ impl StartCmd {
                 let httpd_context = HttpdContext::new(Arc::new(app));
                 tokio::try_join!(
                     Self::run_minimal_httpd_server(&cfg.httpd, httpd_context),
-                    self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, NullIndexer),
+                    self.run_with_indexer(
+                        cfg.grug,
+                        cfg.tendermint,
+                        db,
+                        vm,
+                        snapshot_cfg,
+                        NullIndexer
+                    ),
                     Self::run_metrics_httpd_server(&cfg.metrics_httpd, metrics_handler)
                 )?;
             },
             (false, false, _) => {
                 // No indexer, no HTTP server
-                self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, NullIndexer)
+                self.run_with_indexer(cfg.grug, cfg.tendermint, db, vm, snapshot_cfg, NullIndexer)
                     .await?;
             },
         }
@@ -188,19 +278,24 @@ impl StartCmd {
     }
 
     /// Setup the hooked indexer with both SQL and Dango indexers, and prepare contexts for HTTP servers
-    async fn setup_indexer_stack(
+    async fn setup_indexer_stack<DB>(
         &self,
         cfg: &Config,
         sql_indexer: indexer_sql::Indexer,
         indexer_context: indexer_sql::context::Context,
         indexer_path: IndexerPath,
-        app: Arc<App<DiskDbLite, HybridVm, NaiveProposalPreparer, NullIndexer>>,
+        app: Arc<App<DB, HybridVm, NaiveProposalPreparer, NullIndexer>>,
         tendermint_rpc_addr: &str,
     ) -> anyhow::Result<(
         HookedIndexer,
         indexer_httpd::context::Context,
         dango_httpd::context::Context,
-    )> {
+    )>
+    where
+        DB: Db + Send + Sync + 'static,
+        DB::Error: std::error::Error + Send + Sync + 'static,
+        AppError: From<DB::Error>,
+    {
         let mut hooked_indexer = HookedIndexer::new();
 
         // Create a separate context for dango indexer (shares DB but has independent pubsub)
@@ -314,16 +409,19 @@ impl StartCmd {
             })
     }
 
-    async fn run_with_indexer<ID>(
+    async fn run_with_indexer<DB, ID>(
         self,
         grug_cfg: GrugConfig,
         tendermint_cfg: TendermintConfig,
-        db: DiskDbLite,
+        db: DB,
         vm: HybridVm,
+        snapshot_cfg: Option<SnapshotConfig>,
         indexer: ID,
     ) -> anyhow::Result<()>
     where
+        DB: Db + Send + Sync + 'static,
         ID: Indexer + Send + 'static,
+        AppError: From<DB::Error>,
     {
         let app = App::new(
             db,
@@ -331,7 +429,10 @@ impl StartCmd {
             ProposalPreparer::new(),
             indexer,
             grug_cfg.query_gas_limit,
-            None, // currently there's no chain upgrade
+            grug_cfg
+                .gas_costs_upgrade_height
+                .map(UpgradeHandler::add_gas_costs_to_config),
+            snapshot_cfg,
         );
 
         let (consensus, mempool, snapshot, info) = split::service(app, 1);
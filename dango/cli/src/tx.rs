@@ -9,11 +9,10 @@ use {
     config_parser::parse_config,
     dango_client::{SigningKey, SingleSigner},
     dango_types::config::AppConfig,
-    grug_app::GAS_COSTS,
     grug_client::TendermintRpcClient,
     grug_types::{
-        Addr, BroadcastClientExt, Coins, GasOption, Hash256, Json, JsonDeExt, Message, NonEmpty,
-        QueryClient, Signer,
+        Addr, BroadcastClientExt, Coins, DEFAULT_GAS_COSTS, GasOption, Hash256, Json, JsonDeExt,
+        Message, NonEmpty, QueryClient, Signer,
     },
     std::{fs::File, io::Read, path::PathBuf, str::FromStr},
 };
@@ -194,8 +193,10 @@ impl TxCmd {
                     scale: cfg.transactions.gas_adjustment,
                     // We always increase the simulated gas consumption by this
                     // amount, since signature verification is skipped during
-                    // simulation.
-                    flat_increase: GAS_COSTS.secp256k1_verify,
+                    // simulation. Use the default cost rather than querying the
+                    // chain config for every tx; a chain that charges more
+                    // should be accounted for by the gas adjustment.
+                    flat_increase: DEFAULT_GAS_COSTS.secp256k1_verify,
                 }
             };
 
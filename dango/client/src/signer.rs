@@ -135,12 +135,14 @@ impl Signer for SingleSigner<Defined<u32>> {
     ) -> StdResult<UnsignedTx> {
         Ok(UnsignedTx {
             sender: self.address,
+            fee_payer: None,
             msgs,
             data: Metadata {
                 username: self.username.clone(),
                 chain_id: chain_id.to_string(),
                 nonce: self.nonce.into_inner(),
                 expiry: None, // TODO
+                tip: None,
             }
             .to_json_value()?,
         })
@@ -160,11 +162,13 @@ impl Signer for SingleSigner<Defined<u32>> {
             chain_id: chain_id.to_string(),
             nonce,
             expiry: None, // TODO
+            tip: None,
         };
 
         let sign_doc = SignDoc {
             gas_limit,
             sender: self.address,
+            fee_payer: None,
             messages: msgs.clone(),
             data: metadata.clone(),
         };
@@ -177,6 +181,7 @@ impl Signer for SingleSigner<Defined<u32>> {
 
         Ok(Tx {
             sender: self.address,
+            fee_payer: None,
             gas_limit,
             msgs,
             data: metadata.to_json_value()?,
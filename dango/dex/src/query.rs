@@ -9,9 +9,9 @@ use {
         DangoQuerier,
         account_factory::Username,
         dex::{
-            Direction, LiquidityDepth, LiquidityDepthResponse, OrderId, OrderResponse,
-            OrdersByPairResponse, OrdersByUserResponse, PairId, PairParams, PairUpdate, QueryMsg,
-            ReflectCurveResponse, ReservesResponse, RestingOrderBookState,
+            Direction, LiquidityDepth, LiquidityDepthResponse, OrderCursor, OrderId, OrderResponse,
+            OrdersByPairResponse, OrdersByUserResponse, OrdersPage, PairId, PairParams, PairUpdate,
+            QueryMsg, ReflectCurveResponse, ReservesResponse, RestingOrderBookState,
             RestingOrderBookStatesResponse, SwapRoute,
         },
     },
@@ -339,15 +339,16 @@ fn query_orders(
     ctx: ImmutableCtx,
     start_after: Option<OrderId>,
     limit: Option<u32>,
-) -> StdResult<BTreeMap<OrderId, OrderResponse>> {
+) -> StdResult<OrdersPage<OrderId, OrderResponse>> {
     let start = start_after.map(Bound::Exclusive);
     let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT) as usize;
 
-    ORDERS
+    // Read one order more than the limit, to tell whether there's a next page.
+    let mut orders = ORDERS
         .idx
         .order_id
         .range(ctx.storage, start, None, IterationOrder::Ascending)
-        .take(limit)
+        .take(limit.saturating_add(1))
         .map(|res| {
             let (order_id, ((base_denom, quote_denom), direction, price, _), order) = res?;
             Ok((order_id, OrderResponse {
@@ -360,74 +361,109 @@ fn query_orders(
                 remaining: order.remaining,
             }))
         })
-        .collect()
+        .collect::<StdResult<BTreeMap<_, _>>>()?;
+
+    let next = if orders.len() > limit {
+        orders.pop_last();
+        orders.last_key_value().map(|(order_id, _)| *order_id)
+    } else {
+        None
+    };
+
+    Ok(OrdersPage { orders, next })
 }
 
 fn query_orders_by_pair(
     ctx: ImmutableCtx,
     base_denom: Denom,
     quote_denom: Denom,
-    start_after: Option<OrderId>,
+    start_after: Option<OrderCursor>,
     limit: Option<u32>,
-) -> StdResult<BTreeMap<OrderId, OrdersByPairResponse>> {
-    let start = start_after
-        .map(|order_id| -> StdResult<_> {
-            let ((_, direction, price, _), _) = ORDERS.idx.order_id.load(ctx.storage, order_id)?;
-            Ok(Bound::Exclusive((direction, price, order_id)))
-        })
-        .transpose()?;
-    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT) as usize;
-
-    ORDERS
-        .prefix((base_denom, quote_denom))
-        .range(ctx.storage, start, None, IterationOrder::Ascending)
-        .take(limit)
-        .map(|res| {
-            let ((direction, price, order_id), order) = res?;
-            Ok((order_id, OrdersByPairResponse {
+) -> StdResult<OrdersPage<OrderCursor, OrdersByPairResponse>> {
+    let start_after = start_after.map(|cursor| (cursor.direction, cursor.price, cursor.order_id));
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+
+    let page = ORDERS
+        .prefix((base_denom.clone(), quote_denom.clone()))
+        .paginate(ctx.storage, start_after, limit, IterationOrder::Ascending)?;
+
+    let orders = page
+        .records
+        .into_iter()
+        .map(|((direction, price, order_id), order)| {
+            (order_id, OrdersByPairResponse {
                 user: order.user,
                 direction,
                 price,
                 amount: order.amount,
                 remaining: order.remaining,
-            }))
+            })
         })
-        .collect()
+        .collect();
+
+    let next = page.next.map(|(direction, price, order_id)| OrderCursor {
+        base_denom,
+        quote_denom,
+        direction,
+        price,
+        order_id,
+    });
+
+    Ok(OrdersPage { orders, next })
 }
 
 fn query_orders_by_user(
     ctx: ImmutableCtx,
     user: Addr,
-    start_after: Option<OrderId>,
+    start_after: Option<OrderCursor>,
     limit: Option<u32>,
-) -> StdResult<BTreeMap<OrderId, OrdersByUserResponse>> {
-    let start = start_after
-        .map(|order_id| -> StdResult<_> {
-            let ((pair, direction, price, _), _) =
-                ORDERS.idx.order_id.load(ctx.storage, order_id)?;
-            Ok(Bound::Exclusive((pair, direction, price, order_id)))
-        })
-        .transpose()?;
-    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT) as usize;
+) -> StdResult<OrdersPage<OrderCursor, OrdersByUserResponse>> {
+    let start_after = start_after.map(|cursor| {
+        (
+            (cursor.base_denom, cursor.quote_denom),
+            cursor.direction,
+            cursor.price,
+            cursor.order_id,
+        )
+    });
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+
+    let page = ORDERS.idx.user.prefix(user).paginate(
+        ctx.storage,
+        start_after,
+        limit,
+        IterationOrder::Ascending,
+    )?;
+
+    let orders = page
+        .records
+        .into_iter()
+        .map(
+            |(((base_denom, quote_denom), direction, price, order_id), order)| {
+                (order_id, OrdersByUserResponse {
+                    base_denom,
+                    quote_denom,
+                    direction,
+                    price,
+                    amount: order.amount,
+                    remaining: order.remaining,
+                })
+            },
+        )
+        .collect();
 
-    ORDERS
-        .idx
-        .user
-        .prefix(user)
-        .range(ctx.storage, start, None, IterationOrder::Ascending)
-        .take(limit)
-        .map(|res| {
-            let (((base_denom, quote_denom), direction, price, order_id), order) = res?;
-            Ok((order_id, OrdersByUserResponse {
+    let next =
+        page.next.map(
+            |((base_denom, quote_denom), direction, price, order_id)| OrderCursor {
                 base_denom,
                 quote_denom,
                 direction,
                 price,
-                amount: order.amount,
-                remaining: order.remaining,
-            }))
-        })
-        .collect()
+                order_id,
+            },
+        );
+
+    Ok(OrdersPage { orders, next })
 }
 
 #[inline]
@@ -298,6 +298,8 @@ where
             instantiate: Permission::Somebodies(btree_set! { account_factory }),
         },
         max_orphan_age: opt.grug.max_orphan_age,
+        gas_costs: Box::new(opt.grug.gas_costs),
+        end_block_limits: opt.grug.end_block_limits,
     };
 
     let app_config = AppConfig {
@@ -96,6 +96,7 @@ impl GenesisCodes for RustVm {
 
         let taxman = ContractBuilder::new(Box::new(dango_taxman::instantiate))
             .with_execute(Box::new(dango_taxman::execute))
+            .with_migrate(Box::new(dango_taxman::migrate))
             .with_query(Box::new(dango_taxman::query))
             .with_withhold_fee(Box::new(dango_taxman::withhold_fee))
             .with_finalize_fee(Box::new(dango_taxman::finalize_fee))
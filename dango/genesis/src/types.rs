@@ -10,7 +10,7 @@ use {
         oracle::PriceSource,
         taxman,
     },
-    grug::{Addr, Coin, Coins, Denom, Duration, Hash256, Uint128},
+    grug::{Addr, Coin, Coins, Denom, Duration, EndBlockLimits, GasCosts, Hash256, Uint128},
     hyperlane_types::{isms::multisig::ValidatorSet, mailbox::Domain},
     pyth_types::{GuardianSet, GuardianSetIndex},
     std::collections::{BTreeMap, BTreeSet},
@@ -78,6 +78,12 @@ pub struct GrugOption {
     /// contract).
     /// Once this time is elapsed, the code is deleted and must be uploaded again.
     pub max_orphan_age: Duration,
+    /// Gas costs of host operations, such as storage access and signature
+    /// verification.
+    pub gas_costs: GasCosts,
+    /// Limits on the work performed at the end of each block on behalf of
+    /// contracts, such as performing scheduled messages.
+    pub end_block_limits: EndBlockLimits,
 }
 
 pub struct AccountOption {
@@ -2,6 +2,7 @@ use {
     account::AccountQuery,
     async_graphql::MergedObject,
     dango_indexer_clickhouse::httpd::graphql::query::{candle::CandleQuery, trade::TradeQuery},
+    fee::FeeQuery,
     grug_httpd::graphql::query::grug::GrugQuery,
     indexer_httpd::graphql::query::{
         block::BlockQuery, event::EventQuery, message::MessageQuery, transaction::TransactionQuery,
@@ -11,6 +12,7 @@ use {
 };
 
 pub mod account;
+pub mod fee;
 pub mod transfer;
 pub mod user;
 
@@ -26,4 +28,5 @@ pub struct Query(
     UserQuery,
     CandleQuery,
     TradeQuery,
+    FeeQuery,
 );
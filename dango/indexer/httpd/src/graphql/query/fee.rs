@@ -0,0 +1,62 @@
+use {
+    async_graphql::*,
+    dango_types::taxman,
+    grug::{JsonDeExt, Query, Udec128},
+    grug_httpd::{context::Context, graphql::query::grug::GrugQuery},
+};
+
+#[derive(SimpleObject)]
+pub struct BaseFee {
+    /// Height of the block at which the base fee was queried.
+    block_height: u64,
+    /// Denomination of the token that gas fees are paid in.
+    fee_denom: String,
+    /// Units of the fee token for each unit of gas, that transactions included
+    /// in the next block are charged, excluding tips.
+    base_fee: String,
+}
+
+#[derive(Default, Debug)]
+pub struct FeeQuery {}
+
+#[Object]
+impl FeeQuery {
+    /// Query the base fee of the next block, for estimating the fees of
+    /// transactions.
+    async fn base_fee(&self, ctx: &async_graphql::Context<'_>) -> Result<BaseFee> {
+        let app_ctx = ctx.data::<Context>()?;
+
+        // Make all queries at the same height, in case a block is finalized in
+        // between.
+        let block_height = app_ctx.grug_app.last_finalized_block().await?.height;
+
+        let taxman_addr = GrugQuery::_query_app(app_ctx, Query::config(), Some(block_height))
+            .await?
+            .as_config()
+            .taxman;
+
+        let fee_cfg: taxman::Config = GrugQuery::_query_app(
+            app_ctx,
+            Query::wasm_smart(taxman_addr, &taxman::QueryMsg::Config {})?,
+            Some(block_height),
+        )
+        .await?
+        .as_wasm_smart()
+        .deserialize_json()?;
+
+        let base_fee: Udec128 = GrugQuery::_query_app(
+            app_ctx,
+            Query::wasm_smart(taxman_addr, &taxman::QueryMsg::BaseFee {})?,
+            Some(block_height),
+        )
+        .await?
+        .as_wasm_smart()
+        .deserialize_json()?;
+
+        Ok(BaseFee {
+            block_height,
+            fee_denom: fee_cfg.fee_denom.to_string(),
+            base_fee: base_fee.to_string(),
+        })
+    }
+}
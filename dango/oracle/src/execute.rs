@@ -1,5 +1,5 @@
 use {
-    crate::{PRICE_SOURCES, PRICES, state::GUARDIAN_SETS},
+    crate::{AGGREGATED_PRICES, PRICE_SOURCES, PRICES, state::GUARDIAN_SETS},
     anyhow::{bail, ensure},
     dango_types::oracle::{ExecuteMsg, InstantiateMsg, PrecisionlessPrice, PriceSource},
     grug::{
@@ -28,10 +28,10 @@ pub fn instantiate(ctx: MutableCtx, msg: InstantiateMsg) -> anyhow::Result<Respo
 /// - Auth mode must be `Finalize`. This ensures such transactions are only
 ///   inserted by the block proposer during ABCI++ `PrepareProposal`, not by
 ///   regular users.
-/// - The transaction contains exactly one message.
-/// - This one message is an `Execute`.
+/// - The transaction contains one or two messages.
+/// - Each message is an `Execute`.
 /// - The contract being executed must be the oracle itself.
-/// - the execute message must be `FeedPrices`.
+/// - the execute message must be `FeedPrices` or `FeedAggregatedPrices`.
 #[cfg_attr(not(feature = "library"), grug::export)]
 pub fn authenticate(ctx: AuthCtx, tx: Tx) -> anyhow::Result<AuthResponse> {
     // Authenticate can only be called during finalize.
@@ -40,22 +40,30 @@ pub fn authenticate(ctx: AuthCtx, tx: Tx) -> anyhow::Result<AuthResponse> {
         "you don't have the right, O you don't have the right"
     );
 
-    let mut msgs = tx.msgs.iter();
-
-    // Assert the transaction contains exactly 1 MsgExecute.
-    let (Some(Message::Execute(MsgExecute { contract, msg, .. })), None) =
-        (msgs.next(), msgs.next())
-    else {
-        bail!("transaction must contain exactly one message");
-    };
-
-    // Assert the contract is the oracle.
-    ensure!(contract == ctx.contract, "contract must be the oracle");
+    // Assert the transaction contains one or two messages: the Pyth VAAs, and
+    // the prices aggregated from vote extensions.
+    ensure!(
+        tx.msgs.len() <= 2,
+        "transaction must contain no more than two messages"
+    );
 
-    // Assert the message is `ExecuteMsg::FeedPrices`.
-    let Ok(ExecuteMsg::FeedPrices(..)) = msg.clone().deserialize_json() else {
-        bail!("the execute message must be feed prices");
-    };
+    for msg in tx.msgs.iter() {
+        // Assert the message is a `MsgExecute`.
+        let Message::Execute(MsgExecute { contract, msg, .. }) = msg else {
+            bail!("message must be an execute");
+        };
+
+        // Assert the contract is the oracle.
+        ensure!(*contract == ctx.contract, "contract must be the oracle");
+
+        // Assert the message is `ExecuteMsg::FeedPrices` or
+        // `ExecuteMsg::FeedAggregatedPrices`.
+        let Ok(ExecuteMsg::FeedPrices(..) | ExecuteMsg::FeedAggregatedPrices(..)) =
+            msg.clone().deserialize_json()
+        else {
+            bail!("the execute message must be feed prices or feed aggregated prices");
+        };
+    }
 
     Ok(AuthResponse::new().request_backrun(false))
 }
@@ -67,6 +75,9 @@ pub fn execute(ctx: MutableCtx, msg: ExecuteMsg) -> anyhow::Result<Response> {
             register_price_sources(ctx, price_sources)
         },
         ExecuteMsg::FeedPrices(vaas) => feed_prices(ctx, vaas.into_inner()),
+        ExecuteMsg::FeedAggregatedPrices(prices) => {
+            feed_aggregated_prices(ctx, prices.into_inner())
+        },
     }
 }
 
@@ -133,3 +144,33 @@ fn feed_prices(ctx: MutableCtx, vaas: Vec<Binary>) -> anyhow::Result<Response> {
 
     Ok(Response::new())
 }
+
+fn feed_aggregated_prices(
+    ctx: MutableCtx,
+    prices: BTreeMap<PythId, PrecisionlessPrice>,
+) -> anyhow::Result<Response> {
+    // Unlike VAAs, aggregated prices can't be verified by the contract. As such,
+    // only accept them from the oracle itself, which per `authenticate` means
+    // they are inserted by the block proposer. The proposer includes the signed
+    // vote extensions they are aggregated from, which every validator verifies
+    // in `ProcessProposal` before accepting the block.
+    ensure!(
+        ctx.sender == ctx.contract,
+        "only the oracle itself can feed aggregated prices"
+    );
+
+    for (id, new_price) in prices {
+        // Save the price if there isn't already a price saved, or if the new
+        // price is more recent than the existing one.
+        AGGREGATED_PRICES.may_update(ctx.storage, id, |current_price| -> anyhow::Result<_> {
+            match current_price {
+                Some(current_price) if current_price.timestamp >= new_price.timestamp => {
+                    Ok(current_price)
+                },
+                _ => Ok(new_price),
+            }
+        })?;
+    }
+
+    Ok(Response::new())
+}
@@ -1,5 +1,5 @@
 use {
-    crate::{PRICE_SOURCES, PRICES},
+    crate::{AGGREGATED_PRICES, PRICE_SOURCES, PRICES},
     anyhow::{anyhow, ensure},
     dango_types::{
         DangoQuerier,
@@ -116,6 +116,10 @@ impl<'a> OracleQuerierNoCache<'a> {
                 let (price, _) = self.ctx.get_price(id)?;
                 Ok(price.with_precision(precision))
             },
+            PriceSource::PythAggregate { id, precision } => {
+                let price = self.ctx.get_aggregated_price(id)?;
+                Ok(price.with_precision(precision))
+            },
             PriceSource::LendingLiquidity => {
                 // Get the underlying denom.
                 let underlying_denom =
@@ -168,6 +172,17 @@ impl OracleContext<'_> {
         }
     }
 
+    fn get_aggregated_price(&self, pyth_id: PythId) -> StdResult<PrecisionlessPrice> {
+        match self {
+            OracleContext::Local { storage } => {
+                AGGREGATED_PRICES.load(*storage, pyth_id)
+            },
+            OracleContext::Remote { address, querier } => {
+                querier.query_wasm_path(*address, &AGGREGATED_PRICES.path(pyth_id))
+            },
+        }
+    }
+
     fn get_price_source(&self, denom: &Denom) -> StdResult<PriceSource> {
         match self {
             OracleContext::Local { storage } => {
@@ -11,3 +11,7 @@ pub const PRICE_SOURCES: Map<&Denom, PriceSource> = Map::new("price_source");
 /// Map from PythId to (price, sequence). The sequence is used on update
 /// to ensure that the price is more recent.
 pub const PRICES: Map<PythId, (PrecisionlessPrice, u64)> = Map::new("price");
+
+/// Map from PythId to the median price observed by the validators, as submitted
+/// by the block proposer via `ExecuteMsg::FeedAggregatedPrices`.
+pub const AGGREGATED_PRICES: Map<PythId, PrecisionlessPrice> = Map::new("aggregated_price");
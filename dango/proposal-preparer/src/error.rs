@@ -0,0 +1,46 @@
+use {
+    grug::{Hash160, StdError},
+    grug_app::AppError,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum ProposalPreparerError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("oracle tx must be the first tx in the proposal, found at index {index}")]
+    OracleTxNotFirst { index: usize },
+
+    #[error("duplicate oracle tx found at index {index}")]
+    DuplicateOracleTx { index: usize },
+
+    #[error("oracle tx contains duplicate VAAs")]
+    DuplicateVaas,
+
+    #[error("oracle tx is malformed")]
+    MalformedOracleTx,
+
+    #[error("vote extension is malformed: {reason}")]
+    MalformedVoteExtension { reason: String },
+
+    #[error("oracle tx contains more than one vote extension from validator {validator}")]
+    DuplicateVoteExtension { validator: Hash160 },
+
+    #[error("vote extension from validator {validator} has an invalid signature or voting power")]
+    InvalidVoteExtension { validator: Hash160 },
+
+    #[error(
+        "vote extensions in oracle tx have {power} of {total} voting power; more than 2/3 is required"
+    )]
+    InsufficientVotingPower { power: u64, total: u64 },
+
+    #[error("aggregated prices in oracle tx don't match its vote extensions")]
+    AggregatedPricesMismatch,
+}
+
+impl From<ProposalPreparerError> for AppError {
+    fn from(err: ProposalPreparerError) -> Self {
+        AppError::PrepareProposal(err.to_string())
+    }
+}
@@ -1,4 +1,6 @@
+mod error;
 mod proposal_preparer;
 mod pyth_handler;
+mod vote_extension;
 
-pub use {proposal_preparer::*, pyth_handler::*};
+pub use {error::*, proposal_preparer::*, pyth_handler::*, vote_extension::*};
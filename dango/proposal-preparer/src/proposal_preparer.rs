@@ -1,11 +1,18 @@
 use {
-    crate::pyth_handler::PythHandler,
-    dango_types::{config::AppConfig, oracle::ExecuteMsg},
-    grug::{Coins, Json, JsonSerExt, Message, NonEmpty, QuerierExt, QuerierWrapper, StdError, Tx},
+    crate::{
+        ObservedPrices, ProposalPreparerError, SignedVoteExtension, aggregate_prices,
+        decode_prices, observe_prices, pyth_handler::PythHandler,
+    },
+    dango_types::{config::AppConfig, oracle::ExecuteMsg, taxman::tip_of},
+    grug::{
+        Addr, Binary, BorshSerExt, Coins, Hash160, Inner, Json, JsonDeExt, JsonSerExt, Message,
+        MsgExecute, NonEmpty, QuerierExt, QuerierWrapper, StdResult, Tx,
+    },
+    grug_app::{ProcessProposalOutcome, VoteExtension, VoteExtensionVerifier},
     prost::bytes::Bytes,
     pyth_client::{PythClient, PythClientCache, PythClientTrait},
     pyth_types::constants::PYTH_URL,
-    std::{fmt::Debug, sync::Mutex},
+    std::{cmp::Reverse, collections::BTreeSet, fmt::Debug, sync::Mutex},
     tracing::error,
 };
 #[cfg(feature = "metrics")]
@@ -64,12 +71,13 @@ where
     P: PythClientTrait + Send + 'static,
     P::Error: Debug,
 {
-    type Error = StdError;
+    type Error = ProposalPreparerError;
 
     fn prepare_proposal(
         &self,
         querier: QuerierWrapper,
         mut txs: Vec<Bytes>,
+        vote_extensions: &[VoteExtension],
         _max_tx_bytes: usize,
     ) -> Result<Vec<Bytes>, Self::Error> {
         #[cfg(feature = "metrics")]
@@ -77,6 +85,14 @@ where
 
         let cfg: AppConfig = querier.query_app_config()?;
 
+        // Order the txs by the tips their fee payers are willing to pay, from
+        // the highest to the lowest. Txs with the same tip are kept in the
+        // order they arrived in the mempool. Txs that fail to deserialize are
+        // put last; they are rejected during `FinalizeBlock` anyways.
+        txs.sort_by_cached_key(|raw_tx| {
+            Reverse(raw_tx.deserialize_json::<Tx>().ok().map(|tx| tip_of(&tx)))
+        });
+
         // Should we find a way to start and connect the PythClientPPHandler at startup?
         // How to know which ids should be used?
         let mut pyth_handler = self.pyth_handler.as_ref().unwrap().lock().unwrap();
@@ -89,22 +105,24 @@ where
         // Retrieve the VAAs.
         let vaas = pyth_handler.fetch_latest_vaas();
 
-        // Return if there are no VAAs to feed.
-        if vaas.is_empty() {
-            return Ok(txs);
-        }
+        // Aggregate the prices observed by the validators in the previous block.
+        //
+        // The last commit includes every validator in the set, including those
+        // who didn't vote. Only the votes actually cast are signed, and can be
+        // verified by other validators.
+        let total_power = vote_extensions
+            .iter()
+            .fold(0, |acc: u64, vote| acc.saturating_add(vote.power));
+        let votes = vote_extensions
+            .iter()
+            .filter(|vote| !vote.signature.is_empty())
+            .cloned()
+            .collect::<Vec<_>>();
+        let aggregated = aggregate_prices(&votes, total_power);
 
-        // Build the tx.
-        let tx = Tx {
-            sender: cfg.addresses.oracle,
-            gas_limit: GAS_LIMIT,
-            msgs: NonEmpty::new_unchecked(vec![Message::execute(
-                cfg.addresses.oracle,
-                &ExecuteMsg::FeedPrices(NonEmpty::new(vaas)?),
-                Coins::new(),
-            )?]),
-            data: Json::null(),
-            credential: Json::null(),
+        // Build the tx. Return if there's nothing to feed.
+        let Some(tx) = oracle_tx(cfg.addresses.oracle, vaas, aggregated, &votes)? else {
+            return Ok(txs);
         };
 
         txs.insert(0, tx.to_json_vec()?.into());
@@ -115,6 +133,264 @@ where
 
         Ok(txs)
     }
+
+    fn process_proposal(
+        &self,
+        querier: QuerierWrapper,
+        txs: &[Bytes],
+        verifier: &VoteExtensionVerifier,
+    ) -> Result<ProcessProposalOutcome, Self::Error> {
+        // Failing to query is a local failure, which doesn't make the proposal
+        // invalid, so it's returned as an error rather than a rejection.
+        let cfg: AppConfig = querier.query_app_config()?;
+
+        match validate_proposal(cfg.addresses.oracle, txs, verifier)? {
+            Ok(()) => Ok(ProcessProposalOutcome::Accept),
+            Err(err) => Ok(ProcessProposalOutcome::Reject {
+                reason: err.to_string(),
+            }),
+        }
+    }
+
+    fn extend_vote(&self, querier: QuerierWrapper, _height: u64) -> Result<Bytes, Self::Error> {
+        let cfg: AppConfig = querier.query_app_config()?;
+
+        let ids = PythHandler::<P>::pyth_aggregate_ids(querier, cfg.addresses.oracle)?;
+
+        // Don't extend the vote if no price source needs aggregation.
+        if ids.is_empty() {
+            return Ok(Bytes::new());
+        }
+
+        let mut pyth_handler = self.pyth_handler.as_ref().unwrap().lock().unwrap();
+
+        if let Err(err) = pyth_handler.update_stream(querier, cfg.addresses.oracle) {
+            error!("Failed to update Pyth stream: {:?}", err);
+        }
+
+        // Peek instead of fetch, so that the VAAs remain available to be fed
+        // in `prepare_proposal` if this validator is the next proposer.
+        let prices = observe_prices(pyth_handler.peek_latest_vaas(), &ids);
+
+        if prices.is_empty() {
+            return Ok(Bytes::new());
+        }
+
+        Ok(prices.to_borsh_vec()?.into())
+    }
+
+    fn verify_vote_extension(
+        &self,
+        _querier: QuerierWrapper,
+        data: &[u8],
+    ) -> Result<(), Self::Error> {
+        decode_prices(data).map_err(|err| ProposalPreparerError::MalformedVoteExtension {
+            reason: err.to_string(),
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Build the tx that feeds the given VAAs and aggregated prices to the oracle
+/// contract.
+///
+/// If there are aggregated prices, the vote extensions they are aggregated from
+/// are included as the tx's data, so that other validators can verify them.
+///
+/// Returns `None` if there is nothing to feed.
+fn oracle_tx(
+    oracle: Addr,
+    vaas: Vec<Binary>,
+    aggregated: ObservedPrices,
+    votes: &[VoteExtension],
+) -> StdResult<Option<Tx>> {
+    let mut msgs = Vec::with_capacity(2);
+    let mut data = Json::null();
+
+    if !vaas.is_empty() {
+        msgs.push(Message::execute(
+            oracle,
+            &ExecuteMsg::FeedPrices(NonEmpty::new_unchecked(vaas)),
+            Coins::new(),
+        )?);
+    }
+
+    if !aggregated.is_empty() {
+        data = votes
+            .iter()
+            .map(SignedVoteExtension::from)
+            .collect::<Vec<_>>()
+            .to_json_value()?;
+
+        msgs.push(Message::execute(
+            oracle,
+            &ExecuteMsg::FeedAggregatedPrices(NonEmpty::new_unchecked(aggregated)),
+            Coins::new(),
+        )?);
+    }
+
+    if msgs.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(Tx {
+        sender: oracle,
+        fee_payer: None,
+        gas_limit: GAS_LIMIT,
+        msgs: NonEmpty::new_unchecked(msgs),
+        data,
+        credential: Json::null(),
+    }))
+}
+
+/// Ensure the proposal contains at most one oracle tx, at the very beginning,
+/// and that it's valid.
+///
+/// The outer error indicates a local failure; the inner one, an invalid
+/// proposal.
+fn validate_proposal(
+    oracle: Addr,
+    txs: &[Bytes],
+    verifier: &VoteExtensionVerifier,
+) -> StdResult<Result<(), ProposalPreparerError>> {
+    let mut found = false;
+
+    for (index, raw_tx) in txs.iter().enumerate() {
+        // Txs that fail to deserialize are rejected during `FinalizeBlock`.
+        // Here we're only concerned with the oracle tx.
+        let Ok(tx) = raw_tx.deserialize_json::<Tx>() else {
+            continue;
+        };
+
+        if tx.sender != oracle {
+            continue;
+        }
+
+        // The oracle tx can only be inserted by the proposer, once, at the
+        // very beginning of the block.
+        if found {
+            return Ok(Err(ProposalPreparerError::DuplicateOracleTx { index }));
+        }
+
+        if index != 0 {
+            return Ok(Err(ProposalPreparerError::OracleTxNotFirst { index }));
+        }
+
+        if let Err(err) = validate_oracle_tx(oracle, tx, verifier)? {
+            return Ok(Err(err));
+        }
+
+        found = true;
+    }
+
+    Ok(Ok(()))
+}
+
+/// Ensure the oracle tx is exactly what an honest proposer would have built
+/// in `prepare_proposal`, and it doesn't feed the same VAA more than once.
+///
+/// The aggregated prices must be recomputed from the vote extensions included
+/// in the tx, which must be signed by validators with more than 2/3 of the
+/// voting power.
+fn validate_oracle_tx(
+    oracle: Addr,
+    tx: Tx,
+    verifier: &VoteExtensionVerifier,
+) -> StdResult<Result<(), ProposalPreparerError>> {
+    let mut vaas = Vec::new();
+    let mut aggregated = ObservedPrices::new();
+
+    for msg in tx.msgs.iter() {
+        let Message::Execute(MsgExecute { msg, .. }) = msg else {
+            return Ok(Err(ProposalPreparerError::MalformedOracleTx));
+        };
+
+        match msg.clone().deserialize_json::<ExecuteMsg>() {
+            // VAAs must come before aggregated prices, and each may appear at
+            // most once.
+            Ok(ExecuteMsg::FeedPrices(inner)) if vaas.is_empty() && aggregated.is_empty() => {
+                vaas = inner.into_inner();
+            },
+            Ok(ExecuteMsg::FeedAggregatedPrices(inner)) if aggregated.is_empty() => {
+                aggregated = inner.into_inner();
+            },
+            _ => return Ok(Err(ProposalPreparerError::MalformedOracleTx)),
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    if !vaas.iter().all(|vaa| seen.insert(vaa)) {
+        return Ok(Err(ProposalPreparerError::DuplicateVaas));
+    }
+
+    let Ok(votes) = tx
+        .data
+        .clone()
+        .deserialize_json::<Option<Vec<SignedVoteExtension>>>()
+    else {
+        return Ok(Err(ProposalPreparerError::MalformedOracleTx));
+    };
+
+    let votes = votes
+        .unwrap_or_default()
+        .into_iter()
+        .map(VoteExtension::from)
+        .collect::<Vec<_>>();
+
+    if let Err(err) = verify_vote_extensions(&votes, verifier) {
+        return Ok(Err(err));
+    }
+
+    if aggregate_prices(&votes, verifier.total_power()) != aggregated {
+        return Ok(Err(ProposalPreparerError::AggregatedPricesMismatch));
+    }
+
+    if Some(tx) != oracle_tx(oracle, vaas, aggregated, &votes)? {
+        return Ok(Err(ProposalPreparerError::MalformedOracleTx));
+    }
+
+    Ok(Ok(()))
+}
+
+/// Ensure the vote extensions included in the oracle tx are each signed by a
+/// different validator, who together have more than 2/3 of the voting power.
+fn verify_vote_extensions(
+    votes: &[VoteExtension],
+    verifier: &VoteExtensionVerifier,
+) -> Result<(), ProposalPreparerError> {
+    // No vote extensions, no aggregated prices, nothing to verify.
+    if votes.is_empty() {
+        return Ok(());
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut power: u128 = 0;
+
+    for vote in votes {
+        let validator = Hash160::from_inner(vote.validator);
+
+        if !seen.insert(vote.validator) {
+            return Err(ProposalPreparerError::DuplicateVoteExtension { validator });
+        }
+
+        if !verifier.verify(vote) {
+            return Err(ProposalPreparerError::InvalidVoteExtension { validator });
+        }
+
+        power += vote.power as u128;
+    }
+
+    let total = verifier.total_power();
+
+    if power * 3 <= total as u128 * 2 {
+        return Err(ProposalPreparerError::InsufficientVotingPower {
+            power: power as u64,
+            total,
+        });
+    }
+
+    Ok(())
 }
 
 #[cfg(feature = "metrics")]
@@ -5,6 +5,7 @@ use {
     pyth_types::PythId,
     reqwest::IntoUrl,
     std::{
+        collections::BTreeSet,
         fmt::Debug,
         sync::{
             Arc,
@@ -22,6 +23,9 @@ use {
 pub struct PythHandler<P> {
     client: P,
     shared_vaas: Shared<Vec<Binary>>,
+    /// The most recently received VAAs. Unlike `shared_vaas`, these are not
+    /// consumed when fetched, and are used to extend votes.
+    latest_vaas: Shared<Vec<Binary>>,
     current_ids: Vec<PythId>,
     stoppable_thread: Option<(Arc<AtomicBool>, thread::JoinHandle<()>)>,
 }
@@ -33,6 +37,7 @@ impl PythHandler<PythClient> {
         Self {
             client: PythClient::new(base_url).unwrap(),
             shared_vaas,
+            latest_vaas: Shared::new(vec![]),
             current_ids: vec![],
             stoppable_thread: None,
         }
@@ -49,6 +54,7 @@ impl PythHandler<PythClientCache> {
         Self {
             client: PythClientCache::new(base_url).unwrap(),
             shared_vaas,
+            latest_vaas: Shared::new(vec![]),
             current_ids: vec![],
             stoppable_thread: None,
         }
@@ -65,6 +71,11 @@ where
         self.shared_vaas.replace(vec![])
     }
 
+    /// Return the most recently received VAAs, without consuming them.
+    pub fn peek_latest_vaas(&self) -> Vec<Binary> {
+        self.latest_vaas.read_access().clone()
+    }
+
     pub fn close_stream(&mut self) {
         if let Some((keep_running, _handle)) = self.stoppable_thread.take() {
             keep_running.store(false, Ordering::SeqCst);
@@ -75,25 +86,48 @@ where
     }
 
     //  TODO: optimize this by using the raw WasmScan query.
-    /// Retrieve the Pyth ids from the Oracle contract.
+    /// Retrieve the Pyth ids from the Oracle contract, including those whose
+    /// prices are aggregated from vote extensions.
     pub fn pyth_ids(querier: QuerierWrapper, oracle: Addr) -> StdResult<Vec<PythId>> {
-        let new_ids = querier
-            .query_wasm_smart(oracle, QueryPriceSourcesRequest {
-                start_after: None,
-                limit: Some(u32::MAX),
-            })?
-            .into_values()
-            .filter_map(|price_source| {
-                if let PriceSource::Pyth { id, .. } = price_source {
-                    Some(id)
-                } else {
-                    None
-                }
+        let new_ids = Self::price_sources(querier, oracle)?
+            .filter_map(|price_source| match price_source {
+                PriceSource::Pyth { id, .. } | PriceSource::PythAggregate { id, .. } => Some(id),
+                _ => None,
             })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
             .collect::<Vec<_>>();
 
         Ok(new_ids)
     }
+
+    /// Retrieve the Pyth ids whose prices are aggregated from vote extensions
+    /// from the Oracle contract.
+    pub fn pyth_aggregate_ids(
+        querier: QuerierWrapper,
+        oracle: Addr,
+    ) -> StdResult<BTreeSet<PythId>> {
+        let ids = Self::price_sources(querier, oracle)?
+            .filter_map(|price_source| match price_source {
+                PriceSource::PythAggregate { id, .. } => Some(id),
+                _ => None,
+            })
+            .collect();
+
+        Ok(ids)
+    }
+
+    fn price_sources(
+        querier: QuerierWrapper,
+        oracle: Addr,
+    ) -> StdResult<impl Iterator<Item = PriceSource>> {
+        Ok(querier
+            .query_wasm_smart(oracle, QueryPriceSourcesRequest {
+                start_after: None,
+                limit: Some(u32::MAX),
+            })?
+            .into_values())
+    }
 }
 
 impl<P> PythHandler<P>
@@ -109,6 +143,7 @@ where
         self.close_stream();
 
         let shared_vaas = self.shared_vaas.clone();
+        let latest_vaas = self.latest_vaas.clone();
         let keep_running = Arc::new(AtomicBool::new(true));
         let mut client = self.client.clone();
 
@@ -148,6 +183,7 @@ where
                                 }
 
                                 if let Some(data) = data {
+                                    latest_vaas.write_with(|mut latest_vaas| *latest_vaas = data.clone());
                                     shared_vaas.write_with(|mut shared_vaas| *shared_vaas = data);
                                 }
                             }
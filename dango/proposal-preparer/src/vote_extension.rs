@@ -0,0 +1,274 @@
+use {
+    dango_types::oracle::PrecisionlessPrice,
+    grug::{Binary, BorshDeExt, Hash160, Inner, MockApi, StdResult},
+    grug_app::VoteExtension,
+    pyth_types::{PythId, PythVaa},
+    std::collections::{BTreeMap, BTreeSet},
+    tracing::error,
+};
+
+/// Prices observed by a validator, attached to its vote as a vote extension.
+pub type ObservedPrices = BTreeMap<PythId, PrecisionlessPrice>;
+
+/// A signed vote extension, included in the oracle tx as its `data`, so that
+/// other validators can verify the aggregated prices fed by the proposer.
+#[grug::derive(Serde)]
+pub struct SignedVoteExtension {
+    pub validator: Hash160,
+    pub power: u64,
+    pub data: Binary,
+    pub signature: Binary,
+}
+
+impl From<&VoteExtension> for SignedVoteExtension {
+    fn from(vote: &VoteExtension) -> Self {
+        Self {
+            validator: Hash160::from_inner(vote.validator),
+            power: vote.power,
+            data: vote.data.to_vec().into(),
+            signature: vote.signature.to_vec().into(),
+        }
+    }
+}
+
+impl From<SignedVoteExtension> for VoteExtension {
+    fn from(vote: SignedVoteExtension) -> Self {
+        Self {
+            validator: vote.validator.into_inner(),
+            power: vote.power,
+            data: vote.data.into_inner().into(),
+            signature: vote.signature.into_inner().into(),
+        }
+    }
+}
+
+/// Extract the prices of the given Pyth IDs from the VAAs.
+///
+/// The VAAs are not verified here. These are the prices this validator observes
+/// from its own connection to Pyth, and are only counted towards the median if
+/// enough other validators observe similar ones.
+pub fn observe_prices(vaas: Vec<Binary>, ids: &BTreeSet<PythId>) -> ObservedPrices {
+    let mut prices = ObservedPrices::new();
+
+    for vaa in vaas {
+        let feeds = match PythVaa::new(&MockApi, vaa.into_inner()) {
+            Ok(vaa) => vaa.unverified(),
+            Err(err) => {
+                error!(err = err.to_string(), "Failed to parse Pyth VAA");
+                continue;
+            },
+        };
+
+        for feed in feeds {
+            let id = PythId::from_inner(feed.id.to_bytes());
+            if !ids.contains(&id) {
+                continue;
+            }
+
+            let price = match PrecisionlessPrice::try_from(feed) {
+                Ok(price) => price,
+                Err(err) => {
+                    error!(err = err.to_string(), "Failed to parse Pyth price feed");
+                    continue;
+                },
+            };
+
+            // If there are multiple prices for the same ID, keep the latest.
+            match prices.get(&id) {
+                Some(existing) if existing.timestamp >= price.timestamp => {},
+                _ => {
+                    prices.insert(id, price);
+                },
+            }
+        }
+    }
+
+    prices
+}
+
+/// Decode the prices from a vote extension.
+///
+/// An empty extension, i.e. one from a validator who didn't observe any price,
+/// decodes to an empty map.
+pub fn decode_prices(data: &[u8]) -> StdResult<ObservedPrices> {
+    if data.is_empty() {
+        return Ok(ObservedPrices::new());
+    }
+
+    data.deserialize_borsh()
+}
+
+/// Aggregate the prices observed by the validators into their medians, weighted
+/// by voting power.
+///
+/// A price is only included if it's observed by validators with more than 2/3
+/// of the total voting power of the validator set, so that it can't be decided
+/// by faulty validators alone. Validators who didn't vote, or whose extensions
+/// are malformed, count towards the total but not towards any price.
+pub fn aggregate_prices(vote_extensions: &[VoteExtension], total_power: u64) -> ObservedPrices {
+    let total_power = total_power as u128;
+
+    let mut observations = BTreeMap::<PythId, Vec<(PrecisionlessPrice, u64)>>::new();
+
+    for vote in vote_extensions {
+        // Extensions have been verified by CometBFT before being provided to us,
+        // so this shouldn't fail. Just in case, the validator's power counts
+        // against the quorum of every price, as if it didn't vote.
+        let prices = match decode_prices(&vote.data) {
+            Ok(prices) => prices,
+            Err(err) => {
+                error!(
+                    validator = Hash160::from_inner(vote.validator).to_string(),
+                    err = err.to_string(),
+                    "Malformed vote extension"
+                );
+                continue;
+            },
+        };
+
+        for (id, price) in prices {
+            observations
+                .entry(id)
+                .or_default()
+                .push((price, vote.power));
+        }
+    }
+
+    observations
+        .into_iter()
+        .filter_map(|(id, observations)| {
+            let power = observations
+                .iter()
+                .map(|(_, power)| *power as u128)
+                .sum::<u128>();
+
+            if power * 3 <= total_power * 2 {
+                return None;
+            }
+
+            let price = PrecisionlessPrice::new(
+                weighted_median(&observations, |price| price.humanized_price),
+                weighted_median(&observations, |price| price.humanized_ema),
+                weighted_median(&observations, |price| price.timestamp),
+            );
+
+            Some((id, price))
+        })
+        .collect()
+}
+
+/// Find the weighted median of the values extracted from the observations.
+///
+/// Panics if `observations` is empty.
+fn weighted_median<T, F>(observations: &[(PrecisionlessPrice, u64)], extract: F) -> T
+where
+    T: Ord + Copy,
+    F: Fn(&PrecisionlessPrice) -> T,
+{
+    let mut values = observations
+        .iter()
+        .map(|(price, power)| (extract(price), *power as u128))
+        .collect::<Vec<_>>();
+
+    values.sort_unstable_by_key(|(value, _)| *value);
+
+    let total_power = values.iter().map(|(_, power)| power).sum::<u128>();
+    let mut cumulative_power = 0;
+
+    for (value, power) in &values {
+        cumulative_power += power;
+
+        if cumulative_power * 2 >= total_power {
+            return *value;
+        }
+    }
+
+    values.last().unwrap().0
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        grug::{BorshSerExt, Timestamp, Udec128},
+        pyth_types::constants::{BTC_USD_ID, ETH_USD_ID},
+    };
+
+    fn price(humanized_price: u128, timestamp: u128) -> PrecisionlessPrice {
+        PrecisionlessPrice::new(
+            Udec128::new(humanized_price),
+            Udec128::new(humanized_price),
+            Timestamp::from_seconds(timestamp),
+        )
+    }
+
+    fn vote(power: u64, prices: ObservedPrices) -> VoteExtension {
+        VoteExtension {
+            validator: [0; 20],
+            power,
+            data: if prices.is_empty() {
+                Default::default()
+            } else {
+                prices.to_borsh_vec().unwrap().into()
+            },
+            signature: Default::default(),
+        }
+    }
+
+    #[test]
+    fn aggregating_prices_works() {
+        let votes = [
+            vote(
+                1,
+                BTreeMap::from([(BTC_USD_ID, price(100, 10)), (ETH_USD_ID, price(5, 10))]),
+            ),
+            vote(1, BTreeMap::from([(BTC_USD_ID, price(101, 11))])),
+            vote(1, BTreeMap::from([(BTC_USD_ID, price(1_000_000, 12))])),
+            vote(2, BTreeMap::from([(BTC_USD_ID, price(102, 9))])),
+            // A validator who didn't observe any price.
+            vote(1, BTreeMap::new()),
+        ];
+
+        // BTC is observed by 5/6 of voting power. The outlier is discarded by
+        // the median.
+        //
+        // ETH is only observed by 1/6 of voting power, so it's not included.
+        assert_eq!(
+            aggregate_prices(&votes, 6),
+            BTreeMap::from([(BTC_USD_ID, price(102, 10))])
+        );
+
+        // Validators who didn't vote count towards the total: BTC is now only
+        // observed by 5/8 of voting power.
+        assert!(aggregate_prices(&votes, 8).is_empty());
+    }
+
+    #[test]
+    fn aggregating_prices_requires_supermajority() {
+        let votes = [
+            vote(2, BTreeMap::from([(BTC_USD_ID, price(100, 10))])),
+            vote(1, BTreeMap::new()),
+        ];
+
+        // Exactly 2/3 of voting power isn't enough.
+        assert!(aggregate_prices(&votes, 3).is_empty());
+    }
+
+    #[test]
+    fn malformed_extensions_count_against_quorum() {
+        let votes = [
+            vote(3, BTreeMap::from([(BTC_USD_ID, price(100, 10))])),
+            VoteExtension {
+                validator: [1; 20],
+                power: 2,
+                data: b"garbage".to_vec().into(),
+                signature: Default::default(),
+            },
+        ];
+
+        // Only 3/5 of voting power observes BTC.
+        assert!(aggregate_prices(&votes, 5).is_empty());
+    }
+}
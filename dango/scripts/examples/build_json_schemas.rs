@@ -0,0 +1,100 @@
+//! Write the JSON schemas of the instantiate, execute, and query messages of
+//! all Dango contracts, as well as the query responses, to the given directory,
+//! one file per contract.
+//!
+//! The TypeScript SDK and the indexer are generated from these files, and CI
+//! checks that they are up-to-date with the Rust types.
+
+use {
+    clap::Parser,
+    dango_types::{
+        account, account_factory, bank, dex, gateway, lending, oracle, taxman, vesting, warp,
+    },
+    grug::{ContractSchema, JsonSerExt},
+    hyperlane_types::{isms::multisig, mailbox, va},
+    std::{fs, path::PathBuf},
+};
+
+#[derive(Parser)]
+struct Cli {
+    /// Directory to write the schema files to
+    dir: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    let schemas = [
+        ContractSchema::new("account_factory")
+            .with_instantiate::<account_factory::InstantiateMsg>()
+            .with_execute::<account_factory::ExecuteMsg>()
+            .with_query::<account_factory::QueryMsg>(),
+        ContractSchema::new("account_margin")
+            .with_instantiate::<account::InstantiateMsg>()
+            .with_execute::<account::margin::ExecuteMsg>()
+            .with_query::<account::margin::QueryMsg>(),
+        ContractSchema::new("account_multi")
+            .with_instantiate::<account::InstantiateMsg>()
+            .with_execute::<account::multi::ExecuteMsg>()
+            .with_query::<account::multi::QueryMsg>(),
+        ContractSchema::new("account_spot")
+            .with_instantiate::<account::spot::InstantiateMsg>()
+            .with_query::<account::spot::QueryMsg>(),
+        ContractSchema::new("bank")
+            .with_instantiate::<bank::InstantiateMsg>()
+            .with_execute::<bank::ExecuteMsg>()
+            .with_query::<bank::QueryMsg>(),
+        ContractSchema::new("dex")
+            .with_instantiate::<dex::InstantiateMsg>()
+            .with_execute::<dex::ExecuteMsg>()
+            .with_query::<dex::QueryMsg>(),
+        ContractSchema::new("gateway")
+            .with_instantiate::<gateway::InstantiateMsg>()
+            .with_execute::<gateway::ExecuteMsg>()
+            .with_query::<gateway::QueryMsg>(),
+        ContractSchema::new("hyperlane_ism")
+            .with_instantiate::<multisig::InstantiateMsg>()
+            .with_execute::<multisig::ExecuteMsg>()
+            .with_query::<multisig::QueryMsg>(),
+        ContractSchema::new("hyperlane_mailbox")
+            .with_instantiate::<mailbox::InstantiateMsg>()
+            .with_execute::<mailbox::ExecuteMsg>()
+            .with_query::<mailbox::QueryMsg>(),
+        ContractSchema::new("hyperlane_va")
+            .with_instantiate::<va::InstantiateMsg>()
+            .with_execute::<va::ExecuteMsg>()
+            .with_query::<va::QueryMsg>(),
+        ContractSchema::new("lending")
+            .with_instantiate::<lending::InstantiateMsg>()
+            .with_execute::<lending::ExecuteMsg>()
+            .with_query::<lending::QueryMsg>(),
+        ContractSchema::new("oracle")
+            .with_instantiate::<oracle::InstantiateMsg>()
+            .with_execute::<oracle::ExecuteMsg>()
+            .with_query::<oracle::QueryMsg>(),
+        ContractSchema::new("taxman")
+            .with_instantiate::<taxman::InstantiateMsg>()
+            .with_execute::<taxman::ExecuteMsg>()
+            .with_query::<taxman::QueryMsg>(),
+        ContractSchema::new("vesting")
+            .with_instantiate::<vesting::InstantiateMsg>()
+            .with_execute::<vesting::ExecuteMsg>()
+            .with_query::<vesting::QueryMsg>(),
+        ContractSchema::new("warp")
+            .with_instantiate::<warp::InstantiateMsg>()
+            .with_execute::<warp::ExecuteMsg>()
+            .with_query::<warp::QueryMsg>(),
+    ];
+
+    fs::create_dir_all(&cli.dir)?;
+
+    for schema in schemas {
+        let path = cli.dir.join(format!("{}.json", schema.contract_name));
+
+        fs::write(&path, schema.to_json_string_pretty()? + "\n")?;
+
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
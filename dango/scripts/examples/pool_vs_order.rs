@@ -39,7 +39,8 @@ async fn main() -> anyhow::Result<()> {
                 },
                 None,
             )
-            .await?;
+            .await?
+            .orders;
 
         let (sum_base, sum_quote) = orders.values().fold(
             (Udec128_6::ZERO, Udec128_6::ZERO),
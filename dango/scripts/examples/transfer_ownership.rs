@@ -1,8 +1,10 @@
 use {
     dango_client::SingleSigner,
     dango_types::config::AppConfig,
-    grug::{Addr, BroadcastClientExt, ClientWrapper, GasOption, JsonSerExt, QueryClientExt, addr},
-    grug_app::GAS_COSTS,
+    grug::{
+        Addr, BroadcastClientExt, ClientWrapper, DEFAULT_GAS_COSTS, GasOption, JsonSerExt,
+        QueryClientExt, addr,
+    },
     hex_literal::hex,
     indexer_client::HttpClient,
     std::sync::Arc,
@@ -42,7 +44,7 @@ async fn main() -> anyhow::Result<()> {
             None::<AppConfig>,
             GasOption::Simulate {
                 scale: 2.,
-                flat_increase: GAS_COSTS.secp256k1_verify,
+                flat_increase: DEFAULT_GAS_COSTS.secp256k1_verify,
             },
             CHAIN_ID,
         )
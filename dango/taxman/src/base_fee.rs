@@ -0,0 +1,177 @@
+use {
+    crate::BLOCK_GAS,
+    dango_types::taxman::{Config, DynamicFeeConfig},
+    grug::{Exponentiate, Inner, MathResult, Number, NumberConst, StdResult, Storage, Udec128},
+};
+
+/// Return the base fee, in units of the fee token per unit of gas, of
+/// transactions included in the block of the given height.
+///
+/// The base fee is determined by the gas consumed by the latest block that has
+/// processed any transaction. Blocks after it are considered empty, each of
+/// which lowers the base fee by the maximum change rate.
+pub fn base_fee_at(storage: &dyn Storage, cfg: &Config, height: u64) -> StdResult<Udec128> {
+    let Some(dynamic_fee) = &cfg.dynamic_fee else {
+        return Ok(cfg.fee_rate);
+    };
+
+    let base_fee = match BLOCK_GAS.may_load(storage)? {
+        Some((last_height, base_fee, _)) if last_height >= height => base_fee,
+        Some((last_height, base_fee, gas_used)) => {
+            let base_fee = next_base_fee(dynamic_fee, base_fee, gas_used)?;
+            let empty_blocks = height - last_height - 1;
+
+            decay(dynamic_fee, base_fee, empty_blocks)?
+        },
+        None => cfg.fee_rate,
+    };
+
+    // The bounds may have been changed since the base fee was last adjusted.
+    Ok(base_fee.max(cfg.fee_rate).min(dynamic_fee.max_base_fee))
+}
+
+/// Add the gas consumed by a transaction to the total of the block of the given
+/// height, which must not be lower than that of any block previously recorded.
+pub fn record_gas(
+    storage: &mut dyn Storage,
+    cfg: &Config,
+    height: u64,
+    gas_used: u64,
+) -> StdResult<()> {
+    // There's no need to track the gas consumption if the fee rate is static.
+    if cfg.dynamic_fee.is_none() {
+        return Ok(());
+    }
+
+    let base_fee = base_fee_at(storage, cfg, height)?;
+
+    let block_gas_used = match BLOCK_GAS.may_load(storage)? {
+        Some((last_height, _, block_gas_used)) if last_height == height => block_gas_used,
+        _ => 0,
+    };
+
+    BLOCK_GAS.save(
+        storage,
+        &(height, base_fee, block_gas_used.saturating_add(gas_used)),
+    )
+}
+
+/// Adjust the base fee according to the gas consumed by a block.
+fn next_base_fee(
+    dynamic_fee: &DynamicFeeConfig,
+    base_fee: Udec128,
+    gas_used: u64,
+) -> MathResult<Udec128> {
+    let target_gas = dynamic_fee.target_gas;
+
+    // The relative change is proportional to how far the gas consumption is
+    // from the target, up to the maximum change rate.
+    let change_rate = |diff: u64| -> MathResult<Udec128> {
+        Udec128::checked_from_ratio(diff.min(target_gas) as u128, target_gas as u128)?
+            .checked_mul(*dynamic_fee.max_change_rate.inner())
+    };
+
+    if gas_used >= target_gas {
+        let change = base_fee.checked_mul(change_rate(gas_used - target_gas)?)?;
+
+        Ok(base_fee.saturating_add(change))
+    } else {
+        let change = base_fee.checked_mul(change_rate(target_gas - gas_used)?)?;
+
+        base_fee.checked_sub(change)
+    }
+}
+
+/// Lower the base fee by the maximum change rate for each of the given number
+/// of empty blocks.
+fn decay(
+    dynamic_fee: &DynamicFeeConfig,
+    base_fee: Udec128,
+    empty_blocks: u64,
+) -> MathResult<Udec128> {
+    let factor = Udec128::ONE
+        .checked_sub(*dynamic_fee.max_change_rate.inner())?
+        .checked_pow(empty_blocks.try_into().unwrap_or(u32::MAX))?;
+
+    base_fee.checked_mul(factor)
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        grug::{Bounded, Denom, MockStorage},
+        std::str::FromStr,
+        test_case::test_case,
+    };
+
+    fn config() -> Config {
+        Config {
+            fee_denom: Denom::from_str("uusdc").unwrap(),
+            fee_rate: Udec128::new_percent(1),
+            dynamic_fee: Some(DynamicFeeConfig {
+                target_gas: 1_000,
+                max_change_rate: Bounded::new_unchecked(Udec128::new_percent(10)),
+                max_base_fee: Udec128::new(1),
+            }),
+        }
+    }
+
+    #[test_case(1_000, 1, "0.5"; "on target")]
+    #[test_case(1_500, 1, "0.525"; "above target")]
+    #[test_case(5_000, 1, "0.55"; "far above target")]
+    #[test_case(500, 1, "0.475"; "below target")]
+    #[test_case(1_000, 3, "0.405"; "followed by empty blocks")]
+    #[test_case(0, 100, "0.01"; "capped at minimum")]
+    #[test_case(1_000, 0, "0.5"; "same block")]
+    fn adjusting_base_fee(gas_used: u64, blocks_later: u64, expected: &str) {
+        let mut storage = MockStorage::new();
+        let cfg = config();
+
+        BLOCK_GAS
+            .save(&mut storage, &(10, Udec128::new_percent(50), gas_used))
+            .unwrap();
+
+        assert_eq!(
+            base_fee_at(&storage, &cfg, 10 + blocks_later).unwrap(),
+            Udec128::from_str(expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn capped_at_maximum() {
+        let mut storage = MockStorage::new();
+        let cfg = config();
+
+        BLOCK_GAS
+            .save(&mut storage, &(10, Udec128::new_percent(95), 2_000))
+            .unwrap();
+
+        assert_eq!(base_fee_at(&storage, &cfg, 11).unwrap(), Udec128::new(1));
+    }
+
+    #[test]
+    fn recording_gas() {
+        let mut storage = MockStorage::new();
+        let cfg = config();
+
+        // The first transaction ever processed pays the minimum base fee.
+        record_gas(&mut storage, &cfg, 10, 1_500).unwrap();
+        record_gas(&mut storage, &cfg, 10, 500).unwrap();
+
+        assert_eq!(
+            BLOCK_GAS.load(&storage).unwrap(),
+            (10, Udec128::new_percent(1), 2_000)
+        );
+
+        // In the next block, the base fee is raised by the maximum rate.
+        record_gas(&mut storage, &cfg, 11, 100).unwrap();
+
+        assert_eq!(
+            BLOCK_GAS.load(&storage).unwrap(),
+            (11, Udec128::new_permille(11), 100)
+        );
+    }
+}
@@ -1,9 +1,9 @@
 use {
-    crate::{CONFIG, WITHHELD_FEE},
+    crate::{CONFIG, SPONSOR_LIMITS, WITHHELD_FEE, base_fee_at, record_gas},
     anyhow::ensure,
     dango_types::{
         DangoQuerier, bank,
-        taxman::{Config, ExecuteMsg, FeeType, InstantiateMsg, ReceiveFee},
+        taxman::{Config, ExecuteMsg, FeeType, InstantiateMsg, ReceiveFee, tip_of},
     },
     grug::{
         Addr, AuthCtx, AuthMode, Coins, ContractEvent, IsZero, Message, MultiplyFraction,
@@ -14,7 +14,9 @@ use {
 };
 
 #[cfg_attr(not(feature = "library"), grug::export)]
-pub fn instantiate(ctx: MutableCtx, msg: InstantiateMsg) -> StdResult<Response> {
+pub fn instantiate(ctx: MutableCtx, msg: InstantiateMsg) -> anyhow::Result<Response> {
+    validate_config(&msg.config)?;
+
     CONFIG.save(ctx.storage, &msg.config)?;
 
     Ok(Response::new())
@@ -25,6 +27,7 @@ pub fn execute(ctx: MutableCtx, msg: ExecuteMsg) -> anyhow::Result<Response> {
     match msg {
         ExecuteMsg::Configure { new_cfg } => configure(ctx, new_cfg),
         ExecuteMsg::Pay { ty, payments } => pay(ctx, ty, payments),
+        ExecuteMsg::SetSponsorLimit { limit } => set_sponsor_limit(ctx, limit),
     }
 }
 
@@ -35,11 +38,38 @@ fn configure(ctx: MutableCtx, new_cfg: Config) -> anyhow::Result<Response> {
         "you don't have the right, O you don't have the right"
     );
 
+    validate_config(&new_cfg)?;
+
     CONFIG.save(ctx.storage, &new_cfg)?;
 
     Ok(Response::new())
 }
 
+fn validate_config(cfg: &Config) -> anyhow::Result<()> {
+    if let Some(dynamic_fee) = &cfg.dynamic_fee {
+        ensure!(
+            dynamic_fee.target_gas > 0,
+            "target gas per block must be non-zero"
+        );
+
+        // The base fee is adjusted relative to itself, so starting from zero,
+        // it would remain zero forever.
+        ensure!(
+            cfg.fee_rate.is_non_zero(),
+            "fee rate must be non-zero if the fee is dynamic"
+        );
+
+        ensure!(
+            dynamic_fee.max_base_fee >= cfg.fee_rate,
+            "max base fee can't be lower than the fee rate! max base fee: {}, fee rate: {}",
+            dynamic_fee.max_base_fee,
+            cfg.fee_rate
+        );
+    }
+
+    Ok(())
+}
+
 fn pay(ctx: MutableCtx, ty: FeeType, payments: BTreeMap<Addr, Coins>) -> anyhow::Result<Response> {
     ensure!(ctx.funds.is_non_empty(), "funds cannot be empty!");
 
@@ -79,11 +109,34 @@ fn pay(ctx: MutableCtx, ty: FeeType, payments: BTreeMap<Addr, Coins>) -> anyhow:
     Ok(Response::new().add_events(events)?)
 }
 
+fn set_sponsor_limit(ctx: MutableCtx, limit: Uint128) -> anyhow::Result<Response> {
+    if limit.is_zero() {
+        SPONSOR_LIMITS.remove(ctx.storage, ctx.sender);
+    } else {
+        SPONSOR_LIMITS.save(ctx.storage, ctx.sender, &limit)?;
+    }
+
+    Ok(Response::new())
+}
+
 // TODO: exempt the account factory from paying fee.
 #[cfg_attr(not(feature = "library"), grug::export)]
-pub fn withhold_fee(ctx: AuthCtx, tx: Tx) -> StdResult<Response> {
+pub fn withhold_fee(ctx: AuthCtx, tx: Tx) -> anyhow::Result<Response> {
     let fee_cfg = CONFIG.load(ctx.storage)?;
 
+    // In `CheckTx` and simulation, the block is the last finalized one, while
+    // the transaction can't be included in a block earlier than the next one.
+    // Use the base fee of that block, so that a transaction accepted into the
+    // mempool isn't rejected for having been quoted a lower fee.
+    let height = match ctx.mode {
+        AuthMode::Finalize => ctx.block.height,
+        AuthMode::Check | AuthMode::Simulate => ctx.block.height + 1,
+    };
+
+    // The fee rate consists of the block's base fee, and the tip the payer is
+    // willing to pay to have the transaction prioritized.
+    let fee_rate = base_fee_at(ctx.storage, &fee_cfg, height)?.checked_add(tip_of(&tx))?;
+
     // Compute the maximum amount of fee this transaction may incur.
     // Note that we ceil this amount, instead of flooring.
     //
@@ -102,13 +155,29 @@ pub fn withhold_fee(ctx: AuthCtx, tx: Tx) -> StdResult<Response> {
     } {
         Uint128::ZERO
     } else {
-        Uint128::new(tx.gas_limit as u128).checked_mul_dec_ceil(fee_cfg.fee_rate)?
+        Uint128::new(tx.gas_limit as u128).checked_mul_dec_ceil(fee_rate)?
     };
 
+    // If the transaction is sponsored, the fee payer must have agreed to pay
+    // at least this much on behalf of others.
+    if let Some(fee_payer) = &tx.fee_payer {
+        let limit = SPONSOR_LIMITS
+            .may_load(ctx.storage, fee_payer.address)?
+            .unwrap_or_default();
+
+        ensure!(
+            withhold_amount <= limit,
+            "fee exceeds the fee payer's sponsor limit! fee payer: {}, fee: {}, limit: {}",
+            fee_payer.address,
+            withhold_amount,
+            limit
+        );
+    }
+
     // If the withhold amount is non-zero, we force transfer this amount from
-    // the sender to taxman.
+    // the payer (the fee payer if there is one, otherwise the sender) to taxman.
     //
-    // If the sender doesn't have enough fund to cover the maximum amount of fee
+    // If the payer doesn't have enough fund to cover the maximum amount of fee
     // the tx may incur, this submessage fails, causing the tx to be rejected
     // from entering the mempool.
     let withhold_msg = if withhold_amount.is_non_zero() {
@@ -116,7 +185,7 @@ pub fn withhold_fee(ctx: AuthCtx, tx: Tx) -> StdResult<Response> {
         Some(Message::execute(
             bank,
             &bank::ExecuteMsg::ForceTransfer {
-                from: tx.sender,
+                from: tx.payer(),
                 to: ctx.contract,
                 coins: coins! { fee_cfg.fee_denom.clone() => withhold_amount },
             },
@@ -127,16 +196,23 @@ pub fn withhold_fee(ctx: AuthCtx, tx: Tx) -> StdResult<Response> {
     };
 
     // Save the withheld fee in storage, which we will use in `finalize_fee`.
-    WITHHELD_FEE.save(ctx.storage, &(fee_cfg, withhold_amount))?;
+    WITHHELD_FEE.save(ctx.storage, &(fee_cfg.fee_denom, fee_rate, withhold_amount))?;
 
     Ok(Response::new().may_add_message(withhold_msg))
 }
 
 #[cfg_attr(not(feature = "library"), grug::export)]
 pub fn finalize_fee(ctx: AuthCtx, tx: Tx, outcome: TxOutcome) -> StdResult<Response> {
-    let (fee_cfg, withheld_amount) = WITHHELD_FEE.take(ctx.storage)?;
+    let fee_cfg = CONFIG.load(ctx.storage)?;
+    let (fee_denom, fee_rate, withheld_amount) = WITHHELD_FEE.take(ctx.storage)?;
+
+    // Count the gas consumed by this transaction towards the block's total,
+    // which determines the base fee of the next block.
+    //
+    // Note that this includes transactions that are exempt from gas fees.
+    record_gas(ctx.storage, &fee_cfg, ctx.block.height, outcome.gas_used)?;
 
-    // Compute how much fee to charge the sender, based on the actual amount of
+    // Compute how much fee to charge the payer, based on the actual amount of
     // gas consumed.
     //
     // Again, during simulation, or any tx sent by the account factory, is
@@ -147,23 +223,42 @@ pub fn finalize_fee(ctx: AuthCtx, tx: Tx, outcome: TxOutcome) -> StdResult<Respo
     } {
         Uint128::ZERO
     } else {
-        Uint128::new(outcome.gas_used as u128).checked_mul_dec_ceil(fee_cfg.fee_rate)?
+        Uint128::new(outcome.gas_used as u128).checked_mul_dec_ceil(fee_rate)?
     };
 
+    // If the transaction is sponsored, deduct the fee from the fee payer's
+    // sponsor limit.
+    //
+    // The limit may have been lowered by the transaction itself, after the fee
+    // was withheld, so saturating subtraction is used to make sure this call
+    // doesn't fail.
+    if let Some(fee_payer) = &tx.fee_payer {
+        let limit = SPONSOR_LIMITS
+            .may_load(ctx.storage, fee_payer.address)?
+            .unwrap_or_default()
+            .saturating_sub(charge_amount);
+
+        if limit.is_zero() {
+            SPONSOR_LIMITS.remove(ctx.storage, fee_payer.address);
+        } else {
+            SPONSOR_LIMITS.save(ctx.storage, fee_payer.address, &limit)?;
+        }
+    }
+
     // If we have withheld more funds than the actual charge amount, we need to
     // refund the difference.
     let refund_amount = withheld_amount.saturating_sub(charge_amount);
 
     // Use ForceTransfer instead of Transfer so that we don't need to invoke the
-    // sender's `receive` method (unnecessary).
+    // payer's `receive` method (unnecessary).
     let refund_msg = if refund_amount.is_non_zero() {
         let bank = ctx.querier.query_bank()?;
         Some(Message::execute(
             bank,
             &bank::ExecuteMsg::ForceTransfer {
                 from: ctx.contract,
-                to: tx.sender,
-                coins: coins! { fee_cfg.fee_denom.clone() => refund_amount },
+                to: tx.payer(),
+                coins: coins! { fee_denom => refund_amount },
             },
             Coins::new(),
         )?)
@@ -1,5 +1,7 @@
+mod base_fee;
 mod execute;
+mod migrate;
 mod query;
 mod state;
 
-pub use {execute::*, query::*, state::*};
+pub use {base_fee::*, execute::*, migrate::*, query::*, state::*};
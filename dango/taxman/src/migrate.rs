@@ -0,0 +1,90 @@
+use {
+    crate::CONFIG,
+    dango_types::taxman::Config,
+    grug::{Denom, Empty, Item, Response, StdResult, Storage, SudoCtx, Udec128},
+};
+
+/// The fee config, as stored by versions before the dynamic fee was introduced.
+#[grug::derive(Borsh)]
+struct LegacyConfig {
+    fee_denom: Denom,
+    fee_rate: Udec128,
+}
+
+/// The same storage slot as [`CONFIG`](crate::CONFIG), read in the legacy layout.
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
+#[cfg_attr(not(feature = "library"), grug::export)]
+pub fn migrate(ctx: SudoCtx, _msg: Empty) -> anyhow::Result<Response> {
+    add_dynamic_fee_to_config(ctx.storage)?;
+
+    Ok(Response::new())
+}
+
+/// Rewrite the fee config stored in the legacy layout to the current one, with
+/// the dynamic fee disabled, such that the fee rate stays the same.
+///
+/// Does nothing if the config is already in the current layout.
+fn add_dynamic_fee_to_config(storage: &mut dyn Storage) -> StdResult<()> {
+    if CONFIG.load(storage).is_ok() {
+        return Ok(());
+    }
+
+    let legacy = LEGACY_CONFIG.load(storage)?;
+
+    CONFIG.save(storage, &Config {
+        fee_denom: legacy.fee_denom,
+        fee_rate: legacy.fee_rate,
+        dynamic_fee: None,
+    })
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        dango_types::taxman::DynamicFeeConfig,
+        grug::{Bounded, MockStorage},
+        std::str::FromStr,
+    };
+
+    #[test]
+    fn adding_dynamic_fee_to_config_works() {
+        let mut storage = MockStorage::new();
+
+        LEGACY_CONFIG
+            .save(&mut storage, &LegacyConfig {
+                fee_denom: Denom::from_str("uusdc").unwrap(),
+                fee_rate: Udec128::new_percent(1),
+            })
+            .unwrap();
+
+        // The legacy config can't be read in the current layout.
+        assert!(CONFIG.load(&storage).is_err());
+
+        add_dynamic_fee_to_config(&mut storage).unwrap();
+
+        assert_eq!(CONFIG.load(&storage).unwrap(), Config {
+            fee_denom: Denom::from_str("uusdc").unwrap(),
+            fee_rate: Udec128::new_percent(1),
+            dynamic_fee: None,
+        });
+
+        // Migrating again leaves a config in the current layout untouched.
+        let cfg = Config {
+            dynamic_fee: Some(DynamicFeeConfig {
+                target_gas: 1_000,
+                max_change_rate: Bounded::new_unchecked(Udec128::new_percent(10)),
+                max_base_fee: Udec128::new(1),
+            }),
+            ..CONFIG.load(&storage).unwrap()
+        };
+
+        CONFIG.save(&mut storage, &cfg).unwrap();
+        add_dynamic_fee_to_config(&mut storage).unwrap();
+
+        assert_eq!(CONFIG.load(&storage).unwrap(), cfg);
+    }
+}
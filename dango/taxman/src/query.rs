@@ -1,16 +1,53 @@
 use {
-    crate::CONFIG,
+    crate::{CONFIG, SPONSOR_LIMITS, base_fee_at},
     dango_types::taxman::{Config, QueryMsg},
-    grug::{ImmutableCtx, Json, JsonSerExt, StdResult},
+    grug::{
+        Addr, Bound, DEFAULT_PAGE_LIMIT, ImmutableCtx, Json, JsonSerExt, Order, StdResult, Udec128,
+        Uint128,
+    },
+    std::collections::BTreeMap,
 };
 
 #[cfg_attr(not(feature = "library"), grug::export)]
 pub fn query(ctx: ImmutableCtx, msg: QueryMsg) -> StdResult<Json> {
     match msg {
         QueryMsg::Config {} => query_config(ctx)?.to_json_value(),
+        QueryMsg::BaseFee {} => query_base_fee(ctx)?.to_json_value(),
+        QueryMsg::SponsorLimit { sponsor } => query_sponsor_limit(ctx, sponsor)?.to_json_value(),
+        QueryMsg::SponsorLimits { start_after, limit } => {
+            query_sponsor_limits(ctx, start_after, limit)?.to_json_value()
+        },
     }
 }
 
 fn query_config(ctx: ImmutableCtx) -> StdResult<Config> {
     CONFIG.load(ctx.storage)
 }
+
+fn query_base_fee(ctx: ImmutableCtx) -> StdResult<Udec128> {
+    let cfg = CONFIG.load(ctx.storage)?;
+
+    // Queries are made against the latest finalized block. The transactions
+    // the user is about to send are to be included in the next one.
+    base_fee_at(ctx.storage, &cfg, ctx.block.height + 1)
+}
+
+fn query_sponsor_limit(ctx: ImmutableCtx, sponsor: Addr) -> StdResult<Uint128> {
+    SPONSOR_LIMITS
+        .may_load(ctx.storage, sponsor)
+        .map(Option::unwrap_or_default)
+}
+
+fn query_sponsor_limits(
+    ctx: ImmutableCtx,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<BTreeMap<Addr, Uint128>> {
+    let start = start_after.map(Bound::Exclusive);
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT) as usize;
+
+    SPONSOR_LIMITS
+        .range(ctx.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
@@ -1,8 +1,18 @@
 use {
     dango_types::taxman::Config,
-    grug::{Item, Uint128},
+    grug::{Addr, Denom, Item, Map, Udec128, Uint128},
 };
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
-pub const WITHHELD_FEE: Item<(Config, Uint128)> = Item::new("withheld_fee");
+/// The fee denom and rate (the base fee plus the tip) of the transaction being
+/// processed, and the amount of fee withheld from its payer.
+pub const WITHHELD_FEE: Item<(Denom, Udec128, Uint128)> = Item::new("withheld_fee");
+
+/// The height of the latest block that has processed any transaction, its base
+/// fee, and the total amount of gas consumed by transactions in it.
+pub const BLOCK_GAS: Item<(u64, Udec128, u64)> = Item::new("block_gas");
+
+/// The remaining amount of gas fee each sponsor is willing to pay on behalf of
+/// others, as the fee payer of their transactions.
+pub const SPONSOR_LIMITS: Map<Addr, Uint128> = Map::new("sponsor_limit");
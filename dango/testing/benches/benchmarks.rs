@@ -107,6 +107,7 @@ where
 
             Tx {
                 sender,
+                fee_payer: None,
                 gas_limit: 2_000_000,
                 msgs: NonEmpty::new_unchecked(vec![msg]),
                 data: data.to_json_value().unwrap(),
@@ -11,7 +11,7 @@ use {
     },
     digest::{consts::U32, generic_array::GenericArray},
     grug::{
-        Addr, Addressable, Coins, Defined, Duration, Hash256, HashExt, Json, JsonSerExt,
+        Addr, Addressable, Coins, Defined, Duration, FeePayer, Hash256, HashExt, Json, JsonSerExt,
         MaybeDefined, Message, NonEmpty, QuerierExt, QueryClient, QueryClientExt, ResultExt,
         SignData, Signer, StdError, StdResult, Tx, Undefined, UnsignedTx, btree_map,
     },
@@ -180,6 +180,7 @@ where
             chain_id: chain_id.to_string(),
             expiry,
             nonce,
+            tip: None,
         }
     }
 
@@ -208,6 +209,7 @@ where
 
         let sign_doc = SignDoc {
             sender,
+            fee_payer: None,
             gas_limit,
             messages: msgs.clone(),
             data: data.clone(),
@@ -377,6 +379,60 @@ where
     }
 }
 
+impl TestAccount {
+    /// Sign a transaction whose fee is to be paid by the given fee payer, and
+    /// have the fee payer sign it as well. The internally tracked nonces of
+    /// both accounts are incremented.
+    pub fn sign_sponsored_transaction(
+        &mut self,
+        fee_payer: &mut TestAccount,
+        msgs: NonEmpty<Vec<Message>>,
+        chain_id: &str,
+        gas_limit: u64,
+    ) -> StdResult<Tx> {
+        let sender = self.address();
+        let fee_payer_address = fee_payer.address();
+
+        // The sender and the fee payer sign the same transaction, each with
+        // their own metadata.
+        let sign = |account: &TestAccount| -> StdResult<(Json, Json)> {
+            let data = account.metadata(chain_id, account.nonce, None);
+
+            let sign_doc = SignDoc {
+                sender,
+                fee_payer: Some(fee_payer_address),
+                gas_limit,
+                messages: msgs.clone(),
+                data: data.clone(),
+            };
+
+            let credential =
+                Credential::Standard(account.create_standard_credential(sign_doc.to_sign_data()?));
+
+            Ok((data.to_json_value()?, credential.to_json_value()?))
+        };
+
+        let (data, credential) = sign(&*self)?;
+        let (fee_payer_data, fee_payer_credential) = sign(&*fee_payer)?;
+
+        self.nonce += 1;
+        fee_payer.nonce += 1;
+
+        Ok(Tx {
+            sender,
+            fee_payer: Some(FeePayer {
+                address: fee_payer_address,
+                data: fee_payer_data,
+                credential: fee_payer_credential,
+            }),
+            gas_limit,
+            msgs,
+            data,
+            credential,
+        })
+    }
+}
+
 impl Addressable for TestAccount {
     fn address(&self) -> Addr {
         *self.address.inner()
@@ -391,6 +447,7 @@ impl Signer for TestAccount {
     ) -> StdResult<UnsignedTx> {
         Ok(UnsignedTx {
             sender: self.address(),
+            fee_payer: None,
             msgs,
             data: self.metadata(chain_id, self.nonce, None).to_json_value()?,
         })
@@ -416,6 +473,7 @@ impl Signer for TestAccount {
 
         Ok(Tx {
             sender: self.address(),
+            fee_payer: None,
             gas_limit,
             msgs,
             data: data.to_json_value()?,
@@ -484,6 +542,7 @@ impl Signer for Factory {
     ) -> StdResult<UnsignedTx> {
         Ok(UnsignedTx {
             sender: self.address(),
+            fee_payer: None,
             msgs,
             data: Json::null(),
         })
@@ -497,6 +556,7 @@ impl Signer for Factory {
     ) -> StdResult<Tx> {
         Ok(Tx {
             sender: self.address,
+            fee_payer: None,
             gas_limit,
             msgs,
             data: Json::null(),
@@ -575,6 +635,7 @@ impl Signer for Multi<'_> {
 
         Ok(Tx {
             sender: self.address,
+            fee_payer: None,
             gas_limit,
             msgs,
             data: data.to_json_value()?,
@@ -23,9 +23,9 @@ use {
         taxman,
     },
     grug::{
-        Addressable, BlockInfo, Bounded, Coin, Denom, Duration, GENESIS_BLOCK_HASH,
-        GENESIS_BLOCK_HEIGHT, HashExt, LengthBounded, NonZero, NumberConst, Udec128, Uint128,
-        btree_map, btree_set, coins,
+        Addressable, BlockInfo, Bounded, Coin, Denom, Duration, EndBlockLimits, GENESIS_BLOCK_HASH,
+        GENESIS_BLOCK_HEIGHT, GasCosts, HashExt, LengthBounded, NonZero, NumberConst, Udec128,
+        Uint128, btree_map, btree_set, coins,
     },
     hyperlane_testing::constants::{
         MOCK_HYPERLANE_LOCAL_DOMAIN, MOCK_HYPERLANE_VALIDATOR_ADDRESSES,
@@ -191,8 +191,11 @@ impl Preset for GrugOption {
             fee_cfg: taxman::Config {
                 fee_denom: usdc::DENOM.clone(),
                 fee_rate: Udec128::ZERO, // Use zero gas price for testing.
+                dynamic_fee: None,
             },
             max_orphan_age: Duration::from_weeks(1),
+            gas_costs: GasCosts::default(),
+            end_block_limits: EndBlockLimits::default(),
         }
     }
 }
@@ -403,7 +403,7 @@ where
         }
     }
 
-    let suite = grug::TestSuite::new_with_db_vm_indexer_and_pp(
+    let mut suite = grug::TestSuite::new_with_db_vm_indexer_and_pp(
         db,
         vm,
         pp,
@@ -416,6 +416,8 @@ where
         genesis_state,
     );
 
+    suite.app.balance_overrider = Some(dango_bank::issue);
+
     (suite, accounts, codes, contracts, validator_sets)
 }
 
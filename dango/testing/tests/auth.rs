@@ -41,6 +41,7 @@ fn first_nonce_too_big() {
 
         let tx = Tx {
             sender: accounts.user1.address(),
+            fee_payer: None,
             gas_limit: 100_000,
             msgs,
             data: metadata.to_json_value().unwrap(),
@@ -7,9 +7,9 @@ use {
         config::AppConfig,
         constants::{atom, dango, eth, usdc, xrp},
         dex::{
-            self, CancelOrderRequest, CreateOrderRequest, Direction, Geometric, OrderId,
-            OrderResponse, PairId, PairParams, PairUpdate, PassiveLiquidity, Price,
-            QueryLiquidityDepthRequest, QueryOrdersByPairRequest, QueryOrdersRequest,
+            self, CancelOrderRequest, CreateOrderRequest, Direction, Geometric, OrderCursor,
+            OrderId, OrderResponse, OrdersPage, PairId, PairParams, PairUpdate, PassiveLiquidity,
+            Price, QueryLiquidityDepthRequest, QueryOrdersByPairRequest, QueryOrdersRequest,
             QueryReserveRequest, QueryRestingOrderBookStateRequest, RestingOrderBookState, Xyk,
         },
         gateway::Remote,
@@ -449,6 +449,7 @@ fn dex_works(
             limit: None,
         })
         .unwrap()
+        .orders
         .into_iter()
         .map(|(order_id, order)| (order_id, order.remaining.into_int().into_inner()))
         .collect::<BTreeMap<_, _>>();
@@ -645,7 +646,7 @@ fn submit_and_cancel_orders(
             start_after: None,
             limit: None,
         })
-        .should_succeed_and(|orders| {
+        .should_succeed_and(|OrdersPage { orders, .. }| {
             assert_eq!(orders.len(), expected_orders_after.len());
             expected_orders_after
                 .iter()
@@ -871,7 +872,7 @@ fn submit_orders_then_cancel_and_submit_in_same_message(
             start_after: None,
             limit: None,
         })
-        .should_succeed_and(|orders| {
+        .should_succeed_and(|OrdersPage { orders, .. }| {
             assert_eq!(orders.len(), expected_orders_after.len());
             expected_orders_after
                 .iter()
@@ -952,7 +953,7 @@ fn submit_and_cancel_order_in_same_block() {
             start_after: None,
             limit: None,
         })
-        .should_succeed_and(BTreeMap::is_empty);
+        .should_succeed_and(|OrdersPage { orders, .. }| orders.is_empty());
 }
 
 #[test_case(
@@ -972,7 +973,8 @@ fn submit_and_cancel_order_in_same_block() {
         OrderId::new(!2) => (Direction::Bid, Udec128::new(10), Uint128::new(10)),
         OrderId::new(3)  => (Direction::Ask, Udec128::new(40), Uint128::new(10)),
         OrderId::new(4)  => (Direction::Ask, Udec128::new(50), Uint128::new(10)),
-    };
+    },
+    None;
     "dango/usdc no pagination"
 )]
 #[test_case(
@@ -990,7 +992,8 @@ fn submit_and_cancel_order_in_same_block() {
     btree_map! {
         OrderId::new(!5) => (Direction::Bid, Udec128::new(20), Uint128::new(10)),
         OrderId::new(6)  => (Direction::Ask, Udec128::new(25), Uint128::new(10)),
-    };
+    },
+    None;
     "eth/usdc no pagination"
 )]
 #[test_case(
@@ -1009,7 +1012,8 @@ fn submit_and_cancel_order_in_same_block() {
         OrderId::new(!1) => (Direction::Bid, Udec128::new(30), Uint128::new(10)),
         OrderId::new(!2) => (Direction::Bid, Udec128::new(10), Uint128::new(10)),
         OrderId::new(3)  => (Direction::Ask, Udec128::new(40), Uint128::new(10)),
-    };
+    },
+    Some(OrderId::new(3));
     "dango/usdc with limit no start after"
 )]
 #[test_case(
@@ -1022,11 +1026,12 @@ fn submit_and_cancel_order_in_same_block() {
         ((eth::DENOM.clone(), usdc::DENOM.clone()), Direction::Ask, 25, 10), //  5
     ],
     (dango::DENOM.clone(), usdc::DENOM.clone()),
-    Some(OrderId::new(3)),
+    Some((Direction::Ask, 40, OrderId::new(3))),
     None,
     btree_map! {
         OrderId::new(4) => (Direction::Ask, Udec128::new(50), Uint128::new(10)),
-    };
+    },
+    None;
     "dango/usdc with start after"
 )]
 #[test_case(
@@ -1039,20 +1044,40 @@ fn submit_and_cancel_order_in_same_block() {
         ((eth::DENOM.clone(), usdc::DENOM.clone()), Direction::Ask, 25, 10), //  5
     ],
     (dango::DENOM.clone(), usdc::DENOM.clone()),
-    Some(OrderId::new(!2)),
+    Some((Direction::Bid, 10, OrderId::new(!2))),
     Some(2),
     btree_map! {
         OrderId::new(!1) => (Direction::Bid, Udec128::new(30), Uint128::new(10)),
         OrderId::new(3)  => (Direction::Ask, Udec128::new(40), Uint128::new(10)),
-    };
+    },
+    Some(OrderId::new(3));
     "dango/usdc with start after and limit"
 )]
+#[test_case(
+    vec![
+        ((dango::DENOM.clone(), usdc::DENOM.clone()), Direction::Bid, 30, 10), // !0
+        ((dango::DENOM.clone(), usdc::DENOM.clone()), Direction::Bid, 10, 10), // !1
+        ((dango::DENOM.clone(), usdc::DENOM.clone()), Direction::Ask, 40, 10), //  2
+        ((dango::DENOM.clone(), usdc::DENOM.clone()), Direction::Ask, 50, 10), //  3
+        ((eth::DENOM.clone(), usdc::DENOM.clone()), Direction::Bid, 20, 10), // !4
+        ((eth::DENOM.clone(), usdc::DENOM.clone()), Direction::Ask, 25, 10), //  5
+    ],
+    (dango::DENOM.clone(), usdc::DENOM.clone()),
+    Some((Direction::Ask, 45, OrderId::new(100))),
+    None,
+    btree_map! {
+        OrderId::new(4) => (Direction::Ask, Udec128::new(50), Uint128::new(10)),
+    },
+    None;
+    "dango/usdc with start after an order that no longer exists"
+)]
 fn query_orders_by_pair(
     orders_to_submit: Vec<((Denom, Denom), Direction, u128, u128)>,
     (base_denom, quote_denom): (Denom, Denom),
-    start_after: Option<OrderId>,
+    start_after: Option<(Direction, u128, OrderId)>,
     limit: Option<u32>,
     expected_orders: BTreeMap<OrderId, (Direction, Udec128, Uint128)>,
+    expected_next: Option<OrderId>,
 ) {
     // For this test, we need some ETH and USDC for user1.
     let (mut suite, mut accounts, _, contracts, _) = setup_test_naive(TestOption {
@@ -1135,12 +1160,19 @@ fn query_orders_by_pair(
 
     suite
         .query_wasm_smart(contracts.dex, QueryOrdersByPairRequest {
-            base_denom,
-            quote_denom,
-            start_after,
+            base_denom: base_denom.clone(),
+            quote_denom: quote_denom.clone(),
+            start_after: start_after.map(|(direction, price, order_id)| OrderCursor {
+                base_denom,
+                quote_denom,
+                direction,
+                price: Udec128_24::new(price),
+                order_id,
+            }),
             limit,
         })
-        .should_succeed_and(|orders| {
+        .should_succeed_and(|OrdersPage { orders, next }| {
+            assert_eq!(next.as_ref().map(|cursor| cursor.order_id), expected_next);
             assert_eq!(orders.len(), expected_orders.len());
             expected_orders
                 .iter()
@@ -3045,7 +3077,7 @@ fn curve_on_orderbook(
             start_after: None,
             limit: None,
         })
-        .should_succeed_and(|orders| {
+        .should_succeed_and(|OrdersPage { orders, .. }| {
             // `expected_orders_after_clearing` contains only the expected
             // orders from the users, so we filter off the passive pool orders.
             assert_eq!(
@@ -5385,7 +5417,7 @@ fn market_order_clearing(
             start_after: None,
             limit: None,
         })
-        .should_succeed_and(|orders| {
+        .should_succeed_and(|OrdersPage { orders, .. }| {
             // println!("orders: {:?}", orders);
             assert_eq!(orders.len(), expected_limit_orders_after.len());
             expected_limit_orders_after.iter().all(
@@ -5590,7 +5622,10 @@ fn cron_execute_gracefully_handles_oracle_price_failure() {
             start_after: None,
             limit: None,
         })
-        .should_succeed_and_equal(BTreeMap::new());
+        .should_succeed_and_equal(OrdersPage {
+            orders: BTreeMap::new(),
+            next: None,
+        });
 
     // Balances should have been updated
     suite.balances().should_change(&accounts.user1, btree_map! {
@@ -6985,7 +7020,10 @@ fn decrease_liquidity_depths_minimal_failing_test() {
             start_after: None,
             limit: None,
         })
-        .should_succeed_and_equal(BTreeMap::new());
+        .should_succeed_and_equal(OrdersPage {
+            orders: BTreeMap::new(),
+            next: None,
+        });
 }
 
 #[test_case(
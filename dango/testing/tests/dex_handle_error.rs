@@ -4,7 +4,7 @@ use {
     dango_types::{
         constants::{dango, usdc},
         dex::{
-            self, CreateOrderRequest, Direction, OrderId, OrdersByPairResponse,
+            self, CreateOrderRequest, Direction, OrderId, OrdersByPairResponse, OrdersPage,
             QueryOrdersByPairRequest, QueryPausedRequest,
         },
     },
@@ -165,20 +165,23 @@ fn handling_error_in_auction(f: fn(&Contracts) -> (Addr, ContractWrapper)) {
             start_after: None,
             limit: None,
         })
-        .should_succeed_and_equal(btree_map! {
-            OrderId::new(!1) => OrdersByPairResponse {
-                user: accounts.owner.address(),
-                direction: Direction::Bid,
-                price: Udec128_24::new(100),
-                amount: Uint128::new(3),
-                remaining: Udec128_6::new(3),
-            },
-            OrderId::new(2) => OrdersByPairResponse {
-                user: accounts.owner.address(),
-                direction: Direction::Ask,
-                price: Udec128_24::new(100),
-                amount: Uint128::new(3),
-                remaining: Udec128_6::new(3),
+        .should_succeed_and_equal(OrdersPage {
+            orders: btree_map! {
+                OrderId::new(!1) => OrdersByPairResponse {
+                    user: accounts.owner.address(),
+                    direction: Direction::Bid,
+                    price: Udec128_24::new(100),
+                    amount: Uint128::new(3),
+                    remaining: Udec128_6::new(3),
+                },
+                OrderId::new(2) => OrdersByPairResponse {
+                    user: accounts.owner.address(),
+                    direction: Direction::Ask,
+                    price: Udec128_24::new(100),
+                    amount: Uint128::new(3),
+                    remaining: Udec128_6::new(3),
+                },
             },
+            next: None,
         });
 }
@@ -99,10 +99,12 @@ fn check_balances(
     println!("dex contract balances: {balances:?}");
 
     // Query the open orders.
-    let open_orders = suite.query_wasm_smart(contracts.dex, dex::QueryOrdersRequest {
-        start_after: None,
-        limit: Some(u32::MAX),
-    })?;
+    let open_orders = suite
+        .query_wasm_smart(contracts.dex, dex::QueryOrdersRequest {
+            start_after: None,
+            limit: Some(u32::MAX),
+        })?
+        .orders;
     println!("open orders: {open_orders:?}");
 
     let mut order_balances = Coins::new();
@@ -154,6 +154,7 @@ fn onboarding_existing_user() {
         // Send the register user message with account factory.
         let tx = Tx {
             sender: contracts.account_factory,
+            fee_payer: None,
             gas_limit: 1_000_000,
             msgs: NonEmpty::new_unchecked(vec![
                 Message::execute(
@@ -220,6 +221,7 @@ fn onboarding_without_deposit() {
     // Should fail during `CheckTx` with "data not found" error.
     let tx = Tx {
         sender: contracts.account_factory,
+        fee_payer: None,
         gas_limit: 1_000_000,
         msgs: NonEmpty::new_unchecked(vec![
             Message::execute(
@@ -928,6 +928,7 @@ fn max_nonce_dos_attack() {
 
         let tx = Tx {
             sender: multi_address,
+            fee_payer: None,
             gas_limit: 100_000,
             msgs,
             data: metadata.to_json_value().unwrap(),
@@ -969,6 +970,7 @@ fn max_nonce_dos_attack() {
 
         let tx = Tx {
             sender: multi_address,
+            fee_payer: None,
             gas_limit: 100_000,
             msgs,
             data: metadata.to_json_value().unwrap(),
@@ -34,6 +34,7 @@ fn prepare_tx_with_nonce(accounts: &TestAccounts, nonce: u32, expiry: Option<Dur
         gas_limit: GAS_LIMIT,
         msgs: msgs.clone(),
         sender: accounts.owner.address(),
+        fee_payer: None,
         data: data.to_json_value().unwrap(),
         credential: credential.to_json_value().unwrap(),
     }
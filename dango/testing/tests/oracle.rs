@@ -3,11 +3,11 @@ use {
     dango_testing::{TestAccounts, TestSuite, setup_test_naive},
     dango_types::{
         constants::{atom, bnb, btc, doge, eth, sol, usdc, xrp},
-        oracle::{ExecuteMsg, PrecisionlessPrice, Price, QueryPriceRequest},
+        oracle::{ExecuteMsg, PrecisionlessPrice, Price, PriceSource, QueryPriceRequest},
     },
     grug::{
-        Addr, Binary, Coins, Inner, MockApi, NonEmpty, QuerierExt, ResultExt, StorageQuerier,
-        Timestamp, Udec128, btree_map,
+        Addr, Binary, Coins, Denom, Inner, Json, Message, MockApi, NonEmpty, QuerierExt, ResultExt,
+        StorageQuerier, Timestamp, Tx, Udec128, btree_map,
     },
     grug_app::NaiveProposalPreparer,
     pyth_client::{PythClientCache, PythClientTrait},
@@ -331,3 +331,69 @@ fn sequence() {
     assert_eq!(new_price.humanized_price, price.humanized_price);
     assert_eq!(new_pyth_vaa.wormhole_vaa.sequence, sequence);
 }
+
+#[test]
+fn aggregated_prices() {
+    let (mut suite, mut accounts, oracle) = setup_oracle_test();
+
+    let denom = Denom::from_str("uagg").unwrap();
+
+    // Register a price source that reads from the aggregated prices.
+    suite
+        .execute(
+            &mut accounts.owner,
+            oracle,
+            &ExecuteMsg::RegisterPriceSources(btree_map! {
+                denom.clone() => PriceSource::PythAggregate {
+                    id: BTC_USD_ID,
+                    precision: 8,
+                },
+            }),
+            Coins::new(),
+        )
+        .should_succeed();
+
+    let price = PrecisionlessPrice::new(
+        Udec128::from_str("68744.84759622").unwrap(),
+        Udec128::from_str("68739.906").unwrap(),
+        Timestamp::from_seconds(1730804420),
+    );
+
+    let msg = ExecuteMsg::FeedAggregatedPrices(NonEmpty::new_unchecked(btree_map! {
+        BTC_USD_ID => price.clone(),
+    }));
+
+    // Aggregated prices can't be verified by the contract, so regular users
+    // can't feed them, not even the owner.
+    suite
+        .execute(&mut accounts.owner, oracle, &msg, Coins::new())
+        .should_fail_with_error("only the oracle itself can feed aggregated prices");
+
+    // Feed the prices from the oracle itself, as the block proposer would.
+    let tx = Tx {
+        sender: oracle,
+        fee_payer: None,
+        gas_limit: 50_000_000,
+        msgs: NonEmpty::new_unchecked(vec![Message::execute(oracle, &msg, Coins::new()).unwrap()]),
+        data: Json::null(),
+        credential: Json::null(),
+    };
+
+    suite
+        .make_block(vec![tx])
+        .block_outcome
+        .tx_outcomes
+        .into_iter()
+        .for_each(|outcome| {
+            outcome.should_succeed();
+        });
+
+    let current_price = suite
+        .query_wasm_smart(oracle, QueryPriceRequest { denom })
+        .unwrap();
+
+    assert_eq!(current_price.humanized_price, price.humanized_price);
+    assert_eq!(current_price.humanized_ema, price.humanized_ema);
+    assert_eq!(current_price.timestamp, price.timestamp);
+    assert_eq!(current_price.precision(), 8);
+}
@@ -149,11 +149,13 @@ mod session_account {
                 chain_id: chain_id.to_string(),
                 nonce: self.nonce,
                 expiry: None,
+                tip: None,
             };
 
             let sign_doc = SignDoc {
                 gas_limit,
                 sender: self.address(),
+                fee_payer: None,
                 messages: msgs.clone(),
                 data: data.clone(),
             };
@@ -176,6 +178,7 @@ mod session_account {
 
             Ok(Tx {
                 sender: self.address(),
+                fee_payer: None,
                 gas_limit,
                 msgs,
                 data: data.to_json_value()?,
@@ -2,14 +2,17 @@ use {
     dango_testing::setup_test_naive,
     dango_types::{constants::usdc, taxman},
     grug::{
-        Addressable, BalanceChange, Coins, Inner, MultiplyFraction, ResultExt, Udec128, Uint128,
-        btree_map,
+        Addressable, BalanceChange, Bounded, Coins, Inner, Message, MultiplyFraction, NonEmpty,
+        NumberConst, QuerierExt, ResultExt, Udec128, Uint128, btree_map,
     },
+    std::str::FromStr,
 };
 
 const OLD_FEE_RATE: Udec128 = Udec128::new_percent(1); // 0.01 uusdc per gas unit
 const NEW_FEE_RATE: Udec128 = Udec128::new_percent(2); // 0.02 uusdc per gas unit
 
+const GAS_LIMIT: u64 = 50_000_000;
+
 #[test]
 fn fee_rate_update_works() {
     let (mut suite, mut accounts, _, contracts, _) = setup_test_naive(Default::default());
@@ -28,6 +31,7 @@ fn fee_rate_update_works() {
                 new_cfg: taxman::Config {
                     fee_denom: usdc::DENOM.clone(),
                     fee_rate: OLD_FEE_RATE,
+                    dynamic_fee: None,
                 },
             },
             Coins::new(),
@@ -53,6 +57,7 @@ fn fee_rate_update_works() {
                 new_cfg: taxman::Config {
                     fee_denom: usdc::DENOM.clone(),
                     fee_rate: NEW_FEE_RATE,
+                    dynamic_fee: None,
                 },
             },
             Coins::new(),
@@ -86,3 +91,195 @@ fn fee_rate_update_works() {
         usdc::DENOM.clone() => BalanceChange::Decreased(fee.into_inner()),
     });
 }
+
+#[test]
+fn sponsored_transaction_works() {
+    let (mut suite, mut accounts, _, contracts, _) = setup_test_naive(Default::default());
+
+    suite
+        .execute(
+            &mut accounts.owner,
+            contracts.taxman,
+            &taxman::ExecuteMsg::Configure {
+                new_cfg: taxman::Config {
+                    fee_denom: usdc::DENOM.clone(),
+                    fee_rate: OLD_FEE_RATE,
+                    dynamic_fee: None,
+                },
+            },
+            Coins::new(),
+        )
+        .should_succeed();
+
+    let msgs = NonEmpty::new_unchecked(vec![
+        Message::transfer(
+            accounts.owner.address(),
+            Coins::one(usdc::DENOM.clone(), 100).unwrap(),
+        )
+        .unwrap(),
+    ]);
+
+    // user2 hasn't agreed to sponsor anyone, so the transaction is rejected.
+    let tx = accounts
+        .user1
+        .sign_sponsored_transaction(
+            &mut accounts.user2,
+            msgs.clone(),
+            &suite.chain_id,
+            GAS_LIMIT,
+        )
+        .unwrap();
+
+    suite
+        .check_tx(tx)
+        .should_fail_with_error("fee exceeds the fee payer's sponsor limit");
+
+    // The nonces used in the rejected transaction weren't consumed.
+    accounts.user1.nonce -= 1;
+    accounts.user2.nonce -= 1;
+
+    // user2 agrees to pay up to twice the maximum fee of the transaction.
+    let max_fee = Uint128::new(GAS_LIMIT as u128)
+        .checked_mul_dec_ceil(OLD_FEE_RATE)
+        .unwrap();
+
+    suite
+        .execute(
+            &mut accounts.user2,
+            contracts.taxman,
+            &taxman::ExecuteMsg::SetSponsorLimit {
+                limit: max_fee * Uint128::new(2),
+            },
+            Coins::new(),
+        )
+        .should_succeed();
+
+    suite
+        .balances()
+        .record_many([&accounts.user1, &accounts.user2]);
+
+    let tx = accounts
+        .user1
+        .sign_sponsored_transaction(&mut accounts.user2, msgs, &suite.chain_id, GAS_LIMIT)
+        .unwrap();
+
+    let outcome = suite.send_transaction(tx).should_succeed();
+
+    let fee = Uint128::new(outcome.gas_used as u128)
+        .checked_mul_dec_ceil(OLD_FEE_RATE)
+        .unwrap();
+
+    // The fee is paid by user2, while user1 only pays the transferred amount.
+    suite.balances().should_change(&accounts.user1, btree_map! {
+        usdc::DENOM.clone() => BalanceChange::Decreased(100),
+    });
+    suite.balances().should_change(&accounts.user2, btree_map! {
+        usdc::DENOM.clone() => BalanceChange::Decreased(fee.into_inner()),
+    });
+
+    // The fee is deducted from user2's sponsor limit.
+    suite
+        .query_wasm_smart(contracts.taxman, taxman::QuerySponsorLimitRequest {
+            sponsor: accounts.user2.address(),
+        })
+        .should_succeed_and_equal(max_fee * Uint128::new(2) - fee);
+}
+
+#[test]
+fn dynamic_base_fee_works() {
+    let (mut suite, mut accounts, _, contracts, _) = setup_test_naive(Default::default());
+
+    // Enable the dynamic fee, with a target so low that any block that includes
+    // a transaction raises the base fee by the maximum rate.
+    suite
+        .execute(
+            &mut accounts.owner,
+            contracts.taxman,
+            &taxman::ExecuteMsg::Configure {
+                new_cfg: taxman::Config {
+                    fee_denom: usdc::DENOM.clone(),
+                    fee_rate: OLD_FEE_RATE,
+                    dynamic_fee: Some(taxman::DynamicFeeConfig {
+                        target_gas: 1,
+                        max_change_rate: Bounded::new_unchecked(Udec128::new_percent(10)),
+                        max_base_fee: Udec128::new(1),
+                    }),
+                },
+            },
+            Coins::new(),
+        )
+        .should_succeed();
+
+    // The block above consumed more than the target, so the base fee of the
+    // next block is 10% higher than the minimum.
+    suite
+        .query_wasm_smart(contracts.taxman, taxman::QueryBaseFeeRequest {})
+        .should_succeed_and_equal(Udec128::new_permille(11));
+
+    suite.balances().record(&accounts.user1);
+
+    let success = suite
+        .transfer(&mut accounts.user1, accounts.owner.address(), Coins::new())
+        .should_succeed();
+
+    // The transaction is charged the base fee.
+    let fee = Uint128::new(success.gas_used as u128)
+        .checked_mul_dec_ceil(Udec128::new_permille(11))
+        .unwrap();
+
+    suite.balances().should_change(&accounts.user1, btree_map! {
+        usdc::DENOM.clone() => BalanceChange::Decreased(fee.into_inner()),
+    });
+
+    suite
+        .query_wasm_smart(contracts.taxman, taxman::QueryBaseFeeRequest {})
+        .should_succeed_and_equal(Udec128::new_bps(121));
+
+    // An empty block lowers the base fee by 10%.
+    suite.make_empty_block();
+
+    suite
+        .query_wasm_smart(contracts.taxman, taxman::QueryBaseFeeRequest {})
+        .should_succeed_and_equal(Udec128::from_str("0.01089").unwrap());
+
+    // The dynamic fee can't be configured with a max lower than the minimum.
+    suite
+        .execute(
+            &mut accounts.owner,
+            contracts.taxman,
+            &taxman::ExecuteMsg::Configure {
+                new_cfg: taxman::Config {
+                    fee_denom: usdc::DENOM.clone(),
+                    fee_rate: OLD_FEE_RATE,
+                    dynamic_fee: Some(taxman::DynamicFeeConfig {
+                        target_gas: 1,
+                        max_change_rate: Bounded::new_unchecked(Udec128::new_percent(10)),
+                        max_base_fee: Udec128::ZERO,
+                    }),
+                },
+            },
+            Coins::new(),
+        )
+        .should_fail_with_error("max base fee can't be lower than the fee rate");
+
+    // The dynamic fee can't be configured with a zero fee rate, as the base fee
+    // would then remain zero forever.
+    suite
+        .execute(
+            &mut accounts.owner,
+            contracts.taxman,
+            &taxman::ExecuteMsg::Configure {
+                new_cfg: taxman::Config {
+                    fee_denom: usdc::DENOM.clone(),
+                    fee_rate: Udec128::ZERO,
+                    dynamic_fee: Some(taxman::DynamicFeeConfig {
+                        target_gas: 1,
+                        max_change_rate: Bounded::new_unchecked(Udec128::new_percent(10)),
+                        max_base_fee: Udec128::new(1),
+                    }),
+                },
+            },
+            Coins::new(),
+        )
+        .should_fail_with_error("fee rate must be non-zero if the fee is dynamic");
+}
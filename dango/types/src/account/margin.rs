@@ -11,7 +11,7 @@ use {
 pub type CollateralPower = Bounded<Udec128, ZeroExclusiveOneInclusive>;
 
 /// Necessary input data for computing a margin account's health.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct HealthData {
     pub scaled_debts: BTreeMap<Denom, Udec256>,
     pub collateral_balances: BTreeMap<Denom, Uint128>,
@@ -19,7 +19,7 @@ pub struct HealthData {
 }
 
 /// Output for computing a margin account's health.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct HealthResponse {
     /// The margin account's utilization rate.
     pub utilization_rate: Udec128,
@@ -40,7 +40,7 @@ pub struct HealthResponse {
     pub limit_order_outputs: Coins,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Liquidate the margin account if it has become undercollateralized.
     Liquidate {
@@ -50,7 +50,7 @@ pub enum ExecuteMsg {
 }
 
 /// Query messages for the margin account
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the most recent transaction nonces that have been recorded.
     #[returns(BTreeSet<Nonce>)]
@@ -69,7 +69,7 @@ pub enum QueryMsg {
     },
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("liquidate")]
 pub struct Liquidate {
     pub collateral_denom: Denom,
@@ -15,7 +15,7 @@ pub type ProposalId = u32;
 pub type Power = u32;
 
 /// Parameters of a multi-signature account.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Params {
     /// Users who can votes in this multisig, and their respective voting power.
     pub members: BTreeMap<Username, NonZero<Power>>,
@@ -65,7 +65,7 @@ impl Params {
 }
 
 /// A set of updates to be applied to a multi-signature account.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct ParamUpdates {
     pub members: ChangeSet<Username, NonZero<Power>>,
     pub voting_period: Option<NonZero<Duration>>,
@@ -76,7 +76,7 @@ pub struct ParamUpdates {
 
 // Note: we can't derive the Borsh traits on this because `Message`,
 // which includes `serde_json::Value`, doesn't implement those traits.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct Proposal {
     pub title: String,
     pub description: Option<String>,
@@ -85,7 +85,7 @@ pub struct Proposal {
 }
 
 /// Possible Statuses a proposal can be in.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub enum Status {
     /// The proposal is being voted on by members.
     Voting {
@@ -118,7 +118,7 @@ pub enum Status {
 ///
 /// We currently don't support "abstain" or "no with veto" votes. If you need
 /// them, please let us know.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 #[derive(Copy)]
 pub enum Vote {
     /// The member voices support for this proposal.
@@ -127,7 +127,7 @@ pub enum Vote {
     No,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Create a new proposal with the given title, descriptions, and messages.
     Propose {
@@ -153,7 +153,7 @@ pub enum ExecuteMsg {
 
 // Note: we don't provide a method for querying the Safe's config. Query the
 // account factory for this instead.
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the most recent transaction nonces that have been recorded.
     #[returns(BTreeSet<Nonce>)]
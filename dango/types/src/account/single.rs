@@ -1,7 +1,7 @@
 use crate::account_factory::Username;
 
 /// Parameters of a single-signature account.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 #[non_exhaustive]
 pub struct Params {
     /// User who owns the account.
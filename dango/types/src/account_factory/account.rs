@@ -13,7 +13,7 @@ use {
 pub type AccountIndex = u32;
 
 /// Information of an account.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Account {
     pub index: AccountIndex,
     pub params: AccountParams,
@@ -22,7 +22,7 @@ pub struct Account {
 // ----------------------------------- type ------------------------------------
 
 /// Types of accounts the protocol supports.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 #[derive(Copy, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "async-graphql", graphql(rename_items = "snake_case"))]
@@ -92,7 +92,7 @@ impl Display for AccountType {
 // ---------------------------------- params -----------------------------------
 
 /// Parameters of an account.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub enum AccountParams {
     Spot(single::Params),
     Margin(single::Params),
@@ -152,7 +152,7 @@ impl AccountParams {
 /// Parameter updates to an account.
 ///
 /// Currently only multisig accounts support parameter updates.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum AccountParamUpdates {
     Multi(multi::ParamUpdates),
 }
@@ -7,7 +7,7 @@ use {
 };
 
 /// An event indicating a new user has registered.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("user_registered")]
 pub struct UserRegistered {
     pub username: Username,
@@ -16,7 +16,7 @@ pub struct UserRegistered {
 }
 
 /// An event indicating a new address has been created.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("account_registered")]
 pub struct AccountRegistered {
     pub address: Addr,
@@ -25,7 +25,7 @@ pub struct AccountRegistered {
 }
 
 /// An event indicating a username begins to own an account.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("account_owned")]
 pub struct AccountOwned {
     pub username: Username,
@@ -33,7 +33,7 @@ pub struct AccountOwned {
 }
 
 /// An event indicating a username ceases to own an account.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("account_disowned")]
 pub struct AccountDisowned {
     pub username: Username,
@@ -41,7 +41,7 @@ pub struct AccountDisowned {
 }
 
 /// An event indicating a username begins to own a key.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("key_owned")]
 pub struct KeyOwned {
     pub username: Username,
@@ -50,7 +50,7 @@ pub struct KeyOwned {
 }
 
 /// An event indicating a username ceases to own a key.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("key_disowned")]
 pub struct KeyDisowned {
     pub username: Username,
@@ -11,7 +11,7 @@ use {
 };
 
 /// Information about a user. Used in query response.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct User {
     /// Keys associated with this user, indexes by hashes.
     pub keys: BTreeMap<Hash256, Key>,
@@ -20,7 +20,7 @@ pub struct User {
 }
 
 /// Data the user must sign when onboarding.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct RegisterUserData {
     pub username: Username,
     pub chain_id: String,
@@ -35,7 +35,7 @@ impl SignData for RegisterUserData {
     }
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     /// Code hash to be associated with each account type.
     pub code_hashes: BTreeMap<AccountType, Hash256>,
@@ -47,7 +47,7 @@ pub struct InstantiateMsg {
     pub minimum_deposit: Coins,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Create a new user, following an initial deposit. Creates a spot account too.
     ///
@@ -68,7 +68,7 @@ pub enum ExecuteMsg {
     UpdateAccount(AccountParamUpdates),
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the minimum deposit required to onboard a user.
     #[returns(Coins)]
@@ -123,13 +123,13 @@ pub enum QueryMsg {
     },
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct QueryKeyPaginateParam {
     pub username: Username,
     pub key_hash: Hash256,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct QueryKeyResponseItem {
     pub username: Username,
     pub key_hash: Hash256,
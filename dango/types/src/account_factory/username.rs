@@ -122,6 +122,32 @@ impl FromStr for Username {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Username {
+    fn schema_name() -> String {
+        "Username".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, Metadata, SchemaObject, StringValidation};
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some("A name that uniquely identifies a user.".to_string()),
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                max_length: Some(Self::MAX_LEN as u32),
+                min_length: Some(1),
+                pattern: Some("^[a-z0-9_]+$".to_string()),
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 impl<'de> de::Deserialize<'de> for Username {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -2,7 +2,7 @@ use {
     crate::account_factory::Username,
     grug::{
         Addr, Binary, ByteArray, Hash256, JsonSerExt, Message, NonEmpty, SignData, StdError,
-        Timestamp,
+        Timestamp, Udec128,
     },
     serde::{Deserialize, Serialize},
     sha2::Sha256,
@@ -14,6 +14,7 @@ use {
 pub type Nonce = u32;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
 #[cfg_attr(
     feature = "sea-orm",
@@ -33,7 +34,7 @@ pub enum KeyType {
 }
 
 /// A public key that can be associated with a [`Username`](crate::auth::Username).
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 #[derive(Copy)]
 pub enum Key {
     /// An Secp256r1 public key in compressed form.
@@ -75,7 +76,7 @@ impl Display for Key {
 
 /// Data that the account expects for the transaction's [`credential`](grug::Tx::credential)
 /// field.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum Signature {
     /// An Secp256r1 signature signed by a Passkey, along with necessary metadata.
     Passkey(PasskeySignature),
@@ -85,13 +86,13 @@ pub enum Signature {
     Eip712(Eip712Signature),
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum Credential {
     Standard(StandardCredential),
     Session(SessionCredential),
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct StandardCredential {
     /// Identifies the key which the user used to sign this transaction.
     pub key_hash: Hash256,
@@ -99,7 +100,7 @@ pub struct StandardCredential {
     pub signature: Signature,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct SessionCredential {
     /// The `SessionInfo` that contains data to be signed with user key and otp key.
     pub session_info: SessionInfo,
@@ -109,7 +110,7 @@ pub struct SessionCredential {
     pub authorization: StandardCredential,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct SessionInfo {
     /// Public key of the session key.
     pub session_key: ByteArray<33>,
@@ -132,9 +133,15 @@ impl SignData for SessionInfo {
 ///
 /// This includes the messages to be included in the transaction, as well as
 /// sender, metadata and sender for replay protection.
-#[grug::derive(Serde)]
+///
+/// If the transaction has a fee payer, the fee payer signs the same data, but
+/// with its own metadata.
+#[grug::derive(Serde, Schema)]
 pub struct SignDoc {
     pub sender: Addr,
+    /// The account that pays the fee for the transaction, if it's not the
+    /// sender.
+    pub fee_payer: Option<Addr>,
     pub gas_limit: u64,
     pub messages: NonEmpty<Vec<Message>>,
     pub data: Metadata,
@@ -153,7 +160,7 @@ impl SignData for SignDoc {
 
 /// Data that the account expects for the transaction's [`data`](grug::Tx::data)
 /// field.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct Metadata {
     /// Identifies the user who signed this transaction.
     pub username: Username,
@@ -163,11 +170,18 @@ pub struct Metadata {
     pub nonce: Nonce,
     /// The expiration time of this transaction.
     pub expiry: Option<Timestamp>,
+    /// Units of the fee token for each unit of gas consumed, that the signer
+    /// is willing to pay on top of the base fee, in exchange for the
+    /// transaction being prioritized in the block.
+    ///
+    /// Only the tip in the metadata of the fee payer, if there is one,
+    /// otherwise of the sender, is taken into account.
+    pub tip: Option<Udec128>,
 }
 
 /// An Secp256r1 signature generated by a Passkey via Webauthn, along with
 /// necessary metadata.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct PasskeySignature {
     pub authenticator_data: Binary,
     pub client_data: Binary,
@@ -175,7 +189,7 @@ pub struct PasskeySignature {
 }
 
 /// An EIP712 signature signed with a compatible eth wallet.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct Eip712Signature {
     /// The EIP712 typed data object containing type information, domain, and
     /// the message object.
@@ -188,7 +202,7 @@ pub struct Eip712Signature {
 }
 
 /// Passkey client data.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct ClientData {
     // Should be "webauthn.get".
     #[serde(rename = "type")]
@@ -1,7 +1,7 @@
 use grug::{Addr, Coins};
 
 /// An event indicating a user has sent a transfer of coins.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("sent")]
 pub struct Sent {
     pub user: Addr,
@@ -10,7 +10,7 @@ pub struct Sent {
 }
 
 /// An event indicating a user has received a transfer of coins.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("received")]
 pub struct Received {
     pub user: Addr,
@@ -19,7 +19,7 @@ pub struct Received {
 }
 
 /// An event indicating a user has received newly minted coins.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("minted")]
 pub struct Minted {
     pub user: Addr,
@@ -33,7 +33,7 @@ pub struct Minted {
 }
 
 /// An event indicating a user's coins have been burned from his wallet.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("burned")]
 pub struct Burned {
     pub user: Addr,
@@ -46,7 +46,7 @@ pub struct Burned {
 ///
 /// The funds are temporarily held in the Dango bank contract, and can be
 /// claimed either by the sender or the recipient (once it's been created).
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("transfer_orphaned")]
 pub struct TransferOrphaned {
     pub from: Addr,
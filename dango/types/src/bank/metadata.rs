@@ -1,7 +1,7 @@
 use grug::LengthBounded;
 
 /// Metadata of a token.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Metadata {
     // The length limits were arbitrarily chosen and can be adjusted.
     pub name: LengthBounded<String, 1, 32>,
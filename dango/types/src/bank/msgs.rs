@@ -4,7 +4,7 @@ use {
     std::collections::BTreeMap,
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     /// Initial account balances.
     pub balances: BTreeMap<Addr, Coins>,
@@ -14,7 +14,7 @@ pub struct InstantiateMsg {
     pub metadatas: BTreeMap<Denom, Metadata>,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Grant the control of a namespace to an account.
     /// Can only be called by the chain owner.
@@ -49,7 +49,7 @@ pub enum ExecuteMsg {
     RecoverTransfer { sender: Addr, recipient: Addr },
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the owner of a namespace.
     #[returns(Addr)]
@@ -94,13 +94,13 @@ pub enum QueryMsg {
     },
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct OrphanedTransferPageParam {
     pub sender: Addr,
     pub recipient: Addr,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct OrphanedTransferResponseItem {
     pub sender: Addr,
     pub recipient: Addr,
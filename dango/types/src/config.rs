@@ -5,7 +5,7 @@ use {
 };
 
 /// Application-specific configurations of the Dango chain.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct AppConfig {
     /// Addresses of relevant Dango contracts.
     pub addresses: AppAddresses,
@@ -47,7 +47,7 @@ impl Default for AppConfig {
 }
 
 /// Addresses of relevant Dango contracts.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct AppAddresses {
     pub account_factory: Addr,
     pub dex: Addr,
@@ -76,7 +76,7 @@ impl Default for AppAddresses {
     }
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[derive(Copy)]
 pub struct Hyperlane<T> {
     pub ism: T,
@@ -4,7 +4,7 @@ use {
 };
 
 /// The direction of a trade: buy or sell.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 #[derive(Copy)]
 #[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "async-graphql", graphql(rename_items = "lowercase"))]
@@ -3,7 +3,7 @@ use {
     grug::{Addr, Coin, DecCoin, Denom, Udec128_6, Udec128_24, Uint128},
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("order_created")]
 pub struct OrderCreated {
     pub user: Addr,
@@ -17,7 +17,7 @@ pub struct OrderCreated {
     pub deposit: Coin,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("order_canceled")]
 pub struct OrderCanceled {
     pub user: Addr,
@@ -38,7 +38,7 @@ pub struct OrderCanceled {
     pub amount: Uint128,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 // TODO: change the event name to just `order_filled`. this would be an API-breaking change,
 // so only do this after testnet-2 has ended.
 #[grug::event("limit_orders_matched")]
@@ -50,7 +50,7 @@ pub struct OrdersMatched {
     pub volume: Udec128_6,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("order_filled")]
 pub struct OrderFilled {
     pub user: Addr,
@@ -80,7 +80,7 @@ impl From<&OrderFilled> for PairId {
     }
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("swapped")]
 pub struct Swapped {
     pub user: Addr,
@@ -92,7 +92,7 @@ pub struct Swapped {
 /// the chain owner, or automatically triggered due to an error in `cron_execute`.
 /// Under this state, orders can't be created or canceled, and the end-of-block
 /// auction is skipped.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("paused")]
 pub struct Paused {
     /// `None` if paused by the chain owner manually.
@@ -100,6 +100,6 @@ pub struct Paused {
     pub error: Option<String>,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("unpaused")]
 pub struct Unpaused {}
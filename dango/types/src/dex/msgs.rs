@@ -27,7 +27,7 @@ pub type SwapRoute = MaxLength<UniqueVec<PairId>, 2>;
 pub type MaxSlippage = Bounded<Udec128, ZeroInclusiveOneExclusive>;
 
 /// A request to create a new order.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct CreateOrderRequest {
     pub base_denom: Denom,
     pub quote_denom: Denom,
@@ -80,7 +80,7 @@ impl CreateOrderRequest {
     }
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum PriceOption {
     /// The order is to have the specified limit price.
     Limit(NonZero<Udec128_24>),
@@ -109,7 +109,7 @@ pub enum PriceOption {
     },
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum AmountOption {
     /// To create buy (BUY) orders, the user must send a non-zero amount the
     /// quote asset. Additionally, the order's size, computed as
@@ -134,18 +134,18 @@ impl AmountOption {
     }
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum CancelOrderRequest {
     Some(BTreeSet<OrderId>),
     All,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub pairs: Vec<PairUpdate>,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Messages only the chain owner can call.
     Owner(OwnerMsg),
@@ -195,7 +195,7 @@ pub enum ExecuteMsg {
     },
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum OwnerMsg {
     /// pause or unpause trading.
     SetPaused(bool),
@@ -205,18 +205,18 @@ pub enum OwnerMsg {
     ForceCancelOrders {},
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum CallbackMsg {
     /// perform the batch auction; called during `cron_execute`.
     Auction {},
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ReplyMsg {
     AfterAuction {},
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Returns whether trading is paused.
     #[returns(bool)]
@@ -261,24 +261,24 @@ pub enum QueryMsg {
     #[returns(OrderResponse)]
     Order { order_id: OrderId },
     /// Enumerate active orders across all pairs and from users.
-    #[returns(BTreeMap<OrderId, OrderResponse>)]
+    #[returns(OrdersPage<OrderId, OrderResponse>)]
     Orders {
         start_after: Option<OrderId>,
         limit: Option<u32>,
     },
     /// Enumerate active orders in a single pair from all users.
-    #[returns(BTreeMap<OrderId, OrdersByPairResponse>)]
+    #[returns(OrdersPage<OrderCursor, OrdersByPairResponse>)]
     OrdersByPair {
         base_denom: Denom,
         quote_denom: Denom,
-        start_after: Option<OrderId>,
+        start_after: Option<OrderCursor>,
         limit: Option<u32>,
     },
     /// Enumerate active orders from a single user across all pairs.
-    #[returns(BTreeMap<OrderId, OrdersByUserResponse>)]
+    #[returns(OrdersPage<OrderCursor, OrdersByUserResponse>)]
     OrdersByUser {
         user: Addr,
-        start_after: Option<OrderId>,
+        start_after: Option<OrderCursor>,
         limit: Option<u32>,
     },
     /// Returns the trading volume of a user address since the specified timestamp.
@@ -344,7 +344,7 @@ pub enum QueryMsg {
 
 /// Identifier of a trading pair. Consists of the base asset and quote asset
 /// denominations.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[derive(Hash)]
 pub struct PairId {
     pub base_denom: Denom,
@@ -352,21 +352,49 @@ pub struct PairId {
 }
 
 /// Response type of the `QueryMsg::Reserves` query.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct ReservesResponse {
     pub pair: PairId,
     pub reserve: CoinPair,
 }
 
 /// Response type of the `QueryMsg::RestingOrderBookState` query.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct RestingOrderBookStatesResponse {
     pub pair: PairId,
     pub state: RestingOrderBookState,
 }
 
+/// A position to continue enumerating orders from, in the `OrdersByPair` and
+/// `OrdersByUser` queries.
+///
+/// Orders are enumerated in the order of their storage keys, so the cursor
+/// consists of the full key instead of only the order ID. This way, it remains
+/// valid after the order has been filled or canceled.
+///
+/// `OrdersByPair` takes the pair from the query itself, and ignores the pair in
+/// the cursor.
+#[grug::derive(Serde, Schema)]
+pub struct OrderCursor {
+    pub base_denom: Denom,
+    pub quote_denom: Denom,
+    pub direction: Direction,
+    pub price: Udec128_24,
+    pub order_id: OrderId,
+}
+
+/// A page of orders returned by the `Orders`, `OrdersByPair`, and
+/// `OrdersByUser` queries.
+#[grug::derive(Serde, Schema)]
+pub struct OrdersPage<C, T> {
+    pub orders: BTreeMap<OrderId, T>,
+    /// The cursor to be provided as `start_after` to get the next page. `None`
+    /// if this is the last page.
+    pub next: Option<C>,
+}
+
 /// Response type of the `QueryMsg::Order` and `Orders` queries.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct OrderResponse {
     pub user: Addr,
     pub base_denom: Denom,
@@ -378,7 +406,7 @@ pub struct OrderResponse {
 }
 
 /// Response type of the `QueryMsg::OrdersByPair` query.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct OrdersByPairResponse {
     pub user: Addr,
     pub direction: Direction,
@@ -388,7 +416,7 @@ pub struct OrdersByPairResponse {
 }
 
 /// Response type of the `QueryMsg::OrdersByUser` query.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct OrdersByUserResponse {
     pub base_denom: Denom,
     pub quote_denom: Denom,
@@ -399,21 +427,21 @@ pub struct OrdersByUserResponse {
 }
 
 /// Response type of the `QueryMsg::ReflectCurve` query.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct ReflectCurveResponse {
     pub bids: BTreeMap<Udec128_24, Uint128>, // price => amount in base asset
     pub asks: BTreeMap<Udec128_24, Uint128>, // price => amount in base asset
 }
 
 /// Response type of the `QueryMsg::LiquidityDepth` query.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct LiquidityDepth {
     pub depth_base: Udec128_6,
     pub depth_quote: Udec128_6,
 }
 
 /// Response type of the `QueryMsg::LiquidityDepth` query.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct LiquidityDepthResponse {
     pub bid_depth: Option<Vec<(Udec128_24, LiquidityDepth)>>,
     pub ask_depth: Option<Vec<(Udec128_24, LiquidityDepth)>>,
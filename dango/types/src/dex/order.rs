@@ -56,7 +56,7 @@ use {
 /// use `grug::Uint64`, which is serialized as JSON strings.
 pub type OrderId = Uint64;
 
-#[grug::derive(Borsh, Serde)]
+#[grug::derive(Borsh, Serde, Schema)]
 #[derive(Copy)]
 pub struct Order {
     /// The user who created the order.
@@ -7,7 +7,7 @@ use {
 };
 
 /// Parameters of a trading pair.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct PairParams {
     /// Liquidity token denom of the passive liquidity pool.
     pub lp_denom: Denom,
@@ -23,13 +23,13 @@ pub struct PairParams {
     pub min_order_size: Uint128,
 }
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub enum PassiveLiquidity {
     Xyk(Xyk),
     Geometric(Geometric),
 }
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Xyk {
     /// How far apart each order is placed.
     pub spacing: Udec128,
@@ -51,7 +51,7 @@ pub struct Xyk {
 /// progression of order sizes. Where the first order has size `1 - ratio`,
 /// the second order has size `(1 - ratio) * ratio`, the third order has size
 /// `(1 - ratio) * ratio^2`, and so on.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Geometric {
     /// How far apart each order is placed.
     pub spacing: Udec128,
@@ -63,7 +63,7 @@ pub struct Geometric {
 }
 
 /// Updates to a trading pair's parameters.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct PairUpdate {
     pub base_denom: Denom,
     pub quote_denom: Denom,
@@ -38,7 +38,7 @@ pub type Price = Udec128_24; // TODO: use this alias throughout the types and de
 ///
 /// Resting order book is defined as the order book after the last block's auction,
 /// before any of the order creation or cancelation of this block has been applied.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 #[derive(Default)]
 pub struct RestingOrderBookState {
     /// The highest available bid price after the last block's auction.
@@ -1,6 +1,6 @@
 use grug::{PrimaryKey, RawKey, StdError, StdResult};
 
-#[grug::derive(Borsh, Serde)]
+#[grug::derive(Borsh, Serde, Schema)]
 #[derive(Copy, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
 pub enum TimeInForce {
@@ -1,13 +1,13 @@
 use {super::Remote, grug::Uint128, hyperlane_types::Addr32};
 
 /// Each bridge contract must implement this execute API.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     // NOTE: Bridge contract must ensure only the Gateway contract can call this.
     Bridge(BridgeMsg),
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum BridgeMsg {
     TransferRemote {
         remote: Remote,
@@ -4,21 +4,21 @@ use {
     std::collections::{BTreeMap, BTreeSet},
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct WithdrawalFee {
     pub denom: Denom,
     pub remote: Remote,
     pub fee: Uint128,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub routes: BTreeSet<(Origin, Addr, Remote)>,
     pub rate_limits: BTreeMap<Denom, RateLimit>,
     pub withdrawal_fees: Vec<WithdrawalFee>,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Create new routes.
     ///
@@ -45,7 +45,7 @@ pub enum ExecuteMsg {
     TransferRemote { remote: Remote, recipient: Addr32 },
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Given a `(bridge, remote)` tuple, find the alloyed denom it belongs to.
     #[returns(Option<Denom>)]
@@ -4,7 +4,7 @@ use {
     std::slice,
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[derive(PartialOrd, Ord)]
 pub enum Origin {
     /// Token is issued natively on Dango.
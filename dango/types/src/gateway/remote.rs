@@ -3,7 +3,7 @@ use {
     hyperlane_types::{Addr32, mailbox::Domain},
 };
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 #[derive(Copy, PartialOrd, Ord)]
 pub enum Remote {
     /// Indicates the token was received through Hyperlane's Warp protocol.
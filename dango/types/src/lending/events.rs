@@ -4,7 +4,7 @@ use {
 };
 
 /// An event indicating a user has borrowed coins from the lending contract.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("borrowed")]
 pub struct Borrowed {
     pub user: Addr,
@@ -12,7 +12,7 @@ pub struct Borrowed {
 }
 
 /// An event indicating a user has repaid coins to the lending contract.
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("repaid")]
 pub struct Repaid {
     pub user: Addr,
@@ -8,7 +8,7 @@ use grug::{
 /// This is based on Aave's interest rate model. The first slope is applied when
 /// the utilization is below the optimal utilization rate, and the second slope
 /// is applied when the utilization is above the optimal utilization rate.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct InterestRateModel {
     /// The base interest rate. This is the interest rate that is applied
     /// when the utilization is 0%.
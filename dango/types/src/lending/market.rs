@@ -9,7 +9,7 @@ use {
 pub const SECONDS_PER_YEAR: u128 = 31536000;
 
 /// Configurations and state of a market.
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Market {
     /// The LP token denom that is minted when coins are deposited on the supply
     /// side.
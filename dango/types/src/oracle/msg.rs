@@ -1,25 +1,31 @@
 use {
-    crate::oracle::{PrecisionedPrice, PriceSource},
+    crate::oracle::{PrecisionedPrice, PrecisionlessPrice, PriceSource},
     grug::{Binary, Denom, NonEmpty},
-    pyth_types::{GuardianSet, GuardianSetIndex},
+    pyth_types::{GuardianSet, GuardianSetIndex, PythId},
     std::collections::BTreeMap,
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub guardian_sets: BTreeMap<GuardianSetIndex, GuardianSet>,
     pub price_sources: BTreeMap<Denom, PriceSource>,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Set the price sources for the given denoms.
     RegisterPriceSources(BTreeMap<Denom, PriceSource>),
     /// Submit price data from Pyth Network.
     FeedPrices(NonEmpty<Vec<Binary>>),
+    /// Submit the median Pyth prices observed by the validators, aggregated
+    /// from their vote extensions.
+    ///
+    /// Can only be sent by the oracle itself, in a transaction inserted by the
+    /// block proposer during ABCI++ `PrepareProposal`.
+    FeedAggregatedPrices(NonEmpty<BTreeMap<PythId, PrecisionlessPrice>>),
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the price of the given denom.
     #[returns(PrecisionedPrice)]
@@ -13,7 +13,7 @@ pub type PrecisionlessPrice = Price<Undefined<Precision>>;
 
 pub type PrecisionedPrice = Price<Defined<Precision>>;
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Price<P>
 where
     P: MaybeDefined<Precision>,
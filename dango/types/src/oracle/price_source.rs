@@ -4,7 +4,7 @@ use {
     pyth_types::PythId,
 };
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub enum PriceSource {
     /// A price source that uses a fixed price. For testing purposes only.
     Fixed {
@@ -26,6 +26,19 @@ pub enum PriceSource {
         /// is 10^6 uatom, so the precision is 6.
         precision: Precision,
     },
+    /// A price source that uses the median of Pyth prices as observed by the
+    /// validators, submitted via ABCI++ vote extensions.
+    ///
+    /// Unlike `Pyth`, this doesn't rely on the VAAs fetched by whichever
+    /// validator happens to propose the block.
+    PythAggregate {
+        /// The Pyth ID of the price.
+        id: PythId,
+        /// The number of decimal places of the token that is used to convert
+        /// the price from its smallest unit to a humanized form. E.g. 1 ATOM
+        /// is 10^6 uatom, so the precision is 6.
+        precision: Precision,
+    },
     /// A price source for an LP token of the lending pool.
     LendingLiquidity,
 }
@@ -1,16 +1,44 @@
 use {
-    grug::{Addr, Coins, Denom, Udec128},
+    crate::auth::Metadata,
+    grug::{
+        Addr, Bounded, Coins, Denom, JsonDeExt, NumberConst, Tx, Udec128, Uint128,
+        ZeroExclusiveOneExclusive,
+    },
     std::collections::BTreeMap,
 };
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Config {
     pub fee_denom: Denom,
     /// Units of the fee token for each unit of gas consumed.
+    ///
+    /// If the dynamic fee is enabled, this is the minimum of the base fee.
     pub fee_rate: Udec128,
+    /// Parameters for adjusting the base fee according to how much gas each
+    /// block consumes. If `None`, the base fee is always the fee rate.
+    pub dynamic_fee: Option<DynamicFeeConfig>,
+}
+
+/// Parameters of an [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559)-style
+/// dynamic base fee.
+///
+/// After each block, the base fee is raised if the block consumed more than the
+/// target amount of gas, or lowered if it consumed less, in proportion to the
+/// difference.
+#[grug::derive(Serde, Borsh, Schema)]
+pub struct DynamicFeeConfig {
+    /// The amount of gas each block is expected to consume.
+    pub target_gas: u64,
+    /// The maximum relative change of the base fee from one block to the next.
+    ///
+    /// This is reached when a block consumes either zero, or at least twice the
+    /// target amount of gas.
+    pub max_change_rate: Bounded<Udec128, ZeroExclusiveOneExclusive>,
+    /// The maximum of the base fee.
+    pub max_base_fee: Udec128,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[derive(Copy)]
 pub enum FeeType {
     /// Gas Fee.
@@ -34,12 +62,12 @@ impl FeeType {
     }
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub config: Config,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Update the fee configurations.
     /// Can only be called by the chain's owner.
@@ -50,16 +78,36 @@ pub enum ExecuteMsg {
         ty: FeeType,
         payments: BTreeMap<Addr, Coins>,
     },
+    /// Set the maximum amount of gas fee the sender is willing to pay on behalf
+    /// of others, as the fee payer of their transactions.
+    ///
+    /// The limit is reduced by the amount of each fee paid. Set it to zero to
+    /// stop sponsoring transactions.
+    SetSponsorLimit { limit: Uint128 },
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the fee configurations.
     #[returns(Config)]
     Config {},
+    /// Query the base fee, in units of the fee token per unit of gas, that
+    /// transactions included in the next block are charged, excluding tips.
+    #[returns(Udec128)]
+    BaseFee {},
+    /// Query the remaining amount of gas fee a sponsor is willing to pay on
+    /// behalf of others.
+    #[returns(Uint128)]
+    SponsorLimit { sponsor: Addr },
+    /// Enumerate the remaining spend limits of all sponsors.
+    #[returns(BTreeMap<Addr, Uint128>)]
+    SponsorLimits {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("receive_fee")]
 pub struct ReceiveFee {
     /// The Dango smart contract that handled this fee.
@@ -69,3 +117,22 @@ pub struct ReceiveFee {
     pub ty: FeeType,
     pub amount: Coins,
 }
+
+/// Return the tip, in units of the fee token per unit of gas, that the payer of
+/// the transaction's fee is willing to pay on top of the base fee.
+///
+/// The tip is found in the metadata of the fee payer if there is one, otherwise
+/// of the sender. Transactions whose metadata can't be deserialized, such as
+/// those sent by the oracle, don't have a tip.
+pub fn tip_of(tx: &Tx) -> Udec128 {
+    let data = tx
+        .fee_payer
+        .as_ref()
+        .map_or(&tx.data, |fee_payer| &fee_payer.data);
+
+    data.clone()
+        .deserialize_json::<Metadata>()
+        .ok()
+        .and_then(|metadata| metadata.tip)
+        .unwrap_or(Udec128::ZERO)
+}
@@ -4,19 +4,19 @@ use {
     std::collections::BTreeMap,
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct PositionResponse {
     pub position: Position,
     pub claimable: Uint128,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub unlocking_cliff: Duration,
     pub unlocking_period: Duration,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Create a vesting position for a user with the given schedule.
     ///
@@ -33,7 +33,7 @@ pub enum ExecuteMsg {
     Claim {},
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query a single vesting position by user address.
     #[returns(PositionResponse)]
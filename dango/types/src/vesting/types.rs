@@ -5,7 +5,7 @@ use {
     std::cmp::min,
 };
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Schedule {
     pub start_time: Timestamp,
     pub cliff: Duration,
@@ -33,7 +33,7 @@ impl Schedule {
     }
 }
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub enum VestingStatus {
     /// Position is actively being vested.
     Active(Schedule),
@@ -53,7 +53,7 @@ impl VestingStatus {
     }
 }
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Position {
     pub vesting_status: VestingStatus,
     pub total: Uint128,
@@ -4,13 +4,13 @@ use {
     hyperlane_types::recipients::{RecipientMsg, RecipientQuery, RecipientQueryResponse},
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     /// Address of the mailbox contract.
     pub mailbox: Addr,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Required Hyperlane recipient interface.
     Recipient(RecipientMsg),
@@ -18,7 +18,7 @@ pub enum ExecuteMsg {
     Bridge(BridgeMsg),
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the address of the mailbox contract.
     #[returns(Addr)]
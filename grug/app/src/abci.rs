@@ -1,10 +1,13 @@
 use {
-    crate::{App, AppError, AppResult, Db, Indexer, ProposalPreparer, Vm},
+    crate::{
+        App, AppError, AppResult, ApplySnapshotChunkOutcome, CommitValidator, Db, Indexer,
+        LastCommit, OfferSnapshotOutcome, ProposalPreparer, Snapshot, Vm, VoteExtension,
+    },
+    borsh::BorshDeserialize,
     grug_types::{
-        BlockInfo, CheckTxOutcome, Duration, GENESIS_BLOCK_HASH, GenericResult, Hash256, Inner,
-        JsonSerExt, TxOutcome,
+        Binary, BlockInfo, BorshSerExt, CheckTxOutcome, Duration, GENESIS_BLOCK_HASH,
+        GenericResult, Hash256, Inner, JsonSerExt, StdError, TxOutcome,
     },
-    prost::bytes::Bytes,
     std::{
         any::type_name,
         future::Future,
@@ -14,7 +17,11 @@ use {
     },
     tendermint::{
         AppHash, Hash, Time,
-        abci::{self, Code, request, response, types::ExecTxResult},
+        abci::{
+            self, Code, request,
+            response::{self, ApplySnapshotChunkResult},
+            types::ExecTxResult,
+        },
         block::Height,
         merkle::proof::{ProofOp, ProofOps},
         v0_38::abci::{Request, Response},
@@ -65,21 +72,16 @@ where
                 let res = self.tower_prepare_proposal(req)?;
                 Ok(Response::PrepareProposal(res))
             },
-            Request::ProcessProposal(_) => {
-                // Always accept.
-                let res = response::ProcessProposal::Accept;
+            Request::ProcessProposal(req) => {
+                let res = self.tower_process_proposal(req)?;
                 Ok(Response::ProcessProposal(res))
             },
-            Request::ExtendVote(_) => {
-                // Vote extension isn't supported yet. Do nothing.
-                let res = response::ExtendVote {
-                    vote_extension: Bytes::default(),
-                };
+            Request::ExtendVote(req) => {
+                let res = self.tower_extend_vote(req)?;
                 Ok(Response::ExtendVote(res))
             },
-            Request::VerifyVoteExtension(_) => {
-                // Always accept.
-                let res = response::VerifyVoteExtension::Accept;
+            Request::VerifyVoteExtension(req) => {
+                let res = self.tower_verify_vote_extension(req)?;
                 Ok(Response::VerifyVoteExtension(res))
             },
             Request::FinalizeBlock(req) => {
@@ -108,10 +110,22 @@ where
             },
 
             // ---------------------- state sync methods -----------------------
-            Request::ListSnapshots => Ok(Response::ListSnapshots(Default::default())),
-            Request::OfferSnapshot(_) => Ok(Response::OfferSnapshot(Default::default())),
-            Request::LoadSnapshotChunk(_) => Ok(Response::LoadSnapshotChunk(Default::default())),
-            Request::ApplySnapshotChunk(_) => Ok(Response::ApplySnapshotChunk(Default::default())),
+            Request::ListSnapshots => {
+                let res = self.tower_list_snapshots()?;
+                Ok(Response::ListSnapshots(res))
+            },
+            Request::OfferSnapshot(req) => {
+                let res = self.tower_offer_snapshot(req)?;
+                Ok(Response::OfferSnapshot(res))
+            },
+            Request::LoadSnapshotChunk(req) => {
+                let res = self.tower_load_snapshot_chunk(req)?;
+                Ok(Response::LoadSnapshotChunk(res))
+            },
+            Request::ApplySnapshotChunk(req) => {
+                let res = self.tower_apply_snapshot_chunk(req)?;
+                Ok(Response::ApplySnapshotChunk(res))
+            },
 
             // ------------------------- other methods -------------------------
             Request::Echo(req) => {
@@ -124,6 +138,42 @@ where
         }
     }
 
+    fn tower_apply_snapshot_chunk(
+        &self,
+        req: request::ApplySnapshotChunk,
+    ) -> AppResult<response::ApplySnapshotChunk> {
+        let res = match self.do_apply_snapshot_chunk(req.index, req.chunk.to_vec())? {
+            ApplySnapshotChunkOutcome::Accept => response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Accept,
+                ..Default::default()
+            },
+            ApplySnapshotChunkOutcome::Abort => response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Abort,
+                ..Default::default()
+            },
+            // The chunk doesn't match its hash. Refetch it, and ban the peer
+            // who sent it to us.
+            ApplySnapshotChunkOutcome::Retry => response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![req.index],
+                reject_senders: vec![req.sender],
+            },
+            // The chunk came out of order, which isn't the sender's fault.
+            // Fetch the one we expect, without banning anyone.
+            ApplySnapshotChunkOutcome::OutOfOrder { expected } => response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![expected],
+                ..Default::default()
+            },
+            ApplySnapshotChunkOutcome::RejectSnapshot => response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::RejectSnapshot,
+                ..Default::default()
+            },
+        };
+
+        Ok(res)
+    }
+
     fn tower_check_tx(&self, req: request::CheckTx) -> AppResult<response::CheckTx> {
         // Note: We don't have separate logics for `CheckTyType::New` vs `Recheck`.
         let res = match self.do_check_tx_raw(&req.tx) {
@@ -177,6 +227,12 @@ where
         }
     }
 
+    fn tower_extend_vote(&self, req: request::ExtendVote) -> AppResult<response::ExtendVote> {
+        let vote_extension = self.do_extend_vote(req.height.value());
+
+        Ok(response::ExtendVote { vote_extension })
+    }
+
     fn tower_finalize_block(
         &self,
         req: request::FinalizeBlock,
@@ -191,7 +247,7 @@ where
                     .map(into_tm_tx_result)
                     .collect::<AppResult<_>>()?;
 
-                let cron_events = outcome
+                let mut events = outcome
                     .cron_outcomes
                     .into_iter()
                     .enumerate()
@@ -207,12 +263,35 @@ where
                             )],
                         })
                     })
-                    .collect::<AppResult<_>>()?;
+                    .collect::<AppResult<Vec<_>>>()?;
+
+                for (id, scheduled) in outcome.scheduled_outcomes.into_iter().enumerate() {
+                    events.push(abci::Event {
+                        kind: format!("scheduled-{id}"),
+                        attributes: vec![abci::EventAttribute::V037(abci::v0_37::EventAttribute {
+                            key: format!("scheduled-{id}"),
+                            value: scheduled.to_json_string()?,
+                            index: false,
+                        })],
+                    });
+                }
+
+                for (id, receive_event) in outcome.receive_event_outcomes.into_iter().enumerate() {
+                    events.push(abci::Event {
+                        kind: format!("receive-event-{id}"),
+                        attributes: vec![abci::EventAttribute::V037(abci::v0_37::EventAttribute {
+                            key: format!("receive-event-{id}"),
+                            value: receive_event.to_json_string()?,
+                            index: false,
+                        })],
+                    });
+                }
 
                 Ok(response::FinalizeBlock {
                     app_hash: into_tm_app_hash(outcome.app_hash),
-                    // `events` field is used for cron events.
-                    events: cron_events,
+                    // `events` field is used for cron, scheduled message, and
+                    // receive event events.
+                    events,
                     tx_results,
                     // We haven't implemented any mechanism to alter the
                     // validator set or consensus params yet.
@@ -231,9 +310,7 @@ where
             data: env!("CARGO_PKG_NAME").into(),
             version: env!("CARGO_PKG_VERSION").into(),
             app_version: 1,
-            last_block_height: last_block_height
-                .try_into()
-                .expect("block height exceeds i64"),
+            last_block_height: into_tm_height(last_block_height)?,
             last_block_app_hash: into_tm_app_hash(last_block_version),
         })
     }
@@ -241,7 +318,19 @@ where
     fn tower_init_chain(&self, req: request::InitChain) -> AppResult<response::InitChain> {
         let block = from_tm_block(0, req.time, None);
 
-        match self.do_init_chain_raw(req.chain_id, block, &req.app_state_bytes) {
+        // Only Ed25519 consensus keys are supported for verifying vote
+        // extensions. Validators with other keys can still take part in
+        // consensus, but their vote extensions are ignored.
+        let validators = req
+            .validators
+            .iter()
+            .filter_map(|val| {
+                let pubkey = val.pub_key.ed25519()?;
+                pubkey.as_bytes().try_into().ok()
+            })
+            .collect::<Vec<[u8; 32]>>();
+
+        match self.do_init_chain_raw(req.chain_id, block, &validators, &req.app_state_bytes) {
             Ok(app_hash) => Ok(response::InitChain {
                 consensus_params: Some(req.consensus_params),
                 validators: req.validators,
@@ -251,16 +340,109 @@ where
         }
     }
 
+    fn tower_list_snapshots(&self) -> AppResult<response::ListSnapshots> {
+        let snapshots = self
+            .do_list_snapshots()?
+            .into_iter()
+            .map(into_tm_snapshot)
+            .collect::<AppResult<_>>()?;
+
+        Ok(response::ListSnapshots { snapshots })
+    }
+
+    fn tower_load_snapshot_chunk(
+        &self,
+        req: request::LoadSnapshotChunk,
+    ) -> AppResult<response::LoadSnapshotChunk> {
+        let chunk = self
+            .do_load_snapshot_chunk(req.height.value(), req.format, req.chunk)?
+            .unwrap_or_default();
+
+        Ok(response::LoadSnapshotChunk {
+            chunk: chunk.into(),
+        })
+    }
+
+    fn tower_offer_snapshot(
+        &self,
+        req: request::OfferSnapshot,
+    ) -> AppResult<response::OfferSnapshot> {
+        // If the snapshot's metadata is malformed, or doesn't match the hash
+        // and number of chunks it claims, reject it.
+        let Some(snapshot) = from_tm_snapshot(req.snapshot) else {
+            return Ok(response::OfferSnapshot::Reject);
+        };
+
+        let Ok(app_hash) = Hash256::try_from(req.app_hash.as_bytes()) else {
+            return Ok(response::OfferSnapshot::Reject);
+        };
+
+        let res = match self.do_offer_snapshot(snapshot, app_hash)? {
+            OfferSnapshotOutcome::Accept => response::OfferSnapshot::Accept,
+            OfferSnapshotOutcome::Abort => response::OfferSnapshot::Abort,
+            OfferSnapshotOutcome::Reject => response::OfferSnapshot::Reject,
+            OfferSnapshotOutcome::RejectFormat => response::OfferSnapshot::RejectFormat,
+        };
+
+        Ok(res)
+    }
+
     fn tower_prepare_proposal(
         &self,
         req: request::PrepareProposal,
     ) -> AppResult<response::PrepareProposal> {
         let max_tx_bytes = req.max_tx_bytes.try_into().unwrap_or(0);
-        let txs = self.do_prepare_proposal(req.txs.clone(), max_tx_bytes);
+        let vote_extensions = req
+            .local_last_commit
+            .map(|commit| {
+                commit
+                    .votes
+                    .into_iter()
+                    .map(|vote| VoteExtension {
+                        validator: vote.validator.address,
+                        power: vote.validator.power.value(),
+                        data: vote.vote_extension,
+                        signature: vote
+                            .extension_signature
+                            .map(|sig| sig.into_bytes().into())
+                            .unwrap_or_default(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let txs = self.do_prepare_proposal(req.txs, &vote_extensions, max_tx_bytes);
 
         Ok(response::PrepareProposal { txs })
     }
 
+    fn tower_process_proposal(
+        &self,
+        req: request::ProcessProposal,
+    ) -> AppResult<response::ProcessProposal> {
+        let last_commit = req
+            .proposed_last_commit
+            .map(|commit| LastCommit {
+                round: commit.round.value(),
+                validators: commit
+                    .votes
+                    .into_iter()
+                    .map(|vote| CommitValidator {
+                        address: vote.validator.address,
+                        power: vote.validator.power.value(),
+                    })
+                    .collect(),
+            })
+            .unwrap_or_default();
+
+        // Failing to evaluate the proposal locally is returned as an error,
+        // rather than casting a vote against a possibly valid proposal.
+        if self.do_process_proposal(&req.txs, &last_commit)? {
+            Ok(response::ProcessProposal::Accept)
+        } else {
+            Ok(response::ProcessProposal::Reject)
+        }
+    }
+
     fn tower_query(&self, req: request::Query) -> AppResult<response::Query> {
         let res = match req.path.as_str() {
             "/app" => match self.do_query_app_raw(&req.data, req.height.value(), req.prove) {
@@ -289,19 +471,24 @@ where
                     ..Default::default()
                 },
             },
-            "/store" => match self.do_query_store(&req.data, req.height.value(), req.prove) {
-                Ok((value, proof)) => {
-                    let proof = proof.map(|proof| ProofOps {
+            "/store" => match self
+                .do_query_store(&req.data, req.height.value(), req.prove)
+                .and_then(|res| Ok((into_tm_height(res.height)?, res)))
+            {
+                Ok((height, res)) => {
+                    let proof = res.proof.map(|proof| ProofOps {
                         ops: vec![ProofOp {
                             field_type: type_name::<DB::Proof>().into(),
                             key: req.data.into(),
-                            data: proof,
+                            data: proof.into_inner(),
                         }],
                     });
                     response::Query {
                         code: Code::Ok,
-                        value: value.unwrap_or_default().into(),
-                        height: req.height,
+                        value: res.value.map(Binary::into_inner).unwrap_or_default().into(),
+                        // Report the height at which the query was actually
+                        // performed, in case the request didn't specify one.
+                        height,
                         proof,
                         ..Default::default()
                     }
@@ -313,16 +500,42 @@ where
                     ..Default::default()
                 },
             },
+            "/store_scan" => match self.do_query_store_scan_raw(&req.data, req.height.value()) {
+                Ok(res) => response::Query {
+                    code: Code::Ok,
+                    value: res.into(),
+                    ..Default::default()
+                },
+                Err(err) => response::Query {
+                    code: into_tm_code_error(1),
+                    codespace: "store_scan".into(),
+                    log: err.to_string(),
+                    ..Default::default()
+                },
+            },
             unknown => response::Query {
                 code: into_tm_code_error(1),
                 codespace: "app".into(),
-                log: format!("unknown path `{unknown}`; must be `/app`, `/simulate`, or `/store`"),
+                log: format!(
+                    "unknown path `{unknown}`; must be `/app`, `/simulate`, `/store`, or `/store_scan`"
+                ),
                 ..Default::default()
             },
         };
 
         Ok(res)
     }
+
+    fn tower_verify_vote_extension(
+        &self,
+        req: request::VerifyVoteExtension,
+    ) -> AppResult<response::VerifyVoteExtension> {
+        if self.do_verify_vote_extension(&req.vote_extension) {
+            Ok(response::VerifyVoteExtension::Accept)
+        } else {
+            Ok(response::VerifyVoteExtension::Reject)
+        }
+    }
 }
 
 fn from_tm_block(height: u64, time: Time, hash: Option<Hash>) -> BlockInfo {
@@ -340,6 +553,45 @@ fn from_tm_hash(bytes: Hash) -> Hash256 {
     }
 }
 
+fn from_tm_snapshot(snapshot: abci::types::Snapshot) -> Option<Snapshot> {
+    let chunk_hashes = Vec::<Hash256>::try_from_slice(&snapshot.metadata).ok()?;
+    let snapshot_hash = snapshot.hash;
+    let chunks = snapshot.chunks;
+    let snapshot = Snapshot {
+        height: snapshot.height.value(),
+        format: snapshot.format,
+        chunk_hashes,
+    };
+
+    if snapshot.hash().as_ref() != snapshot_hash.as_ref() {
+        return None;
+    }
+
+    // The number of chunks isn't covered by the hash, so check it separately.
+    if snapshot.chunks().ok()? != chunks {
+        return None;
+    }
+
+    Some(snapshot)
+}
+
+fn into_tm_snapshot(snapshot: Snapshot) -> AppResult<abci::types::Snapshot> {
+    Ok(abci::types::Snapshot {
+        height: into_tm_height(snapshot.height)?,
+        format: snapshot.format,
+        chunks: snapshot.chunks()?,
+        hash: snapshot.hash().into_inner().to_vec().into(),
+        // Chunk hashes are transmitted as the metadata.
+        metadata: snapshot.chunk_hashes.to_borsh_vec()?.into(),
+    })
+}
+
+fn into_tm_height(height: u64) -> AppResult<Height> {
+    height
+        .try_into()
+        .map_err(|_| StdError::out_of_range(height, ">", i64::MAX as u64).into())
+}
+
 fn into_tm_tx_result(outcome: TxOutcome) -> AppResult<ExecTxResult> {
     let (code, codespace) = if outcome.result.is_ok() {
         (Code::Ok, "")
@@ -1,3 +1,5 @@
+#[cfg(feature = "parallel")]
+use crate::parallel::process_txs_parallel;
 #[cfg(all(feature = "abci", feature = "tracing"))]
 use data_encoding::BASE64;
 #[cfg(any(feature = "abci", feature = "tracing"))]
@@ -6,25 +8,33 @@ use grug_types::JsonSerExt;
 use grug_types::{HashExt, JsonDeExt};
 use {
     crate::{
-        APP_CONFIG, AppError, AppResult, CHAIN_ID, CODES, CONFIG, Db, EventResult, GasTracker,
-        Indexer, LAST_FINALIZED_BLOCK, NEXT_CRONJOBS, NaiveProposalPreparer, NaiveQuerier,
-        NullIndexer, ProposalPreparer, QuerierProviderImpl, TraceOption, UpgradeHandler, Vm,
-        catch_and_push_event, catch_and_update_event, do_authenticate, do_backrun, do_configure,
-        do_cron_execute, do_execute, do_finalize_fee, do_instantiate, do_migrate, do_transfer,
-        do_upload, do_withhold_fee, query_app_config, query_balance, query_balances, query_code,
-        query_codes, query_config, query_contract, query_contracts, query_status, query_supplies,
-        query_supply, query_wasm_raw, query_wasm_scan, query_wasm_smart,
+        APP_CONFIG, AppError, AppResult, ApplySnapshotChunkOutcome, CHAIN_ID, CODES, CONFIG,
+        CONTRACT_NAMESPACE, CONTRACTS, Db, EventResult, GasTracker, Indexer, LAST_FINALIZED_BLOCK,
+        LastCommit, NEXT_CRONJOBS, NaiveProposalPreparer, NaiveQuerier, NullIndexer,
+        OfferSnapshotOutcome, ProcessProposalOutcome, ProposalPreparer, QuerierProvider,
+        QuerierProviderImpl, SUBSCRIPTIONS, Snapshot, SnapshotConfig, StorageProvider, TraceOption,
+        Tracer, UpgradeHandler, Vm, VoteExtension, VoteExtensionVerifier, catch_and_push_event,
+        catch_and_update_event, charge_subscription, do_authenticate, do_backrun, do_configure,
+        do_cron_execute, do_execute, do_finalize_fee, do_instantiate, do_migrate, do_receive_event,
+        do_schedule, do_subscribe, do_transfer, do_unschedule, do_unsubscribe, do_upload,
+        do_withhold_fee, find_subscribed_events, query_app_config, query_balance, query_balances,
+        query_code, query_codes, query_config, query_contract, query_contracts, query_status,
+        query_store_scan, query_supplies, query_supply, query_wasm_raw, query_wasm_scan,
+        query_wasm_smart, save_validators, take_due_scheduled_msgs,
     },
     grug_storage::PrefixBound,
     grug_types::{
-        Addr, AuthMode, Block, BlockInfo, BlockOutcome, BorshSerExt, Buffer, CheckTxEvents,
-        CheckTxOutcome, CodeStatus, CommitmentStatus, CronOutcome, Duration, Event, EventStatus,
-        GENESIS_SENDER, GenericResult, GenericResultExt, GenesisState, Hash256, Json, Message,
-        MsgsAndBackrunEvents, Order, Permission, QuerierWrapper, Query, QueryResponse, Shared,
-        StdResult, Storage, Timestamp, Tx, TxEvents, TxOutcome, UnsignedTx,
+        Addr, AuthMode, Binary, Block, BlockInfo, BlockOutcome, BorshSerExt, Buffer, CheckTxEvents,
+        CheckTxOutcome, Code, CodeStatus, Coins, CommitmentStatus, CronOutcome, Duration, Event,
+        EventStatus, EvtAuthenticate, ExecutionTrace, FeePayer, GENESIS_SENDER, GasCosts,
+        GenericResult, GenericResultExt, GenesisState, Hash256, Json, Message,
+        MsgsAndBackrunEvents, Order, Permission, QuerierWrapper, Query, QueryResponse,
+        QueryStoreResponse, QueryStoreScanRequest, ReceiveEventOutcome, ScheduledOutcome,
+        SearchEvent, Shared, StateOverrides, StdError, StdResult, Storage, Timestamp, Tx, TxEvents,
+        TxOutcome, UnsignedTx,
     },
     prost::bytes::Bytes,
-    std::sync::Arc,
+    std::{collections::BTreeMap, sync::Arc},
 };
 
 /// The ABCI application.
@@ -51,8 +61,27 @@ pub struct App<DB, VM, PP = NaiveProposalPreparer, ID = NullIndexer> {
     /// <https://github.com/CosmWasm/wasmd/blob/v0.51.0/x/wasm/types/types.go#L322-L323>
     query_gas_limit: u64,
     upgrade_handler: Arc<Option<UpgradeHandler<VM>>>,
+    /// If provided, take a state sync snapshot periodically after committing
+    /// a block.
+    snapshot_cfg: Option<SnapshotConfig>,
+    /// Whether to process the transactions in a block optimistically in
+    /// parallel. Disabled by default.
+    ///
+    /// Either way, the block outcome and the app hash are the same.
+    #[cfg(feature = "parallel")]
+    pub parallel_execution: bool,
+    /// How to apply balance overrides when simulating transactions. If not
+    /// provided, simulations with balance overrides are rejected.
+    pub balance_overrider: Option<BalanceOverrider>,
 }
 
+/// Give coins to an account by writing directly to the bank contract's
+/// storage, which is provided to this function.
+///
+/// Balance overrides for simulating transactions are applied this way, as how
+/// balances are stored is up to the bank contract.
+pub type BalanceOverrider = fn(&mut dyn Storage, Addr, &Coins) -> StdResult<()>;
+
 impl<DB, VM, PP, ID> App<DB, VM, PP, ID> {
     pub fn new(
         db: DB,
@@ -61,6 +90,7 @@ impl<DB, VM, PP, ID> App<DB, VM, PP, ID> {
         indexer: ID,
         query_gas_limit: u64,
         upgrade_handler: Option<UpgradeHandler<VM>>,
+        snapshot_cfg: Option<SnapshotConfig>,
     ) -> Self {
         Self {
             db,
@@ -69,6 +99,10 @@ impl<DB, VM, PP, ID> App<DB, VM, PP, ID> {
             indexer,
             query_gas_limit,
             upgrade_handler: Arc::new(upgrade_handler),
+            snapshot_cfg,
+            #[cfg(feature = "parallel")]
+            parallel_execution: false,
+            balance_overrider: None,
         }
     }
 }
@@ -87,6 +121,10 @@ where
             indexer: NullIndexer,
             query_gas_limit: self.query_gas_limit,
             upgrade_handler: Arc::clone(&self.upgrade_handler),
+            snapshot_cfg: self.snapshot_cfg,
+            #[cfg(feature = "parallel")]
+            parallel_execution: self.parallel_execution,
+            balance_overrider: self.balance_overrider,
         }
     }
 }
@@ -103,6 +141,7 @@ where
         &self,
         chain_id: String,
         block: BlockInfo,
+        validators: &[[u8; 32]],
         genesis_state: GenesisState,
     ) -> AppResult<Hash256> {
         let mut buffer = Shared::new(Buffer::new(self.db.state_storage(None)?, None));
@@ -118,7 +157,8 @@ where
 
         // Create gas tracker for genesis.
         // During genesis, there is no gas limit.
-        let gas_tracker = GasTracker::new_limitless();
+        let gas_tracker =
+            GasTracker::new_limitless().with_costs(*genesis_state.config.gas_costs.clone());
 
         // Save the config and genesis block, so that they can be queried when
         // executing genesis messages.
@@ -126,6 +166,7 @@ where
         LAST_FINALIZED_BLOCK.save(&mut buffer, &block)?;
         CONFIG.save(&mut buffer, &genesis_state.config)?;
         APP_CONFIG.save(&mut buffer, &genesis_state.app_config)?;
+        save_validators(&mut buffer, validators)?;
 
         // Schedule cronjobs.
         for (contract, interval) in genesis_state.config.cronjobs {
@@ -189,10 +230,15 @@ where
         Ok(root_hash.unwrap())
     }
 
-    pub fn do_prepare_proposal(&self, txs: Vec<Bytes>, max_tx_bytes: usize) -> Vec<Bytes> {
+    pub fn do_prepare_proposal(
+        &self,
+        txs: Vec<Bytes>,
+        vote_extensions: &[VoteExtension],
+        max_tx_bytes: usize,
+    ) -> Vec<Bytes> {
         #[cfg_attr(not(feature = "tracing"), allow(clippy::unnecessary_lazy_evaluations))]
         let txs = self
-            ._do_prepare_proposal(txs.clone(), max_tx_bytes)
+            ._do_prepare_proposal(txs.clone(), vote_extensions, max_tx_bytes)
             .unwrap_or_else(|_err| {
                 #[cfg(feature = "tracing")]
                 tracing::error!(
@@ -205,24 +251,127 @@ where
 
         // Call naive proposal preparer to check the `max_tx_bytes`.
         NaiveProposalPreparer
-            .prepare_proposal(QuerierWrapper::new(&NaiveQuerier), txs, max_tx_bytes)
+            .prepare_proposal(QuerierWrapper::new(&NaiveQuerier), txs, &[], max_tx_bytes)
             .unwrap()
     }
 
     #[inline]
-    fn _do_prepare_proposal(&self, txs: Vec<Bytes>, max_tx_bytes: usize) -> AppResult<Vec<Bytes>> {
+    fn _do_prepare_proposal(
+        &self,
+        txs: Vec<Bytes>,
+        vote_extensions: &[VoteExtension],
+        max_tx_bytes: usize,
+    ) -> AppResult<Vec<Bytes>> {
+        let querier = self.latest_querier()?;
+
+        Ok(self.pp.prepare_proposal(
+            QuerierWrapper::new(&querier),
+            txs,
+            vote_extensions,
+            max_tx_bytes,
+        )?)
+    }
+
+    /// Return whether the proposed block should be accepted.
+    ///
+    /// Error if the proposal can't be evaluated due to a local failure. Such a
+    /// failure is not a reason to reject the proposal, which may well be valid.
+    pub fn do_process_proposal(&self, txs: &[Bytes], last_commit: &LastCommit) -> AppResult<bool> {
+        let querier = self.latest_querier()?;
+        let verifier = self.vote_extension_verifier(last_commit)?;
+
+        let outcome = self
+            .pp
+            .process_proposal(QuerierWrapper::new(&querier), txs, &verifier)
+            .inspect_err(|_err| {
+                #[cfg(feature = "tracing")]
+                tracing::error!(err = _err.to_string(), "Failed to process proposal");
+            })?;
+
+        match outcome {
+            ProcessProposalOutcome::Accept => Ok(true),
+            ProcessProposalOutcome::Reject { reason: _reason } => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(reason = _reason, "Rejected proposal");
+
+                Ok(false)
+            },
+        }
+    }
+
+    /// Return the data to be attached to this validator's vote for the block
+    /// at the given height.
+    ///
+    /// If the proposal preparer fails, the vote is not extended, rather than
+    /// not voting at all.
+    pub fn do_extend_vote(&self, height: u64) -> Bytes {
+        #[cfg_attr(not(feature = "tracing"), allow(clippy::unnecessary_lazy_evaluations))]
+        self._do_extend_vote(height).unwrap_or_else(|_err| {
+            #[cfg(feature = "tracing")]
+            tracing::error!(
+                height,
+                err = _err.to_string(),
+                "Failed to extend vote! Falling back to empty extension."
+            );
+
+            Bytes::new()
+        })
+    }
+
+    #[inline]
+    fn _do_extend_vote(&self, height: u64) -> AppResult<Bytes> {
+        let querier = self.latest_querier()?;
+
+        Ok(self.pp.extend_vote(QuerierWrapper::new(&querier), height)?)
+    }
+
+    /// Return whether the vote extension of another validator should be
+    /// accepted.
+    pub fn do_verify_vote_extension(&self, data: &[u8]) -> bool {
+        match self._do_verify_vote_extension(data) {
+            Ok(()) => true,
+            Err(_err) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(err = _err.to_string(), "Rejected vote extension");
+
+                false
+            },
+        }
+    }
+
+    #[inline]
+    fn _do_verify_vote_extension(&self, data: &[u8]) -> AppResult<()> {
+        let querier = self.latest_querier()?;
+
+        Ok(self
+            .pp
+            .verify_vote_extension(QuerierWrapper::new(&querier), data)?)
+    }
+
+    /// Create a verifier for the vote extensions on the last finalized block.
+    fn vote_extension_verifier(
+        &self,
+        last_commit: &LastCommit,
+    ) -> AppResult<VoteExtensionVerifier> {
+        let storage = self.db.state_storage(None)?;
+        let chain_id = CHAIN_ID.load(&storage)?;
+        let block = LAST_FINALIZED_BLOCK.load(&storage)?;
+
+        VoteExtensionVerifier::load(&storage, chain_id, block.height, last_commit)
+    }
+
+    /// Create a querier for the proposal preparer, based on the state of the
+    /// last finalized block.
+    fn latest_querier(&self) -> AppResult<Box<dyn QuerierProvider>> {
         let storage = self.db.state_storage(None)?;
         let block = LAST_FINALIZED_BLOCK.load(&storage)?;
-        let querier = QuerierProviderImpl::new_boxed(
+
+        Ok(QuerierProviderImpl::new_boxed(
             self.vm.clone(),
             Box::new(storage),
             GasTracker::new_limitless(),
             block,
-        );
-
-        Ok(self
-            .pp
-            .prepare_proposal(QuerierWrapper::new(&querier), txs, max_tx_bytes)?)
+        ))
     }
 
     // Finalize a block by performing the following actions in order:
@@ -230,9 +379,11 @@ where
     // 1. indexer `pre_indexing`
     // 2. execute transactions one by one
     // 3. perform cronjobs
-    // 4. remove orphaned nodes
-    // 5. flush (but not commit) state changes to DB
-    // 5. indexer `index_block`
+    // 4. perform scheduled messages that are due
+    // 5. notify subscribers of the events emitted in the above steps
+    // 6. remove orphaned nodes
+    // 7. flush (but not commit) state changes to DB
+    // 8. indexer `index_block`
     pub fn do_finalize_block(&self, block: Block) -> AppResult<BlockOutcome> {
         let mut buffer = Shared::new(Buffer::new(self.db.state_storage(None)?, None));
         let last_finalized_block = LAST_FINALIZED_BLOCK.load(&buffer)?;
@@ -271,29 +422,24 @@ where
         }
 
         let mut cron_outcomes = vec![];
-        let mut tx_outcomes = vec![];
+        let mut scheduled_outcomes = vec![];
+        let mut receive_event_outcomes = vec![];
 
         let mut indexer_ctx = crate::IndexerContext::new();
         self.indexer
             .pre_indexing(block.info.height, &mut indexer_ctx)?;
 
-        // Process transactions one-by-one.
-        #[cfg_attr(not(feature = "tracing"), allow(clippy::unused_enumerate_index))]
-        for (_idx, (tx, _)) in block.txs.clone().into_iter().enumerate() {
-            #[cfg(feature = "tracing")]
-            tracing::debug!(idx = _idx, "Processing transaction");
-
-            let tx_outcome = process_tx(
-                self.vm.clone(),
-                buffer.clone(),
-                block.info,
-                tx.clone(),
-                AuthMode::Finalize,
-                TraceOption::LOUD,
-            );
+        // Process the transactions, either one-by-one or optimistically in
+        // parallel.
+        #[cfg(feature = "parallel")]
+        let tx_outcomes = if self.parallel_execution {
+            process_txs_parallel(self.vm.clone(), buffer.clone(), block.info, &block.txs)?
+        } else {
+            process_txs(self.vm.clone(), buffer.clone(), block.info, &block.txs)?
+        };
 
-            tx_outcomes.push(tx_outcome);
-        }
+        #[cfg(not(feature = "parallel"))]
+        let tx_outcomes = process_txs(self.vm.clone(), buffer.clone(), block.info, &block.txs)?;
 
         let cfg = CONFIG.load(&buffer)?;
 
@@ -327,7 +473,7 @@ where
             );
 
             let cron_buffer = Shared::new(Buffer::new(buffer.clone(), None));
-            let cron_gas_tracker = GasTracker::new_limitless();
+            let cron_gas_tracker = GasTracker::new_limitless().with_costs(*cfg.gas_costs.clone());
             let next_time = block.info.timestamp + cfg.cronjobs[&contract];
 
             let cron_event = do_cron_execute(
@@ -357,6 +503,158 @@ where
             ));
         }
 
+        // Perform the scheduled messages that are due, in the order they were
+        // scheduled. Each of them is given its own gas limit, specified when
+        // it was scheduled, and already paid for by the sender. The total is
+        // bounded per block; messages beyond that are deferred.
+        #[cfg_attr(not(feature = "tracing"), allow(clippy::unused_enumerate_index))]
+        for (_idx, (id, scheduled)) in take_due_scheduled_msgs(
+            &mut buffer,
+            block.info,
+            cfg.end_block_limits.scheduled_gas_per_block,
+        )?
+        .into_iter()
+        .enumerate()
+        {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                idx = _idx,
+                id,
+                sender = scheduled.sender.to_string(),
+                "Performing scheduled message"
+            );
+
+            let scheduled_buffer = Shared::new(Buffer::new(buffer.clone(), None));
+            let scheduled_gas_tracker =
+                GasTracker::new_limited(scheduled.gas_limit).with_costs(*cfg.gas_costs.clone());
+
+            let execute_event = do_execute(
+                self.vm.clone(),
+                Box::new(scheduled_buffer.clone()),
+                scheduled_gas_tracker.clone(),
+                block.info,
+                0,
+                scheduled.sender,
+                scheduled.msg,
+                TraceOption::LOUD,
+            );
+
+            // Commit state changes if the execution was successful.
+            // Ignore if unsuccessful.
+            if execute_event.is_ok() {
+                scheduled_buffer.disassemble().commit();
+            }
+
+            scheduled_outcomes.push(ScheduledOutcome::new(
+                id,
+                scheduled.gas_limit,
+                scheduled_gas_tracker.used(),
+                execute_event.into_commitment_status(),
+            ));
+        }
+
+        // Notify contracts of the events they subscribed to, among the ones
+        // emitted by the transactions, cronjobs, and scheduled messages above.
+        // Each call is given the gas limit specified in the subscription, and
+        // is isolated the same way as cronjobs are. The gas limit is drawn
+        // from the gas budget the subscriber paid for when subscribing. Events
+        // emitted during these calls don't trigger further notifications.
+        if !SUBSCRIPTIONS.is_empty(&buffer) {
+            let events = tx_outcomes
+                .iter()
+                .flat_map(|outcome| outcome.events.clone().flat())
+                .chain(
+                    cron_outcomes
+                        .iter()
+                        .flat_map(|outcome| outcome.cron_event.clone().flat()),
+                )
+                .chain(
+                    scheduled_outcomes
+                        .iter()
+                        .flat_map(|outcome| outcome.execute_event.clone().flat()),
+                )
+                .collect();
+
+            let mut notification_gas = 0_u64;
+
+            #[cfg_attr(not(feature = "tracing"), allow(clippy::unused_enumerate_index))]
+            for (_idx, (subscriber, event)) in find_subscribed_events(&buffer, events)?
+                .into_iter()
+                .enumerate()
+            {
+                let emitter = event.contract;
+                let event_type = event.ty.clone();
+
+                // The subscription may have been canceled, or run out of gas
+                // budget, during an earlier notification in this block.
+                let Some(subscription) =
+                    SUBSCRIPTIONS.may_load(&buffer, (emitter, &event_type, subscriber))?
+                else {
+                    continue;
+                };
+
+                // Drop the remaining notifications once the gas limits of the
+                // ones performed in this block reach the maximum. Each one
+                // counts at least the minimum gas limit, since notifying costs
+                // work regardless of the gas the call is given.
+                notification_gas = notification_gas.saturating_add(
+                    subscription
+                        .gas_limit
+                        .max(cfg.end_block_limits.min_subscription_gas_limit)
+                        .max(1),
+                );
+
+                if notification_gas > cfg.end_block_limits.notification_gas_per_block {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        idx = _idx,
+                        "Notification gas per block exceeded; dropping remaining notifications"
+                    );
+
+                    break;
+                }
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    idx = _idx,
+                    subscriber = subscriber.to_string(),
+                    emitter = emitter.to_string(),
+                    event_type,
+                    "Notifying contract of event"
+                );
+
+                let receive_buffer = Shared::new(Buffer::new(buffer.clone(), None));
+                let receive_gas_tracker = GasTracker::new_limited(subscription.gas_limit)
+                    .with_costs(*cfg.gas_costs.clone());
+
+                let receive_event = do_receive_event(
+                    self.vm.clone(),
+                    Box::new(receive_buffer.clone()),
+                    receive_gas_tracker.clone(),
+                    block.info,
+                    subscriber,
+                    event,
+                    TraceOption::LOUD,
+                );
+
+                // Commit state changes if the call was successful.
+                // Ignore if unsuccessful.
+                if receive_event.is_ok() {
+                    receive_buffer.disassemble().commit();
+                }
+
+                // The gas limit is drawn from the budget regardless of whether
+                // the call was successful.
+                charge_subscription(&mut buffer, emitter, &event_type, subscriber)?;
+
+                receive_event_outcomes.push(ReceiveEventOutcome::new(
+                    subscription.gas_limit,
+                    receive_gas_tracker.used(),
+                    receive_event.into_commitment_status(),
+                ));
+            }
+        }
+
         // Remove orphaned codes (those that are not used by any contract) that
         // have been orphaned longer than the maximum age.
         if let Some(since) = block
@@ -416,6 +714,8 @@ where
             height: block.info.height,
             app_hash: app_hash.unwrap(),
             cron_outcomes,
+            scheduled_outcomes,
+            receive_event_outcomes,
             tx_outcomes,
         };
 
@@ -451,20 +751,89 @@ where
                     #[cfg(feature = "tracing")]
                     tracing::error!(err = %_err, "Error in `post_indexing`");
                 })?;
+
+            // Take a snapshot if we're at the scheduled interval.
+            //
+            // Failing to take a snapshot only affects peers that want to state
+            // sync from this node, so it isn't considered fatal. We log the
+            // error and move on.
+            if let Some(cfg) = self.snapshot_cfg {
+                if cfg.interval > 0 && block_height > 0 && block_height % cfg.interval == 0 {
+                    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+                    if let Err(err) = self.take_snapshot(block_height, cfg) {
+                        #[cfg(feature = "tracing")]
+                        tracing::error!(
+                            height = block_height,
+                            err = err.to_string(),
+                            "Failed to take snapshot"
+                        );
+                    }
+                }
+            }
         }
 
         Ok(())
     }
 
+    fn take_snapshot(&self, block_height: u64, cfg: SnapshotConfig) -> AppResult<()> {
+        Ok(self
+            .db
+            .create_snapshot_in_background(block_height, cfg.chunk_size, cfg.keep_recent)?)
+    }
+
+    pub fn do_list_snapshots(&self) -> AppResult<Vec<Snapshot>> {
+        Ok(self.db.list_snapshots()?)
+    }
+
+    pub fn do_load_snapshot_chunk(
+        &self,
+        height: u64,
+        format: u32,
+        index: u32,
+    ) -> AppResult<Option<Vec<u8>>> {
+        Ok(self.db.load_snapshot_chunk(height, format, index)?)
+    }
+
+    pub fn do_offer_snapshot(
+        &self,
+        snapshot: Snapshot,
+        app_hash: Hash256,
+    ) -> AppResult<OfferSnapshotOutcome> {
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            height = snapshot.height,
+            format = snapshot.format,
+            chunks = snapshot.chunk_hashes.len(),
+            "Offered snapshot"
+        );
+
+        Ok(self.db.offer_snapshot(snapshot, app_hash)?)
+    }
+
+    pub fn do_apply_snapshot_chunk(
+        &self,
+        index: u32,
+        chunk: Vec<u8>,
+    ) -> AppResult<ApplySnapshotChunkOutcome> {
+        let outcome = self.db.apply_snapshot_chunk(index, chunk)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(index, outcome = ?outcome, "Applied snapshot chunk");
+
+        Ok(outcome)
+    }
+
     // For `CheckTx`, we only do the first two steps of the transaction
     // processing flow:
-    // 1.`withhold_fee`, where the taxman makes sure the sender has sufficient
-    //   tokens to cover the tx fee;
-    // 2. `authenticate`, where the sender account authenticates the transaction.
+    // 1.`withhold_fee`, where the taxman makes sure the sender (or the fee
+    //   payer, if there is one) has sufficient tokens to cover the tx fee;
+    // 2. `authenticate`, where the sender account, followed by the fee payer
+    //   account if there is one, authenticates the transaction.
     pub fn do_check_tx(&self, tx: Tx) -> AppResult<CheckTxOutcome> {
         let buffer = Shared::new(Buffer::new(self.db.state_storage(None)?, None));
         let block = LAST_FINALIZED_BLOCK.load(&buffer)?;
-        let gas_tracker = GasTracker::new_limited(tx.gas_limit);
+        let cfg = CONFIG.load(&buffer)?;
+        let gas_tracker = GasTracker::new_limited(tx.gas_limit).with_costs(*cfg.gas_costs);
 
         let mut events = CheckTxEvents::new(
             do_withhold_fee(
@@ -487,21 +856,45 @@ where
             ));
         }
 
-        events.authenticate = do_authenticate(
-            self.vm.clone(),
-            Box::new(buffer),
-            gas_tracker.clone(),
-            block,
-            &tx,
-            AuthMode::Check,
-            TraceOption::MUTE,
-        )
+        events.authenticate = authenticate_sender(&tx, || {
+            do_authenticate(
+                self.vm.clone(),
+                Box::new(buffer.clone()),
+                gas_tracker.clone(),
+                block,
+                tx.sender,
+                &tx,
+                AuthMode::Check,
+                TraceOption::MUTE,
+            )
+        })
         .into_commitment_status();
 
-        let result = if let Err((_, err)) = events.authenticate.as_result() {
-            Err(err.to_string())
-        } else {
-            Ok(())
+        if let Err((_, err)) = events.authenticate.as_result() {
+            return Ok(new_check_tx_outcome(
+                gas_tracker,
+                Err(err.to_string()),
+                events,
+            ));
+        }
+
+        if let Some(fee_payer) = &tx.fee_payer {
+            events.authenticate_fee_payer = do_authenticate(
+                self.vm.clone(),
+                Box::new(buffer),
+                gas_tracker.clone(),
+                block,
+                fee_payer.address,
+                &tx,
+                AuthMode::Check,
+                TraceOption::MUTE,
+            )
+            .into_commitment_status();
+        }
+
+        let result = match events.authenticate_fee_payer.maybe_error() {
+            Some(err) => Err(err.to_string()),
+            None => Ok(()),
         };
 
         Ok(new_check_tx_outcome(gas_tracker, result, events))
@@ -546,11 +939,12 @@ where
         // Use the state storage at the given version to perform the query.
         let storage = self.db.state_storage(version)?;
         let block = LAST_FINALIZED_BLOCK.load(&storage)?;
+        let cfg = CONFIG.load(&storage)?;
 
         process_query(
             self.vm.clone(),
             Box::new(storage),
-            GasTracker::new_limited(self.query_gas_limit),
+            GasTracker::new_limited(self.query_gas_limit).with_costs(*cfg.gas_costs),
             block,
             0,
             req,
@@ -559,41 +953,107 @@ where
 
     /// Performs a raw query of the app's underlying key-value store.
     ///
-    /// Returns:
-    /// - the value corresponding to the given key; `None` if the key doesn't exist;
-    /// - the Merkle proof; `None` if a proof is not requested (`prove` is false).
+    /// Returns the value corresponding to the given key, and if requested, its
+    /// Merkle proof (Borsh-encoded) along with the root hash to verify it
+    /// against, as well as the height at which the query was performed.
     pub fn do_query_store(
         &self,
         key: &[u8],
         height: u64,
         prove: bool,
-    ) -> AppResult<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+    ) -> AppResult<QueryStoreResponse<Binary>> {
         let version = if height == 0 {
             // Height being zero means unspecified (Protobuf doesn't have a null
-            // type) in which case we use the latest version.
-            None
+            // type) in which case we use the latest version. Resolve it now, so
+            // that the value, the proof, and the root hash are all of the same
+            // version, even if a block is committed in the meantime.
+            self.db.latest_version()
         } else {
             Some(height)
         };
 
-        let proof = if prove {
-            Some(self.db.prove(key, version)?.to_borsh_vec()?)
+        let (proof, root_hash) = if prove {
+            let proof = self.db.prove(key, version)?.to_borsh_vec()?;
+            let root_hash = self.db.root_hash(version)?;
+            (Some(proof.into()), root_hash)
         } else {
-            None
+            (None, None)
         };
 
         let value = self.db.state_storage(version)?.read(key);
 
-        Ok((value, proof))
+        Ok(QueryStoreResponse {
+            height: version.unwrap_or(0),
+            value: value.map(Into::into),
+            proof,
+            root_hash,
+        })
+    }
+
+    /// Performs a raw scan of the app's underlying key-value store, including
+    /// both the app's own state and contracts' internal states.
+    ///
+    /// Like [`do_query_store`](Self::do_query_store), this is only served to
+    /// clients of the node, not to contracts.
+    pub fn do_query_store_scan(
+        &self,
+        req: QueryStoreScanRequest,
+        height: u64,
+    ) -> AppResult<BTreeMap<Binary, Binary>> {
+        let version = if height == 0 {
+            // Height being zero means unspecified (Protobuf doesn't have a null
+            // type) in which case we use the latest version.
+            None
+        } else {
+            Some(height)
+        };
+
+        let storage = self.db.state_storage(version)?;
+        let cfg = CONFIG.load(&storage)?;
+
+        Ok(query_store_scan(
+            Box::new(storage),
+            GasTracker::new_limited(self.query_gas_limit).with_costs(*cfg.gas_costs),
+            req,
+        )?)
     }
 
     pub fn do_simulate(
         &self,
         unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
         height: u64,
         prove: bool,
     ) -> AppResult<TxOutcome> {
-        let buffer = Buffer::new(self.db.state_storage(None)?, None);
+        self._do_simulate(unsigned_tx, overrides, height, prove, None)
+    }
+
+    /// Simulate a transaction, same as [`do_simulate`](Self::do_simulate), while
+    /// recording the contract calls made, and the gas consumed and storage keys
+    /// touched by each of them.
+    pub fn do_simulate_with_trace(
+        &self,
+        unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
+        height: u64,
+        prove: bool,
+    ) -> AppResult<(TxOutcome, ExecutionTrace)> {
+        let tracer = Tracer::new();
+        let outcome =
+            self._do_simulate(unsigned_tx, overrides, height, prove, Some(tracer.clone()))?;
+
+        Ok((outcome, tracer.trace()))
+    }
+
+    fn _do_simulate(
+        &self,
+        unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
+        height: u64,
+        prove: bool,
+        tracer: Option<Tracer>,
+    ) -> AppResult<TxOutcome> {
+        let buffer = Shared::new(Buffer::new(self.db.state_storage(None)?, None));
         let block = LAST_FINALIZED_BLOCK.load(&buffer)?;
 
         // We can't "prove" a gas simulation
@@ -606,11 +1066,24 @@ where
             return Err(AppError::PastHeightNotSupported);
         }
 
+        // Apply the overrides on top of the latest state. As the buffer is
+        // never flushed, the changes are discarded once the simulation is done.
+        let block =
+            apply_state_overrides(buffer.clone(), block, overrides, self.balance_overrider)?;
+
+        // Load the config after the overrides are applied, as they may have
+        // changed the gas costs.
+        let cfg = CONFIG.load(&buffer)?;
+
         // Create a `Tx` from the unsigned transaction.
         // Use using the node's query gas limit as the transaction gas limit,
-        // and empty bytes as credential.
+        // and empty bytes as credentials.
         let tx = Tx {
             sender: unsigned_tx.sender,
+            fee_payer: unsigned_tx.fee_payer.map(|fee_payer| FeePayer {
+                credential: Json::null(),
+                ..fee_payer
+            }),
             gas_limit: self.query_gas_limit,
             msgs: unsigned_tx.msgs,
             data: unsigned_tx.data,
@@ -623,9 +1096,11 @@ where
             self.vm.clone(),
             buffer,
             block,
+            *cfg.gas_costs,
             tx,
             AuthMode::Simulate,
             TraceOption::MUTE, // Mute tracing outputs during simulation.
+            tracer,
         ))
     }
 }
@@ -646,11 +1121,12 @@ where
         &self,
         chain_id: String,
         block: BlockInfo,
+        validators: &[[u8; 32]],
         raw_genesis_state: &[u8],
     ) -> AppResult<Hash256> {
         let genesis_state = raw_genesis_state.deserialize_json()?;
 
-        self.do_init_chain(chain_id, block, genesis_state)
+        self.do_init_chain(chain_id, block, validators, genesis_state)
     }
 
     pub fn do_finalize_block_raw<T>(
@@ -710,7 +1186,7 @@ where
         prove: bool,
     ) -> AppResult<Vec<u8>> {
         let tx = raw_unsigned_tx.deserialize_json()?;
-        let res = self.do_simulate(tx, height, prove)?;
+        let res = self.do_simulate(tx, StateOverrides::default(), height, prove)?;
 
         Ok(res.to_json_vec()?)
     }
@@ -721,15 +1197,64 @@ where
 
         Ok(res.to_json_vec()?)
     }
+
+    pub fn do_query_store_scan_raw(&self, raw_req: &[u8], height: u64) -> AppResult<Vec<u8>> {
+        let req = raw_req.deserialize_json()?;
+        let res = self.do_query_store_scan(req, height)?;
+
+        Ok(res.to_json_vec()?)
+    }
 }
 
-fn process_tx<S, VM>(
+/// Process the transactions in a block one-by-one.
+fn process_txs<S, VM>(
+    vm: VM,
+    buffer: Shared<Buffer<S>>,
+    block: BlockInfo,
+    txs: &[(Tx, Hash256)],
+) -> AppResult<Vec<TxOutcome>>
+where
+    S: Storage + Clone + 'static,
+    VM: Vm + Clone + Send + Sync + 'static,
+    AppError: From<VM::Error>,
+{
+    let mut tx_outcomes = Vec::with_capacity(txs.len());
+
+    #[cfg_attr(not(feature = "tracing"), allow(clippy::unused_enumerate_index))]
+    for (_idx, (tx, _)) in txs.iter().enumerate() {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(idx = _idx, "Processing transaction");
+
+        // Load the gas costs for each tx, as they may have been updated
+        // by a previous tx in the same block.
+        let gas_costs = *CONFIG.load(&buffer)?.gas_costs;
+
+        let tx_outcome = process_tx(
+            vm.clone(),
+            buffer.clone(),
+            block,
+            gas_costs,
+            tx.clone(),
+            AuthMode::Finalize,
+            TraceOption::LOUD,
+            None,
+        );
+
+        tx_outcomes.push(tx_outcome);
+    }
+
+    Ok(tx_outcomes)
+}
+
+pub(crate) fn process_tx<S, VM>(
     vm: VM,
     storage: S,
     block: BlockInfo,
+    gas_costs: GasCosts,
     tx: Tx,
     mode: AuthMode,
     trace_opt: TraceOption,
+    tracer: Option<Tracer>,
 ) -> TxOutcome
 where
     S: Storage + Clone + 'static,
@@ -738,7 +1263,9 @@ where
 {
     // Create the gas tracker, with the limit being the gas limit requested by
     // the transaction.
-    let gas_tracker = GasTracker::new_limited(tx.gas_limit);
+    let gas_tracker = GasTracker::new_limited(tx.gas_limit)
+        .with_costs(gas_costs)
+        .with_tracer(tracer);
 
     // Create two layers of buffers.
     //
@@ -751,8 +1278,9 @@ where
 
     // Call the taxman's `withhold_fee` function.
     //
-    // The purpose of this step is to ensure the tx's sender has sufficient
-    // token balance to cover the maximum possible fee the tx may incur.
+    // The purpose of this step is to ensure the tx's sender, or its fee payer if
+    // there is one, has sufficient token balance to cover the maximum possible
+    // fee the tx may incur.
     //
     // If this succeeds, record the events emitted.
     //
@@ -762,7 +1290,7 @@ where
         do_withhold_fee(
             vm.clone(),
             Box::new(fee_buffer.clone()),
-            GasTracker::new_limitless(),
+            GasTracker::new_limitless().with_tracer(gas_tracker.tracer().cloned()),
             block,
             &tx,
             mode,
@@ -782,6 +1310,9 @@ where
     // verifying a cryptographic signature, to ensure the tx comes from the
     // sender account's rightful owner.
     //
+    // A tx naming its own sender as the fee payer is rejected here, instead of
+    // having the same account authenticate it twice.
+    //
     // Note that we use `msg_buffer` for this.
     //
     // If succeeds, commit state changes in `msg_buffer` into `fee_buffer`, and record
@@ -790,15 +1321,18 @@ where
     // If fails, discard state changes in `msg_buffer` (but keeping those in
     // `fee_buffer`), discard the events, and jump to `finalize_fee`.
 
-    events.authenticate = do_authenticate(
-        vm.clone(),
-        Box::new(msg_buffer.clone()),
-        gas_tracker.clone(),
-        block,
-        &tx,
-        mode,
-        trace_opt,
-    )
+    events.authenticate = authenticate_sender(&tx, || {
+        do_authenticate(
+            vm.clone(),
+            Box::new(msg_buffer.clone()),
+            gas_tracker.clone(),
+            block,
+            tx.sender,
+            &tx,
+            mode,
+            trace_opt,
+        )
+    })
     .into_commitment_status();
 
     let request_backrun = match events.authenticate.as_result() {
@@ -827,6 +1361,47 @@ where
         },
     };
 
+    // If the tx has a fee payer, call the fee payer account's `authenticate`
+    // function, to ensure the fee payer has agreed to pay for the tx.
+    //
+    // This is handled the same way as the sender's authentication, except that
+    // a backrun requested by the fee payer is ignored; only the sender account
+    // may backrun the tx.
+    if let Some(fee_payer) = &tx.fee_payer {
+        events.authenticate_fee_payer = do_authenticate(
+            vm.clone(),
+            Box::new(msg_buffer.clone()),
+            gas_tracker.clone(),
+            block,
+            fee_payer.address,
+            &tx,
+            mode,
+            trace_opt,
+        )
+        .into_commitment_status();
+
+        match events.authenticate_fee_payer.maybe_error() {
+            Some(err) => {
+                drop(msg_buffer);
+                let err = err.to_string();
+                return process_finalize_fee(
+                    vm,
+                    fee_buffer,
+                    gas_tracker,
+                    block,
+                    tx,
+                    mode,
+                    events,
+                    Err(err),
+                    trace_opt,
+                );
+            },
+            None => {
+                msg_buffer.write_access().commit();
+            },
+        }
+    }
+
     // Loop through the messages and execute one by one. Then, call the sender
     // account's `backrun` method.
     //
@@ -949,6 +1524,21 @@ where
     EventResult::Ok(evt)
 }
 
+/// Authenticate the tx's sender using the given callback, unless the tx names
+/// the sender as its own fee payer, in which case the tx is rejected.
+fn authenticate_sender<F>(tx: &Tx, authenticate: F) -> EventResult<EvtAuthenticate>
+where
+    F: FnOnce() -> EventResult<EvtAuthenticate>,
+{
+    match &tx.fee_payer {
+        Some(fee_payer) if fee_payer.address == tx.sender => EventResult::err(
+            EvtAuthenticate::base(tx.sender),
+            AppError::FeePayerIsSender { sender: tx.sender },
+        ),
+        _ => authenticate(),
+    }
+}
+
 fn process_finalize_fee<S, VM>(
     vm: VM,
     buffer: Shared<Buffer<S>>,
@@ -970,7 +1560,7 @@ where
     let evt_finalize = do_finalize_fee(
         vm,
         Box::new(buffer.clone()),
-        GasTracker::new_limitless(),
+        GasTracker::new_limitless().with_tracer(gas_tracker.tracer().cloned()),
         block,
         &tx,
         &outcome_so_far,
@@ -1073,6 +1663,22 @@ where
             );
             res.map(Event::Migrate)
         },
+        Message::Schedule(msg) => {
+            let res = do_schedule(&mut storage, gas_tracker, block, sender, msg, trace_opt);
+            res.map(Event::Schedule)
+        },
+        Message::Unschedule(msg) => {
+            let res = do_unschedule(&mut storage, sender, msg, trace_opt);
+            res.map(Event::Unschedule)
+        },
+        Message::Subscribe(msg) => {
+            let res = do_subscribe(&mut storage, gas_tracker, sender, msg, trace_opt);
+            res.map(Event::Subscribe)
+        },
+        Message::Unsubscribe(msg) => {
+            let res = do_unsubscribe(&mut storage, sender, msg, trace_opt);
+            res.map(Event::Unsubscribe)
+        },
     }
 }
 
@@ -1198,6 +1804,62 @@ pub(crate) fn schedule_cronjob(
     NEXT_CRONJOBS.insert(storage, (next_time, contract))
 }
 
+/// Apply the state overrides for simulating a transaction to the given buffer.
+/// Return the block info to simulate the transaction in.
+fn apply_state_overrides<S>(
+    mut buffer: Shared<Buffer<S>>,
+    mut block: BlockInfo,
+    overrides: StateOverrides,
+    balance_overrider: Option<BalanceOverrider>,
+) -> AppResult<BlockInfo>
+where
+    S: Storage + Clone + 'static,
+{
+    if let Some(height) = overrides.block_height {
+        block.height = height;
+    }
+
+    if let Some(timestamp) = overrides.block_timestamp {
+        block.timestamp = timestamp;
+    }
+
+    for (contract, code_hash) in overrides.code_hashes {
+        if !CODES.has(&buffer, code_hash) {
+            return Err(StdError::data_not_found::<Code>(code_hash.as_ref()).into());
+        }
+
+        CONTRACTS.update(&mut buffer, contract, |mut contract_info| -> StdResult<_> {
+            contract_info.code_hash = code_hash;
+            Ok(contract_info)
+        })?;
+    }
+
+    for (contract, records) in overrides.storage {
+        let mut storage =
+            StorageProvider::new(Box::new(buffer.clone()), &[CONTRACT_NAMESPACE, &contract]);
+
+        for (key, value) in records {
+            match value {
+                Some(value) => storage.write(&key, &value),
+                None => storage.remove(&key),
+            }
+        }
+    }
+
+    if !overrides.balances.is_empty() {
+        let balance_overrider = balance_overrider.ok_or(AppError::BalanceOverridesNotSupported)?;
+        let bank = CONFIG.load(&buffer)?.bank;
+        let mut storage =
+            StorageProvider::new(Box::new(buffer.clone()), &[CONTRACT_NAMESPACE, &bank]);
+
+        for (account, coins) in overrides.balances {
+            balance_overrider(&mut storage, account, &coins)?;
+        }
+    }
+
+    Ok(block)
+}
+
 fn new_check_tx_outcome(
     gas_tracker: GasTracker,
     result: GenericResult<()>,
@@ -1,5 +1,5 @@
 use {
-    grug_types::{Addr, Hash256, StdError},
+    grug_types::{Addr, Due, Hash256, StdError},
     std::{
         collections::HashMap,
         sync::{MutexGuard, PoisonError},
@@ -60,6 +60,40 @@ pub enum AppError {
 
     #[error("max message depth exceeded")]
     ExceedMaxMessageDepth,
+
+    #[error("scheduled message must be due in the future! due: {due:?}")]
+    DueNotInFuture { due: Due },
+
+    #[error("scheduled message with id `{id}` not found")]
+    ScheduledMsgNotFound { id: u64 },
+
+    #[error(
+        "gas limit of scheduled message exceeds the maximum! gas limit: {gas_limit}, max: {max}"
+    )]
+    ScheduledGasLimitTooHigh { gas_limit: u64, max: u64 },
+
+    #[error("contract with address `{address}` not found")]
+    ContractNotFound { address: Addr },
+
+    #[error("gas limit of subscription is below the minimum! gas limit: {gas_limit}, min: {min}")]
+    SubscriptionGasLimitTooLow { gas_limit: u64, min: u64 },
+
+    #[error("gas limit of subscription exceeds the maximum! gas limit: {gas_limit}, max: {max}")]
+    SubscriptionGasLimitTooHigh { gas_limit: u64, max: u64 },
+
+    #[error(
+        "gas budget of subscription can't cover its gas limit! gas budget: {gas_budget}, gas limit: {gas_limit}"
+    )]
+    SubscriptionGasBudgetTooLow { gas_budget: u64, gas_limit: u64 },
+
+    #[error("subscription to event `{event_type}` emitted by `{emitter}` not found")]
+    SubscriptionNotFound { emitter: Addr, event_type: String },
+
+    #[error("balance overrides aren't supported when simulating transactions on this chain")]
+    BalanceOverridesNotSupported,
+
+    #[error("fee payer can't be the sender of the transaction: {sender}")]
+    FeePayerIsSender { sender: Addr },
 }
 
 /// Dedicated error type for indexer operations
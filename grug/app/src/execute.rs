@@ -7,12 +7,16 @@ mod execute;
 mod finalize;
 mod instantiate;
 mod migrate;
+mod receive_event;
 mod reply;
+mod schedule;
+mod subscribe;
 mod transfer;
 mod upload;
 mod withhold;
 
 pub use {
     authenticate::*, backrun::*, configure::*, cron::*, execute::*, finalize::*, instantiate::*,
-    migrate::*, reply::*, transfer::*, upload::*, withhold::*,
+    migrate::*, receive_event::*, reply::*, schedule::*, subscribe::*, transfer::*, upload::*,
+    withhold::*,
 };
@@ -5,14 +5,17 @@ use {
         AppError, CHAIN_ID, CONTRACTS, EventResult, GasTracker, TraceOption, Vm,
         call_in_1_out_1_handle_auth_response, catch_and_update_event, catch_event,
     },
-    grug_types::{AuthMode, BlockInfo, Context, EvtAuthenticate, Storage, Tx},
+    grug_types::{Addr, AuthMode, BlockInfo, Context, EvtAuthenticate, Storage, Tx},
 };
 
+/// Call the `authenticate` method of the given account, which is either the
+/// transaction's sender or its fee payer.
 pub fn do_authenticate<VM>(
     vm: VM,
     storage: Box<dyn Storage>,
     gas_tracker: GasTracker,
     block: BlockInfo,
+    account: Addr,
     tx: &Tx,
     mode: AuthMode,
     trace_opt: TraceOption,
@@ -21,14 +24,23 @@ where
     VM: Vm + Clone + Send + Sync + 'static,
     AppError: From<VM::Error>,
 {
-    let evt = _do_authenticate(vm, storage, gas_tracker, block, tx, mode, trace_opt);
+    let evt = _do_authenticate(
+        vm,
+        storage,
+        gas_tracker,
+        block,
+        account,
+        tx,
+        mode,
+        trace_opt,
+    );
 
     #[cfg(feature = "tracing")]
     evt.debug(
         |_| {
             dyn_event!(
                 trace_opt.ok_level.into(),
-                sender = tx.sender.to_string(),
+                account = account.to_string(),
                 "Authenticated transaction"
             );
         },
@@ -44,6 +56,7 @@ pub fn _do_authenticate<VM>(
     storage: Box<dyn Storage>,
     gas_tracker: GasTracker,
     block: BlockInfo,
+    account: Addr,
     tx: &Tx,
     mode: AuthMode,
     trace_opt: TraceOption,
@@ -52,11 +65,11 @@ where
     VM: Vm + Clone + Send + Sync + 'static,
     AppError: From<VM::Error>,
 {
-    let mut evt = EvtAuthenticate::base(tx.sender);
+    let mut evt = EvtAuthenticate::base(account);
 
     let (code_hash, chain_id) = catch_event! {
         {
-            let code_hash = CONTRACTS.load(&storage, tx.sender)?.code_hash;
+            let code_hash = CONTRACTS.load(&storage, account)?.code_hash;
             let chain_id = CHAIN_ID.load(&storage)?;
 
             Ok((code_hash, chain_id))
@@ -67,7 +80,7 @@ where
     let ctx = Context {
         chain_id,
         block,
-        contract: tx.sender,
+        contract: account,
         sender: None,
         funds: None,
         mode: Some(mode),
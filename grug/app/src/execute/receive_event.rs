@@ -0,0 +1,162 @@
+#[cfg(feature = "tracing")]
+use dyn_event::dyn_event;
+use {
+    crate::{
+        AppError, CHAIN_ID, CONTRACTS, EventResult, GasTracker, SUBSCRIPTIONS, TraceOption, Vm,
+        call_in_1_out_1_handle_response, catch_and_update_event, catch_event,
+    },
+    grug_types::{
+        Addr, BlockInfo, CheckedContractEvent, Context, EventFilter, EvtReceiveEvent,
+        FlatCommitmentStatus, FlatEventInfo, FlatEventStatusDiscriminants, Order, StdResult,
+        Storage,
+    },
+};
+
+pub fn do_receive_event<VM>(
+    vm: VM,
+    storage: Box<dyn Storage>,
+    gas_tracker: GasTracker,
+    block: BlockInfo,
+    contract: Addr,
+    event: CheckedContractEvent,
+    trace_opt: TraceOption,
+) -> EventResult<EvtReceiveEvent>
+where
+    VM: Vm + Clone + Send + Sync + 'static,
+    AppError: From<VM::Error>,
+{
+    let evt = _do_receive_event(vm, storage, gas_tracker, block, contract, event, trace_opt);
+
+    #[cfg(feature = "tracing")]
+    evt.debug(
+        |evt| {
+            dyn_event!(
+                trace_opt.ok_level.into(),
+                contract = contract.to_string(),
+                emitter = evt.event.contract.to_string(),
+                event_type = evt.event.ty,
+                "Notified contract of event"
+            );
+        },
+        "Failed to notify contract of event",
+        trace_opt.error_level.into(),
+    );
+
+    evt
+}
+
+fn _do_receive_event<VM>(
+    vm: VM,
+    storage: Box<dyn Storage>,
+    gas_tracker: GasTracker,
+    block: BlockInfo,
+    contract: Addr,
+    event: CheckedContractEvent,
+    trace_opt: TraceOption,
+) -> EventResult<EvtReceiveEvent>
+where
+    VM: Vm + Clone + Send + Sync + 'static,
+    AppError: From<VM::Error>,
+{
+    let mut evt = EvtReceiveEvent::base(contract, event.clone());
+
+    let (code_hash, chain_id) = catch_event! {
+        {
+            let code_hash = CONTRACTS.load(&storage, contract)?.code_hash;
+            let chain_id = CHAIN_ID.load(&storage)?;
+
+            Ok((code_hash, chain_id))
+        },
+        evt
+    };
+
+    let ctx = Context {
+        chain_id,
+        block,
+        contract,
+        sender: None,
+        funds: None,
+        mode: None,
+    };
+
+    catch_and_update_event! {
+        call_in_1_out_1_handle_response(
+            vm,
+            storage,
+            gas_tracker,
+            0,
+            0,
+            true,
+            "receive_event",
+            code_hash,
+            &ctx,
+            &event,
+            trace_opt,
+        ),
+        evt => guest_event
+    }
+
+    EventResult::Ok(evt)
+}
+
+/// Among the given events, find the contract events that were successfully
+/// emitted and committed, and return them paired with each of their
+/// subscribers.
+///
+/// The events are returned in the order they were emitted. Subscribers of the
+/// same event are ordered by address.
+pub(crate) fn find_subscribed_events(
+    storage: &dyn Storage,
+    events: Vec<FlatEventInfo>,
+) -> StdResult<Vec<(Addr, CheckedContractEvent)>> {
+    let mut subscribed = vec![];
+
+    for filtered in EventFilter::<CheckedContractEvent>::new(events)
+        .with_commitment_status(FlatCommitmentStatus::Committed)
+        .with_event_status(FlatEventStatusDiscriminants::Ok)
+        .take()
+        .all()
+    {
+        let event = filtered.event;
+
+        for res in SUBSCRIPTIONS
+            .prefix(event.contract)
+            .append(event.ty.as_str())
+            .keys(storage, None, None, Order::Ascending)
+        {
+            subscribed.push((res?, event.clone()));
+        }
+    }
+
+    Ok(subscribed)
+}
+
+/// Draw the gas limit of a notification from the subscription's gas budget.
+/// The subscription is removed if the remaining budget can't cover another
+/// notification.
+///
+/// Does nothing if the subscription was canceled during the notification.
+pub(crate) fn charge_subscription(
+    storage: &mut dyn Storage,
+    emitter: Addr,
+    event_type: &str,
+    subscriber: Addr,
+) -> StdResult<()> {
+    let key = (emitter, event_type, subscriber);
+
+    let Some(mut subscription) = SUBSCRIPTIONS.may_load(storage, key)? else {
+        return Ok(());
+    };
+
+    subscription.gas_budget = subscription
+        .gas_budget
+        .saturating_sub(subscription.gas_limit);
+
+    if subscription.gas_budget < subscription.gas_limit {
+        SUBSCRIPTIONS.remove(storage, key);
+    } else {
+        SUBSCRIPTIONS.save(storage, key, &subscription)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+#[cfg(feature = "tracing")]
+use dyn_event::dyn_event;
+use {
+    crate::{
+        AppError, AppResult, CONFIG, EventResult, GasTracker, MeteredMap, NEXT_SCHEDULED_ID,
+        SCHEDULED_BY_HEIGHT, SCHEDULED_BY_TIME, SCHEDULED_MSGS, TraceOption,
+    },
+    grug_storage::PrefixBound,
+    grug_types::{
+        Addr, BlockInfo, Due, EvtSchedule, EvtUnschedule, MsgSchedule, MsgUnschedule, Order,
+        ScheduledMsg, StdResult, Storage,
+    },
+};
+
+pub fn do_schedule(
+    storage: &mut dyn Storage,
+    gas_tracker: GasTracker,
+    block: BlockInfo,
+    sender: Addr,
+    msg: MsgSchedule,
+    #[allow(unused_variables)] trace_opt: TraceOption,
+) -> EventResult<EvtSchedule> {
+    let mut evt = EvtSchedule::base(sender, &msg);
+
+    match _do_schedule(storage, gas_tracker, block, sender, msg) {
+        Ok(id) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.ok_level.into(),
+                id,
+                sender = sender.to_string(),
+                "Scheduled message"
+            );
+
+            evt.id = Some(id);
+
+            EventResult::Ok(evt)
+        },
+        Err(err) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.error_level.into(),
+                err = err.to_string(),
+                "Failed to schedule message"
+            );
+
+            EventResult::err(evt, err)
+        },
+    }
+}
+
+// Return the ID assigned to the scheduled message.
+fn _do_schedule(
+    storage: &mut dyn Storage,
+    gas_tracker: GasTracker,
+    block: BlockInfo,
+    sender: Addr,
+    msg: MsgSchedule,
+) -> AppResult<u64> {
+    // A message that's already due would be performed at the end of the
+    // current block. Senders who want an execution to happen right away should
+    // send an `Execute` message instead.
+    if msg.due.is_reached(&block) {
+        return Err(AppError::DueNotInFuture { due: msg.due });
+    }
+
+    let max_gas_limit = CONFIG
+        .load(storage)?
+        .end_block_limits
+        .max_scheduled_gas_limit;
+
+    if msg.gas_limit > max_gas_limit {
+        return Err(AppError::ScheduledGasLimitTooHigh {
+            gas_limit: msg.gas_limit,
+            max: max_gas_limit,
+        });
+    }
+
+    // The scheduled message is performed at the end of a block, outside of any
+    // transaction, so nobody would pay for the gas it uses. Instead, its gas
+    // limit is consumed here in full, such that the sender pays for it as part
+    // of the transaction fee. It isn't refunded if the message uses less gas,
+    // or is canceled.
+    gas_tracker.consume(msg.gas_limit, "schedule")?;
+
+    let (id, _) = NEXT_SCHEDULED_ID.increment(storage)?;
+
+    match msg.due {
+        Due::Time(time) => SCHEDULED_BY_TIME.insert(storage, (time, id))?,
+        Due::Height(height) => SCHEDULED_BY_HEIGHT.insert(storage, (height, id))?,
+    }
+
+    SCHEDULED_MSGS.save_with_gas(storage, gas_tracker, id, &ScheduledMsg {
+        sender,
+        due: msg.due,
+        gas_limit: msg.gas_limit,
+        msg: msg.msg,
+    })?;
+
+    Ok(id)
+}
+
+pub fn do_unschedule(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    msg: MsgUnschedule,
+    #[allow(unused_variables)] trace_opt: TraceOption,
+) -> EventResult<EvtUnschedule> {
+    let evt = EvtUnschedule { sender, id: msg.id };
+
+    match _do_unschedule(storage, sender, msg) {
+        Ok(_) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.ok_level.into(),
+                id = evt.id,
+                "Unscheduled message"
+            );
+
+            EventResult::Ok(evt)
+        },
+        Err(err) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.error_level.into(),
+                err = err.to_string(),
+                "Failed to unschedule message"
+            );
+
+            EventResult::err(evt, err)
+        },
+    }
+}
+
+fn _do_unschedule(storage: &mut dyn Storage, sender: Addr, msg: MsgUnschedule) -> AppResult<()> {
+    let scheduled = SCHEDULED_MSGS
+        .may_load(storage, msg.id)?
+        .ok_or(AppError::ScheduledMsgNotFound { id: msg.id })?;
+
+    // Only the account that scheduled the message can cancel it.
+    if scheduled.sender != sender {
+        return Err(AppError::Unauthorized);
+    }
+
+    SCHEDULED_MSGS.remove(storage, msg.id);
+
+    match scheduled.due {
+        Due::Time(time) => SCHEDULED_BY_TIME.remove(storage, (time, msg.id)),
+        Due::Height(height) => SCHEDULED_BY_HEIGHT.remove(storage, (height, msg.id)),
+    }
+
+    Ok(())
+}
+
+/// Remove the scheduled messages that are due in the given block from the
+/// storage, and return them in ascending order of their IDs, i.e. the order in
+/// which they were scheduled.
+///
+/// Messages are taken until the sum of their gas limits would exceed the given
+/// budget. The remaining ones stay in the storage, to be taken in the following
+/// blocks. At least one message is taken if any is due, so that one whose gas
+/// limit exceeds the budget doesn't block the others.
+pub(crate) fn take_due_scheduled_msgs(
+    storage: &mut dyn Storage,
+    block: BlockInfo,
+    gas_budget: u64,
+) -> StdResult<Vec<(u64, ScheduledMsg)>> {
+    let mut ids = SCHEDULED_BY_TIME
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::Inclusive(block.timestamp)),
+            Order::Ascending,
+        )
+        .map(|res| res.map(|(_, id)| id))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    ids.extend(
+        SCHEDULED_BY_HEIGHT
+            .prefix_range(
+                storage,
+                None,
+                Some(PrefixBound::Inclusive(block.height)),
+                Order::Ascending,
+            )
+            .map(|res| res.map(|(_, id)| id))
+            .collect::<StdResult<Vec<_>>>()?,
+    );
+
+    ids.sort();
+
+    let mut gas_total = 0_u64;
+    let mut msgs = vec![];
+
+    for id in ids {
+        let scheduled = SCHEDULED_MSGS.load(storage, id)?;
+
+        gas_total = gas_total.saturating_add(scheduled.gas_limit);
+
+        if gas_total > gas_budget && !msgs.is_empty() {
+            break;
+        }
+
+        SCHEDULED_MSGS.remove(storage, id);
+
+        match scheduled.due {
+            Due::Time(time) => SCHEDULED_BY_TIME.remove(storage, (time, id)),
+            Due::Height(height) => SCHEDULED_BY_HEIGHT.remove(storage, (height, id)),
+        }
+
+        msgs.push((id, scheduled));
+    }
+
+    Ok(msgs)
+}
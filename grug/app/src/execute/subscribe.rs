@@ -0,0 +1,170 @@
+#[cfg(feature = "tracing")]
+use dyn_event::dyn_event;
+use {
+    crate::{
+        AppError, AppResult, CONFIG, CONTRACTS, EventResult, GasTracker, MeteredMap, SUBSCRIPTIONS,
+        TraceOption,
+    },
+    grug_types::{
+        Addr, EvtSubscribe, EvtUnsubscribe, MsgSubscribe, MsgUnsubscribe, Storage, Subscription,
+    },
+};
+
+pub fn do_subscribe(
+    storage: &mut dyn Storage,
+    gas_tracker: GasTracker,
+    sender: Addr,
+    msg: MsgSubscribe,
+    #[allow(unused_variables)] trace_opt: TraceOption,
+) -> EventResult<EvtSubscribe> {
+    let evt = EvtSubscribe {
+        subscriber: sender,
+        emitter: msg.emitter,
+        event_type: msg.event_type.clone(),
+        gas_limit: msg.gas_limit,
+        gas_budget: msg.gas_budget,
+    };
+
+    match _do_subscribe(storage, gas_tracker, sender, msg) {
+        Ok(_) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.ok_level.into(),
+                subscriber = sender.to_string(),
+                emitter = evt.emitter.to_string(),
+                event_type = evt.event_type,
+                "Subscribed to event"
+            );
+
+            EventResult::Ok(evt)
+        },
+        Err(err) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.error_level.into(),
+                err = err.to_string(),
+                "Failed to subscribe to event"
+            );
+
+            EventResult::err(evt, err)
+        },
+    }
+}
+
+fn _do_subscribe(
+    storage: &mut dyn Storage,
+    gas_tracker: GasTracker,
+    sender: Addr,
+    msg: MsgSubscribe,
+) -> AppResult<()> {
+    // Only contracts emit events, so subscribing to a non-contract can only be
+    // a mistake.
+    if !CONTRACTS.has(storage, msg.emitter) {
+        return Err(AppError::ContractNotFound {
+            address: msg.emitter,
+        });
+    }
+
+    let limits = CONFIG.load(storage)?.end_block_limits;
+
+    // A zero gas limit is never allowed, even if the configured minimum is zero,
+    // as such a subscription would never draw from its gas budget, so it would
+    // be notified for free forever.
+    let min_gas_limit = limits.min_subscription_gas_limit.max(1);
+
+    if msg.gas_limit < min_gas_limit {
+        return Err(AppError::SubscriptionGasLimitTooLow {
+            gas_limit: msg.gas_limit,
+            min: min_gas_limit,
+        });
+    }
+
+    if msg.gas_limit > limits.max_subscription_gas_limit {
+        return Err(AppError::SubscriptionGasLimitTooHigh {
+            gas_limit: msg.gas_limit,
+            max: limits.max_subscription_gas_limit,
+        });
+    }
+
+    let key = (msg.emitter, msg.event_type.as_str(), sender);
+
+    // Subscribing again overwrites the gas limit of the existing subscription,
+    // and adds to its remaining gas budget.
+    let gas_budget = match SUBSCRIPTIONS.may_load(storage, key)? {
+        Some(existing) => existing.gas_budget.saturating_add(msg.gas_budget),
+        None => msg.gas_budget,
+    };
+
+    if gas_budget < msg.gas_limit {
+        return Err(AppError::SubscriptionGasBudgetTooLow {
+            gas_budget,
+            gas_limit: msg.gas_limit,
+        });
+    }
+
+    // Notifications are performed at the end of a block, outside of any
+    // transaction, so nobody would pay for the gas they use. Instead, the gas
+    // budget is consumed here in full, such that the sender pays for it as part
+    // of the transaction fee. It isn't refunded if the subscription is canceled.
+    gas_tracker.consume(msg.gas_budget, "subscribe")?;
+
+    SUBSCRIPTIONS.save_with_gas(storage, gas_tracker, key, &Subscription {
+        gas_limit: msg.gas_limit,
+        gas_budget,
+    })?;
+
+    Ok(())
+}
+
+pub fn do_unsubscribe(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    msg: MsgUnsubscribe,
+    #[allow(unused_variables)] trace_opt: TraceOption,
+) -> EventResult<EvtUnsubscribe> {
+    let evt = EvtUnsubscribe {
+        subscriber: sender,
+        emitter: msg.emitter,
+        event_type: msg.event_type.clone(),
+    };
+
+    match _do_unsubscribe(storage, sender, msg) {
+        Ok(_) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.ok_level.into(),
+                subscriber = sender.to_string(),
+                emitter = evt.emitter.to_string(),
+                event_type = evt.event_type,
+                "Unsubscribed from event"
+            );
+
+            EventResult::Ok(evt)
+        },
+        Err(err) => {
+            #[cfg(feature = "tracing")]
+            dyn_event!(
+                trace_opt.error_level.into(),
+                err = err.to_string(),
+                "Failed to unsubscribe from event"
+            );
+
+            EventResult::err(evt, err)
+        },
+    }
+}
+
+fn _do_unsubscribe(storage: &mut dyn Storage, sender: Addr, msg: MsgUnsubscribe) -> AppResult<()> {
+    let key = (msg.emitter, msg.event_type.as_str(), sender);
+
+    if !SUBSCRIPTIONS.has(storage, key) {
+        return Err(AppError::SubscriptionNotFound {
+            emitter: msg.emitter,
+            event_type: msg.event_type,
+        });
+    }
+
+    SUBSCRIPTIONS.remove(storage, key);
+
+    Ok(())
+}
@@ -1,5 +1,4 @@
-mod costs;
 mod storage;
 mod tracker;
 
-pub use {costs::*, storage::*, tracker::*};
+pub use {storage::*, tracker::*};
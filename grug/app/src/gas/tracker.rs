@@ -1,6 +1,10 @@
 use {
-    grug_types::{Shared, StdError, StdResult},
-    std::fmt::{self, Display},
+    crate::{CallGuard, Tracer},
+    grug_types::{Addr, GasCosts, Shared, StdError, StdResult},
+    std::{
+        fmt::{self, Display},
+        sync::Arc,
+    },
 };
 
 struct GasTrackerInner {
@@ -14,6 +18,11 @@ struct GasTrackerInner {
 #[derive(Clone)]
 pub struct GasTracker {
     inner: Shared<GasTrackerInner>,
+    // The gas costs don't change during the lifetime of a tracker, so they
+    // don't need to be behind the lock.
+    costs: Arc<GasCosts>,
+    // If provided, gas consumption is reported to the tracer.
+    tracer: Option<Tracer>,
 }
 
 impl GasTracker {
@@ -24,6 +33,8 @@ impl GasTracker {
                 limit: maybe_limit,
                 used: 0,
             }),
+            costs: Arc::new(GasCosts::default()),
+            tracer: None,
         }
     }
 
@@ -34,6 +45,8 @@ impl GasTracker {
                 limit: None,
                 used: 0,
             }),
+            costs: Arc::new(GasCosts::default()),
+            tracer: None,
         }
     }
 
@@ -44,9 +57,43 @@ impl GasTracker {
                 limit: Some(limit),
                 used: 0,
             }),
+            costs: Arc::new(GasCosts::default()),
+            tracer: None,
         }
     }
 
+    /// Use the given gas costs, instead of the default ones, when charging gas
+    /// for host operations.
+    pub fn with_costs(mut self, costs: GasCosts) -> Self {
+        self.costs = Arc::new(costs);
+        self
+    }
+
+    /// Report gas consumption, as well as the contract calls made, to the given
+    /// tracer. If `None`, the execution isn't traced.
+    pub fn with_tracer(mut self, tracer: Option<Tracer>) -> Self {
+        self.tracer = tracer;
+        self
+    }
+
+    /// Return the tracer, if the execution is being traced.
+    pub fn tracer(&self) -> Option<&Tracer> {
+        self.tracer.as_ref()
+    }
+
+    /// Record the start of a call to a contract's entry point, if the execution
+    /// is being traced. The call ends when the returned guard is dropped.
+    pub fn trace_call(&self, contract: Addr, entry_point: &str) -> Option<CallGuard> {
+        self.tracer
+            .as_ref()
+            .map(|tracer| tracer.enter(contract, entry_point))
+    }
+
+    /// Return the gas costs of host operations.
+    pub fn costs(&self) -> &GasCosts {
+        &self.costs
+    }
+
     /// Return the gas limit. `None` if there isn't a limit.
     ///
     /// Panics if lock is poisoned.
@@ -97,6 +144,10 @@ impl GasTracker {
 
             inner.used = used;
 
+            if let Some(tracer) = &self.tracer {
+                tracer.record_gas(consumed, comment);
+            }
+
             Ok(())
         })
     }
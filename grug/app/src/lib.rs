@@ -7,17 +7,22 @@ mod execute;
 mod gas;
 mod indexer;
 mod macros;
+#[cfg(feature = "parallel")]
+mod parallel;
 mod proposal_preparer;
 mod providers;
 mod query;
+mod snapshot;
 mod state;
 mod submessage;
 mod tracing;
 mod traits;
 mod upgrade;
 mod vm;
+mod vote_extension;
 
 pub use crate::{
     app::*, error::*, event::*, execute::*, gas::*, indexer::*, proposal_preparer::*, providers::*,
-    query::*, state::*, submessage::*, tracing::*, traits::*, upgrade::*, vm::*,
+    query::*, snapshot::*, state::*, submessage::*, tracing::*, traits::*, upgrade::*, vm::*,
+    vote_extension::*,
 };
@@ -0,0 +1,329 @@
+use {
+    crate::{AppError, AppResult, CONFIG, TraceOption, Vm, process_tx},
+    grug_types::{
+        AuthMode, Batch, BlockInfo, Buffer, Hash256, Order, Record, Shared, Storage, Tx, TxOutcome,
+    },
+    std::{
+        collections::BTreeSet,
+        num::NonZeroUsize,
+        ops::Bound,
+        panic,
+        sync::atomic::{self, AtomicUsize},
+        thread,
+    },
+};
+
+/// Process the transactions in a block optimistically in parallel.
+///
+/// This produces the exact same outcomes and state changes as processing them
+/// one-by-one in block order, in two phases:
+///
+/// 1. Each transaction is executed speculatively against the state at the
+///    beginning of the block, in its own buffer. The keys and ranges it reads
+///    from the block's state are recorded. This is done across multiple threads.
+/// 2. In block order, each transaction is validated against the keys written
+///    by the transactions before it. If it has read none of them, then it has
+///    seen the same state as it would have in sequential execution, so its
+///    speculative outcome is accepted and its writes are applied. Otherwise, it
+///    is re-executed against the up-to-date state.
+///
+/// Unlike Block-STM, conflicting transactions are re-executed sequentially,
+/// instead of being rescheduled in parallel. This keeps the scheduling simple,
+/// at the cost of being no faster than sequential execution for blocks where
+/// most transactions conflict, e.g. if all of them touch the same account.
+pub(crate) fn process_txs_parallel<S, VM>(
+    vm: VM,
+    buffer: Shared<Buffer<S>>,
+    block: BlockInfo,
+    txs: &[(Tx, Hash256)],
+) -> AppResult<Vec<TxOutcome>>
+where
+    S: Storage + Clone + 'static,
+    VM: Vm + Clone + Send + Sync + 'static,
+    AppError: From<VM::Error>,
+{
+    let speculations = speculate_all(&vm, &buffer, block, txs)?;
+
+    let mut written = BTreeSet::new();
+    let mut tx_outcomes = Vec::with_capacity(txs.len());
+
+    #[cfg_attr(not(feature = "tracing"), allow(clippy::unused_enumerate_index))]
+    for (_idx, ((tx, _), speculation)) in txs.iter().zip(speculations).enumerate() {
+        let (tx_outcome, batch) = if speculation.reads.conflicts_with(&written) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(idx = _idx, "Re-executing conflicting transaction");
+
+            execute_tx(vm.clone(), buffer.clone(), block, tx.clone())?
+        } else {
+            (speculation.tx_outcome, speculation.batch)
+        };
+
+        written.extend(batch.keys().cloned());
+        buffer.write_access().flush(batch);
+        tx_outcomes.push(tx_outcome);
+    }
+
+    Ok(tx_outcomes)
+}
+
+/// The result of executing a transaction speculatively.
+struct Speculation {
+    tx_outcome: TxOutcome,
+    batch: Batch,
+    reads: ReadSet,
+}
+
+/// Speculatively execute all transactions against the given state, spreading
+/// them across as many threads as there are CPUs.
+fn speculate_all<S, VM>(
+    vm: &VM,
+    buffer: &Shared<Buffer<S>>,
+    block: BlockInfo,
+    txs: &[(Tx, Hash256)],
+) -> AppResult<Vec<Speculation>>
+where
+    S: Storage + Clone + 'static,
+    VM: Vm + Clone + Send + Sync + 'static,
+    AppError: From<VM::Error>,
+{
+    let num_workers = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(txs.len());
+    let next_idx = AtomicUsize::new(0);
+
+    let mut results = thread::scope(|scope| {
+        let workers = (0..num_workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = vec![];
+
+                    loop {
+                        let idx = next_idx.fetch_add(1, atomic::Ordering::Relaxed);
+                        let Some((tx, _)) = txs.get(idx) else {
+                            break;
+                        };
+
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(idx, "Processing transaction");
+
+                        results.push((
+                            idx,
+                            speculate(vm.clone(), buffer.clone(), block, tx.clone()),
+                        ));
+                    }
+
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|err| panic::resume_unwind(err))
+            })
+            .collect::<Vec<_>>()
+    });
+
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, res)| res).collect()
+}
+
+fn speculate<S, VM>(
+    vm: VM,
+    buffer: Shared<Buffer<S>>,
+    block: BlockInfo,
+    tx: Tx,
+) -> AppResult<Speculation>
+where
+    S: Storage + Clone + 'static,
+    VM: Vm + Clone + Send + Sync + 'static,
+    AppError: From<VM::Error>,
+{
+    let reads = Shared::new(ReadSet::default());
+    let storage = ReadTracker {
+        base: buffer,
+        reads: reads.clone(),
+    };
+
+    let (tx_outcome, batch) = execute_tx(vm, storage, block, tx)?;
+
+    Ok(Speculation {
+        tx_outcome,
+        batch,
+        reads: reads.disassemble(),
+    })
+}
+
+/// Execute a transaction in a new buffer on top of the given storage, and
+/// return the outcome along with the changes made to the storage.
+fn execute_tx<S, VM>(vm: VM, storage: S, block: BlockInfo, tx: Tx) -> AppResult<(TxOutcome, Batch)>
+where
+    S: Storage + Clone + 'static,
+    VM: Vm + Clone + Send + Sync + 'static,
+    AppError: From<VM::Error>,
+{
+    let buffer = Shared::new(Buffer::new(storage, None));
+
+    // Load the gas costs from the buffer, so that the read is tracked, as they
+    // may have been updated by a previous tx in the same block.
+    let gas_costs = *CONFIG.load(&buffer)?.gas_costs;
+
+    let tx_outcome = process_tx(
+        vm,
+        buffer.clone(),
+        block,
+        gas_costs,
+        tx,
+        AuthMode::Finalize,
+        TraceOption::LOUD,
+        None,
+    );
+
+    let (_, batch) = buffer.disassemble().disassemble();
+
+    Ok((tx_outcome, batch))
+}
+
+// --------------------------------- read set ----------------------------------
+
+/// The keys and ranges a transaction has read from the block's state.
+#[derive(Default)]
+struct ReadSet {
+    keys: BTreeSet<Vec<u8>>,
+    ranges: Vec<(Option<Vec<u8>>, Option<Vec<u8>>)>,
+}
+
+impl ReadSet {
+    /// Return whether any of the given keys is among the keys read, or within
+    /// any of the ranges read.
+    fn conflicts_with(&self, written: &BTreeSet<Vec<u8>>) -> bool {
+        if self.keys.iter().any(|key| written.contains(key)) {
+            return true;
+        }
+
+        self.ranges.iter().any(|(min, max)| {
+            // `BTreeSet::range` panics if min > max. Such a range is empty, so
+            // it can't conflict with anything.
+            if let (Some(min), Some(max)) = (min, max) {
+                if min >= max {
+                    return false;
+                }
+            }
+
+            let min = min.as_deref().map_or(Bound::Unbounded, Bound::Included);
+            let max = max.as_deref().map_or(Bound::Unbounded, Bound::Excluded);
+
+            written.range::<[u8], _>((min, max)).next().is_some()
+        })
+    }
+}
+
+/// A read-only storage that records the keys and ranges read from it.
+#[derive(Clone)]
+struct ReadTracker<S> {
+    base: S,
+    reads: Shared<ReadSet>,
+}
+
+impl<S> ReadTracker<S> {
+    fn track_range(&self, min: Option<&[u8]>, max: Option<&[u8]>) {
+        self.reads.write_with(|mut reads| {
+            reads
+                .ranges
+                .push((min.map(|min| min.to_vec()), max.map(|max| max.to_vec())));
+        });
+    }
+}
+
+impl<S> Storage for ReadTracker<S>
+where
+    S: Storage + Clone,
+{
+    fn read(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.reads.write_with(|mut reads| {
+            reads.keys.insert(key.to_vec());
+        });
+
+        self.base.read(key)
+    }
+
+    fn scan<'a>(
+        &'a self,
+        min: Option<&[u8]>,
+        max: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        self.track_range(min, max);
+        self.base.scan(min, max, order)
+    }
+
+    fn scan_keys<'a>(
+        &'a self,
+        min: Option<&[u8]>,
+        max: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        self.track_range(min, max);
+        self.base.scan_keys(min, max, order)
+    }
+
+    fn scan_values<'a>(
+        &'a self,
+        min: Option<&[u8]>,
+        max: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        self.track_range(min, max);
+        self.base.scan_values(min, max, order)
+    }
+
+    fn write(&mut self, _key: &[u8], _value: &[u8]) {
+        unreachable!("write function called on read-only storage");
+    }
+
+    fn remove(&mut self, _key: &[u8]) {
+        unreachable!("write function called on read-only storage");
+    }
+
+    fn remove_range(&mut self, _min: Option<&[u8]>, _max: Option<&[u8]>) {
+        unreachable!("write function called on read-only storage");
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {super::*, test_case::test_case};
+
+    fn written() -> BTreeSet<Vec<u8>> {
+        BTreeSet::from([b"bar".to_vec(), b"foo".to_vec()])
+    }
+
+    #[test_case(&["foo"], &[] => true; "key read")]
+    #[test_case(&["fuzz"], &[] => false; "key not read")]
+    #[test_case(&[], &[(Some("baa"), Some("bas"))] => true; "within range")]
+    #[test_case(&[], &[(Some("baa"), Some("bar"))] => false; "max is exclusive")]
+    #[test_case(&[], &[(Some("fop"), None)] => false; "after range")]
+    #[test_case(&[], &[(None, Some("bas"))] => true; "unbounded min")]
+    #[test_case(&[], &[(Some("zzz"), Some("aaa"))] => false; "empty range")]
+    fn detecting_conflicts(keys: &[&str], ranges: &[(Option<&str>, Option<&str>)]) -> bool {
+        let reads = ReadSet {
+            keys: keys.iter().map(|key| key.as_bytes().to_vec()).collect(),
+            ranges: ranges
+                .iter()
+                .map(|(min, max)| {
+                    (
+                        min.map(|min| min.as_bytes().to_vec()),
+                        max.map(|max| max.as_bytes().to_vec()),
+                    )
+                })
+                .collect(),
+        };
+
+        reads.conflicts_with(&written())
+    }
+}
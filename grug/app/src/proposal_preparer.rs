@@ -1,7 +1,7 @@
 #[cfg(feature = "tracing")]
 use tracing::info;
 use {
-    crate::{AppError, ProposalPreparer},
+    crate::{AppError, ProposalPreparer, VoteExtension},
     grug_types::{Querier, QuerierWrapper, Query, QueryResponse, StdResult},
     prost::bytes::Bytes,
     std::{
@@ -25,6 +25,7 @@ impl ProposalPreparer for NaiveProposalPreparer {
         &self,
         _querier: QuerierWrapper,
         mut txs: Vec<Bytes>,
+        _vote_extensions: &[VoteExtension],
         max_tx_bytes: usize,
     ) -> Result<Vec<Bytes>, Self::Error> {
         let mut total_tx_bytes: usize = txs
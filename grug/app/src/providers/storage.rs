@@ -1,4 +1,7 @@
-use grug_types::{Order, Record, Storage, concat, increment_last_byte, trim};
+use {
+    crate::Tracer,
+    grug_types::{Order, Record, Storage, concat, increment_last_byte, trim},
+};
 
 /// Provides access to an account's storage to the VM.
 ///
@@ -8,6 +11,9 @@ use grug_types::{Order, Record, Storage, concat, increment_last_byte, trim};
 pub struct StorageProvider {
     storage: Box<dyn Storage>,
     namespace: Vec<u8>,
+    // If provided, the keys that are read or written are reported to the
+    // tracer.
+    tracer: Option<Tracer>,
 }
 
 impl StorageProvider {
@@ -22,7 +28,18 @@ impl StorageProvider {
             namespace.extend_from_slice(prefix);
         }
 
-        Self { storage, namespace }
+        Self {
+            storage,
+            namespace,
+            tracer: None,
+        }
+    }
+
+    /// Report the keys that are read or written to the given tracer. If `None`,
+    /// the keys aren't traced.
+    pub fn with_tracer(mut self, tracer: Option<Tracer>) -> Self {
+        self.tracer = tracer;
+        self
     }
 
     pub fn namespace(&self) -> &[u8] {
@@ -32,6 +49,10 @@ impl StorageProvider {
 
 impl Storage for StorageProvider {
     fn read(&self, key: &[u8]) -> Option<Vec<u8>> {
+        if let Some(tracer) = &self.tracer {
+            tracer.record_read(key);
+        }
+
         let prefixed_key = concat(&self.namespace, key);
         self.storage.read(&prefixed_key)
     }
@@ -46,7 +67,15 @@ impl Storage for StorageProvider {
         let iter = self
             .storage
             .scan(Some(&min), Some(&max), order)
-            .map(|(key, value)| (trim(&self.namespace, &key), value));
+            .map(|(key, value)| {
+                let key = trim(&self.namespace, &key);
+
+                if let Some(tracer) = &self.tracer {
+                    tracer.record_read(&key);
+                }
+
+                (key, value)
+            });
 
         Box::new(iter)
     }
@@ -61,7 +90,15 @@ impl Storage for StorageProvider {
         let iter = self
             .storage
             .scan_keys(Some(&min), Some(&max), order)
-            .map(|key| trim(&self.namespace, &key));
+            .map(|key| {
+                let key = trim(&self.namespace, &key);
+
+                if let Some(tracer) = &self.tracer {
+                    tracer.record_read(&key);
+                }
+
+                key
+            });
 
         Box::new(iter)
     }
@@ -77,11 +114,19 @@ impl Storage for StorageProvider {
     }
 
     fn write(&mut self, key: &[u8], value: &[u8]) {
+        if let Some(tracer) = &self.tracer {
+            tracer.record_write(key);
+        }
+
         let prefixed_key = concat(&self.namespace, key);
         self.storage.write(&prefixed_key, value);
     }
 
     fn remove(&mut self, key: &[u8]) {
+        if let Some(tracer) = &self.tracer {
+            tracer.record_write(key);
+        }
+
         let prefixed_key = concat(&self.namespace, key);
         self.storage.remove(&prefixed_key);
     }
@@ -8,9 +8,9 @@ use {
         Addr, BankQuery, BankQueryResponse, Binary, BlockInfo, Bound, Code, Coin, Coins, Config,
         Context, ContractInfo, DEFAULT_PAGE_LIMIT, GenericResult, Hash256, Json, Order,
         QueryBalanceRequest, QueryBalancesRequest, QueryCodeRequest, QueryCodesRequest,
-        QueryContractRequest, QueryContractsRequest, QueryStatusResponse, QuerySuppliesRequest,
-        QuerySupplyRequest, QueryWasmRawRequest, QueryWasmScanRequest, QueryWasmSmartRequest,
-        StdResult, Storage,
+        QueryContractRequest, QueryContractsRequest, QueryStatusResponse, QueryStoreScanRequest,
+        QuerySuppliesRequest, QuerySupplyRequest, QueryWasmRawRequest, QueryWasmScanRequest,
+        QueryWasmSmartRequest, StdResult, Storage,
     },
     std::collections::BTreeMap,
 };
@@ -153,6 +153,8 @@ where
         mode: None,
     };
 
+    let _call = gas_tracker.trace_call(ctx.contract, "bank_query");
+
     call_in_1_out_1::<_, _, GenericResult<BankQueryResponse>>(
         vm,
         storage,
@@ -245,6 +247,26 @@ pub fn query_wasm_scan(
         .collect()
 }
 
+pub fn query_store_scan(
+    storage: Box<dyn Storage>,
+    gas_tracker: GasTracker,
+    req: QueryStoreScanRequest,
+) -> StdResult<BTreeMap<Binary, Binary>> {
+    let limit = req.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+
+    storage
+        .scan_with_gas(
+            gas_tracker,
+            req.min.as_deref(),
+            req.max.as_deref(),
+            // Order doesn't matter, as we're collecting results into a BTreeMap.
+            Order::Ascending,
+        )?
+        .take(limit as usize)
+        .map(|res| res.map(|(k, v)| (Binary::from(k), Binary::from(v))))
+        .collect()
+}
+
 pub fn query_wasm_smart<VM>(
     vm: VM,
     storage: Box<dyn Storage>,
@@ -269,6 +291,8 @@ where
         mode: None,
     };
 
+    let _call = gas_tracker.trace_call(ctx.contract, "query");
+
     call_in_1_out_1::<_, _, GenericResult<Json>>(
         vm,
         storage,
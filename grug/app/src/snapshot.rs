@@ -0,0 +1,92 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    grug_types::{Hash256, HashExt, StdError, StdResult},
+};
+
+/// Configurations of state sync snapshots.
+///
+/// If provided to the [`App`](crate::App), a snapshot of the state is taken
+/// every `interval` blocks, after the block is committed. Depending on the DB,
+/// this may happen in the background.
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotConfig {
+    /// Take a snapshot every this many blocks.
+    pub interval: u64,
+    /// Number of most recent snapshots to keep. Older ones are deleted.
+    pub keep_recent: usize,
+    /// Approximate size of each chunk, in bytes.
+    ///
+    /// A chunk always contains whole key-value pairs, so a chunk may slightly
+    /// exceed this size.
+    pub chunk_size: usize,
+}
+
+/// Describes a state snapshot, as advertised to peers during state sync.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The block height at which the snapshot was taken.
+    pub height: u64,
+    /// The format of the snapshot. Snapshots of an unknown format are rejected.
+    pub format: u32,
+    /// Hashes of each of the chunks, in order.
+    ///
+    /// When restoring, each chunk is checked against its hash before being
+    /// applied, so that a bad chunk can be refetched from another peer.
+    pub chunk_hashes: Vec<Hash256>,
+}
+
+impl Snapshot {
+    /// Return the number of chunks in the snapshot.
+    ///
+    /// Error if there are more chunks than can be indexed by a `u32`, which is
+    /// how chunks are indexed in ABCI.
+    pub fn chunks(&self) -> StdResult<u32> {
+        let len = self.chunk_hashes.len();
+        u32::try_from(len)
+            .map_err(|_| StdError::length_out_of_range::<Vec<Hash256>>(len, ">", u32::MAX as usize))
+    }
+
+    /// Return the hash of the snapshot, defined as the hash of the concatenated
+    /// chunk hashes.
+    pub fn hash(&self) -> Hash256 {
+        let mut preimage = Vec::with_capacity(self.chunk_hashes.len() * Hash256::LENGTH);
+        for hash in &self.chunk_hashes {
+            preimage.extend_from_slice(hash);
+        }
+        preimage.hash256()
+    }
+}
+
+/// Result of offering a snapshot to the database for restoration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfferSnapshotOutcome {
+    /// The snapshot is accepted. Chunks will be applied next.
+    Accept,
+    /// Abort state sync altogether; do not try any other snapshot.
+    Abort,
+    /// Reject this snapshot, but try others.
+    Reject,
+    /// Reject all snapshots of this format.
+    RejectFormat,
+}
+
+/// Result of applying a snapshot chunk to the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplySnapshotChunkOutcome {
+    /// The chunk is applied. If this is the last chunk, the state has been
+    /// fully restored and verified against the trusted app hash.
+    Accept,
+    /// Abort state sync altogether.
+    Abort,
+    /// The chunk doesn't match its expected hash. Refetch it, preferably from
+    /// a different peer.
+    Retry,
+    /// The chunk isn't the one to be applied next. Refetch the expected one.
+    ///
+    /// Unlike `Retry`, the chunk itself may be perfectly valid, so the peer who
+    /// sent it isn't at fault.
+    OutOfOrder { expected: u32 },
+    /// The snapshot is invalid (e.g. the restored root hash doesn't match the
+    /// trusted app hash). Discard it and try another one.
+    RejectSnapshot,
+}
@@ -1,7 +1,8 @@
 use {
-    grug_storage::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, Set},
+    grug_storage::{Counter, Index, IndexList, IndexedMap, Item, Map, MultiIndex, Set},
     grug_types::{
-        Addr, BlockInfo, Code, CodeStatus, Config, ContractInfo, Hash256, Json, Timestamp,
+        Addr, BlockInfo, ByteArray, Code, CodeStatus, Config, ContractInfo, Hash160, Hash256, Json,
+        ScheduledMsg, Subscription, Timestamp,
     },
 };
 
@@ -17,12 +18,32 @@ pub const APP_CONFIG: Item<Json> = Item::new("app_config");
 /// The most recently finalized block
 pub const LAST_FINALIZED_BLOCK: Item<BlockInfo> = Item::new("last_finalized_block");
 
+/// Ed25519 consensus public keys of the genesis validators: address => public key
+///
+/// Used to verify the signatures of vote extensions included in proposals.
+pub const VALIDATORS: Map<Hash160, ByteArray<32>> = Map::new("validators");
+
 /// Scheduled cronjobs.
 ///
 /// This needs to be a `Set` instead of `Map<Timestamp, Addr>` because there can
 /// be multiple jobs with the same scheduled time.
 pub const NEXT_CRONJOBS: Set<(Timestamp, Addr)> = Set::new("jobs");
 
+/// The ID to be assigned to the next scheduled contract execution.
+pub const NEXT_SCHEDULED_ID: Counter<u64> = Counter::new("next_scheduled_id", 0, 1);
+
+/// Scheduled contract executions: id => scheduled message
+pub const SCHEDULED_MSGS: Map<u64, ScheduledMsg> = Map::new("scheduled");
+
+/// IDs of the scheduled contract executions that are due at a timestamp.
+pub const SCHEDULED_BY_TIME: Set<(Timestamp, u64)> = Set::new("scheduled__time");
+
+/// IDs of the scheduled contract executions that are due at a block height.
+pub const SCHEDULED_BY_HEIGHT: Set<(u64, u64)> = Set::new("scheduled__height");
+
+/// Event subscriptions: (emitter, event_type, subscriber) => subscription
+pub const SUBSCRIPTIONS: Map<(Addr, &str, Addr), Subscription> = Map::new("subscriptions");
+
 /// Wasm contract byte codes: code_hash => byte_code
 pub const CODES: IndexedMap<Hash256, Code, CodeIndexes> = IndexedMap::new("codes", CodeIndexes {
     status: MultiIndex::new(|_, c| c.status, "codes", "codes__status"),
@@ -1,3 +1,5 @@
+use grug_types::{Addr, CallFrame, ExecutionTrace, Shared};
+
 #[derive(Clone, Copy)]
 pub enum Level {
     TRACE,
@@ -46,3 +48,130 @@ impl TraceOption {
         error_level: Level::TRACE,
     };
 }
+
+#[derive(Default)]
+struct TracerInner {
+    trace: ExecutionTrace,
+    // Frames of the contract calls that haven't returned yet, with the
+    // innermost one being the last.
+    stack: Vec<CallFrame>,
+}
+
+/// Records the contract calls made during the execution of a transaction, and
+/// the gas consumed and storage keys touched by each of them.
+///
+/// A tracer is attached to a [`GasTracker`](crate::GasTracker), which reports
+/// to it each time gas is consumed.
+#[derive(Clone)]
+pub struct Tracer {
+    inner: Shared<TracerInner>,
+}
+
+impl Default for Tracer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tracer {
+    pub fn new() -> Self {
+        Self {
+            inner: Shared::new(TracerInner::default()),
+        }
+    }
+
+    /// Record the start of a call to a contract's entry point. The call ends
+    /// when the returned guard is dropped.
+    ///
+    /// Panics if lock is poisoned.
+    pub fn enter(&self, contract: Addr, entry_point: &str) -> CallGuard {
+        self.inner.write_with(|mut inner| {
+            inner.stack.push(CallFrame::new(contract, entry_point));
+        });
+
+        CallGuard {
+            tracer: self.clone(),
+        }
+    }
+
+    fn exit(&self) {
+        self.inner.write_with(|mut inner| {
+            let frame = inner
+                .stack
+                .pop()
+                .expect("exiting a call that wasn't entered");
+
+            match inner.stack.last_mut() {
+                Some(parent) => {
+                    parent.gas_used += frame.gas_used;
+                    parent.children.push(frame);
+                },
+                None => {
+                    inner.trace.calls.push(frame);
+                },
+            }
+        });
+    }
+
+    /// Attribute the given amount of gas to the current call, or to the
+    /// transaction itself if not inside any call.
+    ///
+    /// Panics if lock is poisoned.
+    pub fn record_gas(&self, consumed: u64, comment: &'static str) {
+        self.inner.write_with(|mut inner| {
+            let inner = &mut *inner;
+            let gas_by_category = match inner.stack.last_mut() {
+                Some(frame) => {
+                    frame.gas_used += consumed;
+                    &mut frame.gas_by_category
+                },
+                None => &mut inner.trace.gas_by_category,
+            };
+
+            *gas_by_category.entry(comment.to_string()).or_default() += consumed;
+        });
+    }
+
+    /// Record that a key in the storage of the contract being called was read.
+    ///
+    /// Panics if lock is poisoned.
+    pub fn record_read(&self, key: &[u8]) {
+        self.inner.write_with(|mut inner| {
+            if let Some(frame) = inner.stack.last_mut() {
+                frame.reads.insert(key.to_vec().into());
+            }
+        });
+    }
+
+    /// Record that a key in the storage of the contract being called was
+    /// written to or removed.
+    ///
+    /// Panics if lock is poisoned.
+    pub fn record_write(&self, key: &[u8]) {
+        self.inner.write_with(|mut inner| {
+            if let Some(frame) = inner.stack.last_mut() {
+                frame.writes.insert(key.to_vec().into());
+            }
+        });
+    }
+
+    /// Return the trace recorded so far, excluding the calls that haven't
+    /// returned yet.
+    ///
+    /// Panics if lock is poisoned.
+    pub fn trace(&self) -> ExecutionTrace {
+        self.inner.read_with(|inner| inner.trace.clone())
+    }
+}
+
+/// Marks the end of a contract call when dropped. Returned by [`Tracer::enter`].
+#[must_use = "the call ends as soon as the guard is dropped"]
+pub struct CallGuard {
+    tracer: Tracer,
+}
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        self.tracer.exit();
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(feature = "ibc")]
 use ics23::CommitmentProof;
 use {
+    crate::{ApplySnapshotChunkOutcome, OfferSnapshotOutcome, Snapshot},
     borsh::{BorshDeserialize, BorshSerialize},
     grug_types::{Batch, Hash256, Storage},
 };
@@ -91,6 +92,91 @@ pub trait Db {
         self.commit()?;
         Ok((new_version, root_hash))
     }
+
+    /// Take a snapshot of the state at the given version, splitting it into
+    /// chunks of approximately the given size, and keep it so that it can be
+    /// served to peers doing state sync.
+    ///
+    /// Return `None` if the DB doesn't support snapshots, which is the default.
+    fn create_snapshot(
+        &self,
+        _version: u64,
+        _chunk_size: usize,
+    ) -> Result<Option<Snapshot>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Take a snapshot at the given version, then delete all but the most
+    /// recent `keep_recent` snapshots.
+    ///
+    /// This is invoked right after a block is committed. Taking a snapshot
+    /// involves reading the entire state, so implementations should do it in
+    /// the background instead of stalling the commit, and report their own
+    /// errors. The default implementation does it synchronously.
+    fn create_snapshot_in_background(
+        &self,
+        version: u64,
+        chunk_size: usize,
+        keep_recent: usize,
+    ) -> Result<(), Self::Error> {
+        self.create_snapshot(version, chunk_size)?;
+        self.prune_snapshots(keep_recent)
+    }
+
+    /// Delete all but the most recent `keep_recent` snapshots.
+    fn prune_snapshots(&self, _keep_recent: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Return the snapshots available for serving to peers.
+    ///
+    /// This is typically invoked in the ABCI `ListSnapshots` call.
+    fn list_snapshots(&self) -> Result<Vec<Snapshot>, Self::Error> {
+        Ok(vec![])
+    }
+
+    /// Return a chunk of the snapshot taken at the given height.
+    ///
+    /// `None` if such a snapshot or chunk doesn't exist.
+    ///
+    /// This is typically invoked in the ABCI `LoadSnapshotChunk` call.
+    fn load_snapshot_chunk(
+        &self,
+        _height: u64,
+        _format: u32,
+        _index: u32,
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Begin restoring the state from a snapshot provided by a peer.
+    ///
+    /// `app_hash` is the trusted app hash at the snapshot's height, obtained by
+    /// the consensus engine from a light client. The restored state must match
+    /// this hash.
+    ///
+    /// This is typically invoked in the ABCI `OfferSnapshot` call.
+    fn offer_snapshot(
+        &self,
+        _snapshot: Snapshot,
+        _app_hash: Hash256,
+    ) -> Result<OfferSnapshotOutcome, Self::Error> {
+        Ok(OfferSnapshotOutcome::RejectFormat)
+    }
+
+    /// Apply a chunk of the snapshot that's being restored.
+    ///
+    /// Chunks are applied in order. Once the last chunk is applied, the state
+    /// is verified against the trusted app hash and persisted.
+    ///
+    /// This is typically invoked in the ABCI `ApplySnapshotChunk` call.
+    fn apply_snapshot_chunk(
+        &self,
+        _index: u32,
+        _chunk: Vec<u8>,
+    ) -> Result<ApplySnapshotChunkOutcome, Self::Error> {
+        Ok(ApplySnapshotChunkOutcome::Abort)
+    }
 }
 
 /// Represents a database that can be pruned.
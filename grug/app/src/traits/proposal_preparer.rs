@@ -1,17 +1,95 @@
-use {grug_types::QuerierWrapper, prost::bytes::Bytes};
+use {crate::VoteExtensionVerifier, grug_types::QuerierWrapper, prost::bytes::Bytes};
 
-/// Represents a worker that processes the ABCI++ `PrepareProposal` request.
+/// A vote extension attached by a validator to its precommit vote, as seen by
+/// the proposer of the next block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteExtension {
+    /// Address of the validator, i.e. the first 20 bytes of the SHA-256 hash of
+    /// its consensus public key.
+    pub validator: [u8; 20],
+    /// The validator's voting power.
+    pub power: u64,
+    /// The extension data, as produced by [`ProposalPreparer::extend_vote`].
+    ///
+    /// Empty if the validator didn't vote, or didn't extend its vote.
+    pub data: Bytes,
+    /// The validator's signature over the extension.
+    ///
+    /// Empty if the validator didn't vote.
+    pub signature: Bytes,
+}
+
+/// Verdict on a block proposed by another validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessProposalOutcome {
+    Accept,
+    Reject { reason: String },
+}
+
+/// Represents a worker that processes the ABCI++ `PrepareProposal`,
+/// `ProcessProposal`, `ExtendVote`, and `VerifyVoteExtension` requests.
 pub trait ProposalPreparer {
     type Error: ToString;
 
     /// Process the ABCI++ `PrepareProposal` request.
     ///
     /// The preparer is provided with a querier so that it can do its work based
-    /// on the state of the chain.
+    /// on the state of the chain, and the vote extensions of the validators
+    /// who voted on the previous block.
     fn prepare_proposal(
         &self,
         querier: QuerierWrapper,
         txs: Vec<Bytes>,
+        vote_extensions: &[VoteExtension],
         max_tx_bytes: usize,
     ) -> Result<Vec<Bytes>, Self::Error>;
+
+    /// Process the ABCI++ `ProcessProposal` request.
+    ///
+    /// Called by every validator on a block proposed by another validator.
+    /// This is typically used to verify that the transactions inserted by the
+    /// proposer during `PrepareProposal` are valid. Vote extensions the
+    /// proposer relied on should be included in the proposal, so that they can
+    /// be verified here against the commit of the last block.
+    ///
+    /// Return `Reject` if the proposal is invalid. Return an error only if the
+    /// proposal can't be evaluated due to a local failure (e.g. failing to
+    /// read the state), which says nothing about the proposal itself.
+    ///
+    /// The default implementation accepts all proposals.
+    fn process_proposal(
+        &self,
+        _querier: QuerierWrapper,
+        _txs: &[Bytes],
+        _verifier: &VoteExtensionVerifier,
+    ) -> Result<ProcessProposalOutcome, Self::Error> {
+        Ok(ProcessProposalOutcome::Accept)
+    }
+
+    /// Process the ABCI++ `ExtendVote` request.
+    ///
+    /// Return data to be attached to this validator's precommit vote for the
+    /// block at the given height. The data is made available to the proposer
+    /// of the next block in `PrepareProposal`.
+    ///
+    /// The default implementation doesn't extend the vote.
+    fn extend_vote(&self, _querier: QuerierWrapper, _height: u64) -> Result<Bytes, Self::Error> {
+        Ok(Bytes::new())
+    }
+
+    /// Process the ABCI++ `VerifyVoteExtension` request.
+    ///
+    /// Returning an error means the vote extension, and therefore the vote
+    /// itself, is rejected. As such, implementations should be lenient: only
+    /// reject extensions that are malformed, as rejecting valid votes may
+    /// compromise the liveness of the chain.
+    ///
+    /// The default implementation accepts all vote extensions.
+    fn verify_vote_extension(
+        &self,
+        _querier: QuerierWrapper,
+        _data: &[u8],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use {
-    crate::AppResult,
-    grug_types::{BlockInfo, Storage},
+    crate::{AppResult, CONFIG},
+    borsh::{BorshDeserialize, BorshSerialize},
+    grug_storage::Item,
+    grug_types::{Addr, BlockInfo, Config, Duration, Permissions, Storage},
+    std::collections::BTreeMap,
 };
 
 pub struct UpgradeHandler<VM> {
@@ -12,3 +15,108 @@ pub struct UpgradeHandler<VM> {
     /// The function takes the state storage and the VM instance as inputs, and returns empty.
     pub action: fn(Box<dyn Storage>, VM, BlockInfo) -> AppResult<()>,
 }
+
+impl<VM> UpgradeHandler<VM> {
+    /// An upgrade for chains started before the gas costs became part of the
+    /// chain config. See [`add_gas_costs_to_config`](crate::add_gas_costs_to_config).
+    pub fn add_gas_costs_to_config(height: u64) -> Self {
+        Self {
+            description: Some("Add gas costs to the chain config"),
+            height,
+            action: add_gas_costs_to_config,
+        }
+    }
+}
+
+/// The chain config, as stored by versions before the gas costs became part of
+/// it.
+#[derive(BorshSerialize, BorshDeserialize)]
+struct LegacyConfig {
+    owner: Addr,
+    bank: Addr,
+    taxman: Addr,
+    cronjobs: BTreeMap<Addr, Duration>,
+    permissions: Permissions,
+    max_orphan_age: Duration,
+}
+
+/// The same storage slot as [`CONFIG`](crate::CONFIG), read in the legacy layout.
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
+/// Rewrite the chain config stored in the legacy layout, which doesn't include
+/// the gas costs, to the current one, using the default gas costs.
+///
+/// Does nothing if the config is already in the current layout.
+pub fn add_gas_costs_to_config<VM>(
+    mut storage: Box<dyn Storage>,
+    _vm: VM,
+    _block: BlockInfo,
+) -> AppResult<()> {
+    if CONFIG.load(&storage).is_ok() {
+        return Ok(());
+    }
+
+    let legacy = LEGACY_CONFIG.load(&storage)?;
+
+    CONFIG.save(&mut storage, &Config {
+        owner: legacy.owner,
+        bank: legacy.bank,
+        taxman: legacy.taxman,
+        cronjobs: legacy.cronjobs,
+        permissions: legacy.permissions,
+        max_orphan_age: legacy.max_orphan_age,
+        gas_costs: Default::default(),
+        end_block_limits: Default::default(),
+    })?;
+
+    Ok(())
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        grug_types::{DEFAULT_GAS_COSTS, Hash256, MockStorage, Permission, Shared},
+    };
+
+    #[test]
+    fn adding_gas_costs_to_config_works() {
+        let storage = Shared::new(MockStorage::new());
+        let block = BlockInfo {
+            height: 1,
+            timestamp: Duration::from_seconds(1),
+            hash: Hash256::ZERO,
+        };
+
+        LEGACY_CONFIG
+            .save(&mut storage.clone(), &LegacyConfig {
+                owner: Addr::mock(1),
+                bank: Addr::mock(2),
+                taxman: Addr::mock(3),
+                cronjobs: BTreeMap::from([(Addr::mock(4), Duration::from_seconds(60))]),
+                permissions: Permissions {
+                    upload: Permission::Nobody,
+                    instantiate: Permission::Everybody,
+                },
+                max_orphan_age: Duration::from_seconds(3600),
+            })
+            .unwrap();
+
+        // The legacy config can't be read in the current layout.
+        assert!(CONFIG.load(&storage).is_err());
+
+        add_gas_costs_to_config(Box::new(storage.clone()), (), block).unwrap();
+
+        let cfg = CONFIG.load(&storage).unwrap();
+        assert_eq!(cfg.owner, Addr::mock(1));
+        assert_eq!(cfg.taxman, Addr::mock(3));
+        assert_eq!(cfg.max_orphan_age, Duration::from_seconds(3600));
+        assert_eq!(*cfg.gas_costs, DEFAULT_GAS_COSTS);
+
+        // Running the upgrade again leaves the config untouched.
+        add_gas_costs_to_config(Box::new(storage.clone()), (), block).unwrap();
+        assert_eq!(CONFIG.load(&storage).unwrap(), cfg);
+    }
+}
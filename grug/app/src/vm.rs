@@ -150,6 +150,10 @@ where
     VM: Vm + Clone + Send + Sync + 'static,
     AppError: From<VM::Error>,
 {
+    // The call ends after the submessages are handled, such that calls made
+    // by the submessages are recorded as its children.
+    let _call = gas_tracker.trace_call(ctx.contract, name);
+
     let evt = EvtGuest::base(ctx.contract, name);
 
     let response = catch_event! {
@@ -206,6 +210,8 @@ where
     VM: Vm + Clone + Send + Sync + 'static,
     AppError: From<VM::Error>,
 {
+    let _call = gas_tracker.trace_call(ctx.contract, name);
+
     let evt = EvtGuest::base(ctx.contract, name);
 
     let response = catch_event! {
@@ -261,6 +267,8 @@ where
     VM: Vm + Clone + Send + Sync + 'static,
     AppError: From<VM::Error>,
 {
+    let _call = gas_tracker.trace_call(ctx.contract, name);
+
     let evt = EvtGuest::base(ctx.contract, name);
 
     let auth_response = catch_event! {
@@ -322,6 +330,8 @@ where
     VM: Vm + Clone + Send + Sync + 'static,
     AppError: From<VM::Error>,
 {
+    let _call = gas_tracker.trace_call(ctx.contract, name);
+
     let evt = EvtGuest::base(ctx.contract, name);
 
     let response = catch_event! {
@@ -383,7 +393,8 @@ where
         gas_tracker.clone(),
         block,
     ));
-    let storage = StorageProvider::new(storage, &[CONTRACT_NAMESPACE, &contract]);
+    let storage = StorageProvider::new(storage, &[CONTRACT_NAMESPACE, &contract])
+        .with_tracer(gas_tracker.tracer().cloned());
 
     Ok(vm.build_instance(
         &code.code,
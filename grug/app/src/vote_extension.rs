@@ -0,0 +1,232 @@
+use {
+    crate::{AppResult, VALIDATORS, VoteExtension},
+    grug_crypto::ed25519_batch_verify,
+    grug_types::{Hash160, HashExt, Inner, StdResult, Storage},
+    prost::Message,
+    std::collections::BTreeMap,
+};
+
+/// A validator in the validator set of the last block, as reported by the
+/// consensus engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitValidator {
+    /// Address of the validator, i.e. the first 20 bytes of the SHA-256 hash of
+    /// its consensus public key.
+    pub address: [u8; 20],
+    /// The validator's voting power.
+    pub power: u64,
+}
+
+/// The commit of the last block, as reported by the consensus engine in the
+/// ABCI++ `ProcessProposal` request.
+///
+/// Unlike the vote extensions a proposer puts in its proposal, this comes from
+/// the consensus engine, and can be trusted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LastCommit {
+    /// The round in which the last block was committed.
+    pub round: u32,
+    /// All validators in the validator set of the last block, including those
+    /// who didn't vote.
+    pub validators: Vec<CommitValidator>,
+}
+
+/// Verifies vote extensions included in a proposal against the commit of the
+/// last block.
+#[derive(Debug, Clone)]
+pub struct VoteExtensionVerifier {
+    chain_id: String,
+    height: u64,
+    round: u32,
+    /// Validator address => (voting power, Ed25519 consensus public key).
+    ///
+    /// The public key is `None` if it isn't known to the app, in which case
+    /// the validator's extensions can't be verified.
+    validators: BTreeMap<[u8; 20], (u64, Option<[u8; 32]>)>,
+}
+
+impl VoteExtensionVerifier {
+    /// Create a verifier for vote extensions on the block at the given height,
+    /// loading the validators' public keys from the app's storage.
+    pub fn load(
+        storage: &dyn Storage,
+        chain_id: String,
+        height: u64,
+        last_commit: &LastCommit,
+    ) -> AppResult<Self> {
+        let validators = last_commit
+            .validators
+            .iter()
+            .map(|val| {
+                let pubkey = VALIDATORS
+                    .may_load(storage, Hash160::from_inner(val.address))?
+                    .map(|pubkey| pubkey.into_inner());
+
+                Ok((val.address, (val.power, pubkey)))
+            })
+            .collect::<StdResult<_>>()?;
+
+        Ok(Self {
+            chain_id,
+            height,
+            round: last_commit.round,
+            validators,
+        })
+    }
+
+    /// Return the total voting power of the validator set.
+    pub fn total_power(&self) -> u64 {
+        self.validators
+            .values()
+            .fold(0, |acc, (power, _)| acc.saturating_add(*power))
+    }
+
+    /// Return whether the vote extension was signed by a validator in the
+    /// validator set, and that the voting power it claims is correct.
+    ///
+    /// Only Ed25519 consensus keys are supported.
+    pub fn verify(&self, vote: &VoteExtension) -> bool {
+        let Some((power, Some(pubkey))) = self.validators.get(&vote.validator) else {
+            return false;
+        };
+
+        if *power != vote.power {
+            return false;
+        }
+
+        let sign_bytes =
+            vote_extension_sign_bytes(&self.chain_id, self.height, self.round, &vote.data);
+
+        ed25519_batch_verify(&[&sign_bytes], &[&vote.signature], &[pubkey]).is_ok()
+    }
+}
+
+/// Save the Ed25519 consensus public keys of the validators, so that their
+/// vote extensions can later be verified.
+pub fn save_validators(storage: &mut dyn Storage, pubkeys: &[[u8; 32]]) -> StdResult<()> {
+    for pubkey in pubkeys {
+        VALIDATORS.save(storage, validator_address(pubkey), &(*pubkey).into())?;
+    }
+
+    Ok(())
+}
+
+/// Derive a validator's address from its Ed25519 consensus public key, the same
+/// way CometBFT does.
+pub fn validator_address(pubkey: &[u8; 32]) -> Hash160 {
+    let mut address = [0; 20];
+    address.copy_from_slice(&pubkey.hash256()[..20]);
+    Hash160::from_inner(address)
+}
+
+/// The message a validator signs over its vote extension.
+///
+/// Mirrors the `CanonicalVoteExtension` type in CometBFT's protobuf definitions.
+#[derive(Clone, PartialEq, Message)]
+struct CanonicalVoteExtension {
+    #[prost(bytes = "vec", tag = "1")]
+    extension: Vec<u8>,
+    #[prost(sfixed64, tag = "2")]
+    height: i64,
+    #[prost(sfixed64, tag = "3")]
+    round: i64,
+    #[prost(string, tag = "4")]
+    chain_id: String,
+}
+
+/// Return the bytes a validator signs over its vote extension, the same way
+/// CometBFT does: the length-prefixed protobuf encoding of the canonical vote
+/// extension.
+pub fn vote_extension_sign_bytes(
+    chain_id: &str,
+    height: u64,
+    round: u32,
+    extension: &[u8],
+) -> Vec<u8> {
+    CanonicalVoteExtension {
+        extension: extension.to_vec(),
+        height: height as i64,
+        round: round as i64,
+        chain_id: chain_id.to_string(),
+    }
+    .encode_length_delimited_to_vec()
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        ed25519_dalek::{Signer, SigningKey},
+        grug_types::MockStorage,
+        prost::bytes::Bytes,
+    };
+
+    #[test]
+    fn sign_bytes_match_cometbft() {
+        // extension = "hi", height = 1, round = 0, chain_id = "test"
+        assert_eq!(vote_extension_sign_bytes("test", 1, 0, b"hi"), [
+            19, // length
+            10, 2, b'h', b'i', // extension
+            17, 1, 0, 0, 0, 0, 0, 0, 0, // height
+            // round is zero, hence omitted
+            34, 4, b't', b'e', b's', b't', // chain_id
+        ]);
+    }
+
+    #[test]
+    fn verifying_vote_extensions_works() {
+        let sk = SigningKey::from_bytes(&[1; 32]);
+        let pubkey = sk.verifying_key().to_bytes();
+        let address = validator_address(&pubkey).into_inner();
+
+        let mut storage = MockStorage::new();
+        save_validators(&mut storage, &[pubkey]).unwrap();
+
+        let last_commit = LastCommit {
+            round: 2,
+            validators: vec![
+                CommitValidator { address, power: 1 },
+                // A validator whose public key isn't known.
+                CommitValidator {
+                    address: [2; 20],
+                    power: 2,
+                },
+            ],
+        };
+        let verifier =
+            VoteExtensionVerifier::load(&storage, "test".to_string(), 5, &last_commit).unwrap();
+
+        assert_eq!(verifier.total_power(), 3);
+
+        let sign = |height, round, data: &[u8]| {
+            let sign_bytes = vote_extension_sign_bytes("test", height, round, data);
+            Bytes::from(sk.sign(&sign_bytes).to_vec())
+        };
+
+        let vote = |validator, power, signature| VoteExtension {
+            validator,
+            power,
+            data: Bytes::from_static(b"hello"),
+            signature,
+        };
+
+        // Correctly signed.
+        assert!(verifier.verify(&vote(address, 1, sign(5, 2, b"hello"))));
+
+        // Incorrect voting power.
+        assert!(!verifier.verify(&vote(address, 2, sign(5, 2, b"hello"))));
+
+        // Signed for a different height, round, or data.
+        assert!(!verifier.verify(&vote(address, 1, sign(4, 2, b"hello"))));
+        assert!(!verifier.verify(&vote(address, 1, sign(5, 1, b"hello"))));
+        assert!(!verifier.verify(&vote(address, 1, sign(5, 2, b"world"))));
+
+        // Validator whose public key isn't known.
+        assert!(!verifier.verify(&vote([2; 20], 2, sign(5, 2, b"hello"))));
+
+        // Validator that isn't in the validator set.
+        assert!(!verifier.verify(&vote([3; 20], 1, sign(5, 2, b"hello"))));
+    }
+}
@@ -2,11 +2,13 @@ use {
     anyhow::{bail, ensure},
     async_trait::async_trait,
     grug_types::{
-        Binary, Block, BlockClient, BlockInfo, BlockOutcome, BroadcastClient, BroadcastTxOutcome,
-        CronOutcome, Hash256, Inner, JsonDeExt, JsonSerExt, Query, QueryClient, QueryResponse,
-        SearchTxClient, SearchTxOutcome, StdResult, Timestamp, Tx, TxOutcome, UnsignedTx,
+        Binary, Block, BlockClient, BlockInfo, BlockOutcome, BorshDeExt, BroadcastClient,
+        BroadcastTxOutcome, CronOutcome, Hash256, Inner, JsonDeExt, JsonSerExt, Query, QueryClient,
+        QueryResponse, QueryStoreResponse, QueryStoreScanRequest, ReceiveEventOutcome,
+        ScheduledOutcome, SearchTxClient, SearchTxOutcome, StateOverrides, StdResult, Timestamp,
+        Tx, TxOutcome, UnsignedTx,
     },
-    std::any::type_name,
+    std::{any::type_name, collections::BTreeMap},
     tendermint::block::Height,
     tendermint_rpc::{
         Client, HttpClient,
@@ -77,7 +79,7 @@ impl QueryClient for TendermintRpcClient {
         key: Binary,
         height: Option<u64>,
         prove: bool,
-    ) -> Result<(Option<Binary>, Option<Self::Proof>), Self::Error> {
+    ) -> Result<QueryStoreResponse<Self::Proof>, Self::Error> {
         let res = self
             .query("/store", key.clone().into_inner(), height, prove)
             .await?;
@@ -109,16 +111,59 @@ impl QueryClient for TendermintRpcClient {
             ensure!(proof.ops.len() == 1);
             ensure!(proof.ops[0].field_type == type_name::<Self::Proof>());
             ensure!(proof.ops[0].key == key.into_inner());
-            Some(proof.ops[0].data.deserialize_json()?)
+            Some(proof.ops[0].data.deserialize_borsh()?)
         } else {
             ensure!(res.proof.is_none());
             None
         };
 
-        Ok((value, proof))
+        // The app reports the height at which the query was performed, even if
+        // we didn't specify one.
+        let height = res.height.value();
+
+        // The ABCI query response doesn't include the root hash. Instead, find
+        // it in the results of the block at that height.
+        let root_hash = if prove {
+            Some(self.query_block_outcome(Some(height)).await?.app_hash)
+        } else {
+            None
+        };
+
+        Ok(QueryStoreResponse {
+            height,
+            value,
+            proof,
+            root_hash,
+        })
+    }
+
+    async fn query_store_scan(
+        &self,
+        min: Option<Binary>,
+        max: Option<Binary>,
+        limit: Option<u32>,
+        height: Option<u64>,
+    ) -> Result<BTreeMap<Binary, Binary>, Self::Error> {
+        let req = QueryStoreScanRequest { min, max, limit };
+
+        self.query("/store_scan", req.to_json_vec()?.to_vec(), height, false)
+            .await?
+            .value
+            .deserialize_json()
+            .map_err(Into::into)
     }
 
-    async fn simulate(&self, tx: UnsignedTx) -> Result<TxOutcome, Self::Error> {
+    async fn simulate_with_overrides(
+        &self,
+        tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> Result<TxOutcome, Self::Error> {
+        // The ABCI `Query` request only carries the transaction.
+        ensure!(
+            overrides.is_empty(),
+            "state overrides are not supported by the Tendermint RPC client; use the GraphQL API instead"
+        );
+
         Ok(self
             .query("/simulate", tx.to_json_vec()?, None, false)
             .await?
@@ -164,6 +209,16 @@ impl BlockClient for TendermintRpcClient {
             None => self.inner.latest_block_results().await?,
         };
 
+        // Cron, scheduled message, and receive event outcomes are all emitted
+        // as finalize block events. Tell them apart by the event kinds.
+        let (receive_event_events, events) = response
+            .finalize_block_events
+            .into_iter()
+            .partition::<Vec<_>, _>(|event| event.kind.starts_with("receive-event-"));
+        let (scheduled_events, cron_events) = events
+            .into_iter()
+            .partition::<Vec<_>, _>(|event| event.kind.starts_with("scheduled-"));
+
         Ok(BlockOutcome {
             height: response.height.into(),
             app_hash: Hash256::from_inner(response.app_hash.as_bytes().try_into()?),
@@ -173,11 +228,18 @@ impl BlockClient for TendermintRpcClient {
                 .into_iter()
                 .map(TxOutcome::from_tm_tx_result)
                 .collect::<StdResult<Vec<TxOutcome>>>()?,
-            cron_outcomes: response
-                .finalize_block_events
+            cron_outcomes: cron_events
                 .into_iter()
                 .map(CronOutcome::from_tm_event)
                 .collect::<StdResult<Vec<CronOutcome>>>()?,
+            scheduled_outcomes: scheduled_events
+                .into_iter()
+                .map(ScheduledOutcome::from_tm_event)
+                .collect::<StdResult<Vec<ScheduledOutcome>>>()?,
+            receive_event_outcomes: receive_event_events
+                .into_iter()
+                .map(ReceiveEventOutcome::from_tm_event)
+                .collect::<StdResult<Vec<ReceiveEventOutcome>>>()?,
         })
     }
 }
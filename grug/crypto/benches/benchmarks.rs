@@ -5,9 +5,10 @@ use {
     },
     ed25519_dalek::Signer,
     grug_crypto::{
-        blake2b_512, blake2s_256, blake3, ed25519_batch_verify, ed25519_verify, keccak256,
-        secp256k1_pubkey_recover, secp256k1_verify, secp256r1_verify, sha2_256, sha2_512, sha3_256,
-        sha3_512,
+        BLS12_381_DST, blake2b_512, blake2s_256, blake3, bls12_381_aggregate_verify,
+        bls12_381_pairing_check, bls12_381_verify, ed25519_batch_verify, ed25519_verify, keccak256,
+        secp256k1_batch_verify, secp256k1_pubkey_recover, secp256k1_schnorr_verify,
+        secp256k1_verify, secp256r1_verify, sha2_256, sha2_512, sha3_256, sha3_512,
     },
     identity::{Identity256, Identity512},
     p256::ecdsa::signature::DigestSigner,
@@ -50,12 +51,31 @@ const SIGN_MSG_LEN: usize = 10;
 /// to 150 validators, so we choose a number of batch sizes up to that.
 const ED25519_BATCH_SIZES: [usize; 6] = [25, 50, 75, 100, 125, 150];
 
+/// Batch sizes for benchmarking `secp256k1_batch_verify`.
+const SECP256K1_BATCH_SIZES: [usize; 5] = [10, 20, 30, 40, 50];
+
+/// Numbers of signers for benchmarking `bls12_381_aggregate_verify`.
+///
+/// The typical use case is verifying signatures by validator sets, so we choose
+/// the same numbers as for `ed25519_batch_verify`.
+const BLS12_381_AGGREGATE_SIZES: [usize; 6] = [25, 50, 75, 100, 125, 150];
+
+/// Numbers of pairs of points for benchmarking `bls12_381_pairing_check`.
+///
+/// Verifying a signature takes two pairs; a zk-SNARK proof, typically three or
+/// four.
+const BLS12_381_PAIRING_SIZES: [usize; 5] = [2, 4, 6, 8, 10];
+
 fn generate_random_msg(i: usize) -> Vec<u8> {
     let mut vec = vec![0; i];
     OsRng.fill_bytes(&mut vec);
     vec
 }
 
+fn generate_bls12_381_key() -> blst::min_pk::SecretKey {
+    blst::min_pk::SecretKey::key_gen(&generate_random_msg(32), &[]).unwrap()
+}
+
 fn bench_hashers(c: &mut Criterion) {
     let mut group = c.benchmark_group("hashers");
 
@@ -191,6 +211,67 @@ fn bench_verifiers(c: &mut Criterion) {
         );
     });
 
+    for size in SECP256K1_BATCH_SIZES {
+        group.bench_with_input(
+            BenchmarkId::new("secp256k1_batch_verify", size),
+            &size,
+            |b, size| {
+                b.iter_batched(
+                    || {
+                        let mut msg_hashes = vec![];
+                        let mut sigs = vec![];
+                        let mut vks = vec![];
+
+                        for _ in 0..*size {
+                            let msg_hash = sha2_256(&generate_random_msg(SIGN_MSG_LEN));
+                            let sk = k256::ecdsa::SigningKey::random(&mut OsRng);
+                            let vk = k256::ecdsa::VerifyingKey::from(&sk);
+                            let sig = <k256::ecdsa::SigningKey as DigestSigner<
+                                _,
+                                k256::ecdsa::Signature,
+                            >>::sign_digest(
+                                &sk, Identity256::from(msg_hash)
+                            );
+
+                            msg_hashes.push(msg_hash.to_vec());
+                            sigs.push(sig.to_bytes().to_vec());
+                            vks.push(vk.to_sec1_bytes().to_vec());
+                        }
+
+                        (msg_hashes, sigs, vks)
+                    },
+                    |(msg_hashes, sigs, vks)| {
+                        let msg_hashes: Vec<_> = msg_hashes.iter().map(|m| m.as_slice()).collect();
+                        let sigs: Vec<_> = sigs.iter().map(|s| s.as_slice()).collect();
+                        let vks: Vec<_> = vks.iter().map(|k| k.as_slice()).collect();
+                        assert!(secp256k1_batch_verify(&msg_hashes, &sigs, &vks).is_ok());
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+
+    group.bench_function("secp256k1_schnorr_verify", |b| {
+        b.iter_batched(
+            || {
+                let msg_hash = sha2_256(&generate_random_msg(SIGN_MSG_LEN));
+                let sk = k256::schnorr::SigningKey::random(&mut OsRng);
+                let sig = sk.sign_raw(&msg_hash, &[0; 32]).unwrap();
+
+                (
+                    msg_hash.to_vec(),
+                    sig.to_bytes().to_vec(),
+                    sk.verifying_key().to_bytes().to_vec(),
+                )
+            },
+            |(msg_hash, sig, vk)| {
+                assert!(secp256k1_schnorr_verify(&msg_hash, &sig, &vk).is_ok());
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
     group.bench_function("secp256k1_pubkey_recover", |b| {
         b.iter_batched(
             || {
@@ -279,6 +360,96 @@ fn bench_verifiers(c: &mut Criterion) {
         );
     }
 
+    group.bench_function("bls12_381_verify", |b| {
+        b.iter_batched(
+            || {
+                let msg_hash = sha2_256(&generate_random_msg(SIGN_MSG_LEN));
+                let sk = generate_bls12_381_key();
+                let sig = sk.sign(&msg_hash, BLS12_381_DST, &[]);
+
+                (
+                    msg_hash.to_vec(),
+                    sig.compress().to_vec(),
+                    sk.sk_to_pk().compress().to_vec(),
+                )
+            },
+            |(msg_hash, sig, pk)| {
+                assert!(bls12_381_verify(&msg_hash, &sig, &pk).is_ok());
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    for size in BLS12_381_AGGREGATE_SIZES {
+        group.bench_with_input(
+            BenchmarkId::new("bls12_381_aggregate_verify", size),
+            &size,
+            |b, size| {
+                b.iter_batched(
+                    || {
+                        let msg_hash = sha2_256(&generate_random_msg(SIGN_MSG_LEN));
+                        let mut sigs = vec![];
+                        let mut pks = vec![];
+
+                        for _ in 0..*size {
+                            let sk = generate_bls12_381_key();
+
+                            sigs.push(sk.sign(&msg_hash, BLS12_381_DST, &[]));
+                            pks.push(sk.sk_to_pk().compress().to_vec());
+                        }
+
+                        let sig = blst::min_pk::AggregateSignature::aggregate(
+                            &sigs.iter().collect::<Vec<_>>(),
+                            true,
+                        )
+                        .unwrap()
+                        .to_signature();
+
+                        (msg_hash.to_vec(), sig.compress().to_vec(), pks)
+                    },
+                    |(msg_hash, sig, pks)| {
+                        let pks: Vec<_> = pks.iter().map(|k| k.as_slice()).collect();
+                        assert!(bls12_381_aggregate_verify(&msg_hash, &sig, &pks).is_ok());
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+
+    for size in BLS12_381_PAIRING_SIZES {
+        group.bench_with_input(
+            BenchmarkId::new("bls12_381_pairing_check", size),
+            &size,
+            |b, size| {
+                b.iter_batched(
+                    || {
+                        // The points don't need to satisfy the equation; the
+                        // check takes the same amount of time regardless.
+                        let mut g1s = vec![];
+                        let mut g2s = vec![];
+
+                        for _ in 0..*size {
+                            let sk = generate_bls12_381_key();
+                            let msg_hash = sha2_256(&generate_random_msg(SIGN_MSG_LEN));
+
+                            g1s.push(sk.sk_to_pk().compress().to_vec());
+                            g2s.push(sk.sign(&msg_hash, BLS12_381_DST, &[]).compress().to_vec());
+                        }
+
+                        (g1s, g2s)
+                    },
+                    |(g1s, g2s)| {
+                        let g1s: Vec<_> = g1s.iter().map(|p| p.as_slice()).collect();
+                        let g2s: Vec<_> = g2s.iter().map(|p| p.as_slice()).collect();
+                        let _ = black_box(bls12_381_pairing_check(&g1s, &g2s));
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+
     group.finish();
 }
 
@@ -0,0 +1,265 @@
+use {
+    crate::{CryptoError, CryptoResult, utils::to_sized},
+    blst::{
+        BLST_ERROR, Pairing,
+        min_pk::{AggregatePublicKey, PublicKey, Signature},
+    },
+};
+
+/// Domain separation tag of the BLS signature scheme, in the variant where
+/// public keys are G1 points and signatures are G2 points, with proof of
+/// possession. This is the scheme used by Ethereum's consensus layer.
+pub const BLS12_381_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+const BLS12_381_DIGEST_LEN: usize = 32;
+const BLS12_381_G1_LEN: usize = 48; // compressed
+const BLS12_381_G2_LEN: usize = 96; // compressed
+
+/// Verify a BLS12-381 signature with the given hashed message and public key.
+///
+/// The public key is a compressed G1 point, and the signature a compressed G2
+/// point.
+///
+/// NOTE: This function takes the hash of the message, not the prehash.
+pub fn bls12_381_verify(msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> CryptoResult<()> {
+    let msg_hash = to_sized::<BLS12_381_DIGEST_LEN>(msg_hash)?;
+    let sig = signature_from_bytes(sig)?;
+    let pk = public_key_from_bytes(pk)?;
+
+    // The signature and the public key have already been group-checked.
+    match sig.verify(false, &msg_hash, BLS12_381_DST, &[], &pk, false) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(CryptoError::Unauthentic),
+    }
+}
+
+/// Verify an aggregate BLS12-381 signature, produced by the given public keys
+/// all signing the same hashed message.
+///
+/// The public keys must have been verified for proof of possession, e.g. when
+/// the signers registered them; otherwise, this is vulnerable to rogue key
+/// attacks.
+///
+/// NOTE: This function takes the hash of the message, not the prehash.
+pub fn bls12_381_aggregate_verify(msg_hash: &[u8], sig: &[u8], pks: &[&[u8]]) -> CryptoResult<()> {
+    let msg_hash = to_sized::<BLS12_381_DIGEST_LEN>(msg_hash)?;
+    let sig = signature_from_bytes(sig)?;
+    let pks = pks
+        .iter()
+        .map(|pk| public_key_from_bytes(pk))
+        .collect::<CryptoResult<Vec<_>>>()?;
+
+    // Aggregating an empty list of public keys errors, which is what we want.
+    let pk = AggregatePublicKey::aggregate(&pks.iter().collect::<Vec<_>>(), false)
+        .map_err(|_| CryptoError::InvalidPoint)?
+        .to_public_key();
+
+    match sig.verify(false, &msg_hash, BLS12_381_DST, &[], &pk, false) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(CryptoError::Unauthentic),
+    }
+}
+
+/// Check whether the product of the pairings of the given G1 and G2 points
+/// equals the identity, i.e. `e(g1s[0], g2s[0]) * e(g1s[1], g2s[1]) * ... = 1`.
+///
+/// The points are compressed. Points at infinity are allowed.
+pub fn bls12_381_pairing_check(g1s: &[&[u8]], g2s: &[&[u8]]) -> CryptoResult<()> {
+    if g1s.len() != g2s.len() {
+        return Err(CryptoError::IncorrectLength {
+            expect: g1s.len(),
+            actual: g2s.len(),
+        });
+    }
+
+    let mut pairing = Pairing::new(false, &[]);
+
+    for (g1, g2) in g1s.iter().zip(g2s) {
+        let g1 = to_sized::<BLS12_381_G1_LEN>(g1)?;
+        let g1 = PublicKey::uncompress(&g1).map_err(|_| CryptoError::InvalidPoint)?;
+
+        match g1.validate() {
+            Ok(()) | Err(BLST_ERROR::BLST_PK_IS_INFINITY) => (),
+            Err(_) => return Err(CryptoError::InvalidPoint),
+        }
+
+        let g2 = to_sized::<BLS12_381_G2_LEN>(g2)?;
+        let g2 = Signature::uncompress(&g2).map_err(|_| CryptoError::InvalidPoint)?;
+
+        g2.validate(false).map_err(|_| CryptoError::InvalidPoint)?;
+
+        pairing.raw_aggregate((&g2).into(), (&g1).into());
+    }
+
+    pairing.commit();
+
+    if pairing.finalverify(None) {
+        Ok(())
+    } else {
+        Err(CryptoError::Unauthentic)
+    }
+}
+
+/// Deserialize a compressed G1 point as a public key, and check that it's in
+/// the subgroup and not the point at infinity.
+fn public_key_from_bytes(pk: &[u8]) -> CryptoResult<PublicKey> {
+    let pk = to_sized::<BLS12_381_G1_LEN>(pk)?;
+
+    PublicKey::key_validate(&pk).map_err(|_| CryptoError::InvalidPoint)
+}
+
+/// Deserialize a compressed G2 point as a signature, and check that it's in
+/// the subgroup.
+fn signature_from_bytes(sig: &[u8]) -> CryptoResult<Signature> {
+    let sig = to_sized::<BLS12_381_G2_LEN>(sig)?;
+
+    Signature::sig_validate(&sig, true).map_err(|_| CryptoError::InvalidPoint)
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::sha2_256, blst::min_pk::SecretKey, rand::RngCore};
+
+    fn keygen() -> SecretKey {
+        let mut ikm = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut ikm);
+        SecretKey::key_gen(&ikm, &[]).unwrap()
+    }
+
+    #[test]
+    fn verify_bls12_381() {
+        let sk = keygen();
+        let pk = sk.sk_to_pk().compress();
+        let msg_hash = sha2_256(b"Jake");
+        let sig = sk.sign(&msg_hash, BLS12_381_DST, &[]).compress();
+
+        // Valid signature
+        {
+            assert!(bls12_381_verify(&msg_hash, &sig, &pk).is_ok());
+        }
+
+        // Incorrect private key
+        {
+            let false_sig = keygen().sign(&msg_hash, BLS12_381_DST, &[]).compress();
+            assert!(matches!(
+                bls12_381_verify(&msg_hash, &false_sig, &pk),
+                Err(CryptoError::Unauthentic)
+            ));
+        }
+
+        // Incorrect message
+        {
+            let false_msg_hash = sha2_256(b"Larry");
+            assert!(matches!(
+                bls12_381_verify(&false_msg_hash, &sig, &pk),
+                Err(CryptoError::Unauthentic)
+            ));
+        }
+
+        // Invalid public key
+        {
+            assert!(matches!(
+                bls12_381_verify(&msg_hash, &sig, &[0xff; 48]),
+                Err(CryptoError::InvalidPoint)
+            ));
+        }
+    }
+
+    #[test]
+    fn verify_aggregate_bls12_381() {
+        let sks = [keygen(), keygen(), keygen()];
+        let pks = sks
+            .iter()
+            .map(|sk| sk.sk_to_pk().compress())
+            .collect::<Vec<_>>();
+        let pks = pks.iter().map(|pk| pk.as_slice()).collect::<Vec<_>>();
+        let msg_hash = sha2_256(b"Rhaki");
+        let sigs = sks
+            .iter()
+            .map(|sk| sk.sign(&msg_hash, BLS12_381_DST, &[]))
+            .collect::<Vec<_>>();
+        let sig =
+            blst::min_pk::AggregateSignature::aggregate(&sigs.iter().collect::<Vec<_>>(), true)
+                .unwrap()
+                .to_signature()
+                .compress();
+
+        // Valid signature
+        {
+            assert!(bls12_381_aggregate_verify(&msg_hash, &sig, &pks).is_ok());
+        }
+
+        // Missing a signer
+        {
+            assert!(matches!(
+                bls12_381_aggregate_verify(&msg_hash, &sig, &pks[..2]),
+                Err(CryptoError::Unauthentic)
+            ));
+        }
+
+        // No signer
+        {
+            assert!(bls12_381_aggregate_verify(&msg_hash, &sig, &[]).is_err());
+        }
+    }
+
+    #[test]
+    fn check_pairing_bls12_381() {
+        // For a signature `sig = sk * H(m)` and public key `pk = sk * G1`, we
+        // have `e(pk, H(m)) = e(G1, sig)`, i.e. `e(-G1, sig) * e(pk, H(m)) = 1`.
+        let sk = keygen();
+        let pk = sk.sk_to_pk().compress();
+        let msg_hash = sha2_256(b"Jake");
+        let sig = sk.sign(&msg_hash, BLS12_381_DST, &[]).compress();
+        let hashed_msg = hash_to_g2(&msg_hash);
+        let neg_g1 = negated_g1_generator();
+
+        assert!(bls12_381_pairing_check(&[&neg_g1, &pk], &[&sig, &hashed_msg]).is_ok());
+
+        // Wrong pairs
+        assert!(matches!(
+            bls12_381_pairing_check(&[&neg_g1, &pk], &[&hashed_msg, &sig]),
+            Err(CryptoError::Unauthentic)
+        ));
+
+        // Mismatched lengths
+        assert!(matches!(
+            bls12_381_pairing_check(&[&neg_g1, &pk], &[&sig]),
+            Err(CryptoError::IncorrectLength { .. })
+        ));
+    }
+
+    fn hash_to_g2(msg: &[u8]) -> [u8; 96] {
+        let mut point = blst::blst_p2::default();
+        let mut out = [0u8; 96];
+
+        unsafe {
+            blst::blst_hash_to_g2(
+                &mut point,
+                msg.as_ptr(),
+                msg.len(),
+                BLS12_381_DST.as_ptr(),
+                BLS12_381_DST.len(),
+                std::ptr::null(),
+                0,
+            );
+            blst::blst_p2_compress(out.as_mut_ptr(), &point);
+        }
+
+        out
+    }
+
+    fn negated_g1_generator() -> [u8; 48] {
+        let mut point = unsafe { *blst::blst_p1_generator() };
+        let mut out = [0u8; 48];
+
+        unsafe {
+            blst::blst_p1_cneg(&mut point, true);
+            blst::blst_p1_compress(out.as_mut_ptr(), &point);
+        }
+
+        out
+    }
+}
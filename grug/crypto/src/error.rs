@@ -16,6 +16,12 @@ pub enum CryptoError {
 
     #[error("invalid recovery id {recovery_id}")]
     InvalidRecoveryId { recovery_id: u8 },
+
+    #[error("invalid elliptic curve point")]
+    InvalidPoint,
+
+    #[error("signature is unauthentic")]
+    Unauthentic,
 }
 
 impl CryptoError {
@@ -25,7 +31,8 @@ impl CryptoError {
         match self {
             Self::IncorrectLength { .. } | Self::IncorrectLengths { .. } => 1,
             Self::InvalidRecoveryId { .. } => 2,
-            Self::Signature(_) => 3,
+            Self::Signature(_) | Self::Unauthentic => 3,
+            Self::InvalidPoint => 4,
         }
     }
 }
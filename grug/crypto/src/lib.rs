@@ -1,3 +1,4 @@
+mod bls12_381;
 mod ed25519;
 mod error;
 mod hashers;
@@ -5,4 +6,4 @@ mod secp256k1;
 mod secp256r1;
 mod utils;
 
-pub use crate::{ed25519::*, error::*, hashers::*, secp256k1::*, secp256r1::*};
+pub use crate::{bls12_381::*, ed25519::*, error::*, hashers::*, secp256k1::*, secp256r1::*};
@@ -1,12 +1,16 @@
 use {
     crate::{CryptoError, CryptoResult, utils::to_sized},
     identity::Identity256,
-    k256::ecdsa::{RecoveryId, Signature, VerifyingKey, signature::DigestVerifier},
+    k256::{
+        ecdsa::{RecoveryId, Signature, VerifyingKey, signature::DigestVerifier},
+        schnorr,
+    },
 };
 
 const SECP256K1_DIGEST_LEN: usize = 32;
 const SECP256K1_PUBKEY_LENS: [usize; 2] = [33, 65]; // compressed, uncompressed
 const SECP256K1_SIGNATURE_LEN: usize = 64;
+const SECP256K1_SCHNORR_PUBKEY_LEN: usize = 32; // x-only
 
 /// NOTE: This function takes the hash of the message, not the prehash.
 pub fn secp256k1_verify(msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> CryptoResult<()> {
@@ -35,6 +39,53 @@ pub fn secp256k1_verify(msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> CryptoResult<
         .map_err(Into::into)
 }
 
+/// Verify a batch of Secp256k1 ECDSA signatures with the given hashed messages
+/// and public keys.
+///
+/// ECDSA doesn't allow for actual batch verification, so the signatures are
+/// verified one by one. The advantage over calling `secp256k1_verify` in a loop
+/// is that a Wasm contract only needs to cross the FFI boundary once.
+///
+/// NOTE: This function takes the hashes of the messages, not the prehashes.
+pub fn secp256k1_batch_verify(
+    msg_hashes: &[&[u8]],
+    sigs: &[&[u8]],
+    pks: &[&[u8]],
+) -> CryptoResult<()> {
+    for len in [sigs.len(), pks.len()] {
+        if len != msg_hashes.len() {
+            return Err(CryptoError::IncorrectLength {
+                expect: msg_hashes.len(),
+                actual: len,
+            });
+        }
+    }
+
+    for ((msg_hash, sig), pk) in msg_hashes.iter().zip(sigs).zip(pks) {
+        secp256k1_verify(msg_hash, sig, pk)?;
+    }
+
+    Ok(())
+}
+
+/// Verify a BIP-340 Schnorr signature, as used by Bitcoin Taproot, with the
+/// given message and x-only public key.
+///
+/// NOTE: BIP-340 doesn't hash the message before signing; instead, it's used
+/// in the challenge hash as-is. This function expects a 32-byte message, which
+/// in the case of Taproot is the transaction's signature hash.
+pub fn secp256k1_schnorr_verify(msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> CryptoResult<()> {
+    let msg_hash = to_sized::<SECP256K1_DIGEST_LEN>(msg_hash)?;
+
+    let sig = to_sized::<SECP256K1_SIGNATURE_LEN>(sig)?;
+    let sig = schnorr::Signature::try_from(sig.as_slice())?;
+
+    let pk = to_sized::<SECP256K1_SCHNORR_PUBKEY_LEN>(pk)?;
+    let vk = schnorr::VerifyingKey::from_bytes(&pk)?;
+
+    vk.verify_raw(&msg_hash, &sig).map_err(Into::into)
+}
+
 /// Recover the Secp256k1 public key as SEC1 bytes from the _hashed_ message and
 /// signature.
 ///
@@ -128,6 +179,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn batch_verifying_secp256k1() {
+        let mut msg_hashes = vec![];
+        let mut sigs = vec![];
+        let mut pks = vec![];
+
+        for msg in ["Jake", "Larry", "Rhaki"] {
+            let sk = SigningKey::random(&mut OsRng);
+            let vk = VerifyingKey::from(&sk);
+            let msg_hash = sha2_256(msg.as_bytes());
+            let sig: Signature = sk.sign_digest(Identity256::from(msg_hash));
+
+            msg_hashes.push(msg_hash.to_vec());
+            sigs.push(sig.to_vec());
+            pks.push(vk.to_sec1_bytes().to_vec());
+        }
+
+        let msg_hashes = msg_hashes.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let sigs = sigs.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let pks = pks.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        // Valid signatures
+        {
+            assert!(secp256k1_batch_verify(&msg_hashes, &sigs, &pks).is_ok());
+        }
+
+        // One of the signatures is swapped
+        {
+            let false_sigs = [sigs[1], sigs[0], sigs[2]];
+            assert!(secp256k1_batch_verify(&msg_hashes, &false_sigs, &pks).is_err());
+        }
+
+        // Mismatched lengths
+        {
+            assert!(matches!(
+                secp256k1_batch_verify(&msg_hashes, &sigs[..2], &pks),
+                Err(CryptoError::IncorrectLength {
+                    expect: 3,
+                    actual: 2
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn verifying_secp256k1_schnorr() {
+        let sk = schnorr::SigningKey::random(&mut OsRng);
+        let vk = sk.verifying_key().to_bytes();
+        let msg_hash = sha2_256(b"Jake");
+        let sig = sk.sign_raw(&msg_hash, &[0; 32]).unwrap().to_bytes();
+
+        // Valid signature
+        {
+            assert!(secp256k1_schnorr_verify(&msg_hash, &sig, &vk).is_ok());
+        }
+
+        // Incorrect private key
+        {
+            let false_sk = schnorr::SigningKey::random(&mut OsRng);
+            let false_sig = false_sk.sign_raw(&msg_hash, &[0; 32]).unwrap().to_bytes();
+            assert!(secp256k1_schnorr_verify(&msg_hash, &false_sig, &vk).is_err());
+        }
+
+        // Incorrect message
+        {
+            let false_msg_hash = sha2_256(b"Larry");
+            assert!(secp256k1_schnorr_verify(&false_msg_hash, &sig, &vk).is_err());
+        }
+
+        // Message isn't 32 bytes
+        {
+            assert!(matches!(
+                secp256k1_schnorr_verify(b"Jake", &sig, &vk),
+                Err(CryptoError::IncorrectLength { .. })
+            ));
+        }
+    }
+
     #[test]
     fn recovering_secp256k1() {
         // Generate a valid signature
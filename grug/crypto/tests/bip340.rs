@@ -0,0 +1,71 @@
+use grug_crypto::{CryptoError, secp256k1_schnorr_verify};
+
+const TEST_VECTORS: &str = "./testdata/bip340/test-vectors.csv";
+
+#[derive(Debug)]
+pub struct TestCase {
+    pub index: usize,
+    pub public_key: Vec<u8>,
+    pub message: Vec<u8>,
+    pub signature: Vec<u8>,
+    pub valid: bool,
+    pub comment: String,
+}
+
+fn read_test_cases(path: &str) -> Vec<TestCase> {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .skip(1) // header
+        .map(|line| {
+            // The comment is the last column, and doesn't contain any comma.
+            let columns = line.splitn(8, ',').collect::<Vec<_>>();
+            assert_eq!(columns.len(), 8, "malformed line: {line}");
+
+            TestCase {
+                index: columns[0].parse().unwrap(),
+                public_key: hex::decode(columns[2]).unwrap(),
+                message: hex::decode(columns[4]).unwrap(),
+                signature: hex::decode(columns[5]).unwrap(),
+                valid: match columns[6] {
+                    "TRUE" => true,
+                    "FALSE" => false,
+                    _ => panic!("Found unexpected result value"),
+                },
+                comment: columns[7].to_string(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn schnorr_secp256k1_bip340() {
+    let tests = read_test_cases(TEST_VECTORS);
+    assert!(!tests.is_empty());
+
+    for (i, tc) in tests.into_iter().enumerate() {
+        assert_eq!(tc.index, i);
+
+        let result = secp256k1_schnorr_verify(&tc.message, &tc.signature, &tc.public_key);
+
+        // `secp256k1_schnorr_verify` only accepts 32-byte messages, which is
+        // what Taproot signs. BIP-340 allows messages of any length, so those
+        // vectors must be rejected for their length, not for being unauthentic.
+        if tc.message.len() != 32 {
+            assert!(
+                matches!(result, Err(CryptoError::IncorrectLength { .. })),
+                "test case {}: expected length error, got {result:?}",
+                tc.index
+            );
+            continue;
+        }
+
+        assert_eq!(
+            result.is_ok(),
+            tc.valid,
+            "test case {} ({}): got {result:?}",
+            tc.index,
+            tc.comment
+        );
+    }
+}
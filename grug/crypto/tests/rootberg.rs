@@ -1,7 +1,9 @@
 mod shared;
 
 use {
-    grug_crypto::{keccak256, secp256k1_verify, secp256r1_verify, sha2_256},
+    grug_crypto::{
+        keccak256, secp256k1_batch_verify, secp256k1_verify, secp256r1_verify, sha2_256,
+    },
     serde::Deserialize,
     shared::{read_file, validate_recover_secp256k1, validate_recover_secp256r1},
 };
@@ -156,6 +158,38 @@ macro_rules! rootberg_test {
     };
 }
 
+/// Verify all valid signatures in a file in one batch, then check that each of
+/// the invalid signatures causes a batch to fail.
+macro_rules! rootberg_batch_test {
+    ($test_name:ident, $file_name:expr, $hash_fn:ident) => {
+        #[test]
+        fn $test_name() {
+            let File { tests, .. } = read_file($file_name);
+
+            let (valid, invalid): (Vec<_>, Vec<_>) = tests
+                .into_iter()
+                .map(|test| {
+                    let message_hash = $hash_fn(&test.msg).to_vec();
+                    let signature = combine_signature(&test.sig);
+
+                    (test.valid, message_hash, signature, test.public_key_compressed)
+                })
+                .partition(|(valid, ..)| *valid);
+
+            let msg_hashes = valid.iter().map(|(_, m, ..)| m.as_slice()).collect::<Vec<_>>();
+            let sigs = valid.iter().map(|(_, _, s, _)| s.as_slice()).collect::<Vec<_>>();
+            let pks = valid.iter().map(|(.., p)| p.as_slice()).collect::<Vec<_>>();
+
+            secp256k1_batch_verify(&msg_hashes, &sigs, &pks).unwrap();
+
+            for (_, msg_hash, sig, pk) in &invalid {
+                secp256k1_batch_verify(&[msg_hashes[0], msg_hash], &[sigs[0], sig], &[pks[0], pk])
+                    .unwrap_err();
+            }
+        }
+    };
+}
+
 // ------------------------------ secp256k1 tests ------------------------------
 
 const SECP256K1_SHA256: &str = "./testdata/rootberg/ecdsa_secp256k1_sha_256_raw.json";
@@ -189,6 +223,14 @@ rootberg_test!(K1 =>
     uncompressed
 );
 
+rootberg_batch_test!(ecdsa_secp256k1_sha256_batch, SECP256K1_SHA256, sha2_256);
+
+rootberg_batch_test!(
+    ecdsa_secp256k1_keccak256_batch,
+    SECP256K1_KECCAK256,
+    keccak256
+);
+
 // ------------------------------ secp256r1 tests ------------------------------
 
 const SECP256R1_SHA256: &str = "./testdata/rootberg/ecdsa_secp256r1_sha_256_raw.json";
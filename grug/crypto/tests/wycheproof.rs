@@ -2,8 +2,8 @@ mod shared;
 
 use {
     grug_crypto::{
-        secp256k1_verify, secp256r1_verify, sha2_256, sha2_512_truncated, sha3_256,
-        sha3_512_truncated,
+        secp256k1_batch_verify, secp256k1_verify, secp256r1_verify, sha2_256, sha2_512_truncated,
+        sha3_256, sha3_512_truncated,
     },
     serde::{Deserialize, de},
     shared::{read_file, validate_recover_secp256k1, validate_recover_secp256r1},
@@ -193,6 +193,52 @@ macro_rules! wycheproof_test {
     };
 }
 
+/// Verify all valid signatures in a file in one batch, then check that each of
+/// the invalid signatures causes a batch to fail.
+macro_rules! wycheproof_batch_test {
+    ($test_name:ident, $file_name:expr, $hash_fn:ident) => {
+        #[test]
+        fn $test_name() {
+            let File { test_groups, .. } = read_file($file_name);
+
+            let mut valid = vec![];
+            let mut invalid = vec![];
+
+            for group in test_groups {
+                let public_key = hex::decode(group.public_key.compressed).unwrap();
+
+                for tc in group.tests {
+                    let message_hash = $hash_fn(&hex::decode(tc.msg).unwrap()).to_vec();
+                    let Ok(signature) = from_der(&hex::decode(tc.sig).unwrap()) else {
+                        continue;
+                    };
+
+                    match tc.result.as_str() {
+                        "valid" | "acceptable" => {
+                            valid.push((message_hash, signature.to_vec(), public_key.clone()));
+                        },
+                        "invalid" => {
+                            invalid.push((message_hash, signature.to_vec(), public_key.clone()));
+                        },
+                        _ => panic!("Found unexpected result value"),
+                    }
+                }
+            }
+
+            let msg_hashes = valid.iter().map(|(m, ..)| m.as_slice()).collect::<Vec<_>>();
+            let sigs = valid.iter().map(|(_, s, _)| s.as_slice()).collect::<Vec<_>>();
+            let pks = valid.iter().map(|(.., p)| p.as_slice()).collect::<Vec<_>>();
+
+            secp256k1_batch_verify(&msg_hashes, &sigs, &pks).unwrap();
+
+            for (msg_hash, sig, pk) in &invalid {
+                secp256k1_batch_verify(&[msg_hashes[0], msg_hash], &[sigs[0], sig], &[pks[0], pk])
+                    .unwrap_err();
+            }
+        }
+    };
+}
+
 fn from_der(data: &[u8]) -> Result<[u8; 64], String> {
     const DER_TAG_INTEGER: u8 = 0x02;
 
@@ -375,6 +421,22 @@ wycheproof_test!(K1 =>
     uncompressed
 );
 
+wycheproof_batch_test!(ecdsa_secp256k1_sha256_batch, SECP256K1_SHA256, sha2_256);
+
+wycheproof_batch_test!(
+    ecdsa_secp256k1_sha512_batch,
+    SECP256K1_SHA512,
+    sha2_512_truncated
+);
+
+wycheproof_batch_test!(ecdsa_secp256k1_sha3_256_batch, SECP256K1_SHA3_256, sha3_256);
+
+wycheproof_batch_test!(
+    ecdsa_secp256k1_sha3_512_batch,
+    SECP256K1_SHA3_512,
+    sha3_512_truncated
+);
+
 // ------------------------------ secp256r1 tests ------------------------------
 
 const SECP256R1_SHA256: &str = "./testdata/wycheproof/ecdsa_secp256r1_sha256_test.json";
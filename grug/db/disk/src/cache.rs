@@ -0,0 +1,243 @@
+use {
+    clru::CLruCache,
+    grug_jmt::DEFAULT_NODE_NAMESPACE,
+    grug_types::{Batch, Metrics, Op, Shared, nested_namespaces_with_key},
+    std::num::NonZeroUsize,
+};
+
+/// The default number of Merkle tree nodes to be kept in the node cache.
+///
+/// A node takes up roughly 100 bytes, so the cache uses ~10 MB of memory when
+/// full.
+pub const DEFAULT_NODE_CACHE_CAPACITY: usize = 100_000;
+
+/// An in-memory LRU cache of Merkle tree nodes in the state commitment, keyed
+/// by their raw storage keys.
+///
+/// Each time the tree is updated or a proof is generated, it is walked from
+/// the root down, so the nodes in the upper levels are accessed on every walk
+/// and stay warm across blocks, while the leaves and nodes in the lower levels
+/// get evicted first.
+///
+/// A node's storage key includes the version in which it's created, and nodes
+/// are never overwritten, so a cached node never goes stale. Nodes are only
+/// removed from the cache when they are deleted from the DB (i.e. pruned).
+/// Other records in the state commitment, such as orphan markers, are never
+/// cached.
+#[derive(Clone)]
+pub(crate) struct NodeCache {
+    inner: Shared<NodeCacheInner>,
+    /// The prefix shared by the storage keys of all nodes.
+    node_prefix: Vec<u8>,
+}
+
+struct NodeCacheInner {
+    lru_cache: CLruCache<Vec<u8>, Vec<u8>>,
+    metrics: Metrics,
+    /// Incremented each time nodes are removed from the cache. Used to detect
+    /// whether a node loaded from the DB may have been pruned meanwhile.
+    generation: u64,
+}
+
+impl NodeCache {
+    /// Create an empty cache with the given capacity.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            inner: Shared::new(NodeCacheInner {
+                lru_cache: CLruCache::new(capacity),
+                metrics: Metrics::new(),
+                generation: 0,
+            }),
+            node_prefix: nested_namespaces_with_key(
+                Some(DEFAULT_NODE_NAMESPACE.as_bytes()),
+                &[] as &[&[u8]],
+                None::<&[u8]>,
+            ),
+        }
+    }
+
+    fn is_node(&self, key: &[u8]) -> bool {
+        key.starts_with(&self.node_prefix)
+    }
+
+    /// Attempt to get a cached node by its storage key. If not found, load it
+    /// using the given method, and insert it into the cache if it exists.
+    pub fn get_or_load_with<L>(&self, key: &[u8], loader: L) -> Option<Vec<u8>>
+    where
+        L: FnOnce() -> Option<Vec<u8>>,
+    {
+        if !self.is_node(key) {
+            return loader();
+        }
+
+        let generation = match self.inner.write_with(|mut inner| {
+            if let Some(value) = inner.lru_cache.get(key).cloned() {
+                inner.metrics.increment_hits();
+                Ok(value)
+            } else {
+                inner.metrics.increment_misses();
+                Err(inner.generation)
+            }
+        }) {
+            Ok(value) => return Some(value),
+            Err(generation) => generation,
+        };
+
+        // Load the node without holding the lock, so that other threads can
+        // access the cache meanwhile.
+        let value = loader()?;
+
+        // If nodes have been removed from the cache since we started loading,
+        // the node we've just loaded may be among them, in which case caching
+        // it would undo the removal. Skip caching it to be safe.
+        self.inner.write_with(|mut inner| {
+            if inner.generation == generation {
+                inner.lru_cache.put(key.to_vec(), value.clone());
+            }
+        });
+
+        Some(value)
+    }
+
+    /// Update the cache with a batch that has been written to the DB.
+    ///
+    /// Newly created nodes are inserted, as they make up the latest version of
+    /// the tree, which the next block will walk. Deleted nodes are removed.
+    pub fn apply(&self, batch: &Batch) {
+        self.inner.write_with(|mut inner| {
+            let mut removed = false;
+
+            for (key, op) in batch {
+                if !self.is_node(key) {
+                    continue;
+                }
+
+                if let Op::Insert(value) = op {
+                    inner.lru_cache.put(key.clone(), value.clone());
+                } else {
+                    inner.lru_cache.pop(key);
+                    removed = true;
+                }
+            }
+
+            if removed {
+                inner.generation += 1;
+            }
+        });
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.inner.read_with(|inner| inner.metrics)
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {super::*, grug_jmt::DEFAULT_ORPHAN_NAMESPACE};
+
+    fn key(namespace: &str, name: &[u8]) -> Vec<u8> {
+        nested_namespaces_with_key(Some(namespace.as_bytes()), &[] as &[&[u8]], Some(name))
+    }
+
+    #[test]
+    fn capacity_overflow() {
+        let cache = NodeCache::new(NonZeroUsize::new(1).unwrap());
+        let larry = key(DEFAULT_NODE_NAMESPACE, b"larry");
+        let jake = key(DEFAULT_NODE_NAMESPACE, b"jake");
+
+        // Load the 1st node. Should be a cache miss, and the node is inserted.
+        cache.get_or_load_with(&larry, || Some(b"engineer".to_vec()));
+
+        // Load the 2nd node. Should also be a cache miss, and the node is
+        // inserted, evicting the 1st one.
+        cache.get_or_load_with(&jake, || Some(b"shepherd".to_vec()));
+
+        cache.inner.read_with(|inner| {
+            assert!(!inner.lru_cache.contains(&larry));
+            assert!(inner.lru_cache.contains(&jake));
+            assert_eq!(inner.lru_cache.len(), 1);
+        });
+
+        assert_eq!(cache.metrics(), Metrics { hits: 0, misses: 2 });
+    }
+
+    #[test]
+    fn get_cached() {
+        let cache = NodeCache::new(NonZeroUsize::new(2).unwrap());
+        let larry = key(DEFAULT_NODE_NAMESPACE, b"larry");
+        let jake = key(DEFAULT_NODE_NAMESPACE, b"jake");
+
+        // Load the same node twice. 1st time should be a cache miss, 2nd time
+        // should be a cache hit, without calling the loader.
+        assert_eq!(
+            cache.get_or_load_with(&larry, || Some(b"engineer".to_vec())),
+            Some(b"engineer".to_vec())
+        );
+        assert_eq!(
+            cache.get_or_load_with(&larry, || unreachable!()),
+            Some(b"engineer".to_vec())
+        );
+
+        // Nodes that don't exist aren't cached.
+        assert_eq!(cache.get_or_load_with(&jake, || None), None);
+        assert_eq!(cache.get_or_load_with(&jake, || None), None);
+
+        assert_eq!(cache.metrics(), Metrics { hits: 1, misses: 3 });
+    }
+
+    #[test]
+    fn applying_batch() {
+        let cache = NodeCache::new(NonZeroUsize::new(2).unwrap());
+        let larry = key(DEFAULT_NODE_NAMESPACE, b"larry");
+        let jake = key(DEFAULT_NODE_NAMESPACE, b"jake");
+        let orphan = key(DEFAULT_ORPHAN_NAMESPACE, b"larry");
+
+        cache.get_or_load_with(&larry, || Some(b"engineer".to_vec()));
+
+        cache.apply(&Batch::from([
+            (larry.clone(), Op::Delete),
+            (jake.clone(), Op::Insert(b"shepherd".to_vec())),
+            (orphan.clone(), Op::Insert(vec![])),
+        ]));
+
+        // The deleted node is no longer cached; the inserted one is. The orphan
+        // marker isn't a node, so it isn't cached.
+        assert_eq!(cache.get_or_load_with(&larry, || None), None);
+        assert_eq!(
+            cache.get_or_load_with(&jake, || unreachable!()),
+            Some(b"shepherd".to_vec())
+        );
+        cache.inner.read_with(|inner| {
+            assert!(!inner.lru_cache.contains(&orphan));
+        });
+    }
+
+    #[test]
+    fn pruning_while_loading() {
+        let cache = NodeCache::new(NonZeroUsize::new(2).unwrap());
+        let larry = key(DEFAULT_NODE_NAMESPACE, b"larry");
+
+        // The node is pruned after it's been read from the DB, but before it's
+        // inserted into the cache. It must not be inserted.
+        assert_eq!(
+            cache.get_or_load_with(&larry, || {
+                cache.apply(&Batch::from([(larry.clone(), Op::Delete)]));
+                Some(b"engineer".to_vec())
+            }),
+            Some(b"engineer".to_vec())
+        );
+
+        cache.inner.read_with(|inner| {
+            assert!(!inner.lru_cache.contains(&larry));
+        });
+
+        // Without pruning in between, the node is inserted.
+        cache.get_or_load_with(&larry, || Some(b"engineer".to_vec()));
+
+        cache.inner.read_with(|inner| {
+            assert!(inner.lru_cache.contains(&larry));
+        });
+    }
+}
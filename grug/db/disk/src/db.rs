@@ -1,15 +1,28 @@
 use {
-    crate::{DbError, DbResult, U64Comparator, U64Timestamp},
-    grug_app::{Db, PrunableDb},
+    crate::{
+        DEFAULT_NODE_CACHE_CAPACITY, DbError, DbResult, NodeCache, PREFIX_SNAPSHOT,
+        PREFIX_SNAPSHOT_END, PruningStrategy, Restoration, SNAPSHOT_FORMAT, U64Comparator,
+        U64Timestamp, chunk_key, read_wal, remove_wal, snapshot_key, split_into_chunks, wal_path,
+        write_wal,
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    grug_app::{ApplySnapshotChunkOutcome, Db, OfferSnapshotOutcome, PrunableDb, Snapshot},
     grug_jmt::MerkleTree,
-    grug_types::{Batch, Buffer, Hash256, HashExt, Op, Order, Proof, Record, Storage},
+    grug_types::{
+        Batch, BorshDeExt, BorshSerExt, Buffer, Hash256, HashExt, Merged, Metrics, Op, Order,
+        Proof, Record, Storage,
+    },
     rocksdb::{
         BoundColumnFamily, DBWithThreadMode, IteratorMode, MultiThreaded, Options, ReadOptions,
         WriteBatch,
     },
     std::{
-        path::Path,
-        sync::{Arc, RwLock},
+        iter,
+        num::NonZeroUsize,
+        ops::Bound,
+        path::{Path, PathBuf},
+        sync::{Arc, Mutex, RwLock},
+        thread::{self, JoinHandle},
     },
 };
 
@@ -39,12 +52,24 @@ const CF_NAME_STATE_COMMITMENT: &str = "state_commitment";
 /// https://github.com/left-curve/rust-rocksdb/tree/v0.21.0-cw
 const CF_NAME_STATE_STORAGE: &str = "state_storage";
 
+/// The snapshots column family stores state sync snapshots, which are served
+/// to peers who wish to sync to the latest state without replaying every block
+/// since genesis. See the `snapshot` module for the storage layout.
+const CF_NAME_SNAPSHOTS: &str = "snapshots";
+
 /// Storage key for the latest version.
 const LATEST_VERSION_KEY: &[u8] = b"latest_version";
 
 /// Storage key for the oldest version.
 const OLDEST_VERSION_KEY: &[u8] = b"oldest_version";
 
+/// Storage key for the version of the snapshot being restored, if any.
+///
+/// Data of a restoration in progress are written to the state storage as chunks
+/// arrive. This marks them, so that they can be discarded if the restoration
+/// doesn't complete.
+const RESTORING_VERSION_KEY: &[u8] = b"restoring_version";
+
 /// Jellyfish Merkle tree (JMT) using default namespaces.
 pub(crate) const MERKLE_TREE: MerkleTree = MerkleTree::new_default();
 
@@ -67,14 +92,13 @@ pub(crate) const MERKLE_TREE: MerkleTree = MerkleTree::new_default();
 ///
 /// Our design mostly resembles Sei's with the differences being that:
 /// - we use a binary Jellyfish Merkle tree (JMT) instead of IAVL;
-/// - we store JMT data in a RocksDB instance, instead of using memory map (mmap);
-/// - we don't have asynchronous commit;
-/// - we don't store snapshots or use a WAL to recover the latest state.
+/// - we store JMT data in a RocksDB instance, instead of using memory map (mmap).
 ///
-/// These differences are not because we don't agree with Sei's approach...
-/// it's just because we're having here is sort of a quick hack and we don't
-/// have time to look into those advanced features yet. We will keep experimenting
-/// and maybe our implementation will converge with Sei's some time later.
+/// Like SeiDB, we optionally support asynchronous commit (see
+/// [`DiskDbOptions::async_commit`]): the physical write happens in a
+/// background thread while the next block is being executed, and a write-ahead
+/// log (WAL) is used to recover the latest state if the node crashes before the
+/// write completes.
 pub struct DiskDb {
     pub(crate) inner: Arc<DiskDbInner>,
 }
@@ -84,21 +108,76 @@ pub(crate) struct DiskDbInner {
     // Data that are ready to be persisted to the physical database.
     // Ideally we want to just use a `rocksdb::WriteBatch` here, but it's not
     // thread-safe.
-    pending_data: RwLock<Option<PendingData>>,
+    pub pending_data: RwLock<Option<PendingData>>,
+    // Data that have been committed, but are still being written to the
+    // physical database by the background writer. Reads are served from here
+    // until the write completes.
+    in_flight: RwLock<Option<Arc<PendingData>>>,
+    // The background writer, if async commit is enabled.
+    writer: Option<Mutex<Option<JoinHandle<DbResult<()>>>>>,
+    // Path to the write-ahead log.
+    wal_path: PathBuf,
+    // Cache of Merkle tree nodes, if enabled.
+    node_cache: Option<NodeCache>,
+    // Which versions to keep.
+    pruning: PruningStrategy,
+    // The background thread taking a state sync snapshot, if any.
+    snapshotter: Mutex<Option<JoinHandle<()>>>,
+    // An ongoing restoration from a state sync snapshot, if any.
+    restoration: RwLock<Option<Restoration>>,
 }
 
+#[derive(BorshSerialize, BorshDeserialize)]
 pub(crate) struct PendingData {
-    version: u64,
-    state_commitment: Batch,
-    state_storage: Batch,
+    pub version: u64,
+    pub state_commitment: Batch,
+    pub state_storage: Batch,
+}
+
+/// Options for opening a [`DiskDb`].
+#[derive(Debug, Clone)]
+pub struct DiskDbOptions {
+    /// Whether to physically write committed data in a background thread.
+    ///
+    /// If enabled, upon `commit`, the data are written to the WAL, and then
+    /// written to the physical database in a background thread, so that the
+    /// next block can be executed meanwhile. The data are readable right away.
+    pub async_commit: bool,
+    /// Maximum number of Merkle tree nodes to be kept in the in-memory cache.
+    /// Zero means the cache is disabled.
+    pub node_cache_capacity: usize,
+    /// Which versions to keep. Pruning is performed right after committing.
+    pub pruning: PruningStrategy,
+}
+
+impl Default for DiskDbOptions {
+    fn default() -> Self {
+        Self {
+            async_commit: false,
+            node_cache_capacity: DEFAULT_NODE_CACHE_CAPACITY,
+            pruning: PruningStrategy::default(),
+        }
+    }
 }
 
 impl DiskDb {
-    /// Create a DiskDb instance by opening a physical RocksDB instance.
+    /// Create a DiskDb instance by opening a physical RocksDB instance, using
+    /// the default options.
     pub fn open<P>(data_dir: P) -> DbResult<Self>
     where
         P: AsRef<Path>,
     {
+        Self::open_with_opts(data_dir, DiskDbOptions::default())
+    }
+
+    /// Create a DiskDb instance by opening a physical RocksDB instance, using
+    /// the given options.
+    pub fn open_with_opts<P>(data_dir: P, opts: DiskDbOptions) -> DbResult<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let data_dir = data_dir.as_ref();
+
         // Note: For default and state commitment CFs, don't enable timestamping;
         // for state storage column family, enable timestamping.
         let db = DBWithThreadMode::open_cf_with_opts(&new_db_options(), data_dir, [
@@ -106,14 +185,138 @@ impl DiskDb {
             (CF_NAME_PREIMAGES, new_cf_options_with_ts()),
             (CF_NAME_STATE_STORAGE, new_cf_options_with_ts()),
             (CF_NAME_STATE_COMMITMENT, Options::default()),
+            (CF_NAME_SNAPSHOTS, Options::default()),
         ])?;
 
-        Ok(Self {
+        let db = Self {
             inner: Arc::new(DiskDbInner {
                 db,
                 pending_data: RwLock::new(None),
+                in_flight: RwLock::new(None),
+                writer: opts.async_commit.then(|| Mutex::new(None)),
+                node_cache: NonZeroUsize::new(opts.node_cache_capacity).map(NodeCache::new),
+                pruning: opts.pruning,
+                snapshotter: Mutex::new(None),
+                wal_path: wal_path(data_dir),
+                restoration: RwLock::new(None),
             }),
-        })
+        };
+
+        db.recover_from_wal()?;
+
+        Ok(db)
+    }
+
+    /// Return the usage statistics of the Merkle tree node cache, or `None` if
+    /// the cache is disabled.
+    pub fn node_cache_metrics(&self) -> Option<Metrics> {
+        self.inner.node_cache.as_ref().map(NodeCache::metrics)
+    }
+
+    /// Block until the background writer, if any, has finished writing the
+    /// last committed data to the physical database.
+    pub fn wait_for_commit(&self) -> DbResult<()> {
+        let Some(writer) = &self.inner.writer else {
+            return Ok(());
+        };
+
+        match writer.lock().map_err(|_| DbError::WriterPoisoned)?.take() {
+            Some(handle) => handle.join().map_err(|_| DbError::WriterPanicked)?,
+            None => Ok(()),
+        }
+    }
+
+    /// Block until the background snapshotter, if any, has finished taking
+    /// the snapshot.
+    pub fn wait_for_snapshot(&self) -> DbResult<()> {
+        let mut snapshotter = self
+            .inner
+            .snapshotter
+            .lock()
+            .map_err(|_| DbError::SnapshotterPoisoned)?;
+
+        match snapshotter.take() {
+            Some(handle) => handle.join().map_err(|_| DbError::SnapshotterPanicked),
+            None => Ok(()),
+        }
+    }
+
+    /// If the node crashed after data were committed but before they were
+    /// physically written, write them now.
+    fn recover_from_wal(&self) -> DbResult<()> {
+        if let Some(pending) = read_wal(&self.inner.wal_path)? {
+            let is_newer = match self.latest_version() {
+                Some(latest_version) => pending.version > latest_version,
+                None => true,
+            };
+
+            if is_newer {
+                self.inner.write(&pending)?;
+            }
+        }
+
+        remove_wal(&self.inner.wal_path)
+    }
+}
+
+impl DiskDbInner {
+    /// Return the data being written by the background writer, if any.
+    fn in_flight(&self) -> Option<Arc<PendingData>> {
+        self.in_flight
+            .read()
+            .unwrap_or_else(|err| {
+                panic!("failed to read the in-flight write batch: {err}");
+            })
+            .clone()
+    }
+
+    /// Physically write the pending data to the database.
+    fn write(&self, pending: &PendingData) -> DbResult<()> {
+        let mut batch = WriteBatch::default();
+        let ts = U64Timestamp::from(pending.version);
+
+        // Set the new version (note: use little endian)
+        let cf = cf_default(&self.db);
+        batch.put_cf(&cf, LATEST_VERSION_KEY, pending.version.to_le_bytes());
+
+        // Writes in state commitment
+        let cf = cf_state_commitment(&self.db);
+        for (key, op) in &pending.state_commitment {
+            if let Op::Insert(value) = op {
+                batch.put_cf(&cf, key, value);
+            } else {
+                batch.delete_cf(&cf, key);
+            }
+        }
+
+        // Writes in preimages (note: don't forget timestamping, and deleting
+        // key hashes that are deleted in state storage - see Zellic audut).
+        let cf = cf_preimages(&self.db);
+        for (key, op) in &pending.state_storage {
+            if let Op::Insert(_) = op {
+                batch.put_cf_with_ts(&cf, key.hash256(), ts, key);
+            } else {
+                batch.delete_cf_with_ts(&cf, key.hash256(), ts);
+            }
+        }
+
+        // Writes in state storage (note: don't forget timestamping)
+        let cf = cf_state_storage(&self.db);
+        for (key, op) in &pending.state_storage {
+            if let Op::Insert(value) = op {
+                batch.put_cf_with_ts(&cf, key, ts, value);
+            } else {
+                batch.delete_cf_with_ts(&cf, key, ts);
+            }
+        }
+
+        self.db.write(batch)?;
+
+        if let Some(node_cache) = &self.node_cache {
+            node_cache.apply(&pending.state_commitment);
+        }
+
+        Ok(())
     }
 }
 
@@ -132,8 +335,11 @@ impl Db for DiskDb {
     type StateStorage = StateStorage;
 
     fn state_commitment(&self) -> StateCommitment {
+        // Merkle tree nodes are never overwritten, so the in-flight data can
+        // always be overlaid, regardless of the version being accessed.
         StateCommitment {
             inner: Arc::clone(&self.inner),
+            overlay: self.inner.in_flight(),
         }
     }
 
@@ -170,13 +376,25 @@ impl Db for DiskDb {
             }
         }
 
+        // If the requested version is being written by the background writer,
+        // overlay the in-flight data on top of the physical DB.
+        let overlay = self
+            .inner
+            .in_flight()
+            .filter(|pending| pending.version == version);
+
         Ok(StateStorage {
             inner: Arc::clone(&self.inner),
             version,
+            overlay,
         })
     }
 
     fn latest_version(&self) -> Option<u64> {
+        if let Some(pending) = self.inner.in_flight() {
+            return Some(pending.version);
+        }
+
         let cf = cf_default(&self.inner.db);
         let bytes = self
             .inner
@@ -245,45 +463,320 @@ impl Db for DiskDb {
             .write()?
             .take()
             .ok_or(DbError::PendingDataNotSet)?;
+
+        let Some(writer) = &self.inner.writer else {
+            self.inner.write(&pending)?;
+            self.prune_by_strategy(pending.version);
+            return Ok(());
+        };
+
+        let mut writer = writer.lock().map_err(|_| DbError::WriterPoisoned)?;
+
+        // Writes must happen in order, so wait for the previous one to finish.
+        if let Some(handle) = writer.take() {
+            handle.join().map_err(|_| DbError::WriterPanicked)??;
+        }
+
+        // Once the data are in the WAL, they can be recovered if the node
+        // crashes, so we can consider them committed.
+        write_wal(&self.inner.wal_path, &pending)?;
+
+        let pending = Arc::new(pending);
+        *(self.inner.in_flight.write()?) = Some(Arc::clone(&pending));
+
+        let db = self.clone();
+        *writer = Some(thread::spawn(move || {
+            db.inner.write(&pending)?;
+            *(db.inner.in_flight.write()?) = None;
+            remove_wal(&db.inner.wal_path)?;
+            db.prune_by_strategy(pending.version);
+            Ok(())
+        }));
+
+        Ok(())
+    }
+
+    fn create_snapshot(&self, version: u64, chunk_size: usize) -> DbResult<Option<Snapshot>> {
+        let (snapshot, chunks) = split_into_chunks(self, version, chunk_size)?;
+
         let mut batch = WriteBatch::default();
-        let ts = U64Timestamp::from(pending.version);
+        let cf = cf_snapshots(&self.inner.db);
+        for (index, chunk) in (0..snapshot.chunks()?).zip(chunks) {
+            batch.put_cf(&cf, chunk_key(version, index), chunk);
+        }
 
-        // Set the new version (note: use little endian)
-        let cf = cf_default(&self.inner.db);
-        batch.put_cf(&cf, LATEST_VERSION_KEY, pending.version.to_le_bytes());
+        // Write the metadata last, in the same batch, so that a snapshot is
+        // only listed once all its chunks are available.
+        batch.put_cf(&cf, snapshot_key(version), snapshot.to_borsh_vec()?);
 
-        // Writes in state commitment
-        let cf = cf_state_commitment(&self.inner.db);
-        for (key, op) in pending.state_commitment {
-            if let Op::Insert(value) = op {
-                batch.put_cf(&cf, key, value);
-            } else {
-                batch.delete_cf(&cf, key);
-            }
+        self.inner.db.write(batch)?;
+
+        Ok(Some(snapshot))
+    }
+
+    fn create_snapshot_in_background(
+        &self,
+        version: u64,
+        chunk_size: usize,
+        keep_recent: usize,
+    ) -> DbResult<()> {
+        let mut snapshotter = self
+            .inner
+            .snapshotter
+            .lock()
+            .map_err(|_| DbError::SnapshotterPoisoned)?;
+
+        // Snapshotting the full state may well take longer than the snapshot
+        // interval. In that case, skip this one instead of piling up threads.
+        if snapshotter
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+        {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                height = version,
+                "Previous snapshot still in progress; skipping"
+            );
+
+            return Ok(());
         }
 
-        // Writes in preimages (note: don't forget timestamping, and deleting
-        // key hashes that are deleted in state storage - see Zellic audut).
-        let cf = cf_preimages(&self.inner.db);
-        for (key, op) in &pending.state_storage {
-            if let Op::Insert(_) = op {
-                batch.put_cf_with_ts(&cf, key.hash256(), ts, key);
-            } else {
-                batch.delete_cf_with_ts(&cf, key.hash256(), ts);
+        let db = self.clone();
+        *snapshotter = Some(thread::spawn(move || {
+            #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+            let result = db
+                .create_snapshot(version, chunk_size)
+                .and_then(|snapshot| {
+                    db.prune_snapshots(keep_recent)?;
+                    Ok(snapshot)
+                });
+
+            #[cfg(feature = "tracing")]
+            match result {
+                Ok(Some(snapshot)) => {
+                    tracing::info!(
+                        height = version,
+                        chunks = snapshot.chunk_hashes.len(),
+                        hash = snapshot.hash().to_string(),
+                        "Took snapshot"
+                    );
+                },
+                Ok(None) => {},
+                Err(err) => {
+                    tracing::error!(
+                        height = version,
+                        err = err.to_string(),
+                        "Failed to take snapshot"
+                    );
+                },
+            }
+        }));
+
+        Ok(())
+    }
+
+    fn prune_snapshots(&self, keep_recent: usize) -> DbResult<()> {
+        let mut batch = WriteBatch::default();
+        let cf = cf_snapshots(&self.inner.db);
+
+        // Snapshots are listed in ascending order by height, so the ones to be
+        // deleted are at the front.
+        let snapshots = self.list_snapshots()?;
+        let num_to_delete = snapshots.len().saturating_sub(keep_recent);
+
+        for snapshot in snapshots.into_iter().take(num_to_delete) {
+            for index in 0..snapshot.chunks()? {
+                batch.delete_cf(&cf, chunk_key(snapshot.height, index));
             }
+            batch.delete_cf(&cf, snapshot_key(snapshot.height));
         }
 
-        // Writes in state storage (note: don't forget timestamping)
-        let cf = cf_state_storage(&self.inner.db);
-        for (key, op) in pending.state_storage {
+        Ok(self.inner.db.write(batch)?)
+    }
+
+    fn list_snapshots(&self) -> DbResult<Vec<Snapshot>> {
+        let opts = new_read_options(None, Some(PREFIX_SNAPSHOT), Some(PREFIX_SNAPSHOT_END));
+        self.inner
+            .db
+            .iterator_cf_opt(&cf_snapshots(&self.inner.db), opts, IteratorMode::Start)
+            .map(|item| -> DbResult<_> {
+                let (_, value) = item?;
+                Ok(value.deserialize_borsh()?)
+            })
+            .collect()
+    }
+
+    fn load_snapshot_chunk(
+        &self,
+        height: u64,
+        format: u32,
+        index: u32,
+    ) -> DbResult<Option<Vec<u8>>> {
+        if format != SNAPSHOT_FORMAT {
+            return Ok(None);
+        }
+
+        Ok(self
+            .inner
+            .db
+            .get_cf(&cf_snapshots(&self.inner.db), chunk_key(height, index))?)
+    }
+
+    fn offer_snapshot(
+        &self,
+        snapshot: Snapshot,
+        app_hash: Hash256,
+    ) -> DbResult<OfferSnapshotOutcome> {
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return Ok(OfferSnapshotOutcome::RejectFormat);
+        }
+
+        // We only restore into an empty DB. A node that already has some state
+        // shouldn't be doing state sync in the first place.
+        if self.latest_version().is_some() {
+            return Ok(OfferSnapshotOutcome::Abort);
+        }
+
+        // The state is never empty (it contains at least the chain ID and
+        // config), so a snapshot can't have zero chunks.
+        if snapshot.chunk_hashes.is_empty() {
+            return Ok(OfferSnapshotOutcome::Reject);
+        }
+
+        // Accepting a new snapshot discards any restoration in progress,
+        // including one interrupted by the node being shut down.
+        let mut restoration = self.inner.restoration.write()?;
+
+        self.discard_restoration()?;
+
+        *restoration = Some(Restoration {
+            snapshot,
+            app_hash,
+            next_chunk: 0,
+            leaves: Vec::new(),
+        });
+
+        Ok(OfferSnapshotOutcome::Accept)
+    }
+
+    fn apply_snapshot_chunk(
+        &self,
+        index: u32,
+        chunk: Vec<u8>,
+    ) -> DbResult<ApplySnapshotChunkOutcome> {
+        let mut restoration_guard = self.inner.restoration.write()?;
+
+        let Some(restoration) = restoration_guard.as_mut() else {
+            return Ok(ApplySnapshotChunkOutcome::Abort);
+        };
+
+        // Chunks must be applied in order. A chunk that arrives out of order
+        // isn't necessarily bad, so just ask for the expected one.
+        if index != restoration.next_chunk {
+            return Ok(ApplySnapshotChunkOutcome::OutOfOrder {
+                expected: restoration.next_chunk,
+            });
+        }
+
+        // Each chunk must match its hash. `next_chunk` is always in range while
+        // the restoration is ongoing, so this indexing doesn't panic.
+        if restoration.snapshot.chunk_hashes[index as usize] != chunk.hash256() {
+            return Ok(ApplySnapshotChunkOutcome::Retry);
+        }
+
+        // The chunk matches its hash, but fails to decode. The snapshot itself
+        // must be bad.
+        let Ok(records) = chunk.deserialize_borsh::<Vec<Record>>() else {
+            *restoration_guard = None;
+            self.discard_restoration()?;
+            return Ok(ApplySnapshotChunkOutcome::RejectSnapshot);
+        };
+
+        // Write the chunk's records to the state storage right away, instead of
+        // holding the whole state in memory. Only the hashes are kept, to build
+        // the Merkle tree once all chunks have been received. The data aren't
+        // readable until the version is set below, and are discarded if the
+        // snapshot turns out to be bad.
+        let version = restoration.snapshot.height;
+        let ts = U64Timestamp::from(version);
+        let mut batch = WriteBatch::default();
+
+        let cf = cf_default(&self.inner.db);
+        batch.put_cf(&cf, RESTORING_VERSION_KEY, version.to_le_bytes());
+
+        let cf_preimages = cf_preimages(&self.inner.db);
+        let cf_state_storage = cf_state_storage(&self.inner.db);
+        for (key, value) in records {
+            let key_hash = key.hash256();
+            restoration
+                .leaves
+                .push((key_hash, Op::Insert(value.hash256())));
+            batch.put_cf_with_ts(&cf_preimages, key_hash, ts, &key);
+            batch.put_cf_with_ts(&cf_state_storage, key, ts, value);
+        }
+
+        self.inner.db.write(batch)?;
+
+        restoration.next_chunk += 1;
+
+        // If there are more chunks to come, we're done for now.
+        if (restoration.next_chunk as usize) < restoration.snapshot.chunk_hashes.len() {
+            return Ok(ApplySnapshotChunkOutcome::Accept);
+        }
+
+        // All chunks have been received. Rebuild the Merkle tree, and check its
+        // root hash against the trusted app hash.
+        //
+        // The restored tree consists only of nodes of the snapshot's version.
+        // It is structurally different from the original tree (where nodes are
+        // of different versions), but the root hash is the same, since node
+        // hashes don't depend on versions.
+        let Restoration {
+            app_hash,
+            mut leaves,
+            ..
+        } = restoration_guard.take().unwrap();
+
+        leaves.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
+
+        // A key appearing more than once, with the later value overwriting the
+        // earlier one in the state storage, would make the data differ from
+        // what the root hash commits to. The snapshot must be bad.
+        if leaves.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            self.discard_restoration()?;
+            return Ok(ApplySnapshotChunkOutcome::RejectSnapshot);
+        }
+
+        let mut buffer = Buffer::new(self.state_commitment(), None);
+        let root_hash = MERKLE_TREE.apply(&mut buffer, 0, version, leaves)?;
+        let (_, state_commitment) = buffer.disassemble();
+
+        if root_hash != Some(app_hash) {
+            self.discard_restoration()?;
+            return Ok(ApplySnapshotChunkOutcome::RejectSnapshot);
+        }
+
+        // The root hash is verified. Persist the tree and the version in one
+        // atomic write, which makes the restored data available.
+        let mut batch = WriteBatch::default();
+
+        let cf = cf_state_commitment(&self.inner.db);
+        for (key, op) in state_commitment {
             if let Op::Insert(value) = op {
-                batch.put_cf_with_ts(&cf, key, ts, value);
-            } else {
-                batch.delete_cf_with_ts(&cf, key, ts);
+                batch.put_cf(&cf, key, value);
             }
         }
 
-        Ok(self.inner.db.write(batch)?)
+        // Versions prior to the snapshot's are not available in this DB, so
+        // mark the snapshot's version as the oldest.
+        let cf = cf_default(&self.inner.db);
+        batch.put_cf(&cf, LATEST_VERSION_KEY, version.to_le_bytes());
+        batch.put_cf(&cf, OLDEST_VERSION_KEY, version.to_le_bytes());
+        batch.delete_cf(&cf, RESTORING_VERSION_KEY);
+
+        self.inner.db.write(batch)?;
+
+        Ok(ApplySnapshotChunkOutcome::Accept)
     }
 }
 
@@ -307,8 +800,109 @@ impl PrunableDb for DiskDb {
     }
 
     fn prune(&self, up_to_version: u64) -> DbResult<()> {
-        let ts = U64Timestamp::from(up_to_version);
+        // Pruning writes directly to the physical DB, so it must not race with
+        // the background writer.
+        self.wait_for_commit()?;
+        // The snapshotter reads the state storage at the snapshot's version,
+        // which must not be pruned from under it.
+        self.wait_for_snapshot()?;
+        self.prune_unchecked(up_to_version, None)
+    }
+}
+
+impl DiskDb {
+    /// Delete the data written by a restoration that didn't complete, if any.
+    ///
+    /// The data are deleted at the restored version, so that they are hidden
+    /// from reads at that version or later, while a later restoration at the
+    /// same or a later version overwrites them.
+    fn discard_restoration(&self) -> DbResult<()> {
+        let cf = cf_default(&self.inner.db);
+        let Some(bytes) = self.inner.db.get_cf(&cf, RESTORING_VERSION_KEY)? else {
+            return Ok(());
+        };
+
+        let array = bytes.try_into().unwrap_or_else(|bytes: Vec<u8>| {
+            panic!(
+                "restoring version is of incorrect byte length: {}",
+                bytes.len()
+            );
+        });
+        let version = u64::from_le_bytes(array);
+        let ts = U64Timestamp::from(version);
+
+        let mut batch = WriteBatch::default();
+        batch.delete_cf(&cf, RESTORING_VERSION_KEY);
+
+        for cf in [
+            cf_preimages(&self.inner.db),
+            cf_state_storage(&self.inner.db),
+        ] {
+            let opts = new_read_options(Some(version), None, None);
+            for item in self
+                .inner
+                .db
+                .iterator_cf_opt(&cf, opts, IteratorMode::Start)
+            {
+                let (key, _) = item?;
+                batch.delete_cf_with_ts(&cf, key, ts);
+            }
+        }
+
+        Ok(self.inner.db.write(batch)?)
+    }
+
+    /// Prune the DB if it's due according to the pruning strategy.
+    ///
+    /// This happens after the committed data have been written, so failing to
+    /// prune doesn't fail the commit. The error is logged, and the versions are
+    /// pruned next time instead.
+    ///
+    /// If a snapshot is being taken, pruning is skipped rather than waiting for
+    /// it, so that a slow snapshot doesn't stall block production.
+    fn prune_by_strategy(&self, latest_version: u64) {
+        let Some(up_to_version) = self
+            .inner
+            .pruning
+            .prune_up_to(latest_version, self.oldest_version())
+        else {
+            return;
+        };
+
+        // Hold the lock while pruning, so that no snapshot starts meanwhile.
+        let Ok(snapshotter) = self.inner.snapshotter.lock() else {
+            #[cfg(feature = "tracing")]
+            tracing::error!(up_to_version, "Snapshotter poisoned; skipping pruning");
+
+            return;
+        };
 
+        // The snapshotter reads the state storage at the snapshot's version,
+        // which must not be pruned from under it.
+        if snapshotter
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+        {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(up_to_version, "Snapshot in progress; skipping pruning");
+
+            return;
+        }
+
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        if let Err(err) = self.prune_unchecked(up_to_version, self.inner.pruning.keep_every()) {
+            #[cfg(feature = "tracing")]
+            tracing::error!(up_to_version, err = err.to_string(), "Failed to prune");
+        }
+    }
+
+    /// Prune the DB without waiting for the background writer or snapshotter.
+    /// The caller must make sure neither is in progress.
+    ///
+    /// If `keep_every` is provided, the versions divisible by it are kept, in
+    /// which case only the Merkle tree is pruned. See
+    /// [`PruningStrategy::KeepRecentAndEvery`].
+    fn prune_unchecked(&self, up_to_version: u64, keep_every: Option<u64>) -> DbResult<()> {
         // Prune state storage.
         //
         // We do this by increase the state storage column family's
@@ -318,21 +912,33 @@ impl PrunableDb for DiskDb {
         // Note, this does _not_ incur an immediate full compaction, i.e. this
         // performs a lazy prune. Future compactions will honor the increased
         // `full_history_ts_low` and trim history when possible.
-        let cf = cf_state_storage(&self.inner.db);
-        self.inner.db.increase_full_history_ts_low(&cf, ts)?;
+        //
+        // The watermark can't skip over versions, so this is only possible if
+        // no sparse versions are to be kept.
+        if keep_every.is_none() {
+            let ts = U64Timestamp::from(up_to_version);
 
-        // Same for preimages.
-        let cf = cf_preimages(&self.inner.db);
-        self.inner.db.increase_full_history_ts_low(&cf, ts)?;
+            let cf = cf_state_storage(&self.inner.db);
+            self.inner.db.increase_full_history_ts_low(&cf, ts)?;
+
+            // Same for preimages.
+            let cf = cf_preimages(&self.inner.db);
+            self.inner.db.increase_full_history_ts_low(&cf, ts)?;
+        }
 
         // Prune state commitment.
         let mut buffer = Buffer::new(self.state_commitment(), None);
-        MERKLE_TREE.prune(&mut buffer, up_to_version)?;
+        match keep_every {
+            Some(keep_every) => {
+                MERKLE_TREE.prune_except_every(&mut buffer, up_to_version, keep_every)?
+            },
+            None => MERKLE_TREE.prune(&mut buffer, up_to_version)?,
+        }
 
         let (_, pending) = buffer.disassemble();
         let mut batch = WriteBatch::default();
         let cf = cf_state_commitment(&self.inner.db);
-        for (key, op) in pending {
+        for (key, op) in &pending {
             if let Op::Insert(value) = op {
                 batch.put_cf(&cf, key, value);
             } else {
@@ -340,11 +946,21 @@ impl PrunableDb for DiskDb {
             }
         }
 
-        // Finally, update the oldest available version value.
-        let cf = cf_default(&self.inner.db);
-        batch.put_cf(&cf, OLDEST_VERSION_KEY, up_to_version.to_le_bytes());
+        // Finally, update the oldest available version value, unless it's
+        // still available in the state storage.
+        if keep_every.is_none() {
+            let cf = cf_default(&self.inner.db);
+            batch.put_cf(&cf, OLDEST_VERSION_KEY, up_to_version.to_le_bytes());
+        }
 
-        Ok(self.inner.db.write(batch)?)
+        self.inner.db.write(batch)?;
+
+        // Evict the pruned nodes from the cache.
+        if let Some(node_cache) = &self.inner.node_cache {
+            node_cache.apply(&pending);
+        }
+
+        Ok(())
     }
 }
 
@@ -352,24 +968,41 @@ impl PrunableDb for DiskDb {
 
 pub struct StateCommitment {
     inner: Arc<DiskDbInner>,
+    overlay: Option<Arc<PendingData>>,
 }
 
 impl Clone for StateCommitment {
     fn clone(&self) -> Self {
         Self {
             inner: Arc::clone(&self.inner),
+            overlay: self.overlay.clone(),
         }
     }
 }
 
 impl Storage for StateCommitment {
     fn read(&self, key: &[u8]) -> Option<Vec<u8>> {
-        self.inner
-            .db
-            .get_cf(&cf_state_commitment(&self.inner.db), key)
-            .unwrap_or_else(|err| {
-                panic!("failed to read from state commitment: {err}");
-            })
+        if let Some(op) = self
+            .overlay
+            .as_ref()
+            .and_then(|pending| pending.state_commitment.get(key))
+        {
+            return read_op(op);
+        }
+
+        let load = || {
+            self.inner
+                .db
+                .get_cf(&cf_state_commitment(&self.inner.db), key)
+                .unwrap_or_else(|err| {
+                    panic!("failed to read from state commitment: {err}");
+                })
+        };
+
+        match &self.inner.node_cache {
+            Some(node_cache) => node_cache.get_or_load_with(key, load),
+            None => load(),
+        }
     }
 
     fn scan<'a>(
@@ -390,7 +1023,13 @@ impl Storage for StateCommitment {
                 });
                 (k.to_vec(), v.to_vec())
             });
-        Box::new(iter)
+
+        match &self.overlay {
+            Some(pending) => {
+                merge_overlay(Box::new(iter), &pending.state_commitment, min, max, order)
+            },
+            None => Box::new(iter),
+        }
     }
 
     fn scan_keys<'a>(
@@ -399,6 +1038,10 @@ impl Storage for StateCommitment {
         max: Option<&[u8]>,
         order: Order,
     ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        if self.overlay.is_some() {
+            return Box::new(self.scan(min, max, order).map(|(k, _)| k));
+        }
+
         let opts = new_read_options(None, min, max);
         let mode = into_iterator_mode(order);
         let iter = self
@@ -420,6 +1063,10 @@ impl Storage for StateCommitment {
         max: Option<&[u8]>,
         order: Order,
     ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        if self.overlay.is_some() {
+            return Box::new(self.scan(min, max, order).map(|(_, v)| v));
+        }
+
         let opts = new_read_options(None, min, max);
         let mode = into_iterator_mode(order);
         let iter = self
@@ -454,10 +1101,19 @@ impl Storage for StateCommitment {
 pub struct StateStorage {
     inner: Arc<DiskDbInner>,
     version: u64,
+    overlay: Option<Arc<PendingData>>,
 }
 
 impl Storage for StateStorage {
     fn read(&self, key: &[u8]) -> Option<Vec<u8>> {
+        if let Some(op) = self
+            .overlay
+            .as_ref()
+            .and_then(|pending| pending.state_storage.get(key))
+        {
+            return read_op(op);
+        }
+
         let opts = new_read_options(Some(self.version), None, None);
         self.inner
             .db
@@ -485,7 +1141,11 @@ impl Storage for StateStorage {
                 });
                 (k.to_vec(), v.to_vec())
             });
-        Box::new(iter)
+
+        match &self.overlay {
+            Some(pending) => merge_overlay(Box::new(iter), &pending.state_storage, min, max, order),
+            None => Box::new(iter),
+        }
     }
 
     fn scan_keys<'a>(
@@ -494,6 +1154,10 @@ impl Storage for StateStorage {
         max: Option<&[u8]>,
         order: Order,
     ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        if self.overlay.is_some() {
+            return Box::new(self.scan(min, max, order).map(|(k, _)| k));
+        }
+
         let opts = new_read_options(Some(self.version), min, max);
         let mode = into_iterator_mode(order);
         let iter = self
@@ -515,6 +1179,10 @@ impl Storage for StateStorage {
         max: Option<&[u8]>,
         order: Order,
     ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        if self.overlay.is_some() {
+            return Box::new(self.scan(min, max, order).map(|(_, v)| v));
+        }
+
         let opts = new_read_options(Some(self.version), min, max);
         let mode = into_iterator_mode(order);
         let iter = self
@@ -553,6 +1221,40 @@ fn into_iterator_mode(order: Order) -> IteratorMode<'static> {
     }
 }
 
+#[inline]
+fn read_op(op: &Op) -> Option<Vec<u8>> {
+    match op {
+        Op::Insert(value) => Some(value.clone()),
+        Op::Delete => None,
+    }
+}
+
+/// Merge records iterated from the physical DB with the in-flight data that
+/// are yet to be written to it.
+fn merge_overlay<'a>(
+    base: Box<dyn Iterator<Item = Record> + 'a>,
+    pending: &'a Batch,
+    min: Option<&[u8]>,
+    max: Option<&[u8]>,
+    order: Order,
+) -> Box<dyn Iterator<Item = Record> + 'a> {
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Box::new(iter::empty());
+        }
+    }
+
+    let min = min.map_or(Bound::Unbounded, |bytes| Bound::Included(bytes.to_vec()));
+    let max = max.map_or(Bound::Unbounded, |bytes| Bound::Excluded(bytes.to_vec()));
+    let pending_raw = pending.range((min, max));
+    let pending: Box<dyn Iterator<Item = _>> = match order {
+        Order::Ascending => Box::new(pending_raw),
+        Order::Descending => Box::new(pending_raw.rev()),
+    };
+
+    Box::new(Merged::new(base, pending, order))
+}
+
 // TODO: rocksdb tuning? see:
 // https://github.com/sei-protocol/sei-db/blob/main/ss/rocksdb/opts.go#L29-L65
 // https://github.com/turbofish-org/merk/blob/develop/src/merk/mod.rs#L84-L102
@@ -618,12 +1320,18 @@ fn cf_state_commitment(db: &DBWithThreadMode<MultiThreaded>) -> Arc<BoundColumnF
     })
 }
 
+fn cf_snapshots(db: &DBWithThreadMode<MultiThreaded>) -> Arc<BoundColumnFamily<'_>> {
+    db.cf_handle(CF_NAME_SNAPSHOTS).unwrap_or_else(|| {
+        panic!("failed to find snapshots column family");
+    })
+}
+
 // ----------------------------------- test ------------------------------------
 
 #[cfg(test)]
 mod tests {
     use {
-        crate::DiskDb,
+        crate::{DiskDb, DiskDbOptions, PruningStrategy},
         grug_app::{Db, PrunableDb},
         grug_jmt::verify_proof,
         grug_types::{
@@ -962,6 +1670,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn node_cache_works() {
+        let path = TempDataDir::new("_grug_disk_db_node_cache_works");
+        let db = DiskDb::open(&path).unwrap();
+
+        // Nodes of the first version are inserted into the cache upon commit.
+        let (_, root_hash) = db
+            .flush_and_commit(Batch::from([
+                (b"donald".to_vec(), Op::Insert(b"trump".to_vec())),
+                (b"jake".to_vec(), Op::Insert(b"shepherd".to_vec())),
+                (b"joe".to_vec(), Op::Insert(b"biden".to_vec())),
+                (b"larry".to_vec(), Op::Insert(b"engineer".to_vec())),
+            ]))
+            .unwrap();
+
+        // Looking up the root node of the previous version, which doesn't
+        // exist, is a cache miss.
+        let metrics_before = db.node_cache_metrics().unwrap();
+        assert_eq!(metrics_before.hits, 0);
+
+        // Applying the next batch walks the tree from the root. All nodes on
+        // the path are cached.
+        db.flush_and_commit(Batch::from([(
+            b"pumpkin".to_vec(),
+            Op::Insert(b"cat".to_vec()),
+        )]))
+        .unwrap();
+
+        let metrics = db.node_cache_metrics().unwrap();
+        assert!(metrics.hits > 0);
+        assert_eq!(metrics.misses, metrics_before.misses);
+
+        // The result is the same as without the cache.
+        assert_eq!(db.root_hash(Some(0)).unwrap(), root_hash);
+
+        let path = TempDataDir::new("_grug_disk_db_node_cache_disabled");
+        let db = DiskDb::open_with_opts(&path, DiskDbOptions {
+            node_cache_capacity: 0,
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert_eq!(db.node_cache_metrics(), None);
+    }
+
     #[test]
     fn disk_db_pruning_works() {
         let path = TempDataDir::new("_grug_disk_db_pruning_works");
@@ -1029,4 +1782,89 @@ mod tests {
             }));
         }
     }
+
+    #[test]
+    fn automatic_pruning_works() {
+        let path = TempDataDir::new("_grug_disk_db_automatic_pruning_works");
+        let db = DiskDb::open_with_opts(&path, DiskDbOptions {
+            pruning: PruningStrategy::KeepRecent {
+                keep_recent: 2,
+                interval: 2,
+            },
+            ..Default::default()
+        })
+        .unwrap();
+
+        // v0 and v1: not enough versions to prune.
+        for version in 0..2_u64 {
+            db.flush_and_commit(Batch::from([(
+                b"version".to_vec(),
+                Op::Insert(version.to_le_bytes().to_vec()),
+            )]))
+            .unwrap();
+        }
+
+        assert_eq!(db.oldest_version(), None);
+
+        // v2: prune up to v1.
+        // v3: not at the interval, so no pruning.
+        // v4: prune up to v3.
+        for version in 2..5_u64 {
+            db.flush_and_commit(Batch::from([(
+                b"version".to_vec(),
+                Op::Insert(version.to_le_bytes().to_vec()),
+            )]))
+            .unwrap();
+
+            let expected = if version < 4 {
+                Some(1)
+            } else {
+                Some(3)
+            };
+            assert_eq!(db.oldest_version(), expected);
+        }
+
+        assert!(db.state_storage(Some(2)).is_err());
+        assert!(db.prove(b"version", Some(3)).is_ok());
+        assert_eq!(
+            db.state_storage(Some(3)).unwrap().read(b"version"),
+            Some(3_u64.to_le_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn keeping_every_nth_version_works() {
+        let path = TempDataDir::new("_grug_disk_db_keeping_every_nth_version_works");
+        let db = DiskDb::open_with_opts(&path, DiskDbOptions {
+            pruning: PruningStrategy::KeepRecentAndEvery {
+                keep_recent: 2,
+                keep_every: 3,
+                interval: 1,
+            },
+            ..Default::default()
+        })
+        .unwrap();
+
+        for version in 0..8_u64 {
+            db.flush_and_commit(Batch::from([(
+                b"version".to_vec(),
+                Op::Insert(version.to_le_bytes().to_vec()),
+            )]))
+            .unwrap();
+        }
+
+        // Every 3rd version, and the 2 most recent ones, can still be proven.
+        // The others have been pruned from the Merkle tree.
+        for version in 0..8_u64 {
+            let kept = version % 3 == 0 || version >= 6;
+            assert_eq!(db.prove(b"version", Some(version)).is_ok(), kept);
+        }
+
+        // The state storage is kept in full.
+        assert_eq!(db.oldest_version(), None);
+        assert_eq!(
+            db.state_storage(Some(1)).unwrap().read(b"version"),
+            Some(1_u64.to_le_bytes().to_vec())
+        );
+    }
 }
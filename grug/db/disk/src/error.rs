@@ -1,8 +1,11 @@
 use {
-    crate::PendingData,
+    crate::{PendingData, Restoration},
     grug_app::AppError,
     grug_types::StdError,
-    std::sync::{PoisonError, RwLockReadGuard, RwLockWriteGuard},
+    std::{
+        io,
+        sync::{Arc, PoisonError, RwLockReadGuard, RwLockWriteGuard},
+    },
     thiserror::Error,
 };
 
@@ -14,6 +17,9 @@ pub enum DbError {
     #[error(transparent)]
     RocksDb(#[from] rocksdb::Error),
 
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
     #[error("cannot flush when the in-memory write batch is already set")]
     PendingDataAlreadySet,
 
@@ -23,6 +29,24 @@ pub enum DbError {
     #[error("rwlock for the write batch is poisoned")]
     PendingDataPoisoned,
 
+    #[error("rwlock for the in-flight write batch is poisoned")]
+    InFlightDataPoisoned,
+
+    #[error("mutex for the background writer is poisoned")]
+    WriterPoisoned,
+
+    #[error("background writer panicked")]
+    WriterPanicked,
+
+    #[error("mutex for the background snapshotter is poisoned")]
+    SnapshotterPoisoned,
+
+    #[error("background snapshotter panicked")]
+    SnapshotterPanicked,
+
+    #[error("rwlock for the snapshot restoration is poisoned")]
+    RestorationPoisoned,
+
     #[error("requested version ({version}) is newer than the latest version ({latest_version})")]
     VersionTooNew { version: u64, latest_version: u64 },
 
@@ -44,6 +68,30 @@ impl<'a> From<PoisonError<RwLockWriteGuard<'a, Option<PendingData>>>> for DbErro
     }
 }
 
+impl<'a> From<PoisonError<RwLockReadGuard<'a, Option<Arc<PendingData>>>>> for DbError {
+    fn from(_: PoisonError<RwLockReadGuard<'a, Option<Arc<PendingData>>>>) -> Self {
+        Self::InFlightDataPoisoned
+    }
+}
+
+impl<'a> From<PoisonError<RwLockWriteGuard<'a, Option<Arc<PendingData>>>>> for DbError {
+    fn from(_: PoisonError<RwLockWriteGuard<'a, Option<Arc<PendingData>>>>) -> Self {
+        Self::InFlightDataPoisoned
+    }
+}
+
+impl<'a> From<PoisonError<RwLockReadGuard<'a, Option<Restoration>>>> for DbError {
+    fn from(_: PoisonError<RwLockReadGuard<'a, Option<Restoration>>>) -> Self {
+        Self::RestorationPoisoned
+    }
+}
+
+impl<'a> From<PoisonError<RwLockWriteGuard<'a, Option<Restoration>>>> for DbError {
+    fn from(_: PoisonError<RwLockWriteGuard<'a, Option<Restoration>>>) -> Self {
+        Self::RestorationPoisoned
+    }
+}
+
 impl From<DbError> for AppError {
     fn from(err: DbError) -> Self {
         AppError::Db(err.to_string())
@@ -16,6 +16,10 @@ impl IbcDb for DiskDb {
         key: Vec<u8>,
         version: Option<u64>,
     ) -> Result<CommitmentProof, Self::Error> {
+        // Preimages are read directly from the physical DB, so wait for the
+        // background writer to finish.
+        self.wait_for_commit()?;
+
         let version = version.unwrap_or_else(|| self.latest_version().unwrap_or(0));
         let state_storage = self.state_storage(Some(version))?;
         let state_commitment = self.state_commitment();
@@ -1,7 +1,11 @@
+mod cache;
 mod db;
 mod error;
 #[cfg(feature = "ibc")]
 mod ics23;
+mod pruning;
+mod snapshot;
 mod timestamp;
+mod wal;
 
-pub use {db::*, error::*, timestamp::*};
+pub use {cache::*, db::*, error::*, pruning::*, snapshot::*, timestamp::*, wal::*};
@@ -0,0 +1,104 @@
+/// Describes which versions of the state a [`DiskDb`](crate::DiskDb) keeps, and
+/// hence how it's automatically pruned upon each commit.
+///
+/// Note that historical state storage data are trimmed by increasing RocksDB's
+/// `full_history_ts_low` watermark, which always removes a contiguous range of
+/// the oldest versions. Merkle tree nodes, on the other hand, are deleted
+/// individually once they are orphaned, so sparse versions (e.g. every 1,000th
+/// version) can be kept while the ones in between are pruned.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PruningStrategy {
+    /// Keep all versions. Suitable for archive nodes.
+    #[default]
+    Nothing,
+    /// Keep the most recent `keep_recent` versions, pruning older ones every
+    /// `interval` versions.
+    ///
+    /// Pruning involves scanning the orphaned Merkle tree nodes, so it's more
+    /// efficient to do it in batches than on every single version.
+    KeepRecent { keep_recent: u64, interval: u64 },
+    /// Keep the most recent `keep_recent` versions, as well as every
+    /// `keep_every`-th version (i.e. the versions divisible by it), pruning the
+    /// others every `interval` versions.
+    ///
+    /// As the state storage watermark can't skip over the versions to be kept,
+    /// historical state storage data are kept in full; only the Merkle tree
+    /// nodes that aren't part of any kept version are pruned. These make up
+    /// the bulk of the historical data, as each write orphans a whole path of
+    /// nodes. Merkle proofs are only available for the kept versions, and the
+    /// oldest available version is unchanged.
+    KeepRecentAndEvery {
+        keep_recent: u64,
+        keep_every: u64,
+        interval: u64,
+    },
+}
+
+impl PruningStrategy {
+    /// Given the latest version and the oldest version currently available,
+    /// return the version up to which the DB should be pruned, or `None` if
+    /// there's no need to prune at this time.
+    pub fn prune_up_to(&self, latest_version: u64, oldest_version: Option<u64>) -> Option<u64> {
+        let (keep_recent, interval) = match *self {
+            PruningStrategy::Nothing => return None,
+            PruningStrategy::KeepRecent {
+                keep_recent,
+                interval,
+            }
+            | PruningStrategy::KeepRecentAndEvery {
+                keep_recent,
+                interval,
+                ..
+            } => (keep_recent, interval),
+        };
+
+        if latest_version % interval.max(1) != 0 {
+            return None;
+        }
+
+        // The latest version is always kept, even if `keep_recent` is zero.
+        let up_to_version = latest_version.checked_sub(keep_recent.max(1) - 1)?;
+
+        // Only prune if there's anything to be pruned.
+        if up_to_version <= oldest_version.unwrap_or(0) {
+            return None;
+        }
+
+        Some(up_to_version)
+    }
+
+    /// Return the interval of the sparse versions to be kept beyond the most
+    /// recent ones, or `None` if the strategy doesn't keep any.
+    pub fn keep_every(&self) -> Option<u64> {
+        match *self {
+            PruningStrategy::KeepRecentAndEvery { keep_every, .. } => Some(keep_every),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {super::*, test_case::test_case};
+
+    #[test_case(PruningStrategy::Nothing, 1000, None => None; "nothing")]
+    #[test_case(PruningStrategy::KeepRecent { keep_recent: 100, interval: 10 }, 1000, None => Some(901); "keep recent")]
+    #[test_case(PruningStrategy::KeepRecent { keep_recent: 100, interval: 10 }, 1005, None => None; "not at interval")]
+    #[test_case(PruningStrategy::KeepRecent { keep_recent: 100, interval: 10 }, 90, None => None; "not enough versions")]
+    #[test_case(PruningStrategy::KeepRecent { keep_recent: 100, interval: 10 }, 1000, Some(901) => None; "already pruned")]
+    #[test_case(PruningStrategy::KeepRecent { keep_recent: 100, interval: 10 }, 1010, Some(901) => Some(911); "pruned before")]
+    #[test_case(PruningStrategy::KeepRecent { keep_recent: 0, interval: 0 }, 5, None => Some(5); "keep latest only")]
+    #[test_case(PruningStrategy::KeepRecentAndEvery { keep_recent: 100, keep_every: 1000, interval: 10 }, 1000, None => Some(901); "keep recent and every")]
+    #[test_case(PruningStrategy::KeepRecentAndEvery { keep_recent: 100, keep_every: 1000, interval: 10 }, 1005, None => None; "keep recent and every, not at interval")]
+    #[test_case(PruningStrategy::KeepRecentAndEvery { keep_recent: 100, keep_every: 1000, interval: 10 }, 90, None => None; "keep recent and every, not enough versions")]
+    #[test_case(PruningStrategy::KeepRecentAndEvery { keep_recent: 100, keep_every: 1000, interval: 10 }, 1010, Some(0) => Some(911); "keep recent and every, pruned before")]
+    fn computing_prune_up_to(
+        strategy: PruningStrategy,
+        latest_version: u64,
+        oldest_version: Option<u64>,
+    ) -> Option<u64> {
+        strategy.prune_up_to(latest_version, oldest_version)
+    }
+}
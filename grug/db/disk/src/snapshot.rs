@@ -0,0 +1,306 @@
+use {
+    crate::{DbResult, DiskDb},
+    grug_app::{Db, Snapshot},
+    grug_types::{BorshSerExt, Hash256, HashExt, Op, Order, Record, Storage},
+    std::mem,
+};
+
+/// The only snapshot format we currently support: each chunk is a Borsh-encoded
+/// list of raw key-value pairs in the state storage, in ascending order by key.
+///
+/// A chunk always contains whole key-value pairs, so that it can be decoded on
+/// its own.
+pub const SNAPSHOT_FORMAT: u32 = 1;
+
+/// Prefix of storage keys for snapshot metadata in the snapshots column family.
+///
+/// Key: `PREFIX_SNAPSHOT | height (big endian)`.
+pub(crate) const PREFIX_SNAPSHOT: &[u8] = b"s";
+
+/// Exclusive upper bound for iterating snapshot metadata.
+pub(crate) const PREFIX_SNAPSHOT_END: &[u8] = b"t";
+
+/// Prefix of storage keys for snapshot chunks in the snapshots column family.
+///
+/// Key: `PREFIX_CHUNK | height (big endian) | index (big endian)`.
+pub(crate) const PREFIX_CHUNK: &[u8] = b"c";
+
+/// State of an ongoing restoration from a snapshot.
+///
+/// Data contained in the chunks are written to the state storage as they
+/// arrive, while only the hashed keys and values are kept in memory. Once all
+/// chunks have been received, the Merkle tree is built from the hashes, and
+/// its root hash verified against the trusted app hash. Only then is the
+/// version set, making the data available. This way, a bad snapshot never
+/// leaves the DB in a half-restored state.
+pub(crate) struct Restoration {
+    pub snapshot: Snapshot,
+    pub app_hash: Hash256,
+    pub next_chunk: u32,
+    pub leaves: Vec<(Hash256, Op<Hash256>)>,
+}
+
+pub(crate) fn snapshot_key(height: u64) -> Vec<u8> {
+    let mut key = Vec::with_capacity(PREFIX_SNAPSHOT.len() + 8);
+    key.extend_from_slice(PREFIX_SNAPSHOT);
+    key.extend_from_slice(&height.to_be_bytes());
+    key
+}
+
+pub(crate) fn chunk_key(height: u64, index: u32) -> Vec<u8> {
+    let mut key = Vec::with_capacity(PREFIX_CHUNK.len() + 12);
+    key.extend_from_slice(PREFIX_CHUNK);
+    key.extend_from_slice(&height.to_be_bytes());
+    key.extend_from_slice(&index.to_be_bytes());
+    key
+}
+
+/// Split the state storage at the given version into chunks of approximately
+/// the given size. Return the chunks, and a snapshot describing them.
+pub(crate) fn split_into_chunks(
+    db: &DiskDb,
+    version: u64,
+    chunk_size: usize,
+) -> DbResult<(Snapshot, Vec<Vec<u8>>)> {
+    let storage = db.state_storage(Some(version))?;
+    let mut chunks = vec![];
+    let mut records = Vec::<Record>::new();
+    let mut size = 0;
+
+    for (key, value) in storage.scan(None, None, Order::Ascending) {
+        // Borsh prefixes each byte vector with its length as a `u32`.
+        size += key.len() + value.len() + 8;
+        records.push((key, value));
+
+        if size >= chunk_size {
+            chunks.push(mem::take(&mut records).to_borsh_vec()?);
+            size = 0;
+        }
+    }
+
+    if !records.is_empty() {
+        chunks.push(records.to_borsh_vec()?);
+    }
+
+    let snapshot = Snapshot {
+        height: version,
+        format: SNAPSHOT_FORMAT,
+        chunk_hashes: chunks.iter().map(|chunk| chunk.hash256()).collect(),
+    };
+
+    Ok((snapshot, chunks))
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{DiskDb, SNAPSHOT_FORMAT},
+        grug_app::{ApplySnapshotChunkOutcome, Db, OfferSnapshotOutcome, PrunableDb, Snapshot},
+        grug_types::{Batch, Hash256, Op, Order, Storage},
+        temp_rocksdb::TempDataDir,
+    };
+
+    /// Write a few batches into the DB, so that the state at the latest version
+    /// has a good number of keys, some of which have been overwritten or deleted.
+    fn populate(db: &DiskDb) -> Hash256 {
+        let mut root_hash = None;
+
+        for version in 0..3_u32 {
+            let mut batch = Batch::new();
+            for i in 0..100_u32 {
+                batch.insert(
+                    format!("key-{i:03}").into_bytes(),
+                    Op::Insert(format!("value-{version}-{i}").into_bytes()),
+                );
+            }
+            for i in (0..100_u32).filter(|i| i % 7 == version) {
+                batch.insert(format!("key-{i:03}").into_bytes(), Op::Delete);
+            }
+            root_hash = db.flush_and_commit(batch).unwrap().1;
+        }
+
+        root_hash.unwrap()
+    }
+
+    /// Feed all chunks of the given snapshot from `source` into `target`.
+    fn restore(source: &DiskDb, target: &DiskDb, snapshot: &Snapshot) -> ApplySnapshotChunkOutcome {
+        let mut outcome = ApplySnapshotChunkOutcome::Abort;
+        for index in 0..snapshot.chunks().unwrap() {
+            let chunk = source
+                .load_snapshot_chunk(snapshot.height, snapshot.format, index)
+                .unwrap()
+                .unwrap();
+            outcome = target.apply_snapshot_chunk(index, chunk).unwrap();
+        }
+        outcome
+    }
+
+    #[test]
+    fn restoring_from_snapshot_works() {
+        let source_path = TempDataDir::new("_grug_disk_db_snapshot_source");
+        let source = DiskDb::open(&source_path).unwrap();
+        let root_hash = populate(&source);
+
+        // Take a snapshot with a small chunk size, so that it's split into
+        // multiple chunks.
+        let snapshot = source.create_snapshot(2, 256).unwrap().unwrap();
+        assert_eq!(snapshot.height, 2);
+        assert_eq!(snapshot.format, SNAPSHOT_FORMAT);
+        assert!(snapshot.chunks().unwrap() > 1);
+        assert_eq!(source.list_snapshots().unwrap(), vec![snapshot.clone()]);
+
+        // Restore the snapshot into an empty DB.
+        let target_path = TempDataDir::new("_grug_disk_db_snapshot_target");
+        let target = DiskDb::open(&target_path).unwrap();
+        assert_eq!(
+            target.offer_snapshot(snapshot.clone(), root_hash).unwrap(),
+            OfferSnapshotOutcome::Accept
+        );
+        assert_eq!(
+            restore(&source, &target, &snapshot),
+            ApplySnapshotChunkOutcome::Accept
+        );
+
+        // The restored DB should have the same version, root hash, and data.
+        assert_eq!(target.latest_version(), Some(2));
+        assert_eq!(target.oldest_version(), Some(2));
+        assert_eq!(target.root_hash(None).unwrap(), Some(root_hash));
+        assert!(
+            target
+                .state_storage(None)
+                .unwrap()
+                .scan(None, None, Order::Ascending)
+                .eq(source
+                    .state_storage(None)
+                    .unwrap()
+                    .scan(None, None, Order::Ascending))
+        );
+
+        // The restored DB should be able to continue from where it left off,
+        // arriving at the same root hash as the source.
+        let batch = Batch::from([
+            (b"key-000".to_vec(), Op::Delete),
+            (b"key-100".to_vec(), Op::Insert(b"hello".to_vec())),
+        ]);
+        assert_eq!(
+            source.flush_and_commit(batch.clone()).unwrap(),
+            target.flush_and_commit(batch).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejecting_bad_snapshots() {
+        let source_path = TempDataDir::new("_grug_disk_db_bad_snapshot_source");
+        let source = DiskDb::open(&source_path).unwrap();
+        let root_hash = populate(&source);
+        let snapshot = source.create_snapshot(2, 256).unwrap().unwrap();
+
+        let target_path = TempDataDir::new("_grug_disk_db_bad_snapshot_target");
+        let target = DiskDb::open(&target_path).unwrap();
+
+        // Snapshot of an unknown format should be rejected.
+        let mut bad_snapshot = snapshot.clone();
+        bad_snapshot.format = SNAPSHOT_FORMAT + 1;
+        assert_eq!(
+            target.offer_snapshot(bad_snapshot, root_hash).unwrap(),
+            OfferSnapshotOutcome::RejectFormat
+        );
+
+        // Applying a chunk without first accepting a snapshot should abort.
+        assert_eq!(
+            target.apply_snapshot_chunk(0, vec![]).unwrap(),
+            ApplySnapshotChunkOutcome::Abort
+        );
+
+        // A chunk that arrives out of order should be replaced by the expected
+        // one, even if its index is out of range.
+        target.offer_snapshot(snapshot.clone(), root_hash).unwrap();
+        assert_eq!(
+            target.apply_snapshot_chunk(1, b"garbage".to_vec()).unwrap(),
+            ApplySnapshotChunkOutcome::OutOfOrder { expected: 0 }
+        );
+        assert_eq!(
+            target.apply_snapshot_chunk(u32::MAX, vec![]).unwrap(),
+            ApplySnapshotChunkOutcome::OutOfOrder { expected: 0 }
+        );
+
+        // A chunk that doesn't match its hash should be refetched.
+        assert_eq!(
+            target.apply_snapshot_chunk(0, b"garbage".to_vec()).unwrap(),
+            ApplySnapshotChunkOutcome::Retry
+        );
+
+        // If the restored state doesn't match the trusted app hash, the
+        // snapshot should be rejected, and nothing should be written.
+        target
+            .offer_snapshot(snapshot.clone(), Hash256::ZERO)
+            .unwrap();
+        assert_eq!(
+            restore(&source, &target, &snapshot),
+            ApplySnapshotChunkOutcome::RejectSnapshot
+        );
+        assert_eq!(target.latest_version(), None);
+
+        // The data written by the rejected restoration should be discarded, so
+        // that a good snapshot can still be restored afterwards.
+        target.offer_snapshot(snapshot.clone(), root_hash).unwrap();
+        assert_eq!(
+            restore(&source, &target, &snapshot),
+            ApplySnapshotChunkOutcome::Accept
+        );
+        assert_eq!(target.root_hash(None).unwrap(), Some(root_hash));
+
+        // A DB that already has data should refuse to restore.
+        assert_eq!(
+            source.offer_snapshot(snapshot, root_hash).unwrap(),
+            OfferSnapshotOutcome::Abort
+        );
+    }
+
+    #[test]
+    fn pruning_snapshots_works() {
+        let path = TempDataDir::new("_grug_disk_db_pruning_snapshots");
+        let db = DiskDb::open(&path).unwrap();
+        populate(&db);
+
+        for version in 0..=2 {
+            db.create_snapshot(version, 256).unwrap();
+        }
+
+        db.prune_snapshots(2).unwrap();
+
+        let heights = db
+            .list_snapshots()
+            .unwrap()
+            .into_iter()
+            .map(|snapshot| snapshot.height)
+            .collect::<Vec<_>>();
+        assert_eq!(heights, [1, 2]);
+
+        // Chunks of the pruned snapshot should have been deleted as well.
+        assert!(
+            db.load_snapshot_chunk(0, SNAPSHOT_FORMAT, 0)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn snapshotting_in_background_works() {
+        let path = TempDataDir::new("_grug_disk_db_snapshotting_in_background");
+        let db = DiskDb::open(&path).unwrap();
+        populate(&db);
+
+        for version in 1..=2 {
+            db.create_snapshot_in_background(version, 256, 1).unwrap();
+            db.wait_for_snapshot().unwrap();
+        }
+
+        // Only the most recent snapshot should be kept.
+        let snapshots = db.list_snapshots().unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0], db.create_snapshot(2, 256).unwrap().unwrap());
+    }
+}
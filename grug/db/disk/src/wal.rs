@@ -0,0 +1,202 @@
+use {
+    crate::{DbResult, PendingData},
+    grug_types::{BorshDeExt, BorshSerExt, Hash256, HashExt},
+    std::{
+        fs::{self, File},
+        io::{ErrorKind, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+/// Name of the write-ahead log (WAL) file, located in the data directory.
+///
+/// Note that we must not use the `.log` extension, which RocksDB uses for its
+/// own WAL files.
+pub const WAL_FILE_NAME: &str = "pending_commit.wal";
+
+/// Return the path to the WAL file in the given data directory.
+pub(crate) fn wal_path(data_dir: &Path) -> PathBuf {
+    data_dir.join(WAL_FILE_NAME)
+}
+
+/// Durably write the data to be committed into the WAL, replacing the existing
+/// entry, if any.
+///
+/// The file consists of the SHA-256 hash of the payload followed by the payload
+/// itself, which is the Borsh-encoded pending data. The entry is first written
+/// to a temporary file, which is then atomically renamed to the WAL file, so a
+/// crash in the middle of this function never leaves a partially written entry.
+pub(crate) fn write_wal(path: &Path, pending: &PendingData) -> DbResult<()> {
+    let payload = pending.to_borsh_vec()?;
+    let tmp_path = path.with_extension("tmp");
+
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(&payload.hash256())?;
+    file.write_all(&payload)?;
+    file.sync_all()?;
+
+    fs::rename(&tmp_path, path)?;
+
+    // Sync the directory, so that the rename itself is durable.
+    if let Some(dir) = path.parent() {
+        File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// Read the entry in the WAL, if any.
+///
+/// An entry that fails the checksum is ignored. Since `DiskDb::commit` only
+/// returns after the entry has been synced to disk, such an entry can only
+/// result from a crash before the commit completed, which consensus will redo.
+pub(crate) fn read_wal(path: &Path) -> DbResult<Option<PendingData>> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    if bytes.len() < Hash256::LENGTH {
+        return Ok(None);
+    }
+
+    let (checksum, payload) = bytes.split_at(Hash256::LENGTH);
+    if checksum != &payload.hash256()[..] {
+        return Ok(None);
+    }
+
+    Ok(Some(payload.deserialize_borsh()?))
+}
+
+/// Delete the WAL file, if it exists.
+pub(crate) fn remove_wal(path: &Path) -> DbResult<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{DiskDb, DiskDbOptions},
+        grug_app::Db,
+        grug_types::{Batch, Op, Order, Storage},
+        temp_rocksdb::TempDataDir,
+    };
+
+    fn batch(version: u32) -> Batch {
+        let mut batch = Batch::new();
+        for i in 0..10_u32 {
+            batch.insert(
+                format!("key-{i}").into_bytes(),
+                Op::Insert(format!("value-{version}-{i}").into_bytes()),
+            );
+        }
+        batch.insert(format!("key-{version}").into_bytes(), Op::Delete);
+        batch
+    }
+
+    #[test]
+    fn async_commit_works() {
+        let path = TempDataDir::new("_grug_disk_db_async_commit");
+        let mut root_hashes = vec![];
+
+        {
+            let db = DiskDb::open_with_opts(&path, DiskDbOptions {
+                async_commit: true,
+                ..Default::default()
+            })
+            .unwrap();
+
+            // Commit a few batches without waiting. Each block must see the
+            // state of the previous one, whether it has been physically
+            // written or not.
+            for version in 0..5_u32 {
+                let (v, root_hash) = db.flush_and_commit(batch(version)).unwrap();
+                assert_eq!(v, version as u64);
+                assert_eq!(db.latest_version(), Some(version as u64));
+                assert_eq!(db.root_hash(None).unwrap(), root_hash);
+
+                let storage = db.state_storage(None).unwrap();
+                assert_eq!(storage.read(format!("key-{version}").as_bytes()), None);
+                assert_eq!(
+                    storage.scan(None, None, Order::Ascending).count(),
+                    9,
+                    "version {version}"
+                );
+
+                root_hashes.push(root_hash);
+            }
+
+            db.wait_for_commit().unwrap();
+
+            // Once all writes are done, the WAL is cleared.
+            assert!(read_wal(&wal_path((&path).as_ref())).unwrap().is_none());
+        }
+
+        // Reopen the DB. The data should have been persisted.
+        let db = DiskDb::open(&path).unwrap();
+        assert_eq!(db.latest_version(), Some(4));
+
+        for (version, root_hash) in root_hashes.into_iter().enumerate() {
+            assert_eq!(db.root_hash(Some(version as u64)).unwrap(), root_hash);
+        }
+
+        let storage = db.state_storage(Some(2)).unwrap();
+        assert_eq!(storage.read(b"key-3"), Some(b"value-2-3".to_vec()));
+        assert_eq!(storage.read(b"key-2"), None);
+    }
+
+    #[test]
+    fn recovering_from_wal_works() {
+        let path = TempDataDir::new("_grug_disk_db_wal_recovery");
+
+        let root_hash = {
+            let db = DiskDb::open(&path).unwrap();
+            db.flush_and_commit(batch(0)).unwrap();
+
+            // Simulate a crash after the WAL is written, but before the data
+            // is physically written.
+            let (_, root_hash) = db.flush_but_not_commit(batch(1)).unwrap();
+            let pending = db.inner.pending_data.write().unwrap().take().unwrap();
+            write_wal(&wal_path((&path).as_ref()), &pending).unwrap();
+
+            assert_eq!(db.latest_version(), Some(0));
+
+            root_hash
+        };
+
+        // Reopen the DB. The WAL should be replayed.
+        let db = DiskDb::open(&path).unwrap();
+        assert_eq!(db.latest_version(), Some(1));
+        assert_eq!(db.root_hash(None).unwrap(), root_hash);
+
+        let storage = db.state_storage(None).unwrap();
+        assert_eq!(storage.read(b"key-0"), Some(b"value-1-0".to_vec()));
+        assert_eq!(storage.read(b"key-1"), None);
+
+        assert!(read_wal(&wal_path((&path).as_ref())).unwrap().is_none());
+    }
+
+    #[test]
+    fn ignoring_corrupted_wal() {
+        let path = TempDataDir::new("_grug_disk_db_wal_corrupted");
+
+        {
+            let db = DiskDb::open(&path).unwrap();
+            db.flush_and_commit(batch(0)).unwrap();
+        }
+
+        // A torn write.
+        fs::write(wal_path((&path).as_ref()), b"garbage").unwrap();
+
+        let db = DiskDb::open(&path).unwrap();
+        assert_eq!(db.latest_version(), Some(0));
+        assert!(read_wal(&wal_path((&path).as_ref())).unwrap().is_none());
+    }
+}
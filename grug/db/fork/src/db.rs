@@ -0,0 +1,767 @@
+use {
+    crate::{DbError, DbResult},
+    futures::future::try_join_all,
+    grug_app::{CONTRACT_NAMESPACE, Db},
+    grug_jmt::verify_proof,
+    grug_types::{
+        Addr, Batch, Binary, BorshSerExt, Empty, Hash256, HashExt, Inner, Op, Order, Proof,
+        QueryClient, QueryStoreResponse, QueryWasmScanRequest, Record, Shared, Storage, concat,
+        extend_one_byte, increment_last_byte,
+    },
+    std::{
+        cmp::Ordering,
+        collections::{BTreeMap, HashMap},
+        fmt::Display,
+        future::Future,
+        iter::Peekable,
+        ops::Bound,
+        panic,
+        sync::Arc,
+        thread, vec,
+    },
+    tokio::runtime::{Handle, Runtime},
+};
+
+/// Maximum number of records to request from the remote node in each page when
+/// discovering the keys in a range. This is also the maximum number of proofs
+/// requested concurrently, i.e. the number of values fetched at a time when
+/// iterating a range.
+const SCAN_PAGE_LIMIT: u32 = 100;
+
+/// A database that forks the state of a remote node at a pinned height.
+///
+/// Upon reading a key that hasn't been written locally, the value is fetched
+/// from the remote node, along with a Merkle proof, which is verified against
+/// the root hash that the DB was created with. Verified values, including the
+/// non-existence of keys, are cached, so each key is fetched at most once.
+/// Writes are kept in memory, overlaying the remote state; the remote node is
+/// never written to.
+///
+/// This allows replaying real transactions against a copy of production state,
+/// e.g. using `grug_testing::TestSuite`, without having to sync the chain.
+///
+/// ## Limitations
+///
+/// - Since the remote state can't be iterated over without the full Merkle
+///   tree, the keys in a range are discovered using a `WasmScan` query if the
+///   range falls within a single contract's storage, or a raw store scan query
+///   otherwise, e.g. for the list of codes or contracts in the app's own state.
+///   The values are then verified like any other read, but the completeness of
+///   the list of keys can't be proven.
+/// - Ranges are iterated lazily, one page at a time, in ascending order. In
+///   descending order, since the remote node can only be scanned in ascending
+///   order, all keys in the range are discovered first, but values are still
+///   fetched one page at a time.
+/// - The DB doesn't Merklize the local writes. The root hash of each local
+///   version is instead the hash of the previous root hash and the changeset,
+///   similar to `DiskDbLite`. For the same reason, Merkle proofs aren't
+///   supported.
+/// - Versions older than the fork height aren't available.
+pub struct ForkDb<C> {
+    inner: Arc<ForkDbInner<C>>,
+}
+
+struct ForkDbInner<C> {
+    client: C,
+    /// The runtime in which queries to the remote node are run. Always `Some`,
+    /// except while the DB is being dropped.
+    runtime: Option<Runtime>,
+    fork_height: u64,
+    root_hash: Hash256,
+    remote: Shared<RemoteCache>,
+    local: Shared<LocalState>,
+}
+
+/// Data fetched from the remote node at the fork height.
+#[derive(Default)]
+struct RemoteCache {
+    /// Values that have been fetched and verified. `None` means the key has
+    /// been proven to not exist.
+    values: HashMap<Vec<u8>, Option<Vec<u8>>>,
+    /// Pages of keys that have been discovered, indexed by the contract, if the
+    /// range falls within a single contract's storage, and the bounds of the
+    /// page. The keys and bounds are relative to the contract's namespace.
+    pages: HashMap<PageRequest, Vec<Binary>>,
+}
+
+type PageRequest = (Option<Addr>, Option<Binary>, Option<Binary>);
+
+struct LocalState {
+    latest_version: u64,
+    /// Root hash of each version, starting from the fork height.
+    root_hashes: BTreeMap<u64, Hash256>,
+    /// Locally written data: key => (version => op).
+    ///
+    /// Unlike in `MemDb`, deletions must be kept as well, as they shadow the
+    /// remote state.
+    state_storage: BTreeMap<Vec<u8>, BTreeMap<u64, Op>>,
+    /// Uncommitted changes.
+    changeset: Option<ChangeSet>,
+}
+
+struct ChangeSet {
+    version: u64,
+    root_hash: Hash256,
+    batch: Batch,
+}
+
+impl<C> ForkDb<C>
+where
+    C: QueryClient<Proof = Proof>,
+    C::Error: Display + Send,
+{
+    /// Create a DB that forks the state of the remote node at the given height.
+    ///
+    /// `root_hash` is the Merkle root hash of the state at that height, against
+    /// which the data fetched from the remote node are verified. It must come
+    /// from a trusted source, e.g. the app hash in a block header signed by the
+    /// validators, as opposed to the remote node itself.
+    pub fn new(client: C, fork_height: u64, root_hash: Hash256) -> DbResult<Self> {
+        let runtime = Runtime::new()?;
+
+        Ok(Self {
+            inner: Arc::new(ForkDbInner {
+                client,
+                runtime: Some(runtime),
+                fork_height,
+                root_hash,
+                remote: Shared::new(RemoteCache::default()),
+                local: Shared::new(LocalState {
+                    latest_version: fork_height,
+                    root_hashes: BTreeMap::from([(fork_height, root_hash)]),
+                    state_storage: BTreeMap::new(),
+                    changeset: None,
+                }),
+            }),
+        })
+    }
+
+    pub fn fork_height(&self) -> u64 {
+        self.inner.fork_height
+    }
+
+    /// Read a key from the remote state, fetching and verifying it if it isn't
+    /// already cached.
+    fn fetch(&self, key: &[u8]) -> DbResult<Option<Vec<u8>>> {
+        if let Some(value) = self
+            .inner
+            .remote
+            .read_with(|remote| remote.values.get(key).cloned())
+        {
+            return Ok(value);
+        }
+
+        let res = self.query(self.inner.client.query_store(
+            key.to_vec().into(),
+            Some(self.inner.fork_height),
+            true,
+        ))?;
+
+        let value = self.verify(key, res)?;
+
+        self.inner.remote.write_with(|mut remote| {
+            remote.values.insert(key.to_vec(), value.clone());
+        });
+
+        Ok(value)
+    }
+
+    /// Fetch and verify the keys that aren't already cached, concurrently in
+    /// batches, so that reading a range doesn't take one round trip per key.
+    fn fetch_many(&self, keys: &[Vec<u8>]) -> DbResult<()> {
+        let missing = self.inner.remote.read_with(|remote| {
+            keys.iter()
+                .filter(|key| !remote.values.contains_key(*key))
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+
+        for batch in missing.chunks(SCAN_PAGE_LIMIT as usize) {
+            let responses = self.query(try_join_all(batch.iter().map(|key| {
+                self.inner.client.query_store(
+                    key.clone().into(),
+                    Some(self.inner.fork_height),
+                    true,
+                )
+            })))?;
+
+            let values = batch
+                .iter()
+                .zip(responses)
+                .map(|(key, res)| Ok((key.clone(), self.verify(key, res)?)))
+                .collect::<DbResult<Vec<_>>>()?;
+
+            self.inner.remote.write_with(|mut remote| {
+                remote.values.extend(values);
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verify the value of a key fetched from the remote node against the root
+    /// hash.
+    fn verify(&self, key: &[u8], res: QueryStoreResponse<Proof>) -> DbResult<Option<Vec<u8>>> {
+        let proof = res
+            .proof
+            .ok_or_else(|| DbError::ProofNotFound { key: key.into() })?;
+        let value = res.value.map(Binary::into_inner);
+
+        verify_proof(
+            self.inner.root_hash,
+            key.hash256(),
+            value.as_ref().map(|value| value.hash256()),
+            &proof,
+        )
+        .map_err(|reason| DbError::InvalidProof {
+            key: key.into(),
+            reason,
+        })?;
+
+        Ok(value)
+    }
+
+    /// Return a page of keys in the remote state, starting from `min`.
+    ///
+    /// If `contract` is provided, the keys are discovered with a `WasmScan`
+    /// query, and the bounds and the keys are relative to the contract's
+    /// namespace; otherwise, with a raw store scan query.
+    fn fetch_page(
+        &self,
+        contract: Option<Addr>,
+        min: Option<Binary>,
+        max: Option<Binary>,
+    ) -> DbResult<Vec<Binary>> {
+        let page_request = (contract, min, max);
+
+        if let Some(keys) = self
+            .inner
+            .remote
+            .read_with(|remote| remote.pages.get(&page_request).cloned())
+        {
+            return Ok(keys);
+        }
+
+        let (contract, min, max) = page_request.clone();
+        let height = Some(self.inner.fork_height);
+        let limit = Some(SCAN_PAGE_LIMIT);
+
+        let page = match contract {
+            Some(contract) => self
+                .query(
+                    self.inner.client.query_app(
+                        QueryWasmScanRequest {
+                            contract,
+                            min,
+                            max,
+                            limit,
+                        }
+                        .into(),
+                        height,
+                    ),
+                )?
+                .as_wasm_scan(),
+            None => self.query(self.inner.client.query_store_scan(min, max, limit, height))?,
+        };
+
+        let keys = page.into_keys().collect::<Vec<_>>();
+
+        self.inner.remote.write_with(|mut remote| {
+            remote.pages.insert(page_request, keys.clone());
+        });
+
+        Ok(keys)
+    }
+
+    /// Run a query to the remote node to completion.
+    fn query<F, T>(&self, future: F) -> DbResult<T>
+    where
+        F: Future<Output = Result<T, C::Error>> + Send,
+        T: Send,
+    {
+        let runtime = self.inner.runtime.as_ref().unwrap();
+        let run = || {
+            runtime
+                .block_on(future)
+                .map_err(|err| DbError::Remote(err.to_string()))
+        };
+
+        // A runtime can't be blocked on from within an async context, which is
+        // the case if e.g. the DB is used in a `#[tokio::test]`. In this case,
+        // do it in a separate thread.
+        if Handle::try_current().is_err() {
+            return run();
+        }
+
+        thread::scope(|scope| {
+            scope
+                .spawn(run)
+                .join()
+                .unwrap_or_else(|err| panic::resume_unwind(err))
+        })
+    }
+}
+
+impl<C> Drop for ForkDbInner<C> {
+    fn drop(&mut self) {
+        // Dropping a runtime blocks the current thread, which panics if done in
+        // an async context. Shutting it down in the background doesn't.
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl<C> Clone for ForkDb<C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<C> Db for ForkDb<C>
+where
+    C: QueryClient<Proof = Proof> + 'static,
+    C::Error: Display + Send,
+{
+    type Error = DbError;
+    // Local writes aren't Merklized, so Merkle proofs aren't supported.
+    type Proof = Empty;
+    // The fork DB doesn't utilize a state commitment storage.
+    type StateCommitment = StateStorage<C>;
+    type StateStorage = StateStorage<C>;
+
+    fn state_commitment(&self) -> Self::StateCommitment {
+        unimplemented!("`ForkDb` does not support state commitment");
+    }
+
+    fn state_storage(&self, version: Option<u64>) -> DbResult<Self::StateStorage> {
+        let latest_version = self.inner.local.read_with(|local| local.latest_version);
+        let version = version.unwrap_or(latest_version);
+
+        if version < self.inner.fork_height {
+            return Err(DbError::VersionBeforeFork {
+                fork_height: self.inner.fork_height,
+                requested: version,
+            });
+        }
+
+        if version > latest_version {
+            return Err(DbError::VersionTooNew {
+                latest_version,
+                requested: version,
+            });
+        }
+
+        Ok(StateStorage {
+            db: self.clone(),
+            version,
+        })
+    }
+
+    fn latest_version(&self) -> Option<u64> {
+        Some(self.inner.local.read_with(|local| local.latest_version))
+    }
+
+    fn root_hash(&self, version: Option<u64>) -> DbResult<Option<Hash256>> {
+        Ok(self.inner.local.read_with(|local| {
+            let version = version.unwrap_or(local.latest_version);
+            local.root_hashes.get(&version).copied()
+        }))
+    }
+
+    fn prove(&self, _key: &[u8], _version: Option<u64>) -> DbResult<Self::Proof> {
+        Err(DbError::ProofUnsupported)
+    }
+
+    fn flush_but_not_commit(&self, batch: Batch) -> DbResult<(u64, Option<Hash256>)> {
+        let (version, prev_root_hash) = self.inner.local.read_with(|local| {
+            if local.changeset.is_some() {
+                return Err(DbError::ChangeSetAlreadySet);
+            }
+
+            Ok((
+                local.latest_version + 1,
+                local.root_hashes[&local.latest_version],
+            ))
+        })?;
+
+        let root_hash = (prev_root_hash, &batch).to_borsh_vec()?.hash256();
+
+        self.inner.local.write_with(|mut local| {
+            local.changeset = Some(ChangeSet {
+                version,
+                root_hash,
+                batch,
+            });
+        });
+
+        Ok((version, Some(root_hash)))
+    }
+
+    fn commit(&self) -> DbResult<()> {
+        self.inner.local.write_with(|mut local| {
+            let changeset = local.changeset.take().ok_or(DbError::ChangeSetNotSet)?;
+
+            for (key, op) in changeset.batch {
+                local
+                    .state_storage
+                    .entry(key)
+                    .or_default()
+                    .insert(changeset.version, op);
+            }
+
+            local
+                .root_hashes
+                .insert(changeset.version, changeset.root_hash);
+            local.latest_version = changeset.version;
+
+            Ok(())
+        })
+    }
+}
+
+/// If the range `[min, max)` falls within a single contract's storage, return
+/// the contract address, and the bounds relative to the contract's namespace.
+fn contract_range(min: &[u8], max: &[u8]) -> Option<(Addr, Option<Binary>, Option<Binary>)> {
+    let prefix_len = CONTRACT_NAMESPACE.len() + Addr::LENGTH;
+
+    if min.len() < prefix_len || !min.starts_with(CONTRACT_NAMESPACE) {
+        return None;
+    }
+
+    let (prefix, min) = min.split_at(prefix_len);
+
+    let max = if let Some(max) = max.strip_prefix(prefix) {
+        Some(max.to_vec().into())
+    } else if max == increment_last_byte(prefix.to_vec()) {
+        None
+    } else {
+        return None;
+    };
+
+    let contract = Addr::from_inner(prefix[CONTRACT_NAMESPACE.len()..].try_into().ok()?);
+    let min = (!min.is_empty()).then(|| min.to_vec().into());
+
+    Some((contract, min, max))
+}
+
+// ------------------------------- state storage -------------------------------
+
+pub struct StateStorage<C> {
+    db: ForkDb<C>,
+    version: u64,
+}
+
+impl<C> Clone for StateStorage<C> {
+    fn clone(&self) -> Self {
+        Self {
+            db: self.db.clone(),
+            version: self.version,
+        }
+    }
+}
+
+impl<C> StateStorage<C>
+where
+    C: QueryClient<Proof = Proof>,
+    C::Error: Display + Send,
+{
+    /// Return the latest local write to the key as of this version, if any.
+    fn read_local(&self, key: &[u8]) -> Option<Op> {
+        self.db.inner.local.read_with(|local| {
+            local
+                .state_storage
+                .get(key)?
+                .range(..=self.version)
+                .next_back()
+                .map(|(_, op)| op.clone())
+        })
+    }
+}
+
+impl<C> Storage for StateStorage<C>
+where
+    C: QueryClient<Proof = Proof>,
+    C::Error: Display + Send,
+{
+    fn read(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.read_local(key) {
+            Some(op) => op.into_option(),
+            None => self.db.fetch(key).unwrap_or_else(|err| {
+                panic!("failed to fetch data from the remote node: {err}");
+            }),
+        }
+    }
+
+    fn scan<'a>(
+        &'a self,
+        min: Option<&[u8]>,
+        max: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        // Collect the local writes in the range. These are in memory anyway.
+        let range = (
+            min.map_or(Bound::Unbounded, Bound::Included),
+            max.map_or(Bound::Unbounded, Bound::Excluded),
+        );
+        let mut local = self.db.inner.local.read_with(|local| {
+            local
+                .state_storage
+                .range::<[u8], _>(range)
+                .filter_map(|(key, versions)| {
+                    let (_, op) = versions.range(..=self.version).next_back()?;
+                    Some((key.clone(), op.clone()))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        // The remote node can only be scanned in ascending order, so in
+        // descending order, all keys in the range must be discovered first.
+        let keys: Box<dyn Iterator<Item = Vec<u8>>> = match order {
+            Order::Ascending => Box::new(RemoteKeys::new(&self.db, min, max)),
+            Order::Descending => {
+                local.reverse();
+
+                let mut keys = RemoteKeys::new(&self.db, min, max).collect::<Vec<_>>();
+                keys.reverse();
+                Box::new(keys.into_iter())
+            },
+        };
+
+        Box::new(Overlaid {
+            remote: RemoteRecords::new(&self.db, keys).peekable(),
+            local: local.into_iter().peekable(),
+            order,
+        })
+    }
+
+    fn scan_keys<'a>(
+        &'a self,
+        min: Option<&[u8]>,
+        max: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        let iter = self.scan(min, max, order).map(|(k, _)| k);
+        Box::new(iter)
+    }
+
+    fn scan_values<'a>(
+        &'a self,
+        min: Option<&[u8]>,
+        max: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Vec<u8>> + 'a> {
+        let iter = self.scan(min, max, order).map(|(_, v)| v);
+        Box::new(iter)
+    }
+
+    fn write(&mut self, _key: &[u8], _value: &[u8]) {
+        unreachable!("write function called on read-only storage");
+    }
+
+    fn remove(&mut self, _key: &[u8]) {
+        unreachable!("write function called on read-only storage");
+    }
+
+    fn remove_range(&mut self, _min: Option<&[u8]>, _max: Option<&[u8]>) {
+        unreachable!("write function called on read-only storage");
+    }
+}
+
+/// Iterator over the keys in a range of the remote state, in ascending order,
+/// fetching one page at a time.
+struct RemoteKeys<'a, C> {
+    db: &'a ForkDb<C>,
+    /// The contract whose storage the range falls within, if any.
+    contract: Option<Addr>,
+    /// Prefix to prepend to the keys returned by the remote node.
+    namespace: Vec<u8>,
+    /// Lower bound of the next page.
+    min: Option<Binary>,
+    max: Option<Binary>,
+    page: vec::IntoIter<Binary>,
+    done: bool,
+}
+
+impl<'a, C> RemoteKeys<'a, C> {
+    fn new(db: &'a ForkDb<C>, min: Option<&[u8]>, max: Option<&[u8]>) -> Self {
+        // For a `WasmScan` query, the bounds and the returned keys are relative
+        // to the contract's namespace; for a raw store scan query, they're not.
+        let (contract, namespace, min, max) =
+            match min.zip(max).and_then(|(min, max)| contract_range(min, max)) {
+                Some((contract, min, max)) => (
+                    Some(contract),
+                    concat(CONTRACT_NAMESPACE, &contract),
+                    min,
+                    max,
+                ),
+                None => (
+                    None,
+                    vec![],
+                    min.map(|min| min.to_vec().into()),
+                    max.map(|max| max.to_vec().into()),
+                ),
+            };
+
+        Self {
+            db,
+            contract,
+            namespace,
+            min,
+            max,
+            page: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+}
+
+impl<C> Iterator for RemoteKeys<'_, C>
+where
+    C: QueryClient<Proof = Proof>,
+    C::Error: Display + Send,
+{
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(key) = self.page.next() {
+                return Some(concat(&self.namespace, &key));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let page = self
+                .db
+                .fetch_page(self.contract, self.min.clone(), self.max.clone())
+                .unwrap_or_else(|err| {
+                    panic!("failed to fetch keys from the remote node: {err}");
+                });
+
+            // A page shorter than the limit is the last one.
+            match page.last() {
+                Some(last_key) if page.len() >= SCAN_PAGE_LIMIT as usize => {
+                    self.min = Some(extend_one_byte(last_key.to_vec()).into());
+                },
+                _ => {
+                    self.done = true;
+                },
+            }
+
+            self.page = page.into_iter();
+        }
+    }
+}
+
+/// Iterator over the records of the given keys in the remote state, fetching
+/// the values one page at a time. Keys that don't exist are skipped.
+struct RemoteRecords<'a, C, I> {
+    db: &'a ForkDb<C>,
+    keys: I,
+    page: vec::IntoIter<Record>,
+}
+
+impl<'a, C, I> RemoteRecords<'a, C, I> {
+    fn new(db: &'a ForkDb<C>, keys: I) -> Self {
+        Self {
+            db,
+            keys,
+            page: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<C, I> Iterator for RemoteRecords<'_, C, I>
+where
+    C: QueryClient<Proof = Proof>,
+    C::Error: Display + Send,
+    I: Iterator<Item = Vec<u8>>,
+{
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.page.next() {
+                return Some(record);
+            }
+
+            let keys = self
+                .keys
+                .by_ref()
+                .take(SCAN_PAGE_LIMIT as usize)
+                .collect::<Vec<_>>();
+
+            if keys.is_empty() {
+                return None;
+            }
+
+            self.db.fetch_many(&keys).unwrap_or_else(|err| {
+                panic!("failed to fetch data from the remote node: {err}");
+            });
+
+            // The values have just been fetched, so these are read from the
+            // cache.
+            self.page = keys
+                .into_iter()
+                .filter_map(|key| {
+                    let value = self.db.fetch(&key).unwrap_or_else(|err| {
+                        panic!("failed to fetch data from the remote node: {err}");
+                    })?;
+                    Some((key, value))
+                })
+                .collect::<Vec<_>>()
+                .into_iter();
+        }
+    }
+}
+
+/// Iterator that merges the remote records with the local writes on top of
+/// them. Both must be sorted in the given order.
+///
+/// Similar to `grug_types::Merged`, except that it owns the local writes, as
+/// they can't be borrowed from behind the lock.
+struct Overlaid<R>
+where
+    R: Iterator<Item = Record>,
+{
+    remote: Peekable<R>,
+    local: Peekable<vec::IntoIter<(Vec<u8>, Op)>>,
+    order: Order,
+}
+
+impl<R> Iterator for Overlaid<R>
+where
+    R: Iterator<Item = Record>,
+{
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ordering = match (self.remote.peek(), self.local.peek()) {
+                (Some((remote_key, _)), Some((local_key, _))) => match self.order {
+                    Order::Ascending => remote_key.cmp(local_key),
+                    Order::Descending => remote_key.cmp(local_key).reverse(),
+                },
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => return None,
+            };
+
+            match ordering {
+                Ordering::Less => return self.remote.next(),
+                // The local write shadows the remote record.
+                Ordering::Equal => {
+                    self.remote.next();
+                },
+                Ordering::Greater => {},
+            }
+
+            if let Some((key, Op::Insert(value))) = self.local.next() {
+                return Some((key, value));
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use {
+    grug_app::AppError,
+    grug_jmt::ProofError,
+    grug_types::{Binary, StdError},
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum DbError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to query the remote node: {0}")]
+    Remote(String),
+
+    #[error("remote node didn't return a proof for key `{key}`")]
+    ProofNotFound { key: Binary },
+
+    #[error("invalid proof for key `{key}`: {reason}")]
+    InvalidProof { key: Binary, reason: ProofError },
+
+    #[error("requested version ({requested}) is older than the fork height ({fork_height})")]
+    VersionBeforeFork { fork_height: u64, requested: u64 },
+
+    #[error("requested version ({requested}) is newer than the latest version ({latest_version})")]
+    VersionTooNew { latest_version: u64, requested: u64 },
+
+    #[error("cannot flush when changeset is already set")]
+    ChangeSetAlreadySet,
+
+    #[error("cannot commit when changeset is not yet set")]
+    ChangeSetNotSet,
+
+    #[error("state proof is not supported")]
+    ProofUnsupported,
+}
+
+impl From<DbError> for AppError {
+    fn from(err: DbError) -> Self {
+        AppError::Db(err.to_string())
+    }
+}
+
+pub type DbResult<T> = core::result::Result<T, DbError>;
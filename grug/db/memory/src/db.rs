@@ -12,12 +12,14 @@ use {
 
 const MERKLE_TREE: MerkleTree = MerkleTree::new_default();
 
+#[derive(Clone)]
 struct ChangeSet {
     version: u64,
     state_commitment: Batch,
     state_storage: Batch,
 }
 
+#[derive(Clone)]
 struct MemDbInner {
     /// Version of the DB. Initilialized to `None` when the DB instance is
     /// created. Set of 0 the first time a batch of data is committed, and
@@ -50,6 +52,16 @@ impl MemDb {
         }
     }
 
+    /// Copy the DB's data into a new, independent instance.
+    ///
+    /// Unlike `clone`, which returns another handle to the same data, writes
+    /// to either instance aren't visible to the other.
+    pub fn deep_clone(&self) -> Self {
+        self.with_read(|inner| Self {
+            inner: Arc::new(RwLock::new(inner.clone())),
+        })
+    }
+
     fn with_read<C, T>(&self, callback: C) -> T
     where
         C: FnOnce(RwLockReadGuard<MemDbInner>) -> T,
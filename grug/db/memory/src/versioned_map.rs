@@ -8,6 +8,7 @@ use {
     },
 };
 
+#[derive(Clone)]
 pub struct VersionedMap<K, V> {
     // Initialized to `None`.
     // Set to 0 the first time a batch is written.
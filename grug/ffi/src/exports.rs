@@ -2,9 +2,10 @@ use {
     crate::{ExternalApi, ExternalQuerier, ExternalStorage, Region},
     grug_types::{
         AuthCtx, AuthResponse, BankMsg, BankQuery, BankQueryResponse, BorshDeExt, BorshSerExt,
-        Context, GenericResult, GenericResultExt, ImmutableCtx, Json, JsonDeExt, MutableCtx,
-        QuerierWrapper, Response, SubMsgResult, SudoCtx, Tx, TxOutcome, make_auth_ctx,
-        make_immutable_ctx, make_mutable_ctx, make_sudo_ctx, unwrap_into_generic_result,
+        CheckedContractEvent, Context, GenericResult, GenericResultExt, ImmutableCtx, Json,
+        JsonDeExt, MutableCtx, QuerierWrapper, Response, SubMsgResult, SudoCtx, Tx, TxOutcome,
+        make_auth_ctx, make_immutable_ctx, make_mutable_ctx, make_sudo_ctx,
+        unwrap_into_generic_result,
     },
     serde::de::DeserializeOwned,
     std::fmt::Display,
@@ -209,6 +210,30 @@ where
     Region::release_buffer(res_bytes) as usize
 }
 
+pub fn do_receive_event<E>(
+    receive_event_fn: &dyn Fn(SudoCtx, CheckedContractEvent) -> Result<Response, E>,
+    ctx_ptr: usize,
+    event_ptr: usize,
+) -> usize
+where
+    E: Display,
+{
+    let ctx_bytes = unsafe { Region::consume(ctx_ptr as *mut Region) };
+    let event_bytes = unsafe { Region::consume(event_ptr as *mut Region) };
+
+    let res = (|| {
+        let ctx: Context = unwrap_into_generic_result!(ctx_bytes.deserialize_borsh());
+        let ctx = make_sudo_ctx!(ctx, &mut ExternalStorage, &ExternalApi, &ExternalQuerier);
+        let event = unwrap_into_generic_result!(event_bytes.deserialize_borsh());
+
+        receive_event_fn(ctx, event).into_generic_result()
+    })();
+
+    let res_bytes = res.to_borsh_vec().unwrap();
+
+    Region::release_buffer(res_bytes) as usize
+}
+
 pub fn do_authenticate<E>(
     authenticate_fn: &dyn Fn(AuthCtx, Tx) -> Result<AuthResponse, E>,
     ctx_ptr: usize,
@@ -34,8 +34,13 @@ unsafe extern "C" {
         recovery_id: u8,
         compressed: u8,
     ) -> u64;
+    fn secp256k1_batch_verify(msg_hashes_ptr: usize, sigs_ptr: usize, pks_ptr: usize) -> u32;
+    fn secp256k1_schnorr_verify(msg_hash_ptr: usize, sig_ptr: usize, pk_ptr: usize) -> u32;
     fn ed25519_verify(msg_hash_ptr: usize, sig_ptr: usize, pk_ptr: usize) -> u32;
     fn ed25519_batch_verify(prehash_msgs_ptr: usize, sigs_ptr: usize, pks_ptr: usize) -> u32;
+    fn bls12_381_verify(msg_hash_ptr: usize, sig_ptr: usize, pk_ptr: usize) -> u32;
+    fn bls12_381_aggregate_verify(msg_hash_ptr: usize, sig_ptr: usize, pks_ptr: usize) -> u32;
+    fn bls12_381_pairing_check(g1s_ptr: usize, g2s_ptr: usize) -> u32;
 
     // Hashes
     fn sha2_256(data_ptr: usize) -> usize;
@@ -384,6 +389,56 @@ impl Api for ExternalApi {
         }
     }
 
+    fn secp256k1_batch_verify(
+        &self,
+        msg_hashes: &[&[u8]],
+        sigs: &[&[u8]],
+        pks: &[&[u8]],
+    ) -> StdResult<()> {
+        let msg_hashes = encode_sections(msg_hashes)?;
+        let msg_hashes_region = Region::build(&msg_hashes);
+        let msg_hashes_ptr = &*msg_hashes_region as *const Region;
+
+        let sigs = encode_sections(sigs)?;
+        let sigs_region = Region::build(&sigs);
+        let sigs_ptr = &*sigs_region as *const Region;
+
+        let pks = encode_sections(pks)?;
+        let pks_region = Region::build(&pks);
+        let pks_ptr = &*pks_region as *const Region;
+
+        let return_value = unsafe {
+            secp256k1_batch_verify(msg_hashes_ptr as usize, sigs_ptr as usize, pks_ptr as usize)
+        };
+
+        if return_value == 0 {
+            Ok(())
+        } else {
+            Err(VerificationError::from_error_code(return_value).into())
+        }
+    }
+
+    fn secp256k1_schnorr_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()> {
+        let msg_hash_region = Region::build(msg_hash);
+        let msg_hash_ptr = &*msg_hash_region as *const Region;
+
+        let sig_region = Region::build(sig);
+        let sig_ptr = &*sig_region as *const Region;
+
+        let pk_region = Region::build(pk);
+        let pk_ptr = &*pk_region as *const Region;
+
+        let return_value = unsafe {
+            secp256k1_schnorr_verify(msg_hash_ptr as usize, sig_ptr as usize, pk_ptr as usize)
+        };
+
+        if return_value == 0 {
+            Ok(())
+        } else {
+            Err(VerificationError::from_error_code(return_value).into())
+        }
+    }
+
     fn ed25519_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()> {
         let msg_hash_region = Region::build(msg_hash);
         let msg_hash_ptr = &*msg_hash_region as *const Region;
@@ -436,6 +491,71 @@ impl Api for ExternalApi {
             Err(VerificationError::from_error_code(return_value).into())
         }
     }
+
+    fn bls12_381_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()> {
+        let msg_hash_region = Region::build(msg_hash);
+        let msg_hash_ptr = &*msg_hash_region as *const Region;
+
+        let sig_region = Region::build(sig);
+        let sig_ptr = &*sig_region as *const Region;
+
+        let pk_region = Region::build(pk);
+        let pk_ptr = &*pk_region as *const Region;
+
+        let return_value =
+            unsafe { bls12_381_verify(msg_hash_ptr as usize, sig_ptr as usize, pk_ptr as usize) };
+
+        if return_value == 0 {
+            Ok(())
+        } else {
+            Err(VerificationError::from_error_code(return_value).into())
+        }
+    }
+
+    fn bls12_381_aggregate_verify(
+        &self,
+        msg_hash: &[u8],
+        sig: &[u8],
+        pks: &[&[u8]],
+    ) -> StdResult<()> {
+        let msg_hash_region = Region::build(msg_hash);
+        let msg_hash_ptr = &*msg_hash_region as *const Region;
+
+        let sig_region = Region::build(sig);
+        let sig_ptr = &*sig_region as *const Region;
+
+        let pks = encode_sections(pks)?;
+        let pks_region = Region::build(&pks);
+        let pks_ptr = &*pks_region as *const Region;
+
+        let return_value = unsafe {
+            bls12_381_aggregate_verify(msg_hash_ptr as usize, sig_ptr as usize, pks_ptr as usize)
+        };
+
+        if return_value == 0 {
+            Ok(())
+        } else {
+            Err(VerificationError::from_error_code(return_value).into())
+        }
+    }
+
+    fn bls12_381_pairing_check(&self, g1s: &[&[u8]], g2s: &[&[u8]]) -> StdResult<()> {
+        let g1s = encode_sections(g1s)?;
+        let g1s_region = Region::build(&g1s);
+        let g1s_ptr = &*g1s_region as *const Region;
+
+        let g2s = encode_sections(g2s)?;
+        let g2s_region = Region::build(&g2s);
+        let g2s_ptr = &*g2s_region as *const Region;
+
+        let return_value = unsafe { bls12_381_pairing_check(g1s_ptr as usize, g2s_ptr as usize) };
+
+        if return_value == 0 {
+            Ok(())
+        } else {
+            Err(VerificationError::from_error_code(return_value).into())
+        }
+    }
 }
 
 // ---------------------------------- querier ----------------------------------
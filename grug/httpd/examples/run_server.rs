@@ -67,13 +67,19 @@ impl grug_httpd::traits::QueryApp for MockGrugApp {
         _key: &[u8],
         _height: Option<u64>,
         _prove: bool,
-    ) -> grug_app::AppResult<(Option<Vec<u8>>, Option<Vec<u8>>)> {
-        Ok((Some(b"mock_value".to_vec()), None))
+    ) -> grug_app::AppResult<grug_types::QueryStoreResponse<grug_types::Binary>> {
+        Ok(grug_types::QueryStoreResponse {
+            height: 1,
+            value: Some(b"mock_value".to_vec().into()),
+            proof: None,
+            root_hash: None,
+        })
     }
 
     async fn simulate(
         &self,
         _unsigned_tx: grug_types::UnsignedTx,
+        _overrides: grug_types::StateOverrides,
     ) -> grug_app::AppResult<grug_types::TxOutcome> {
         Ok(grug_types::TxOutcome {
             gas_limit: 0,
@@ -38,7 +38,7 @@ impl GrugQuery {
         #[cfg(feature = "metrics")]
         let start = Instant::now();
 
-        let (value, proof) = app_ctx
+        let res = app_ctx
             .grug_app
             .query_store(key.inner(), height, prove)
             .await?;
@@ -46,15 +46,13 @@ impl GrugQuery {
         #[cfg(feature = "metrics")]
         histogram!("http.grug.query_store.duration").record(start.elapsed().as_secs_f64());
 
-        let value = if let Some(value) = value {
-            Binary::from(value).to_string()
-        } else {
-            return Err(Error::new(format!("Key not found: {key}")));
-        };
-
+        // If the key doesn't exist, return a null value instead of an error, as
+        // the non-membership proof may have been requested.
         Ok(Store {
-            value,
-            proof: proof.map(|proof| Binary::from(proof).to_string()),
+            height: res.height,
+            value: res.value.map(|value| value.to_string()),
+            proof: res.proof.map(|proof| proof.to_string()),
+            root_hash: res.root_hash.map(|root_hash| root_hash.to_string()),
         })
     }
 
@@ -109,9 +107,14 @@ impl GrugQuery {
         &self,
         ctx: &async_graphql::Context<'_>,
         #[graphql(desc = "Transaction as Json")] tx: grug_types::UnsignedTx,
+        #[graphql(desc = "Changes to apply to the state before simulating, as Json")]
+        overrides: Option<grug_types::StateOverrides>,
     ) -> Result<TxOutcome, Error> {
         let app_ctx = ctx.data::<crate::context::Context>()?;
 
-        Ok(app_ctx.grug_app.simulate(tx).await?)
+        Ok(app_ctx
+            .grug_app
+            .simulate(tx, overrides.unwrap_or_default())
+            .await?)
     }
 }
@@ -2,8 +2,12 @@ use async_graphql::SimpleObject;
 
 #[derive(SimpleObject)]
 pub struct Store {
-    /// The base64 encoded value
-    pub value: String,
+    /// The block height at which the query was performed
+    pub height: u64,
+    /// The base64 encoded value; null if the key doesn't exist
+    pub value: Option<String>,
     /// The base64 encoded proof
     pub proof: Option<String>,
+    /// The hex encoded Merkle root hash to verify the proof against
+    pub root_hash: Option<String>,
 }
@@ -5,7 +5,10 @@ use {
     grug_app::{
         App, AppError, AppResult, CHAIN_ID, Db, Indexer, LAST_FINALIZED_BLOCK, ProposalPreparer, Vm,
     },
-    grug_types::{BlockInfo, Query, QueryResponse, TxOutcome, UnsignedTx},
+    grug_types::{
+        Binary, BlockInfo, Query, QueryResponse, QueryStoreResponse, StateOverrides, TxOutcome,
+        UnsignedTx,
+    },
 };
 
 #[async_trait]
@@ -13,16 +16,22 @@ pub trait QueryApp {
     /// Query the app, return a JSON String.
     async fn query_app(&self, raw_req: Query, height: Option<u64>) -> AppResult<QueryResponse>;
 
-    /// Query the app's underlying key-value store, return `(value, proof)`.
+    /// Query the app's underlying key-value store, return the value, and if
+    /// requested, the Borsh-encoded Merkle proof and the root hash.
     async fn query_store(
         &self,
         key: &[u8],
         height: Option<u64>,
         prove: bool,
-    ) -> AppResult<(Option<Vec<u8>>, Option<Vec<u8>>)>;
+    ) -> AppResult<QueryStoreResponse<Binary>>;
 
-    /// Simulate a transaction.
-    async fn simulate(&self, unsigned_tx: UnsignedTx) -> AppResult<TxOutcome>;
+    /// Simulate a transaction, with the given changes applied to the state
+    /// beforehand.
+    async fn simulate(
+        &self,
+        unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> AppResult<TxOutcome>;
 
     /// Query the chain ID.
     async fn chain_id(&self) -> AppResult<String>;
@@ -49,12 +58,16 @@ where
         key: &[u8],
         height: Option<u64>,
         prove: bool,
-    ) -> AppResult<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+    ) -> AppResult<QueryStoreResponse<Binary>> {
         self.do_query_store(key, height.unwrap_or(0), prove)
     }
 
-    async fn simulate(&self, unsigned_tx: UnsignedTx) -> AppResult<TxOutcome> {
-        Ok(self.do_simulate(unsigned_tx, 0, false)?)
+    async fn simulate(
+        &self,
+        unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> AppResult<TxOutcome> {
+        self.do_simulate(unsigned_tx, overrides, 0, false)
     }
 
     async fn chain_id(&self) -> AppResult<String> {
@@ -91,12 +104,16 @@ where
         key: &[u8],
         height: Option<u64>,
         prove: bool,
-    ) -> AppResult<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+    ) -> AppResult<QueryStoreResponse<Binary>> {
         self.app.query_store(key, height, prove).await
     }
 
-    async fn simulate(&self, unsigned_tx: UnsignedTx) -> AppResult<TxOutcome> {
-        self.app.simulate(unsigned_tx).await
+    async fn simulate(
+        &self,
+        unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> AppResult<TxOutcome> {
+        self.app.simulate(unsigned_tx, overrides).await
     }
 
     async fn chain_id(&self) -> AppResult<String> {
@@ -122,12 +139,16 @@ where
         key: &[u8],
         height: Option<u64>,
         prove: bool,
-    ) -> AppResult<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+    ) -> AppResult<QueryStoreResponse<Binary>> {
         self.lock().await.query_store(key, height, prove).await
     }
 
-    async fn simulate(&self, unsigned_tx: UnsignedTx) -> AppResult<TxOutcome> {
-        self.lock().await.simulate(unsigned_tx).await
+    async fn simulate(
+        &self,
+        unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> AppResult<TxOutcome> {
+        self.lock().await.simulate(unsigned_tx, overrides).await
     }
 
     async fn chain_id(&self) -> AppResult<String> {
@@ -555,6 +555,47 @@ impl<'a> MerkleTree<'a> {
     /// Note: We must make sure `up_to_version` is smaller or equal to the
     /// latest version. We assert this in `DiskDb::prune`.
     pub fn prune(&self, storage: &mut dyn Storage, up_to_version: u64) -> StdResult<()> {
+        self.prune_orphans(storage, up_to_version, |_, _| false)
+    }
+
+    /// Delete nodes that are no longer part of the tree as of `up_to_version`,
+    /// except for those that are part of the tree at any version divisible by
+    /// `keep_every`, so that those versions can still be proven.
+    ///
+    /// The nodes that are kept are no longer tracked as orphans, so they are
+    /// never pruned afterwards.
+    pub fn prune_except_every(
+        &self,
+        storage: &mut dyn Storage,
+        up_to_version: u64,
+        keep_every: u64,
+    ) -> StdResult<()> {
+        let keep_every = keep_every.max(1);
+
+        // A node is part of the tree from the version it was created at
+        // (inclusive), until the version it was orphaned at (exclusive). Keep
+        // it if a version to be kept falls within this range.
+        self.prune_orphans(storage, up_to_version, |orphaned_since_version, version| {
+            version
+                .div_ceil(keep_every)
+                .checked_mul(keep_every)
+                .is_some_and(|first_kept_version| first_kept_version < orphaned_since_version)
+        })
+    }
+
+    /// Delete nodes that have been orphaned prior or at `up_to_version`, except
+    /// for those for which `keep` returns true, given the version it was
+    /// orphaned at and the version it was created at. Orphan records are
+    /// deleted either way.
+    fn prune_orphans<F>(
+        &self,
+        storage: &mut dyn Storage,
+        up_to_version: u64,
+        keep: F,
+    ) -> StdResult<()>
+    where
+        F: Fn(u64, u64) -> bool,
+    {
         // Find all nodes that have been orphaned prior or at the `up_to_version`.
         let orphans = self
             .orphans
@@ -567,8 +608,10 @@ impl<'a> MerkleTree<'a> {
             .collect::<StdResult<Vec<_>>>()?;
 
         // Delete the nodes
-        for (_, version, bits) in orphans {
-            self.nodes.remove(storage, (version, &bits));
+        for (orphaned_since_version, version, bits) in orphans {
+            if !keep(orphaned_since_version, version) {
+                self.nodes.remove(storage, (version, &bits));
+            }
         }
 
         // Delete the orphan records
@@ -1039,8 +1082,8 @@ mod tests {
             ));
     }
 
-    #[test]
-    fn pruning() {
+    /// Build the test case, and then delete one node in each of versions 1-4.
+    fn build_pruning_test_case() -> MockStorage {
         let (mut storage, _) = build_test_case().unwrap();
 
         // Do a few batches. For simplicity, we just delete one nodes each version.
@@ -1080,6 +1123,13 @@ mod tests {
         )
         .unwrap();
 
+        storage
+    }
+
+    #[test]
+    fn pruning() {
+        let mut storage = build_pruning_test_case();
+
         // Before doing any pruning, check nodes and orphans are correct.
         assert_tree(
             &storage,
@@ -1175,6 +1225,56 @@ mod tests {
         assert_tree(&storage, [], []);
     }
 
+    #[test]
+    fn pruning_except_every() {
+        let mut storage = build_pruning_test_case();
+
+        // Prune up to v3, keeping every 2nd version, i.e. v0 and v2. Only the
+        // nodes that are exclusively part of v1 are deleted.
+        TREE.prune_except_every(&mut storage, 3, 2).unwrap();
+        assert_tree(
+            &storage,
+            [
+                (0, ROOT_BITS),
+                (0, BitArray::from_bits(&[0])),
+                (0, BitArray::from_bits(&[1])),
+                (0, BitArray::from_bits(&[0, 1])),
+                (0, BitArray::from_bits(&[0, 1, 0])),
+                (0, BitArray::from_bits(&[0, 1, 1])),
+                (0, BitArray::from_bits(&[0, 1, 1, 0])),
+                (0, BitArray::from_bits(&[0, 1, 1, 1])),
+                (2, ROOT_BITS),
+                (2, BitArray::from_bits(&[0])),
+                (3, ROOT_BITS),
+            ],
+            [(4, 3, ROOT_BITS)],
+        );
+
+        // The kept versions can still be proven.
+        for version in [0, 2] {
+            TREE.prove(&storage, b"a".hash256(), version).unwrap();
+        }
+
+        // Prune up to v4. The root of v3 is deleted, while the kept nodes stay.
+        TREE.prune_except_every(&mut storage, 4, 2).unwrap();
+        assert_tree(
+            &storage,
+            [
+                (0, ROOT_BITS),
+                (0, BitArray::from_bits(&[0])),
+                (0, BitArray::from_bits(&[1])),
+                (0, BitArray::from_bits(&[0, 1])),
+                (0, BitArray::from_bits(&[0, 1, 0])),
+                (0, BitArray::from_bits(&[0, 1, 1])),
+                (0, BitArray::from_bits(&[0, 1, 1, 0])),
+                (0, BitArray::from_bits(&[0, 1, 1, 1])),
+                (2, ROOT_BITS),
+                (2, BitArray::from_bits(&[0])),
+            ],
+            [],
+        );
+    }
+
     #[test]
     fn no_extra_saves() {
         let mut storage = MockStorage::new();
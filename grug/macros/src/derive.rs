@@ -13,6 +13,7 @@ use {
 struct Args {
     serde: bool,
     borsh: bool,
+    schema: bool,
     query: bool,
 }
 
@@ -20,6 +21,7 @@ impl Parse for Args {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut serde = false;
         let mut borsh = false;
+        let mut schema = false;
         let mut query = false;
 
         while !input.is_empty() {
@@ -38,6 +40,12 @@ impl Parse for Args {
                 "Borsh" if !borsh => {
                     borsh = true;
                 },
+                "Schema" if schema => {
+                    return Err(input.error("don't input `Schema` attribute twice"));
+                },
+                "Schema" if !schema => {
+                    schema = true;
+                },
                 "QueryRequest" if query => {
                     return Err(input.error("don't input `QueryRequest` attribute twice"));
                 },
@@ -46,7 +54,7 @@ impl Parse for Args {
                 },
                 _ => {
                     return Err(input.error(
-                        "unsupported attribute, expecting `Serde`, `Borsh` or `QueryRequest`",
+                        "unsupported attribute, expecting `Serde`, `Borsh`, `Schema` or `QueryRequest`",
                     ));
                 },
             }
@@ -56,9 +64,14 @@ impl Parse for Args {
             }
         }
 
+        if schema && !serde {
+            return Err(input.error("`Schema` attribute requires `Serde`"));
+        }
+
         Ok(Args {
             borsh,
             serde,
+            schema,
             query,
         })
     }
@@ -117,17 +130,32 @@ pub fn process(attr: TokenStream, input: TokenStream) -> TokenStream {
         },
     };
 
-    let query_derive = if attrs.query {
+    // The schema derives are gated behind the `schemars` feature of the crate
+    // that uses the macro, such that generating schemas is opt-in.
+    let schema_derive = if attrs.schema {
         quote! {
-            #[derive(::grug::QueryRequest)]
+            #[cfg_attr(feature = "schemars", derive(::grug::__private::schemars::JsonSchema))]
+            #[cfg_attr(feature = "schemars", schemars(crate = "::grug::__private::schemars"))]
         }
     } else {
         quote! {}
     };
 
+    let query_derive = match (attrs.query, attrs.schema) {
+        (true, false) => quote! {
+            #[derive(::grug::QueryRequest)]
+        },
+        (true, true) => quote! {
+            #[derive(::grug::QueryRequest)]
+            #[cfg_attr(feature = "schemars", derive(::grug::QueryResponses))]
+        },
+        (false, _) => quote! {},
+    };
+
     match input.data {
         Data::Struct(_) | Data::Enum(_) => quote! {
             #derives
+            #schema_derive
             #query_derive
             #input
         },
@@ -3,6 +3,7 @@ mod event;
 mod export;
 mod index_list;
 mod query;
+mod query_responses;
 
 use proc_macro::TokenStream;
 
@@ -60,3 +61,16 @@ pub fn index_list(attr: TokenStream, input: TokenStream) -> TokenStream {
 pub fn derive_query(input: TokenStream) -> TokenStream {
     query::process(input)
 }
+
+/// Implement the `QueryResponses` trait for a query message, which maps each
+/// variant to the JSON schema of its return type, as specified by the
+/// `#[returns]` attribute.
+///
+/// Requires the `schemars` feature of the `grug` crate. Typically derived via
+/// `#[grug::derive(Serde, Schema, QueryRequest)]` instead of directly, in which
+/// case it's only derived if the crate using the macro enables its own
+/// `schemars` feature.
+#[proc_macro_derive(QueryResponses, attributes(returns))]
+pub fn derive_query_responses(input: TokenStream) -> TokenStream {
+    query_responses::process(input)
+}
@@ -2,7 +2,7 @@ use {
     core::panic,
     proc_macro::TokenStream,
     quote::{ToTokens, quote},
-    syn::{Data, DeriveInput, Fields, Ident, Type, parse_macro_input},
+    syn::{Data, DeriveInput, Fields, Ident, Type, Variant, parse_macro_input},
 };
 
 /// Throughout the function, we will use comments to illustrate how it works,
@@ -44,13 +44,7 @@ pub fn process(input: TokenStream) -> TokenStream {
 
         // Return type for this variant specified in the `#[return]` attribute.
         // E.g. for `Foo`, this would be `String`.
-        let return_type: Type = variant
-            .attrs
-            .iter()
-            .find(|attr| attr.path().get_ident().unwrap() == "returns")
-            .expect("returns attribute missing")
-            .parse_args()
-            .expect("only one type supported");
+        let return_type = return_type(&variant);
 
         // Iterate through fields in the query message variant.
         match variant.fields {
@@ -183,3 +177,15 @@ pub fn process(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Find the return type of a query message variant, specified in its
+/// `#[returns]` attribute.
+pub(crate) fn return_type(variant: &Variant) -> Type {
+    variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().get_ident().unwrap() == "returns")
+        .expect("returns attribute missing")
+        .parse_args()
+        .expect("only one type supported")
+}
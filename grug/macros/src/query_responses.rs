@@ -0,0 +1,73 @@
+use {
+    crate::query::return_type,
+    proc_macro::TokenStream,
+    quote::quote,
+    syn::{Data, DeriveInput, parse_macro_input},
+};
+
+/// Based on the same example as the `QueryRequest` derive macro, generates:
+///
+/// ```rust ignore
+/// impl grug::QueryResponses for QueryMsg {
+///     fn response_schemas() -> BTreeMap<String, RootSchema> {
+///         let mut schemas = BTreeMap::new();
+///         schemas.insert("foo".to_string(), schema_for!(String));
+///         schemas.insert("fuzz".to_string(), schema_for!(Addr));
+///         schemas.insert("buzz".to_string(), schema_for!(Hash256));
+///         schemas
+///     }
+/// }
+/// ```
+pub fn process(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let Data::Enum(data) = input.data else {
+        panic!("query message must be an enum")
+    };
+
+    let inserts = data.variants.iter().map(|variant| {
+        // The variant's name as it appears in JSON, which `#[grug::derive]`
+        // converts to snake_case. E.g. `Foo` => `"foo"`.
+        let key = to_snake_case(&variant.ident.to_string());
+        let return_type = return_type(variant);
+
+        quote! {
+            schemas.insert(
+                #key.to_string(),
+                ::grug::__private::schemars::schema_for!(#return_type),
+            );
+        }
+    });
+
+    quote! {
+        impl ::grug::QueryResponses for #name {
+            fn response_schemas() -> ::std::collections::BTreeMap<
+                ::std::string::String,
+                ::grug::__private::schemars::schema::RootSchema,
+            > {
+                let mut schemas = ::std::collections::BTreeMap::new();
+                #(#inserts)*
+                schemas
+            }
+        }
+    }
+    .into()
+}
+
+/// Convert a variant name from PascalCase to snake_case, the same way as
+/// `#[serde(rename_all = "snake_case")]` does.
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+
+    for (i, ch) in name.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            snake.push('_');
+        }
+
+        snake.push(ch.to_ascii_lowercase());
+    }
+
+    snake
+}
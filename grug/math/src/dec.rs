@@ -1,3 +1,9 @@
+#[cfg(feature = "schemars")]
+use schemars::{
+    JsonSchema,
+    r#gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, Schema, SchemaObject},
+};
 use {
     crate::{
         Exponentiate, FixedPoint, Int, Int128, Int256, IsZero, MathError, MathResult,
@@ -314,6 +320,29 @@ where
     }
 }
 
+#[cfg(feature = "schemars")]
+impl<U, const S: u32> JsonSchema for Dec<U, S> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "Dec".to_string()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some("A string-encoded decimal number.".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 impl<U, const S: u32> Add for Dec<U, S>
 where
     Self: Number,
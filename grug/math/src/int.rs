@@ -1,3 +1,9 @@
+#[cfg(feature = "schemars")]
+use schemars::{
+    JsonSchema,
+    r#gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, Schema, SchemaObject},
+};
 use {
     crate::{
         Integer, MathError, MathResult, NextNumber, Number, NumberConst,
@@ -135,6 +141,29 @@ where
     }
 }
 
+#[cfg(feature = "schemars")]
+impl<U> JsonSchema for Int<U> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "Int".to_string()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some("A string-encoded integer.".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 impl<U> Neg for Int<U>
 where
     U: Neg<Output = U>,
@@ -26,8 +26,16 @@ pub fn update_key(ctx: MutableCtx, new_public_key: &PublicKey) -> anyhow::Result
 pub fn authenticate(ctx: AuthCtx, tx: Tx) -> anyhow::Result<AuthResponse> {
     let public_key = PUBLIC_KEY.load(ctx.storage)?;
 
+    // The account may be authenticating the transaction either as its sender,
+    // or as its fee payer. In the latter case, use the fee payer's credential.
+    let fee_payer = tx.fee_payer.as_ref().map(|fee_payer| fee_payer.address);
+    let raw_credential = match tx.fee_payer {
+        Some(fee_payer) if fee_payer.address == ctx.contract => fee_payer.credential,
+        _ => tx.credential,
+    };
+
     // Decode the credential, which should contain the sequence and signature.
-    let credential: Credential = tx.credential.deserialize_json()?;
+    let credential: Credential = raw_credential.deserialize_json()?;
 
     // Incrementing the sequence. We expect the transaction to be signed by the
     // sequence _before_ the incrementing.
@@ -58,6 +66,7 @@ pub fn authenticate(ctx: AuthCtx, tx: Tx) -> anyhow::Result<AuthResponse> {
     // Prepare the hash that is expected to have been signed.
     let sign_doc = SignDoc {
         sender: tx.sender,
+        fee_payer,
         msgs: &tx.msgs,
         chain_id: &ctx.chain_id,
         sequence: credential.sequence,
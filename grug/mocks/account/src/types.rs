@@ -12,17 +12,19 @@ pub type Signature = ByteArray<64>;
 
 pub struct SignDoc<'a> {
     pub sender: Addr,
+    pub fee_payer: Option<Addr>,
     pub msgs: &'a [Message],
     pub chain_id: &'a str,
     pub sequence: u32,
 }
 
-// Generate the bytes that the sender of a transaction needs to sign.
+// Generate the bytes that the sender of a transaction, as well as the fee payer
+// if there is one, needs to sign.
 //
 // The bytes are defined as:
 //
 // ```plain
-// bytes := hasher(json(msgs) | sender | chain_id | sequence)
+// bytes := hasher(json(msgs) | sender | [fee_payer] | chain_id | sequence)
 // ```
 //
 // Parameters:
@@ -30,8 +32,10 @@ pub struct SignDoc<'a> {
 // - `hasher` is a hash function; this account implementation uses SHA2-256;
 // - `msgs` is the list of messages in the transaction;
 // - `sender` is a 32 bytes address of the sender;
+// - `fee_payer` is a 32 bytes address of the fee payer, omitted if the
+//   transaction doesn't have a fee payer;
 // - `chain_id` is the chain ID in UTF-8 encoding;
-// - `sequence` is the sender account's sequence in 32-bit big endian encoding.
+// - `sequence` is the signing account's sequence in 32-bit big endian encoding.
 //
 // Chain ID and sequence are included in the sign bytes, as they are necessary
 // for preventing replat attacks (e.g. user signs a transaction for chain A;
@@ -46,6 +50,9 @@ impl grug_types::SignData for SignDoc<'_> {
         // into JSON. Here we use `grug::to_json_vec` as the source of truth.
         prehash.extend(self.msgs.to_json_vec()?);
         prehash.extend(self.sender.as_ref());
+        if let Some(fee_payer) = self.fee_payer {
+            prehash.extend(fee_payer.as_ref());
+        }
         prehash.extend(self.chain_id.as_bytes());
         prehash.extend(self.sequence.to_be_bytes());
         Ok(prehash)
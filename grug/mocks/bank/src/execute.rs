@@ -107,6 +107,19 @@ pub fn transfer(storage: &mut dyn Storage, from: Addr, to: Addr, coins: &Coins)
     Ok(())
 }
 
+/// Create new tokens out of thin air and give them to an account.
+///
+/// This isn't reachable from any message. It's meant for the host to apply
+/// balance overrides when simulating transactions. See `grug_app::BalanceOverrider`.
+pub fn issue(storage: &mut dyn Storage, to: Addr, coins: &Coins) -> StdResult<()> {
+    for coin in coins {
+        increase_supply(storage, coin.denom, *coin.amount)?;
+        increase_balance(storage, to, coin.denom, *coin.amount)?;
+    }
+
+    Ok(())
+}
+
 /// Increase the total supply of a token by the given amount.
 /// Return the total supply value after the increase.
 fn increase_supply(
@@ -25,9 +25,10 @@ pub fn withhold_fee(ctx: AuthCtx, tx: Tx) -> StdResult<Response> {
     };
 
     // If the fee amount is non-zero, we force transfer the max fee amount from
-    // the sender to here (the taxman). If zero, nothing to do.
+    // the payer (the fee payer if there is one, otherwise the sender) to here
+    // (the taxman). If zero, nothing to do.
     //
-    // If the sender doesn't have enough coin balance to cover the max fee, this
+    // If the payer doesn't have enough coin balance to cover the max fee, this
     // submessage would error, causing the tx to be aborted.
     //
     // Since `withhold_fee` is called during `CheckTx`, this prevents an
@@ -38,7 +39,7 @@ pub fn withhold_fee(ctx: AuthCtx, tx: Tx) -> StdResult<Response> {
         Some(Message::execute(
             bank,
             &grug_mock_bank::ExecuteMsg::ForceTransfer {
-                from: tx.sender,
+                from: tx.payer(),
                 to: ctx.contract,
                 denom: fee_cfg.fee_denom.clone(),
                 amount: withhold_amount,
@@ -78,7 +79,7 @@ pub fn finalize_fee(ctx: AuthCtx, tx: Tx, outcome: TxOutcome) -> anyhow::Result<
             bank,
             &grug_mock_bank::ExecuteMsg::ForceTransfer {
                 from: ctx.contract,
-                to: tx.sender,
+                to: tx.payer(),
                 denom: fee_cfg.fee_denom,
                 amount: refund_amount,
             },
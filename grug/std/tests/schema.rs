@@ -0,0 +1,67 @@
+#![cfg(feature = "schemars")]
+
+use {
+    grug::{Addr, ContractSchema, Hash256, Uint128},
+    serde_json::json,
+};
+
+#[grug::derive(Serde, Schema)]
+pub struct InstantiateMsg {
+    pub owner: Addr,
+    pub limit: Option<Uint128>,
+}
+
+#[grug::derive(Serde, Schema)]
+pub enum ExecuteMsg {
+    Increment { amount: Uint128 },
+    Reset {},
+}
+
+#[grug::derive(Serde, Schema, QueryRequest)]
+pub enum QueryMsg {
+    #[returns(String)]
+    Foo { bar: u64 },
+    #[returns(Addr)]
+    Fuzz(u8),
+    #[returns(Hash256)]
+    BuzzBuzz,
+}
+
+#[test]
+fn generating_contract_schema() {
+    let schema = ContractSchema::new("counter")
+        .with_instantiate::<InstantiateMsg>()
+        .with_execute::<ExecuteMsg>()
+        .with_query::<QueryMsg>();
+
+    let schema = serde_json::to_value(schema).unwrap();
+
+    assert_eq!(schema["contract_name"], json!("counter"));
+
+    // Fields are renamed the same way as Serde does, and optional fields aren't
+    // required.
+    let instantiate = &schema["instantiate"];
+    assert_eq!(instantiate["title"], json!("InstantiateMsg"));
+    assert_eq!(instantiate["required"], json!(["owner"]));
+    assert_eq!(instantiate["properties"]["owner"]["type"], json!("string"));
+    assert_eq!(
+        instantiate["properties"]["limit"]["type"],
+        json!(["string", "null"])
+    );
+
+    // Enum variants are in snake_case.
+    let execute = &schema["execute"];
+    assert_eq!(execute["oneOf"][0]["required"], json!(["increment"]));
+    assert_eq!(execute["oneOf"][1]["required"], json!(["reset"]));
+
+    // Each query variant is mapped to the schema of its response type.
+    let responses = schema["responses"].as_object().unwrap();
+    assert_eq!(responses.keys().collect::<Vec<_>>(), [
+        "buzz_buzz",
+        "foo",
+        "fuzz"
+    ]);
+    assert_eq!(responses["foo"]["title"], json!("String"));
+    assert_eq!(responses["fuzz"]["type"], json!("string"));
+    assert_eq!(responses["buzz_buzz"]["type"], json!("string"));
+}
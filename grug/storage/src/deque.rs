@@ -0,0 +1,310 @@
+use {
+    crate::{Borsh, Codec, Item, Map},
+    grug_types::{Bound, Order, StdError, StdResult, Storage},
+};
+
+/// The position of the first element in an empty deque.
+///
+/// Positions start from the middle of the `u64` range, so that elements can
+/// be pushed to either end without wrapping around. This way, the order of the
+/// elements in storage is the same as their order in the deque.
+const START: u64 = 1 << 63;
+
+/// A double-ended queue, that allows pushing and popping elements at both ends,
+/// and accessing elements by index.
+///
+/// Internally, the positions of the front and back ends are stored in a single
+/// entry under the namespace, and each element is stored under its position in
+/// a [`Map`](crate::Map). As such, pushing or popping an element takes two
+/// writes, regardless of the size of the deque.
+pub struct Deque<'a, T, C = Borsh>
+where
+    C: Codec<T>,
+{
+    // The ends are encoded with Borsh regardless of which codec the elements use.
+    // (position of the front element, position after the back element)
+    ends: Item<'a, (u64, u64)>,
+    elements: Map<'a, u64, T, C>,
+}
+
+impl<'a, T, C> Deque<'a, T, C>
+where
+    C: Codec<T>,
+{
+    pub const fn new(namespace: &'a str) -> Self {
+        Self {
+            ends: Item::new(namespace),
+            elements: Map::new(namespace),
+        }
+    }
+
+    fn load_ends(&self, storage: &dyn Storage) -> StdResult<(u64, u64)> {
+        self.ends
+            .may_load(storage)
+            .map(|maybe_ends| maybe_ends.unwrap_or((START, START)))
+    }
+
+    fn save_ends(&self, storage: &mut dyn Storage, head: u64, tail: u64) -> StdResult<()> {
+        // Remove the entry instead of saving `(START, START)`, such that an
+        // emptied deque leaves nothing in storage.
+        if head == tail {
+            self.ends.remove(storage);
+            return Ok(());
+        }
+
+        self.ends.save(storage, &(head, tail))
+    }
+
+    pub fn len(&self, storage: &dyn Storage) -> StdResult<u64> {
+        let (head, tail) = self.load_ends(storage)?;
+        Ok(tail - head)
+    }
+
+    pub fn is_empty(&self, storage: &dyn Storage) -> bool {
+        !self.ends.exists(storage)
+    }
+
+    // ---------------------- methods for single elements ----------------------
+
+    pub fn push_back(&self, storage: &mut dyn Storage, data: &T) -> StdResult<()> {
+        let (head, tail) = self.load_ends(storage)?;
+
+        self.elements.save(storage, tail, data)?;
+        self.save_ends(storage, head, tail + 1)
+    }
+
+    pub fn push_front(&self, storage: &mut dyn Storage, data: &T) -> StdResult<()> {
+        let (head, tail) = self.load_ends(storage)?;
+
+        self.elements.save(storage, head - 1, data)?;
+        self.save_ends(storage, head - 1, tail)
+    }
+
+    pub fn pop_back(&self, storage: &mut dyn Storage) -> StdResult<Option<T>> {
+        let (head, tail) = self.load_ends(storage)?;
+
+        if head == tail {
+            return Ok(None);
+        }
+
+        let data = self.elements.take(storage, tail - 1)?;
+        self.save_ends(storage, head, tail - 1)?;
+
+        Ok(Some(data))
+    }
+
+    pub fn pop_front(&self, storage: &mut dyn Storage) -> StdResult<Option<T>> {
+        let (head, tail) = self.load_ends(storage)?;
+
+        if head == tail {
+            return Ok(None);
+        }
+
+        let data = self.elements.take(storage, head)?;
+        self.save_ends(storage, head + 1, tail)?;
+
+        Ok(Some(data))
+    }
+
+    pub fn front(&self, storage: &dyn Storage) -> StdResult<Option<T>> {
+        self.get(storage, 0)
+    }
+
+    pub fn back(&self, storage: &dyn Storage) -> StdResult<Option<T>> {
+        let (head, tail) = self.load_ends(storage)?;
+
+        if head == tail {
+            return Ok(None);
+        }
+
+        self.elements.may_load(storage, tail - 1)
+    }
+
+    /// Load the element at the given index, counting from the front.
+    pub fn get(&self, storage: &dyn Storage, index: u64) -> StdResult<Option<T>> {
+        let (head, tail) = self.load_ends(storage)?;
+
+        if index >= tail - head {
+            return Ok(None);
+        }
+
+        self.elements.may_load(storage, head + index)
+    }
+
+    /// Overwrite the element at the given index, counting from the front.
+    ///
+    /// Errors if the index is out of bounds.
+    pub fn set(&self, storage: &mut dyn Storage, index: u64, data: &T) -> StdResult<()> {
+        let (head, tail) = self.load_ends(storage)?;
+
+        if index >= tail - head {
+            return Err(StdError::out_of_range(index, ">=", tail - head));
+        }
+
+        self.elements.save(storage, head + index, data)
+    }
+
+    // --------------------------- iteration methods ---------------------------
+
+    /// Iterate the elements, along with their indexes counting from the front,
+    /// within the given bounds of indexes.
+    pub fn range<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<u64>>,
+        max: Option<Bound<u64>>,
+        order: Order,
+    ) -> StdResult<Box<dyn Iterator<Item = StdResult<(u64, T)>> + 'b>>
+    where
+        T: 'b,
+    {
+        let (head, _) = self.load_ends(storage)?;
+
+        // Indexes are converted to positions. There are no elements in storage
+        // outside the two ends, so no need to clamp the bounds.
+        let to_position = |bound| match bound {
+            Bound::Inclusive(index) => Bound::Inclusive(head.saturating_add(index)),
+            Bound::Exclusive(index) => Bound::Exclusive(head.saturating_add(index)),
+        };
+
+        let iter = self
+            .elements
+            .range(storage, min.map(to_position), max.map(to_position), order)
+            .map(move |res| res.map(|(position, data)| (position - head, data)));
+
+        Ok(Box::new(iter))
+    }
+
+    /// Iterate the elements within the given bounds of indexes.
+    pub fn values<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<u64>>,
+        max: Option<Bound<u64>>,
+        order: Order,
+    ) -> StdResult<Box<dyn Iterator<Item = StdResult<T>> + 'b>>
+    where
+        T: 'b,
+    {
+        let iter = self
+            .range(storage, min, max, order)?
+            .map(|res| res.map(|(_, data)| data));
+
+        Ok(Box::new(iter))
+    }
+
+    /// Remove all elements.
+    pub fn clear(&self, storage: &mut dyn Storage) {
+        self.elements.clear(storage, None, None);
+        self.ends.remove(storage);
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{Codec, Deque, Raw, Serde},
+        borsh::{BorshDeserialize, BorshSerialize},
+        grug_types::{Bound, MockStorage, Order, StdResult, Storage},
+        serde::{Deserialize, Serialize},
+        std::fmt::Debug,
+        test_case::test_case,
+    };
+
+    #[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, PartialEq)]
+    struct Job {
+        id: u32,
+    }
+
+    const JOBS: Deque<Job> = Deque::new("jobs");
+
+    fn all(storage: &MockStorage) -> Vec<u32> {
+        JOBS.values(storage, None, None, Order::Ascending)
+            .unwrap()
+            .map(|res| res.map(|job| job.id))
+            .collect::<StdResult<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn deque_works() {
+        let mut storage = MockStorage::new();
+
+        assert!(JOBS.is_empty(&storage));
+        assert_eq!(JOBS.pop_front(&mut storage).unwrap(), None);
+        assert_eq!(JOBS.pop_back(&mut storage).unwrap(), None);
+
+        JOBS.push_back(&mut storage, &Job { id: 2 }).unwrap();
+        JOBS.push_back(&mut storage, &Job { id: 3 }).unwrap();
+        JOBS.push_front(&mut storage, &Job { id: 1 }).unwrap();
+        JOBS.push_front(&mut storage, &Job { id: 0 }).unwrap();
+
+        assert_eq!(JOBS.len(&storage).unwrap(), 4);
+        assert_eq!(all(&storage), [0, 1, 2, 3]);
+        assert_eq!(JOBS.front(&storage).unwrap(), Some(Job { id: 0 }));
+        assert_eq!(JOBS.back(&storage).unwrap(), Some(Job { id: 3 }));
+        assert_eq!(JOBS.get(&storage, 2).unwrap(), Some(Job { id: 2 }));
+        assert_eq!(JOBS.get(&storage, 4).unwrap(), None);
+
+        JOBS.set(&mut storage, 1, &Job { id: 5 }).unwrap();
+        assert!(JOBS.set(&mut storage, 4, &Job { id: 5 }).is_err());
+
+        assert_eq!(JOBS.pop_front(&mut storage).unwrap(), Some(Job { id: 0 }));
+        assert_eq!(JOBS.pop_back(&mut storage).unwrap(), Some(Job { id: 3 }));
+        assert_eq!(all(&storage), [5, 2]);
+
+        JOBS.pop_back(&mut storage).unwrap();
+        JOBS.pop_back(&mut storage).unwrap();
+
+        // An emptied deque leaves nothing in storage.
+        assert!(JOBS.is_empty(&storage));
+        assert_eq!(storage.scan(None, None, Order::Ascending).count(), 0);
+    }
+
+    #[test]
+    fn range_works() {
+        let mut storage = MockStorage::new();
+
+        for id in 1..=5 {
+            JOBS.push_back(&mut storage, &Job { id }).unwrap();
+        }
+
+        // Indexes count from the front, so they shift after popping from the
+        // front.
+        JOBS.pop_front(&mut storage).unwrap();
+
+        let records = JOBS
+            .range(
+                &storage,
+                Some(Bound::Exclusive(0)),
+                Some(Bound::Inclusive(2)),
+                Order::Descending,
+            )
+            .unwrap()
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(records, [(2, Job { id: 4 }), (1, Job { id: 3 })]);
+
+        JOBS.clear(&mut storage);
+
+        assert!(JOBS.is_empty(&storage));
+        assert!(all(&storage).is_empty());
+    }
+
+    #[test_case(Deque::<Job, Serde>::new("serde"), Job { id: 1 }; "serde")]
+    #[test_case(Deque::<Vec<u8>, Raw>::new("raw"), b"raw".to_vec(); "raw")]
+    fn deque_works_with_codecs<T, C>(deque: Deque<T, C>, data: T)
+    where
+        T: Debug + PartialEq,
+        C: Codec<T>,
+    {
+        let mut storage = MockStorage::new();
+
+        deque.push_back(&mut storage, &data).unwrap();
+
+        assert_eq!(deque.pop_front(&mut storage).unwrap(), Some(data));
+    }
+}
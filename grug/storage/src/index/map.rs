@@ -253,7 +253,7 @@ where
         self.replace(storage, key, None, old_data.as_ref())
     }
 
-    fn replace(
+    pub(crate) fn replace(
         &'a self,
         storage: &mut dyn Storage,
         key: K,
@@ -369,6 +369,11 @@ mod tests {
             "foo__name_surname",
         ),
         id: UniqueIndex::new(|_, data| data.id, "foo", "foo__id"),
+        name_id: UniqueIndex::new(
+            |_, data| (data.name.clone(), data.id),
+            "foo",
+            "foo__name_id",
+        ),
     });
 
     #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
@@ -392,12 +397,13 @@ mod tests {
         pub name: MultiIndex<'a, (u64, u64), String, Foo>,
         pub name_surname: MultiIndex<'a, (u64, u64), (String, String), Foo>,
         pub id: UniqueIndex<'a, (u64, u64), u32, Foo>,
+        pub name_id: UniqueIndex<'a, (u64, u64), (String, u32), Foo>,
     }
 
     impl IndexList<(u64, u64), Foo> for FooIndexes<'_> {
         fn get_indexes(&self) -> Box<dyn Iterator<Item = &'_ dyn Index<(u64, u64), Foo>> + '_> {
             let v: Vec<&dyn Index<(u64, u64), Foo>> =
-                vec![&self.name, &self.id, &self.name_surname];
+                vec![&self.name, &self.id, &self.name_surname, &self.name_id];
             Box::new(v.into_iter())
         }
     }
@@ -744,6 +750,169 @@ mod tests {
             ]);
         }
     }
+
+    #[test]
+    fn multi_index_count_works() {
+        let mut storage = setup_test();
+
+        let name_surname = &FOOS.idx.name_surname;
+
+        assert_eq!(
+            name_surname
+                .sub_prefix("bar".to_string())
+                .count(&storage, None, None),
+            4
+        );
+        assert_eq!(
+            name_surname.sub_prefix("bar".to_string()).prefix_count(
+                &storage,
+                None,
+                Some(PrefixBound::Inclusive("s_bar".to_string())),
+            ),
+            3
+        );
+        assert_eq!(
+            name_surname
+                .prefix(("bar".to_string(), "s_bar".to_string()))
+                .count(&storage, Some(Bound::Exclusive((0, 1))), None),
+            2
+        );
+        assert!(
+            name_surname
+                .sub_prefix("baz".to_string())
+                .is_empty(&storage)
+        );
+
+        // The count follows writes to the indexed map.
+        FOOS.remove(&mut storage, (0, 1)).unwrap();
+
+        assert_eq!(
+            name_surname
+                .prefix(("bar".to_string(), "s_bar".to_string()))
+                .count(&storage, None, None),
+            2
+        );
+        assert!(
+            !name_surname
+                .prefix(("foo".to_string(), "s_foo".to_string()))
+                .is_empty(&storage)
+        );
+    }
+
+    #[test]
+    fn multi_index_pagination_survives_writes() {
+        let mut storage = setup_test();
+
+        let page = |storage: &MockStorage, start_after: Option<(u64, u64)>| {
+            let page = FOOS
+                .idx
+                .name
+                .prefix("bar".to_string())
+                .paginate(storage, start_after, 2, Order::Descending)
+                .unwrap();
+            let keys = page
+                .records
+                .into_iter()
+                .map(|(pk, _)| pk)
+                .collect::<Vec<_>>();
+            (keys, page.next)
+        };
+
+        assert_eq!(page(&storage, None), (vec![(1, 2), (1, 1)], Some((1, 1))));
+
+        // Remove the cursor itself, and add a record before it. The next page
+        // continues right after the cursor.
+        FOOS.remove(&mut storage, (1, 1)).unwrap();
+        FOOS.save(&mut storage, (0, 3), &Foo::new("bar", "s_bar", 106))
+            .unwrap();
+
+        assert_eq!(
+            page(&storage, Some((1, 1))),
+            (vec![(0, 3), (0, 2)], Some((0, 2)))
+        );
+        assert_eq!(page(&storage, Some((0, 2))), (vec![(0, 1)], None));
+    }
+
+    /// Unique index, where the index key is a tuple.
+    #[test]
+    fn unique_index_composite_works() {
+        let mut storage = setup_test();
+
+        let name_id = &FOOS.idx.name_id;
+
+        // Iterate the records under an index prefix.
+        {
+            let val = name_id
+                .prefix("bar".to_string())
+                .range(
+                    &storage,
+                    Some(Bound::Exclusive(101)),
+                    None,
+                    Order::Ascending,
+                )
+                .map(|res| res.map(|(id, pk, _)| (id, pk)))
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap();
+
+            assert_eq!(val, [(102, (0, 2)), (103, (1, 1)), (104, (1, 2))]);
+        }
+
+        // Iterate using a bound on the index prefix.
+        {
+            let val = name_id
+                .prefix_keys(
+                    &storage,
+                    Some(PrefixBound::Exclusive("bar".to_string())),
+                    None,
+                    Order::Ascending,
+                )
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap();
+
+            assert_eq!(val, [(("foo".to_string(), 105), (1, 3))]);
+        }
+
+        // Count the records under an index prefix.
+        {
+            assert_eq!(
+                name_id
+                    .prefix("bar".to_string())
+                    .count(&storage, None, None),
+                4
+            );
+            assert!(name_id.prefix("baz".to_string()).is_empty(&storage));
+        }
+
+        // Paginate the records under an index prefix.
+        {
+            let page = name_id
+                .prefix("bar".to_string())
+                .paginate(&storage, None, 3, Order::Ascending)
+                .unwrap();
+
+            assert_eq!(page.records.len(), 3);
+            assert_eq!(
+                page.records[2],
+                (103, (1, 1), Foo::new("bar", "s_bar", 103))
+            );
+            assert_eq!(page.next, Some(103));
+
+            // The next page survives the removal of the cursor.
+            FOOS.remove(&mut storage, (1, 1)).unwrap();
+
+            let page = name_id
+                .prefix("bar".to_string())
+                .paginate(&storage, page.next, 3, Order::Ascending)
+                .unwrap();
+
+            assert_eq!(page.records, [(
+                104,
+                (1, 2),
+                Foo::new("bar", "s_fooes", 104)
+            )]);
+            assert_eq!(page.next, None);
+        }
+    }
 }
 
 // ---------------------- tests copied over from cosmwasm ----------------------
@@ -1,8 +1,9 @@
 use {
     crate::{
-        Borsh, Codec, Index, Map, Prefix, PrefixBound, Prefixer, PrimaryKey, Set, split_first_key,
+        Borsh, Codec, Index, Map, Page, Prefix, PrefixBound, Prefixer, PrimaryKey, Set, paginate,
+        split_first_key, start_after_bounds,
     },
-    grug_types::{Bound, Empty, Order, Record, StdResult, Storage},
+    grug_types::{Bound, Empty, Order, Record, StdResult, Storage, trim},
     std::marker::PhantomData,
 };
 
@@ -522,6 +523,74 @@ where
         Box::new(iter)
     }
 
+    /// Return whether there are no records under the given index value.
+    pub fn is_empty(&self, storage: &dyn Storage) -> bool {
+        self.prefix
+            .keys_raw_no_trimmer(storage, None, None, Order::Ascending)
+            .next()
+            .is_none()
+    }
+
+    /// Return at most `limit` primary keys and values under the given index
+    /// value that come after the given key in the given order, along with the
+    /// cursor to the next page.
+    pub fn paginate(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<B>,
+        limit: u32,
+        order: Order,
+    ) -> StdResult<Page<B::Output, (PK::Output, T)>> {
+        let (min, max) = start_after_bounds(start_after, order);
+        let namespace = self.prefix.namespace();
+
+        paginate(
+            self.prefix.keys_raw_no_trimmer(storage, min, max, order),
+            limit,
+            |key| B::from_slice(&trim(namespace, key)),
+            |key| {
+                let pk_raw = self.trim_key(&key);
+                let pk = PK::from_slice(pk_raw)?;
+                let v_raw = self.primary_map.load_raw(storage, pk_raw)?;
+                let v = C::decode(&v_raw)?;
+                Ok((pk, v))
+            },
+        )
+    }
+
+    /// Count the records under the given index value within the bounds.
+    ///
+    /// Only the index set is iterated; the records aren't loaded from the
+    /// primary map. Still, every index entry within the bounds is visited, so
+    /// the cost grows with the number of records. Narrow the bounds, or use
+    /// [`paginate`](Self::paginate), where the number of records is unbounded.
+    pub fn count(
+        &self,
+        storage: &dyn Storage,
+        min: Option<Bound<B>>,
+        max: Option<Bound<B>>,
+    ) -> usize {
+        self.prefix
+            .keys_raw_no_trimmer(storage, min, max, Order::Ascending)
+            .count()
+    }
+
+    /// Count the records under the given index value within the bounds, using
+    /// IK::Suffix as Bound.
+    ///
+    /// Like [`count`](Self::count), every index entry within the bounds is
+    /// visited.
+    pub fn prefix_count(
+        &self,
+        storage: &dyn Storage,
+        min: Option<PrefixBound<B>>,
+        max: Option<PrefixBound<B>>,
+    ) -> usize {
+        self.prefix
+            .prefix_keys_raw_no_trim(storage, min, max, Order::Ascending)
+            .count()
+    }
+
     fn trim_key<'b>(&self, key: &'b [u8]) -> &'b [u8] {
         let mut key = &key[self.idx_ns + 2..];
 
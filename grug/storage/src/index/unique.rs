@@ -1,5 +1,8 @@
 use {
-    crate::{Borsh, Codec, Index, Map, PrimaryKey, Raw},
+    crate::{
+        Borsh, Codec, Index, Map, Page, Prefix, PrefixBound, PrimaryKey, Raw, paginate,
+        start_after_bounds,
+    },
     grug_types::{Bound, Order, StdError, StdResult, Storage},
 };
 
@@ -113,6 +116,17 @@ where
             })
     }
 
+    /// Iterate records under a specific index prefix value.
+    ///
+    /// E.g. If the index key is `(A, B)`, this allows you to give a value of
+    /// `A` and iterate all `B` values, along with the primary keys and values.
+    pub fn prefix(&self, idx: IK::Prefix) -> UniqueIndexPrefix<'_, IK::Suffix, PK, T, C> {
+        UniqueIndexPrefix {
+            prefix: self.index_map.prefix(idx),
+            primary_map: &self.primary_map,
+        }
+    }
+
     /// Iterate all {index, primary key, value} tuples within a bound of indexes,
     /// without deserialization.
     pub fn range_raw<'b>(
@@ -239,6 +253,76 @@ where
 
         Box::new(iter)
     }
+
+    /// Iterate all {index, primary key, value} tuples within a bound of index
+    /// prefixes.
+    pub fn prefix_range<'b>(
+        &'b self,
+        storage: &'b dyn Storage,
+        min: Option<PrefixBound<IK>>,
+        max: Option<PrefixBound<IK>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(IK::Output, PK::Output, T)>> + 'b>
+    where
+        'a: 'b,
+    {
+        let iter = self
+            .index_map
+            .prefix_range_raw(storage, min, max, order)
+            .map(|(ik_raw, pk_raw)| {
+                let ik = IK::from_slice(&ik_raw)?;
+                let pk = PK::from_slice(&pk_raw)?;
+                let v_raw = self.primary_map.may_load_raw(storage, &pk_raw).unwrap();
+                let v = C::decode(&v_raw)?;
+                Ok((ik, pk, v))
+            });
+
+        Box::new(iter)
+    }
+
+    /// Iterate all {index, primary key} tuples within a bound of index prefixes.
+    pub fn prefix_keys<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        min: Option<PrefixBound<IK>>,
+        max: Option<PrefixBound<IK>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(IK::Output, PK::Output)>> + 'b> {
+        let iter = self
+            .index_map
+            .prefix_range_raw(storage, min, max, order)
+            .map(|(ik_raw, pk_raw)| {
+                let ik = IK::from_slice(&ik_raw)?;
+                let pk = PK::from_slice(&pk_raw)?;
+                Ok((ik, pk))
+            });
+
+        Box::new(iter)
+    }
+
+    /// Iterate all {index, value} tuples within a bound of index prefixes.
+    pub fn prefix_values<'b>(
+        &'b self,
+        storage: &'b dyn Storage,
+        min: Option<PrefixBound<IK>>,
+        max: Option<PrefixBound<IK>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(IK::Output, T)>> + 'b>
+    where
+        'a: 'b,
+    {
+        let iter = self
+            .index_map
+            .prefix_range_raw(storage, min, max, order)
+            .map(|(ik_raw, pk_raw)| {
+                let ik = IK::from_slice(&ik_raw)?;
+                let v_raw = self.primary_map.may_load_raw(storage, &pk_raw).unwrap();
+                let v = C::decode(&v_raw)?;
+                Ok((ik, v))
+            });
+
+        Box::new(iter)
+    }
 }
 
 impl<PK, IK, T, C> Index<PK, T> for UniqueIndex<'_, PK, IK, T, C>
@@ -264,3 +348,166 @@ where
         self.index_map.remove(storage, idx)
     }
 }
+
+// ---------------------------------- prefix -----------------------------------
+
+pub struct UniqueIndexPrefix<'a, B, PK, T, C>
+where
+    C: Codec<T>,
+{
+    // Index suffix => _raw_ primary key
+    prefix: Prefix<B, Vec<u8>, Raw>,
+    primary_map: &'a Map<'a, PK, T, C>,
+}
+
+impl<'a, B, PK, T, C> UniqueIndexPrefix<'a, B, PK, T, C>
+where
+    B: PrimaryKey,
+    PK: PrimaryKey,
+    C: Codec<T>,
+{
+    pub fn append(self, prefix: B::Prefix) -> UniqueIndexPrefix<'a, B::Suffix, PK, T, C> {
+        UniqueIndexPrefix {
+            prefix: self.prefix.append(prefix),
+            primary_map: self.primary_map,
+        }
+    }
+
+    /// Return whether there are no records under the given index prefix value.
+    pub fn is_empty(&self, storage: &dyn Storage) -> bool {
+        self.prefix.is_empty(storage)
+    }
+
+    /// Iterate all {index suffix, primary key, value} tuples under the given
+    /// index prefix value.
+    pub fn range<'b>(
+        &'b self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<B>>,
+        max: Option<Bound<B>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(B::Output, PK::Output, T)>> + 'b>
+    where
+        'a: 'b,
+    {
+        let iter = self
+            .prefix
+            .range_raw(storage, min, max, order)
+            .map(|(ik_raw, pk_raw)| self.decode(storage, ik_raw, pk_raw));
+
+        Box::new(iter)
+    }
+
+    /// Iterate all {index suffix, primary key, value} tuples under the given
+    /// index prefix value, using B::Prefix as Bound.
+    pub fn prefix_range<'b>(
+        &'b self,
+        storage: &'b dyn Storage,
+        min: Option<PrefixBound<B>>,
+        max: Option<PrefixBound<B>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(B::Output, PK::Output, T)>> + 'b>
+    where
+        'a: 'b,
+    {
+        let iter = self
+            .prefix
+            .prefix_range_raw(storage, min, max, order)
+            .map(|(ik_raw, pk_raw)| self.decode(storage, ik_raw, pk_raw));
+
+        Box::new(iter)
+    }
+
+    /// Iterate all {index suffix, primary key} tuples under the given index
+    /// prefix value.
+    pub fn keys<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<B>>,
+        max: Option<Bound<B>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(B::Output, PK::Output)>> + 'b> {
+        let iter = self
+            .prefix
+            .range_raw(storage, min, max, order)
+            .map(|(ik_raw, pk_raw)| {
+                let ik = B::from_slice(&ik_raw)?;
+                let pk = PK::from_slice(&pk_raw)?;
+                Ok((ik, pk))
+            });
+
+        Box::new(iter)
+    }
+
+    /// Iterate all values under the given index prefix value.
+    pub fn values<'b>(
+        &'b self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<B>>,
+        max: Option<Bound<B>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<T>> + 'b>
+    where
+        'a: 'b,
+    {
+        let iter = self
+            .prefix
+            .values_raw(storage, min, max, order)
+            .map(|pk_raw| {
+                let v_raw = self.primary_map.load_raw(storage, &pk_raw)?;
+                C::decode(&v_raw)
+            });
+
+        Box::new(iter)
+    }
+
+    /// Return at most `limit` {index suffix, primary key, value} tuples under
+    /// the given index prefix value that come after the given index suffix in
+    /// the given order, along with the cursor to the next page.
+    pub fn paginate(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<B>,
+        limit: u32,
+        order: Order,
+    ) -> StdResult<Page<B::Output, (B::Output, PK::Output, T)>> {
+        let (min, max) = start_after_bounds(start_after, order);
+
+        paginate(
+            self.prefix.range_raw(storage, min, max, order),
+            limit,
+            |(ik_raw, _)| B::from_slice(ik_raw),
+            |(ik_raw, pk_raw)| self.decode(storage, ik_raw, pk_raw),
+        )
+    }
+
+    /// Count the records under the given index prefix value within the bounds.
+    ///
+    /// Only the index map is iterated; the records aren't loaded from the
+    /// primary map. Still, every index entry within the bounds is visited, so
+    /// the cost grows with the number of records. Narrow the bounds, or use
+    /// [`paginate`](Self::paginate), where the number of records is unbounded.
+    pub fn count(
+        &self,
+        storage: &dyn Storage,
+        min: Option<Bound<B>>,
+        max: Option<Bound<B>>,
+    ) -> usize {
+        self.prefix
+            .keys_raw(storage, min, max, Order::Ascending)
+            .count()
+    }
+
+    fn decode(
+        &self,
+        storage: &dyn Storage,
+        ik_raw: Vec<u8>,
+        pk_raw: Vec<u8>,
+    ) -> StdResult<(B::Output, PK::Output, T)> {
+        let ik = B::from_slice(&ik_raw)?;
+        let pk = PK::from_slice(&pk_raw)?;
+        let v_raw = self.primary_map.load_raw(storage, &pk_raw)?;
+        let v = C::decode(&v_raw)?;
+        Ok((ik, pk, v))
+    }
+}
@@ -1,18 +1,24 @@
 mod bound;
 mod codec;
 mod counter;
+mod deque;
 mod index;
 mod item;
 mod map;
+mod migration;
+mod page;
 mod path;
 mod prefix;
 mod prefixer;
 mod primary_key;
+mod priority_queue;
 mod querier;
 mod raw_key;
 mod set;
+mod snapshot;
 
 pub use {
-    bound::*, codec::*, counter::*, index::*, item::*, map::*, path::*, prefix::*, prefixer::*,
-    primary_key::*, querier::*, raw_key::*, set::*,
+    bound::*, codec::*, counter::*, deque::*, index::*, item::*, map::*, migration::*, page::*,
+    path::*, prefix::*, prefixer::*, primary_key::*, priority_queue::*, querier::*, raw_key::*,
+    set::*, snapshot::*,
 };
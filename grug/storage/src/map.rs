@@ -8,7 +8,7 @@ pub struct Map<'a, K, T, C = Borsh>
 where
     C: Codec<T>,
 {
-    namespace: &'a [u8],
+    pub(crate) namespace: &'a [u8],
     key: PhantomData<K>,
     data: PhantomData<T>,
     codec: PhantomData<C>,
@@ -315,7 +315,7 @@ mod test {
     use {
         crate::{Map, PrefixBound},
         borsh::{BorshDeserialize, BorshSerialize},
-        grug_types::{MockStorage, StdResult},
+        grug_types::{MockStorage, Order, StdResult},
     };
 
     const FOOS: Map<u64, Foo> = Map::new("foo");
@@ -359,6 +359,43 @@ mod test {
         assert_eq!(first, Foo::new("name_1", "surname_1"));
     }
 
+    #[test]
+    fn paginate_works() {
+        let storage = setup_test();
+
+        let page = FOOS
+            .no_prefix()
+            .paginate(&storage, Some(1), 2, Order::Ascending)
+            .unwrap();
+
+        assert_eq!(page.records, [
+            (2, Foo::new("name_2", "surname_2")),
+            (3, Foo::new("name_3", "surname_3")),
+        ]);
+        assert_eq!(page.next, Some(3));
+
+        // The last page has no cursor.
+        let page = FOOS
+            .no_prefix()
+            .paginate(&storage, Some(3), 2, Order::Descending)
+            .unwrap();
+
+        assert_eq!(page.records, [
+            (2, Foo::new("name_2", "surname_2")),
+            (1, Foo::new("name_1", "surname_1")),
+        ]);
+        assert_eq!(page.next, None);
+
+        // A limit larger than the number of records.
+        let page = FOOS
+            .no_prefix()
+            .paginate(&storage, None, u32::MAX, Order::Ascending)
+            .unwrap();
+
+        assert_eq!(page.records.len(), 4);
+        assert_eq!(page.next, None);
+    }
+
     #[test]
     fn range_prefix() {
         const MAP: Map<(u64, &str), String> = Map::new("foo");
@@ -0,0 +1,543 @@
+//! A toolkit for migrating the data a contract keeps in storage, when the
+//! contract is migrated to a new code hash that lays out its storage
+//! differently.
+//!
+//! The layout of a contract's storage is identified by a version, saved under
+//! [`STORAGE_VERSION`]. A new layout typically puts data under new namespaces
+//! suffixed with the version (e.g. `orders` becomes `orders_v2`), so that the
+//! old records can be read with their old types while being migrated.
+//!
+//! A contract registers the steps that migrate one version to the next in a
+//! [`Migrations`], and calls [`Migrations::migrate`] in its `migrate` entry
+//! point. Steps that would be too expensive to run in a single transaction,
+//! such as rewriting a large `IndexedMap`, can be chunked: each call migrates a
+//! limited number of records, and the rest are resumed in subsequent blocks
+//! from the contract's `cron_execute` entry point with [`Migrations::resume`].
+//!
+//! Records of a `Map` are migrated with [`migrate_records`], and those of an
+//! `IndexedMap` with [`migrate_indexed_records`], which also moves their index
+//! entries.
+
+use {
+    crate::{Codec, IndexList, IndexedMap, Item, Map, PrimaryKey},
+    borsh::{BorshDeserialize, BorshSerialize},
+    grug_types::{
+        Order, StdError, StdResult, Storage, concat, extend_one_byte, increment_last_byte,
+        nested_namespaces_with_key, trim,
+    },
+    std::collections::BTreeMap,
+};
+
+/// The version of the contract's storage layout.
+///
+/// A contract should save the version of its layout here at instantiation.
+/// Contracts that never saved a version are considered to be at version 0.
+pub const STORAGE_VERSION: Item<u32> = Item::new("storage_version");
+
+/// The migration in progress, if any. The step in progress is the one from the
+/// current storage version.
+const MIGRATION_PROGRESS: Item<MigrationProgress> = Item::new("migration_progress");
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct MigrationProgress {
+    target: u32,
+    cursor: Option<Vec<u8>>,
+}
+
+/// A migration step that migrates all data in a single call.
+pub type MigrateFn = fn(&mut dyn Storage) -> StdResult<()>;
+
+/// A migration step that migrates at most the given number of records, starting
+/// after the given cursor, in each call. Returns the cursor to continue from in
+/// the next call, or `None` if all records have been migrated.
+///
+/// The cursor is typically the raw key of the last migrated record, as
+/// returned by [`migrate_records`].
+pub type MigrateChunkFn = fn(&mut dyn Storage, Option<Vec<u8>>, u32) -> StdResult<Option<Vec<u8>>>;
+
+enum Step {
+    Full(MigrateFn),
+    Chunked(MigrateChunkFn),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationStatus {
+    /// The storage has been migrated to the version.
+    Done { version: u32 },
+    /// The storage is being migrated to the target version, and is currently
+    /// at the given version. Call `Migrations::resume` to continue.
+    InProgress { version: u32, target: u32 },
+}
+
+/// A registry of the steps that migrate the storage from one version to another.
+#[derive(Default)]
+pub struct Migrations {
+    /// From version => (to version, step)
+    steps: BTreeMap<u32, (u32, Step)>,
+}
+
+impl Migrations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_step(self, from: u32, to: u32, step: MigrateFn) -> Self {
+        self.add(from, to, Step::Full(step))
+    }
+
+    pub fn add_chunked_step(self, from: u32, to: u32, step: MigrateChunkFn) -> Self {
+        self.add(from, to, Step::Chunked(step))
+    }
+
+    fn add(mut self, from: u32, to: u32, step: Step) -> Self {
+        assert!(
+            from < to,
+            "migration step must increase the version: {from} -> {to}"
+        );
+        assert!(
+            self.steps.insert(from, (to, step)).is_none(),
+            "duplicate migration step from version {from}"
+        );
+
+        self
+    }
+
+    /// Load the current storage version.
+    pub fn version(storage: &dyn Storage) -> StdResult<u32> {
+        STORAGE_VERSION
+            .may_load(storage)
+            .map(|maybe_version| maybe_version.unwrap_or(0))
+    }
+
+    /// Error if a migration is in progress. Contracts should call this before
+    /// handling messages that access migrated data.
+    pub fn ensure_done(storage: &dyn Storage) -> StdResult<()> {
+        if let Some(progress) = MIGRATION_PROGRESS.may_load(storage)? {
+            let version = Self::version(storage)?;
+            return Err(StdError::migration_in_progress(version, progress.target));
+        }
+
+        Ok(())
+    }
+
+    /// Start migrating the storage to the target version, and run as many steps
+    /// as possible, with chunked steps migrating at most `limit` records.
+    ///
+    /// Errors if another migration is in progress, if there isn't a chain of
+    /// steps from the current version to the target, or if the limit is zero.
+    pub fn migrate(
+        &self,
+        storage: &mut dyn Storage,
+        target: u32,
+        limit: u32,
+    ) -> StdResult<MigrationStatus> {
+        ensure_nonzero_limit(limit)?;
+        Self::ensure_done(storage)?;
+
+        let version = Self::version(storage)?;
+
+        // Make sure the target can be reached before migrating anything.
+        let mut next = version;
+        while next < target {
+            match self.steps.get(&next) {
+                Some((to, _)) if *to <= target => next = *to,
+                _ => return Err(StdError::no_migration_path(version, target)),
+            }
+        }
+
+        if next != target {
+            return Err(StdError::no_migration_path(version, target));
+        }
+
+        MIGRATION_PROGRESS.save(storage, &MigrationProgress {
+            target,
+            cursor: None,
+        })?;
+
+        self.resume(storage, limit)
+    }
+
+    /// Continue the migration in progress, if any, with chunked steps migrating
+    /// at most `limit` records.
+    ///
+    /// Errors if the limit is zero.
+    pub fn resume(&self, storage: &mut dyn Storage, limit: u32) -> StdResult<MigrationStatus> {
+        ensure_nonzero_limit(limit)?;
+
+        let mut version = Self::version(storage)?;
+
+        let Some(mut progress) = MIGRATION_PROGRESS.may_load(storage)? else {
+            return Ok(MigrationStatus::Done { version });
+        };
+
+        while version < progress.target {
+            let Some((to, step)) = self.steps.get(&version) else {
+                return Err(StdError::no_migration_path(version, progress.target));
+            };
+
+            match step {
+                Step::Full(step) => {
+                    step(storage)?;
+                },
+                Step::Chunked(step) => {
+                    if let Some(cursor) = step(storage, progress.cursor.take(), limit)? {
+                        progress.cursor = Some(cursor);
+                        MIGRATION_PROGRESS.save(storage, &progress)?;
+
+                        return Ok(MigrationStatus::InProgress {
+                            version,
+                            target: progress.target,
+                        });
+                    }
+                },
+            }
+
+            version = *to;
+            STORAGE_VERSION.save(storage, &version)?;
+        }
+
+        MIGRATION_PROGRESS.remove(storage);
+
+        Ok(MigrationStatus::Done { version })
+    }
+}
+
+/// A chunked step can't make progress with a zero limit, and would otherwise
+/// be mistaken for being done.
+fn ensure_nonzero_limit(limit: u32) -> StdResult<()> {
+    if limit == 0 {
+        return Err(StdError::out_of_range(limit, "==", 0));
+    }
+
+    Ok(())
+}
+
+/// Apply the action to at most `limit` records of the map, starting after the
+/// cursor. Return the raw key of the last record as the cursor to continue
+/// from, or `None` if there are no more records.
+///
+/// Errors if the limit is zero.
+///
+/// Meant to be used in chunked migration steps. The action may freely write to
+/// the map, e.g. to overwrite the record in a new format, or to remove it.
+pub fn migrate_records<K, T, C, F>(
+    storage: &mut dyn Storage,
+    map: &Map<K, T, C>,
+    cursor: Option<Vec<u8>>,
+    limit: u32,
+    mut action: F,
+) -> StdResult<Option<Vec<u8>>>
+where
+    K: PrimaryKey,
+    C: Codec<T>,
+    F: FnMut(&mut dyn Storage, K::Output, T) -> StdResult<()>,
+{
+    ensure_nonzero_limit(limit)?;
+
+    let namespace = nested_namespaces_with_key(Some(map.namespace), &[] as &[&[u8]], None::<&[u8]>);
+    let min = match cursor {
+        Some(cursor) => concat(&namespace, &extend_one_byte(cursor)),
+        None => namespace.clone(),
+    };
+    let max = increment_last_byte(namespace.clone());
+
+    // Collect the records first, as the action may write to the storage.
+    let records = storage
+        .scan(Some(&min), Some(&max), Order::Ascending)
+        .take(limit as usize)
+        .map(|(key, value)| (trim(&namespace, &key), value))
+        .collect::<Vec<_>>();
+
+    let next_cursor = if records.len() < limit as usize {
+        None
+    } else {
+        records.last().map(|(key_raw, _)| key_raw.clone())
+    };
+
+    for (key_raw, value_raw) in records {
+        let key = K::from_slice(&key_raw)?;
+        let value = C::decode(&value_raw)?;
+
+        action(storage, key, value)?;
+    }
+
+    Ok(next_cursor)
+}
+
+/// Move at most `limit` records from one indexed map to another, starting
+/// after the cursor, converting each with the given function. Return the raw
+/// key of the last record as the cursor to continue from, or `None` if there
+/// are no more records.
+///
+/// Errors if the limit is zero.
+///
+/// Each record is removed from the old map along with its index entries, and
+/// the converted record is saved to the new map, which indexes it. Records the
+/// function returns `None` for are dropped. The two maps may share namespaces,
+/// e.g. to rebuild the indexes in place after the record type has changed.
+pub fn migrate_indexed_records<'a, K, T, U, I, J, C, D, F>(
+    storage: &mut dyn Storage,
+    from: &'a IndexedMap<'a, K, T, I, C>,
+    to: &'a IndexedMap<'a, K, U, J, D>,
+    cursor: Option<Vec<u8>>,
+    limit: u32,
+    mut convert: F,
+) -> StdResult<Option<Vec<u8>>>
+where
+    K: PrimaryKey<Output = K> + Clone,
+    I: IndexList<K, T>,
+    J: IndexList<K, U>,
+    C: Codec<T>,
+    D: Codec<U>,
+    F: FnMut(&K, T) -> StdResult<Option<U>>,
+{
+    migrate_records(
+        storage,
+        &from.primary,
+        cursor,
+        limit,
+        |storage, key, value| {
+            from.replace(storage, key.clone(), None, Some(&value))?;
+
+            if let Some(value) = convert(&key, value)? {
+                to.save(storage, key, &value)?;
+            }
+
+            Ok(())
+        },
+    )
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Index, UniqueIndex},
+        grug_types::{MockStorage, StdResult},
+    };
+
+    const NAMES_V1: Map<u32, String> = Map::new("names");
+    const NAMES_V2: Map<u32, (String, u32)> = Map::new("names_v2");
+    const COUNT: Item<u32> = Item::new("count");
+
+    const USERS_V1: IndexedMap<u32, String, UsersV1Indexes> =
+        IndexedMap::new("users", UsersV1Indexes {
+            name: UniqueIndex::new(|_, name| name.clone(), "users", "users__name"),
+        });
+
+    const USERS_V2: IndexedMap<u32, (String, u32), UsersV2Indexes> =
+        IndexedMap::new("users_v2", UsersV2Indexes {
+            name: UniqueIndex::new(|_, (name, _)| name.clone(), "users_v2", "users_v2__name"),
+        });
+
+    struct UsersV1Indexes<'a> {
+        pub name: UniqueIndex<'a, u32, String, String>,
+    }
+
+    impl IndexList<u32, String> for UsersV1Indexes<'_> {
+        fn get_indexes(&self) -> Box<dyn Iterator<Item = &'_ dyn Index<u32, String>> + '_> {
+            let v: Vec<&dyn Index<u32, String>> = vec![&self.name];
+            Box::new(v.into_iter())
+        }
+    }
+
+    struct UsersV2Indexes<'a> {
+        pub name: UniqueIndex<'a, u32, String, (String, u32)>,
+    }
+
+    impl IndexList<u32, (String, u32)> for UsersV2Indexes<'_> {
+        fn get_indexes(&self) -> Box<dyn Iterator<Item = &'_ dyn Index<u32, (String, u32)>> + '_> {
+            let v: Vec<&dyn Index<u32, (String, u32)>> = vec![&self.name];
+            Box::new(v.into_iter())
+        }
+    }
+
+    fn v1_to_v2(
+        storage: &mut dyn Storage,
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> StdResult<Option<Vec<u8>>> {
+        migrate_records(storage, &NAMES_V1, cursor, limit, |storage, id, name| {
+            NAMES_V1.remove(storage, id);
+            NAMES_V2.save(storage, id, &(name, 0))
+        })
+    }
+
+    fn v2_to_v3(storage: &mut dyn Storage) -> StdResult<()> {
+        let count = NAMES_V2.keys(storage, None, None, Order::Ascending).count();
+        COUNT.save(storage, &(count as u32))
+    }
+
+    fn migrations() -> Migrations {
+        Migrations::new()
+            .add_chunked_step(1, 2, v1_to_v2)
+            .add_step(2, 3, v2_to_v3)
+    }
+
+    fn setup_test() -> MockStorage {
+        let mut storage = MockStorage::new();
+
+        STORAGE_VERSION.save(&mut storage, &1).unwrap();
+
+        for id in 1..=5 {
+            NAMES_V1
+                .save(&mut storage, id, &format!("name_{id}"))
+                .unwrap();
+        }
+
+        storage
+    }
+
+    #[test]
+    fn chunked_migration_works() {
+        let mut storage = setup_test();
+        let migrations = migrations();
+
+        assert_eq!(
+            migrations.migrate(&mut storage, 3, 2).unwrap(),
+            MigrationStatus::InProgress {
+                version: 1,
+                target: 3
+            }
+        );
+        assert!(matches!(
+            Migrations::ensure_done(&storage),
+            Err(StdError::MigrationInProgress {
+                version: 1,
+                target: 3
+            })
+        ));
+
+        // Another migration can't be started before this one is done.
+        assert!(migrations.migrate(&mut storage, 3, 2).is_err());
+
+        assert_eq!(
+            migrations.resume(&mut storage, 2).unwrap(),
+            MigrationStatus::InProgress {
+                version: 1,
+                target: 3
+            }
+        );
+        assert_eq!(
+            migrations.resume(&mut storage, 2).unwrap(),
+            MigrationStatus::Done { version: 3 }
+        );
+
+        Migrations::ensure_done(&storage).unwrap();
+
+        assert_eq!(Migrations::version(&storage).unwrap(), 3);
+        assert!(NAMES_V1.is_empty(&storage));
+        assert_eq!(
+            NAMES_V2.load(&storage, 5).unwrap(),
+            ("name_5".to_string(), 0)
+        );
+        assert_eq!(COUNT.load(&storage).unwrap(), 5);
+
+        // Resuming after the migration is done is a no-op.
+        assert_eq!(
+            migrations.resume(&mut storage, 2).unwrap(),
+            MigrationStatus::Done { version: 3 }
+        );
+    }
+
+    #[test]
+    fn migrating_without_path_fails() {
+        let mut storage = setup_test();
+        let migrations = migrations();
+
+        for target in [0, 4] {
+            assert!(matches!(
+                migrations.migrate(&mut storage, target, 10),
+                Err(StdError::NoMigrationPath { from: 1, to }) if to == target
+            ));
+        }
+
+        // A zero limit would never make progress.
+        for status in [
+            migrations.migrate(&mut storage, 3, 0),
+            migrations.resume(&mut storage, 0),
+        ] {
+            assert!(matches!(status, Err(StdError::OutOfRange { .. })));
+        }
+
+        // The storage wasn't touched.
+        assert_eq!(Migrations::version(&storage).unwrap(), 1);
+        assert_eq!(
+            NAMES_V1
+                .keys(&storage, None, None, Order::Ascending)
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn migrating_indexed_records_works() {
+        let mut storage = MockStorage::new();
+
+        for id in 1..=5 {
+            USERS_V1
+                .save(&mut storage, id, &format!("name_{id}"))
+                .unwrap();
+        }
+
+        // Migrate two records at a time, dropping one of them.
+        let mut cursor = None;
+        let mut calls = 0;
+        loop {
+            cursor = migrate_indexed_records(
+                &mut storage,
+                &USERS_V1,
+                &USERS_V2,
+                cursor,
+                2,
+                |id, name| Ok((*id != 3).then_some((name, id * 10))),
+            )
+            .unwrap();
+            calls += 1;
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(calls, 3);
+
+        // The old records and their index entries are gone.
+        assert!(USERS_V1.is_empty(&storage));
+        assert_eq!(
+            USERS_V1
+                .idx
+                .name
+                .may_load_key(&storage, "name_1".to_string())
+                .unwrap(),
+            None
+        );
+
+        // The new records are indexed.
+        assert_eq!(
+            USERS_V2
+                .idx
+                .name
+                .load(&storage, "name_2".to_string())
+                .unwrap(),
+            (2, ("name_2".to_string(), 20))
+        );
+        assert_eq!(
+            USERS_V2
+                .idx
+                .name
+                .may_load_key(&storage, "name_3".to_string())
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            USERS_V2
+                .keys(&storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap(),
+            vec![1, 2, 4, 5]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use grug_types::{Bound, Order, StdResult};
+
+/// A page of records, returned by the `paginate` methods of prefixes and
+/// indexes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<K, R> {
+    /// The records in this page.
+    pub records: Vec<R>,
+    /// The key of the last record in this page, to be provided as `start_after`
+    /// to get the next page. `None` if this is the last page.
+    ///
+    /// As the cursor is a key rather than a position, the next page continues
+    /// right after it even if records are saved or removed in between,
+    /// including the record at the cursor itself.
+    pub next: Option<K>,
+}
+
+/// Return the iteration bounds that start after the cursor, in the given order.
+pub(crate) fn start_after_bounds<K>(
+    start_after: Option<K>,
+    order: Order,
+) -> (Option<Bound<K>>, Option<Bound<K>>) {
+    match order {
+        Order::Ascending => (start_after.map(Bound::Exclusive), None),
+        Order::Descending => (None, start_after.map(Bound::Exclusive)),
+    }
+}
+
+/// Take at most `limit` items from the iterator as a page.
+///
+/// One item more than the limit is read from the iterator, to tell whether
+/// there is a next page, but isn't decoded.
+pub(crate) fn paginate<I, K, R, F, G>(
+    iter: I,
+    limit: u32,
+    decode_cursor: F,
+    decode_record: G,
+) -> StdResult<Page<K, R>>
+where
+    I: Iterator,
+    F: FnOnce(&I::Item) -> StdResult<K>,
+    G: FnMut(I::Item) -> StdResult<R>,
+{
+    let limit = limit as usize;
+    let mut items = iter.take(limit.saturating_add(1)).collect::<Vec<_>>();
+
+    let next = if items.len() > limit {
+        items.truncate(limit);
+        items.last().map(decode_cursor).transpose()?
+    } else {
+        None
+    };
+
+    let records = items
+        .into_iter()
+        .map(decode_record)
+        .collect::<StdResult<_>>()?;
+
+    Ok(Page { records, next })
+}
@@ -1,5 +1,8 @@
 use {
-    crate::{Codec, PrefixBound, Prefixer, PrimaryKey, RawBound, RawKey},
+    crate::{
+        Codec, Page, PrefixBound, Prefixer, PrimaryKey, RawBound, RawKey, paginate,
+        start_after_bounds,
+    },
     grug_types::{
         Bound, Order, Record, StdResult, Storage, concat, encode_length, extend_one_byte,
         increment_last_byte, nested_namespaces_with_key, trim,
@@ -54,6 +57,13 @@ where
         }
     }
 
+    /// The raw bytes under which the records of this prefix are stored.
+    ///
+    /// This is used internally for the indexed map.
+    pub(crate) fn namespace(&self) -> &[u8] {
+        &self.namespace
+    }
+
     pub fn is_empty(&self, storage: &dyn Storage) -> bool {
         self.keys_raw(storage, None, None, Order::Ascending)
             .next()
@@ -182,6 +192,29 @@ where
         Box::new(iter)
     }
 
+    /// Return at most `limit` records that come after the given key in the
+    /// given order, along with the cursor to the next page.
+    pub fn paginate(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<K>,
+        limit: u32,
+        order: Order,
+    ) -> StdResult<Page<K::Output, (K::Output, T)>> {
+        let (min, max) = start_after_bounds(start_after, order);
+
+        paginate(
+            self.range_raw(storage, min, max, order),
+            limit,
+            |(key_raw, _)| K::from_slice(key_raw),
+            |(key_raw, value_raw)| {
+                let key = K::from_slice(&key_raw)?;
+                let value = C::decode(&value_raw)?;
+                Ok((key, value))
+            },
+        )
+    }
+
     // TODO: this isn't very optimized because we can `range_bounds` function
     // twice, once in `self.range`, once in `self.clear`. Optimize this to only
     // call it once.
@@ -0,0 +1,287 @@
+use {
+    crate::{Borsh, Codec, Counter, Map, PrefixBound, PrimaryKey, RawKey},
+    grug_types::{
+        Bound, Order, StdResult, Storage, concat, increment_last_byte, nested_namespaces_with_key,
+        trim,
+    },
+};
+
+/// A queue where each element is pushed with a priority, and can be popped in
+/// ascending or descending order of priorities. Elements of the same priority
+/// are popped in the order they were pushed.
+///
+/// Internally, each element is stored in a [`Map`](crate::Map) under the key
+/// `(priority, sequence)`, where the sequence is a counter stored under the
+/// namespace. Priorities are ordered the same way as the keys of a `Map` are,
+/// so fixed-length types such as numbers are the best suited.
+pub struct PriorityQueue<'a, K, T, C = Borsh>
+where
+    C: Codec<T>,
+{
+    namespace: &'a [u8],
+    // The sequence is encoded with Borsh regardless of which codec the elements use.
+    sequence: Counter<'a, u64>,
+    elements: Map<'a, (K, u64), T, C>,
+}
+
+impl<'a, K, T, C> PriorityQueue<'a, K, T, C>
+where
+    C: Codec<T>,
+{
+    pub const fn new(namespace: &'a str) -> Self {
+        Self {
+            namespace: namespace.as_bytes(),
+            sequence: Counter::new(namespace, 0, 1),
+            elements: Map::new(namespace),
+        }
+    }
+}
+
+impl<K, T, C> PriorityQueue<'_, K, T, C>
+where
+    K: PrimaryKey,
+    C: Codec<T>,
+{
+    pub fn is_empty(&self, storage: &dyn Storage) -> bool {
+        self.elements.is_empty(storage)
+    }
+
+    pub fn push(&self, storage: &mut dyn Storage, priority: K, data: &T) -> StdResult<()> {
+        let (sequence, _) = self.sequence.increment(storage)?;
+
+        self.elements.save(storage, (priority, sequence), data)
+    }
+
+    /// Load the element of the lowest priority without removing it.
+    pub fn peek_min(&self, storage: &dyn Storage) -> StdResult<Option<(K::Output, T)>> {
+        self.peek(storage, Order::Ascending)
+    }
+
+    /// Load the element of the highest priority without removing it.
+    pub fn peek_max(&self, storage: &dyn Storage) -> StdResult<Option<(K::Output, T)>> {
+        self.peek(storage, Order::Descending)
+    }
+
+    /// Remove and return the element of the lowest priority.
+    pub fn pop_min(&self, storage: &mut dyn Storage) -> StdResult<Option<(K::Output, T)>> {
+        self.pop(storage, Order::Ascending)
+    }
+
+    /// Remove and return the element of the highest priority.
+    pub fn pop_max(&self, storage: &mut dyn Storage) -> StdResult<Option<(K::Output, T)>> {
+        self.pop(storage, Order::Descending)
+    }
+
+    fn peek(&self, storage: &dyn Storage, order: Order) -> StdResult<Option<(K::Output, T)>> {
+        let Some(key_raw) = self.first_key_raw(storage, order) else {
+            return Ok(None);
+        };
+
+        let (priority, _) = <(K, u64)>::from_slice(&key_raw)?;
+        let data = self.elements.path_raw(&key_raw).load(storage)?;
+
+        Ok(Some((priority, data)))
+    }
+
+    fn pop(&self, storage: &mut dyn Storage, order: Order) -> StdResult<Option<(K::Output, T)>> {
+        let Some(key_raw) = self.first_key_raw(storage, order) else {
+            return Ok(None);
+        };
+
+        let (priority, _) = <(K, u64)>::from_slice(&key_raw)?;
+        let data = self.elements.path_raw(&key_raw).take(storage)?;
+
+        Ok(Some((priority, data)))
+    }
+
+    /// Find the raw key of the element of the lowest or highest priority, that
+    /// was pushed the earliest among elements of the same priority.
+    fn first_key_raw(&self, storage: &dyn Storage, order: Order) -> Option<Vec<u8>> {
+        let key_raw = self.elements.keys_raw(storage, None, None, order).next()?;
+
+        if order == Order::Ascending {
+            return Some(key_raw);
+        }
+
+        // In descending order, this is the element of the highest priority that
+        // was pushed the latest. Find the earliest one of the same priority by
+        // scanning the priority in ascending order. The priority is everything
+        // but the sequence, which is a fixed-length `u64` at the end.
+        let namespace = nested_namespaces_with_key(
+            Some(self.namespace),
+            &[] as &[RawKey],
+            Option::<RawKey>::None,
+        );
+        let min = concat(&namespace, &key_raw[..key_raw.len() - 8]);
+        let max = increment_last_byte(min.clone());
+
+        storage
+            .scan(Some(&min), Some(&max), Order::Ascending)
+            .next()
+            .map(|(key, _)| trim(&namespace, &key))
+    }
+
+    // --------------------------- iteration methods ---------------------------
+
+    /// Iterate the elements, along with their priorities, within the given
+    /// bounds of priorities.
+    ///
+    /// Elements of the same priority are iterated in the order they were pushed
+    /// if ascending, or the reverse if descending.
+    pub fn range<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<K>>,
+        max: Option<Bound<K>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'b>
+    where
+        K::Output: 'b,
+        T: 'b,
+    {
+        let iter = self
+            .elements
+            .prefix_range(
+                storage,
+                min.map(to_prefix_bound),
+                max.map(to_prefix_bound),
+                order,
+            )
+            .map(|res| res.map(|((priority, _), data)| (priority, data)));
+
+        Box::new(iter)
+    }
+
+    /// Remove all elements.
+    pub fn clear(&self, storage: &mut dyn Storage) {
+        self.elements.clear(storage, None, None);
+        self.sequence.reset(storage);
+    }
+}
+
+fn to_prefix_bound<K>(bound: Bound<K>) -> PrefixBound<(K, u64)>
+where
+    K: PrimaryKey,
+{
+    match bound {
+        Bound::Inclusive(priority) => PrefixBound::Inclusive(priority),
+        Bound::Exclusive(priority) => PrefixBound::Exclusive(priority),
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{PriorityQueue, Serde},
+        grug_math::{Udec128, Uint128},
+        grug_types::{Bound, MockStorage, Order, StdResult},
+        std::str::FromStr,
+    };
+
+    const BIDS: PriorityQueue<Udec128, String> = PriorityQueue::new("bids");
+
+    fn setup_test() -> MockStorage {
+        let mut storage = MockStorage::new();
+
+        for (price, bidder) in [
+            ("1.5", "alice"),
+            ("2", "bob"),
+            ("1.5", "charlie"),
+            ("0.5", "dave"),
+            ("2", "eve"),
+        ] {
+            BIDS.push(
+                &mut storage,
+                Udec128::from_str(price).unwrap(),
+                &bidder.to_string(),
+            )
+            .unwrap();
+        }
+
+        storage
+    }
+
+    fn pop_all<F>(storage: &mut MockStorage, pop: F) -> Vec<String>
+    where
+        F: Fn(&mut MockStorage) -> StdResult<Option<(Udec128, String)>>,
+    {
+        let mut bidders = Vec::new();
+
+        while let Some((_, bidder)) = pop(storage).unwrap() {
+            bidders.push(bidder);
+        }
+
+        bidders
+    }
+
+    #[test]
+    fn popping_works() {
+        // Elements of the same priority are popped in the order they were
+        // pushed, regardless of whether popping the min or the max.
+        let mut storage = setup_test();
+
+        assert_eq!(
+            BIDS.peek_min(&storage).unwrap(),
+            Some((Udec128::from_str("0.5").unwrap(), "dave".to_string()))
+        );
+        assert_eq!(pop_all(&mut storage, |storage| BIDS.pop_min(storage)), [
+            "dave", "alice", "charlie", "bob", "eve"
+        ]);
+        assert!(BIDS.is_empty(&storage));
+
+        let mut storage = setup_test();
+
+        assert_eq!(
+            BIDS.peek_max(&storage).unwrap(),
+            Some((Udec128::from_str("2").unwrap(), "bob".to_string()))
+        );
+        assert_eq!(pop_all(&mut storage, |storage| BIDS.pop_max(storage)), [
+            "bob", "eve", "alice", "charlie", "dave"
+        ]);
+        assert!(BIDS.is_empty(&storage));
+    }
+
+    #[test]
+    fn range_works() {
+        let mut storage = setup_test();
+
+        let bidders = BIDS
+            .range(
+                &storage,
+                Some(Bound::Inclusive(Udec128::from_str("1").unwrap())),
+                Some(Bound::Exclusive(Udec128::from_str("2").unwrap())),
+                Order::Ascending,
+            )
+            .map(|res| res.map(|(_, bidder)| bidder))
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(bidders, ["alice", "charlie"]);
+
+        BIDS.clear(&mut storage);
+
+        assert!(BIDS.is_empty(&storage));
+        assert_eq!(BIDS.pop_min(&mut storage).unwrap(), None);
+    }
+
+    #[test]
+    fn priority_queue_works_with_serde() {
+        const QUEUE: PriorityQueue<u32, Uint128, Serde> = PriorityQueue::new("queue");
+
+        let mut storage = MockStorage::new();
+
+        QUEUE.push(&mut storage, 2, &Uint128::new(20)).unwrap();
+        QUEUE.push(&mut storage, 1, &Uint128::new(10)).unwrap();
+
+        assert_eq!(
+            QUEUE.pop_min(&mut storage).unwrap(),
+            Some((1, Uint128::new(10)))
+        );
+        assert_eq!(
+            QUEUE.pop_min(&mut storage).unwrap(),
+            Some((2, Uint128::new(20)))
+        );
+    }
+}
@@ -0,0 +1,734 @@
+use {
+    crate::{Borsh, Codec, Item, Map, PrimaryKey, RawBound},
+    grug_types::{Bound, Order, StdError, StdResult, Storage},
+    std::collections::BTreeSet,
+};
+
+/// Describes at which heights a snapshot storage type records the values of
+/// keys before they're changed, which determines at which heights historical
+/// values can be queried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Record changes made at every height. Historical values can be queried
+    /// at any height.
+    EveryBlock,
+    /// Never record changes. Historical values can't be queried.
+    Never,
+    /// Only record changes made at or after heights that are checkpointed using
+    /// `add_checkpoint`. Historical values can only be queried at these heights.
+    Selected,
+}
+
+/// The changelog and checkpoints shared by `SnapshotItem` and `SnapshotMap`.
+///
+/// For each key, the changelog records the value before the first change made
+/// at a height, i.e. the value at the start of that height. To find the value
+/// at the start of height `h`, we find the first record at or after `h`. If
+/// there isn't one, the key hasn't been changed since, so the value is the
+/// current value.
+struct Snapshot<'a> {
+    /// Height => number of checkpoints at this height.
+    checkpoints: Map<'a, u64, u32>,
+    /// (Raw key, height) => value of the key at the start of the height, in
+    /// the codec of the snapshotted data, or `None` if it didn't exist.
+    changelog: Map<'a, (Vec<u8>, u64), Option<Vec<u8>>>,
+    strategy: Strategy,
+}
+
+impl<'a> Snapshot<'a> {
+    const fn new(
+        checkpoints_namespace: &'a str,
+        changelog_namespace: &'a str,
+        strategy: Strategy,
+    ) -> Self {
+        Self {
+            checkpoints: Map::new(checkpoints_namespace),
+            changelog: Map::new(changelog_namespace),
+            strategy,
+        }
+    }
+
+    fn add_checkpoint(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.checkpoints
+            .may_update(storage, height, |count| -> StdResult<_> {
+                Ok(count.unwrap_or(0) + 1)
+            })?;
+
+        Ok(())
+    }
+
+    fn remove_checkpoint(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.checkpoints
+            .may_modify(storage, height, |count| -> StdResult<_> {
+                Ok(count.filter(|count| *count > 1).map(|count| count - 1))
+            })?;
+
+        Ok(())
+    }
+
+    fn assert_checkpointed(&self, storage: &dyn Storage, height: u64) -> StdResult<()> {
+        let checkpointed = match self.strategy {
+            Strategy::EveryBlock => true,
+            Strategy::Never => false,
+            Strategy::Selected => self.checkpoints.has(storage, height),
+        };
+
+        if !checkpointed {
+            return Err(StdError::not_checkpointed(height));
+        }
+
+        Ok(())
+    }
+
+    fn should_record(&self, storage: &dyn Storage, key_raw: &[u8], height: u64) -> StdResult<bool> {
+        // Only the value before the first change at each height is recorded.
+        if self.changelog.has(storage, (key_raw.to_vec(), height)) {
+            return Ok(false);
+        }
+
+        match self.strategy {
+            Strategy::EveryBlock => Ok(true),
+            Strategy::Never => Ok(false),
+            Strategy::Selected => {
+                let Some(checkpoint) = self
+                    .checkpoints
+                    .keys(
+                        storage,
+                        None,
+                        Some(Bound::Inclusive(height)),
+                        Order::Descending,
+                    )
+                    .next()
+                    .transpose()?
+                else {
+                    return Ok(false);
+                };
+
+                // If the key has already been changed since the latest
+                // checkpoint, its value at the checkpoint has been recorded.
+                let changed = self
+                    .changelog
+                    .prefix(key_raw.to_vec())
+                    .keys_raw(
+                        storage,
+                        Some(Bound::Inclusive(checkpoint)),
+                        None,
+                        Order::Ascending,
+                    )
+                    .next()
+                    .is_some();
+
+                Ok(!changed)
+            },
+        }
+    }
+
+    /// Record the value under the storage key, if it's about to be changed for
+    /// the first time at this height and the strategy calls for it.
+    fn record(
+        &self,
+        storage: &mut dyn Storage,
+        key_raw: &[u8],
+        storage_key: &[u8],
+        height: u64,
+    ) -> StdResult<()> {
+        if self.should_record(storage, key_raw, height)? {
+            let old_value_raw = storage.read(storage_key);
+
+            self.changelog
+                .save(storage, (key_raw.to_vec(), height), &old_value_raw)?;
+        }
+
+        Ok(())
+    }
+
+    /// Find the value of the key at the start of the height, if it has been
+    /// changed since. Return `None` if it hasn't been changed since, in which
+    /// case it's the same as the current value.
+    ///
+    /// Doesn't check whether the height is checkpointed.
+    fn may_load_changelog(
+        &self,
+        storage: &dyn Storage,
+        key_raw: &[u8],
+        height: u64,
+    ) -> StdResult<Option<Option<Vec<u8>>>> {
+        self.changelog
+            .prefix(key_raw.to_vec())
+            .values(
+                storage,
+                Some(Bound::Inclusive(height)),
+                None,
+                Order::Ascending,
+            )
+            .next()
+            .transpose()
+    }
+
+    /// Collect the raw keys that have been changed at or after the height.
+    fn changed_keys(&self, storage: &dyn Storage, height: u64) -> StdResult<BTreeSet<Vec<u8>>> {
+        let mut keys = BTreeSet::new();
+
+        for res in self.changelog.keys(storage, None, None, Order::Ascending) {
+            let (key_raw, changed_at) = res?;
+
+            if changed_at >= height {
+                keys.insert(key_raw);
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+// ------------------------------- snapshot item -------------------------------
+
+/// An [`Item`](crate::Item) that also allows querying its value at an earlier
+/// height.
+pub struct SnapshotItem<'a, T, C = Borsh>
+where
+    C: Codec<T>,
+{
+    primary: Item<'a, T, C>,
+    snapshot: Snapshot<'a>,
+}
+
+impl<'a, T, C> SnapshotItem<'a, T, C>
+where
+    C: Codec<T>,
+{
+    pub const fn new(
+        storage_key: &'a str,
+        checkpoints_namespace: &'a str,
+        changelog_namespace: &'a str,
+        strategy: Strategy,
+    ) -> Self {
+        Self {
+            primary: Item::new(storage_key),
+            snapshot: Snapshot::new(checkpoints_namespace, changelog_namespace, strategy),
+        }
+    }
+
+    pub fn storage_key(&self) -> &[u8] {
+        self.primary.storage_key()
+    }
+
+    /// Checkpoint the height, so that the value at this height can be queried
+    /// later. Only necessary for the `Selected` strategy.
+    ///
+    /// Should be done before the value is changed at this height; otherwise,
+    /// changes made earlier in the same block are lost.
+    pub fn add_checkpoint(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.snapshot.add_checkpoint(storage, height)
+    }
+
+    /// Remove a checkpoint added earlier. A height that has been checkpointed
+    /// multiple times remains checkpointed until all of them are removed.
+    pub fn remove_checkpoint(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.snapshot.remove_checkpoint(storage, height)
+    }
+
+    // -------------------------- current value ---------------------------
+
+    pub fn exists(&self, storage: &dyn Storage) -> bool {
+        self.primary.exists(storage)
+    }
+
+    pub fn may_load(&self, storage: &dyn Storage) -> StdResult<Option<T>> {
+        self.primary.may_load(storage)
+    }
+
+    pub fn load(&self, storage: &dyn Storage) -> StdResult<T> {
+        self.primary.load(storage)
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage, data: &T, height: u64) -> StdResult<()> {
+        self.record(storage, height)?;
+        self.primary.save(storage, data)
+    }
+
+    pub fn remove(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.record(storage, height)?;
+        self.primary.remove(storage);
+
+        Ok(())
+    }
+
+    pub fn may_update<F, E>(
+        &self,
+        storage: &mut dyn Storage,
+        height: u64,
+        action: F,
+    ) -> Result<T, E>
+    where
+        F: FnOnce(Option<T>) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        let data = action(self.may_load(storage)?)?;
+
+        self.save(storage, &data, height)?;
+
+        Ok(data)
+    }
+
+    pub fn update<F, E>(&self, storage: &mut dyn Storage, height: u64, action: F) -> Result<T, E>
+    where
+        F: FnOnce(T) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        let data = action(self.load(storage)?)?;
+
+        self.save(storage, &data, height)?;
+
+        Ok(data)
+    }
+
+    // ------------------------- historical value -------------------------
+
+    /// Load the value at the start of the given height, i.e. before any change
+    /// made during that block.
+    ///
+    /// Errors if the height isn't checkpointed under the strategy.
+    pub fn may_load_at_height(&self, storage: &dyn Storage, height: u64) -> StdResult<Option<T>> {
+        self.snapshot.assert_checkpointed(storage, height)?;
+
+        let value_raw = match self.snapshot.may_load_changelog(storage, &[], height)? {
+            Some(old_value_raw) => old_value_raw,
+            None => self.primary.may_load_raw(storage),
+        };
+
+        value_raw.map(|raw| C::decode(&raw)).transpose()
+    }
+
+    fn record(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.snapshot
+            .record(storage, &[], self.primary.storage_key(), height)
+    }
+}
+
+// ------------------------------- snapshot map --------------------------------
+
+/// A [`Map`](crate::Map) that also allows querying its values at an earlier
+/// height.
+///
+/// E.g. the voting powers of the members of a multisig at the height when a
+/// proposal is created.
+pub struct SnapshotMap<'a, K, T, C = Borsh>
+where
+    C: Codec<T>,
+{
+    primary: Map<'a, K, T, C>,
+    snapshot: Snapshot<'a>,
+}
+
+impl<'a, K, T, C> SnapshotMap<'a, K, T, C>
+where
+    C: Codec<T>,
+{
+    pub const fn new(
+        namespace: &'a str,
+        checkpoints_namespace: &'a str,
+        changelog_namespace: &'a str,
+        strategy: Strategy,
+    ) -> Self {
+        Self {
+            primary: Map::new(namespace),
+            snapshot: Snapshot::new(checkpoints_namespace, changelog_namespace, strategy),
+        }
+    }
+
+    /// Checkpoint the height, so that the values at this height can be queried
+    /// later. Only necessary for the `Selected` strategy.
+    ///
+    /// Should be done before any value is changed at this height; otherwise,
+    /// changes made earlier in the same block are lost.
+    pub fn add_checkpoint(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.snapshot.add_checkpoint(storage, height)
+    }
+
+    /// Remove a checkpoint added earlier. A height that has been checkpointed
+    /// multiple times remains checkpointed until all of them are removed.
+    pub fn remove_checkpoint(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.snapshot.remove_checkpoint(storage, height)
+    }
+}
+
+impl<K, T, C> SnapshotMap<'_, K, T, C>
+where
+    K: PrimaryKey,
+    C: Codec<T>,
+{
+    // -------------------------- current values --------------------------
+
+    pub fn has(&self, storage: &dyn Storage, key: K) -> bool {
+        self.primary.has(storage, key)
+    }
+
+    pub fn may_load(&self, storage: &dyn Storage, key: K) -> StdResult<Option<T>> {
+        self.primary.may_load(storage, key)
+    }
+
+    pub fn load(&self, storage: &dyn Storage, key: K) -> StdResult<T> {
+        self.primary.load(storage, key)
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage, key: K, data: &T, height: u64) -> StdResult<()> {
+        self.record(storage, &key, height)?;
+        self.primary.save(storage, key, data)
+    }
+
+    pub fn remove(&self, storage: &mut dyn Storage, key: K, height: u64) -> StdResult<()> {
+        self.record(storage, &key, height)?;
+        self.primary.remove(storage, key);
+
+        Ok(())
+    }
+
+    pub fn may_update<F, E>(
+        &self,
+        storage: &mut dyn Storage,
+        key: K,
+        height: u64,
+        action: F,
+    ) -> Result<T, E>
+    where
+        F: FnOnce(Option<T>) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        self.record(storage, &key, height)?;
+        self.primary.may_update(storage, key, action)
+    }
+
+    pub fn update<F, E>(
+        &self,
+        storage: &mut dyn Storage,
+        key: K,
+        height: u64,
+        action: F,
+    ) -> Result<T, E>
+    where
+        F: FnOnce(T) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        self.record(storage, &key, height)?;
+        self.primary.update(storage, key, action)
+    }
+
+    pub fn range<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<K>>,
+        max: Option<Bound<K>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'b> {
+        self.primary.range(storage, min, max, order)
+    }
+
+    pub fn keys<'b>(
+        &self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<K>>,
+        max: Option<Bound<K>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<K::Output>> + 'b> {
+        self.primary.keys(storage, min, max, order)
+    }
+
+    // ------------------------ historical values -------------------------
+
+    /// Load the value under the key at the start of the given height, i.e.
+    /// before any change made during that block.
+    ///
+    /// Errors if the height isn't checkpointed under the strategy.
+    pub fn may_load_at_height(
+        &self,
+        storage: &dyn Storage,
+        key: K,
+        height: u64,
+    ) -> StdResult<Option<T>> {
+        self.snapshot.assert_checkpointed(storage, height)?;
+
+        self.may_load_raw_at_height(storage, &key.joined_key(), height)?
+            .map(|raw| C::decode(&raw))
+            .transpose()
+    }
+
+    /// Iterate the records as they were at the start of the given height.
+    ///
+    /// Keys removed since the height can't be found in the current state, so
+    /// this reads through the entire changelog. It's meant to be used in
+    /// queries rather than during transactions, or when the changelog is
+    /// otherwise known to be small.
+    ///
+    /// Errors if the height isn't checkpointed under the strategy.
+    pub fn range_at_height<'b>(
+        &'b self,
+        storage: &'b dyn Storage,
+        min: Option<Bound<K>>,
+        max: Option<Bound<K>>,
+        order: Order,
+        height: u64,
+    ) -> StdResult<Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'b>>
+    where
+        K: Clone,
+    {
+        self.snapshot.assert_checkpointed(storage, height)?;
+
+        let raw_min = min.clone().map(RawBound::from);
+        let raw_max = max.clone().map(RawBound::from);
+
+        let mut keys_raw = self
+            .primary
+            .keys_raw(storage, min, max, Order::Ascending)
+            .collect::<BTreeSet<_>>();
+
+        for key_raw in self.snapshot.changed_keys(storage, height)? {
+            if is_within_bounds(&key_raw, raw_min.as_ref(), raw_max.as_ref()) {
+                keys_raw.insert(key_raw);
+            }
+        }
+
+        let keys_raw: Box<dyn Iterator<Item = Vec<u8>>> = match order {
+            Order::Ascending => Box::new(keys_raw.into_iter()),
+            Order::Descending => Box::new(keys_raw.into_iter().rev()),
+        };
+
+        let iter = keys_raw.filter_map(move |key_raw| {
+            match self.may_load_raw_at_height(storage, &key_raw, height) {
+                Ok(Some(value_raw)) => {
+                    Some(K::from_slice(&key_raw).and_then(|key| Ok((key, C::decode(&value_raw)?))))
+                },
+                Ok(None) => None,
+                Err(err) => Some(Err(err)),
+            }
+        });
+
+        Ok(Box::new(iter))
+    }
+
+    fn may_load_raw_at_height(
+        &self,
+        storage: &dyn Storage,
+        key_raw: &[u8],
+        height: u64,
+    ) -> StdResult<Option<Vec<u8>>> {
+        match self.snapshot.may_load_changelog(storage, key_raw, height)? {
+            Some(old_value_raw) => Ok(old_value_raw),
+            None => Ok(self.primary.may_load_raw(storage, key_raw)),
+        }
+    }
+
+    fn record(&self, storage: &mut dyn Storage, key: &K, height: u64) -> StdResult<()> {
+        let key_raw = key.joined_key();
+        let path = self.primary.path_raw(&key_raw);
+
+        self.snapshot
+            .record(storage, &key_raw, path.storage_key(), height)
+    }
+}
+
+fn is_within_bounds(key_raw: &[u8], min: Option<&RawBound>, max: Option<&RawBound>) -> bool {
+    let above_min = match min {
+        None => true,
+        Some(RawBound::Inclusive(min)) => key_raw >= min.as_slice(),
+        Some(RawBound::Exclusive(min)) => key_raw > min.as_slice(),
+    };
+    let below_max = match max {
+        None => true,
+        Some(RawBound::Inclusive(max)) => key_raw <= max.as_slice(),
+        Some(RawBound::Exclusive(max)) => key_raw < max.as_slice(),
+    };
+
+    above_min && below_max
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{SnapshotItem, SnapshotMap, Strategy},
+        grug_types::{Bound, MockStorage, Order, StdError, StdResult},
+    };
+
+    const POWERS: SnapshotMap<&str, u32> = SnapshotMap::new(
+        "powers",
+        "powers__checkpoints",
+        "powers__changelog",
+        Strategy::EveryBlock,
+    );
+
+    const SELECTED_POWERS: SnapshotMap<&str, u32> = SnapshotMap::new(
+        "powers",
+        "powers__checkpoints",
+        "powers__changelog",
+        Strategy::Selected,
+    );
+
+    const TOTAL: SnapshotItem<u32> = SnapshotItem::new(
+        "total",
+        "total__checkpoints",
+        "total__changelog",
+        Strategy::EveryBlock,
+    );
+
+    #[test]
+    fn snapshot_item_works() {
+        let mut storage = MockStorage::new();
+
+        TOTAL.save(&mut storage, &10, 1).unwrap();
+        // Only the value before the first change at a height is recorded.
+        TOTAL.save(&mut storage, &20, 3).unwrap();
+        TOTAL.save(&mut storage, &30, 3).unwrap();
+        TOTAL.remove(&mut storage, 5).unwrap();
+
+        assert_eq!(TOTAL.may_load(&storage).unwrap(), None);
+
+        for (height, value) in [
+            (0, None),
+            (1, None),
+            (2, Some(10)),
+            (3, Some(10)),
+            (4, Some(30)),
+            (5, Some(30)),
+            (6, None),
+        ] {
+            assert_eq!(TOTAL.may_load_at_height(&storage, height).unwrap(), value);
+        }
+
+        TOTAL
+            .may_update(&mut storage, 6, |total| -> StdResult<_> {
+                Ok(total.unwrap_or(0) + 5)
+            })
+            .unwrap();
+
+        assert_eq!(TOTAL.load(&storage).unwrap(), 5);
+        assert_eq!(TOTAL.may_load_at_height(&storage, 6).unwrap(), None);
+        assert_eq!(TOTAL.may_load_at_height(&storage, 7).unwrap(), Some(5));
+    }
+
+    #[test]
+    fn snapshot_map_works() {
+        let mut storage = MockStorage::new();
+
+        POWERS.save(&mut storage, "alice", &1, 1).unwrap();
+        POWERS.save(&mut storage, "bob", &2, 1).unwrap();
+        POWERS.save(&mut storage, "alice", &3, 2).unwrap();
+        POWERS.remove(&mut storage, "bob", 3).unwrap();
+        POWERS.save(&mut storage, "charlie", &4, 3).unwrap();
+
+        for (height, alice, bob, charlie) in [
+            (1, None, None, None),
+            (2, Some(1), Some(2), None),
+            (3, Some(3), Some(2), None),
+            (4, Some(3), None, Some(4)),
+        ] {
+            assert_eq!(
+                POWERS
+                    .may_load_at_height(&storage, "alice", height)
+                    .unwrap(),
+                alice
+            );
+            assert_eq!(
+                POWERS.may_load_at_height(&storage, "bob", height).unwrap(),
+                bob
+            );
+            assert_eq!(
+                POWERS
+                    .may_load_at_height(&storage, "charlie", height)
+                    .unwrap(),
+                charlie
+            );
+        }
+
+        // Ranging at a height includes keys that have been removed since, but
+        // not those that have been added since.
+        let records = POWERS
+            .range_at_height(&storage, None, None, Order::Ascending, 3)
+            .unwrap()
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(records, [("alice".to_string(), 3), ("bob".to_string(), 2)]);
+
+        let records = POWERS
+            .range_at_height(
+                &storage,
+                Some(Bound::Exclusive("alice")),
+                None,
+                Order::Descending,
+                2,
+            )
+            .unwrap()
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(records, [("bob".to_string(), 2)]);
+    }
+
+    #[test]
+    fn selected_strategy_works() {
+        let mut storage = MockStorage::new();
+
+        SELECTED_POWERS.save(&mut storage, "alice", &1, 1).unwrap();
+
+        // Heights that aren't checkpointed can't be queried.
+        assert!(matches!(
+            SELECTED_POWERS.may_load_at_height(&storage, "alice", 1),
+            Err(StdError::NotCheckpointed { height: 1 })
+        ));
+
+        SELECTED_POWERS.add_checkpoint(&mut storage, 2).unwrap();
+        SELECTED_POWERS.save(&mut storage, "alice", &2, 3).unwrap();
+        SELECTED_POWERS.save(&mut storage, "alice", &3, 4).unwrap();
+
+        assert_eq!(
+            SELECTED_POWERS
+                .may_load_at_height(&storage, "alice", 2)
+                .unwrap(),
+            Some(1)
+        );
+        assert!(
+            SELECTED_POWERS
+                .may_load_at_height(&storage, "alice", 3)
+                .is_err()
+        );
+
+        // The height remains checkpointed until all checkpoints at it are
+        // removed.
+        SELECTED_POWERS.add_checkpoint(&mut storage, 2).unwrap();
+        SELECTED_POWERS.remove_checkpoint(&mut storage, 2).unwrap();
+
+        assert!(
+            SELECTED_POWERS
+                .may_load_at_height(&storage, "alice", 2)
+                .is_ok()
+        );
+
+        SELECTED_POWERS.remove_checkpoint(&mut storage, 2).unwrap();
+
+        assert!(
+            SELECTED_POWERS
+                .may_load_at_height(&storage, "alice", 2)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn never_strategy_works() {
+        const NEVER: SnapshotItem<u32> = SnapshotItem::new(
+            "never",
+            "never__checkpoints",
+            "never__changelog",
+            Strategy::Never,
+        );
+
+        let mut storage = MockStorage::new();
+
+        NEVER.save(&mut storage, &1, 1).unwrap();
+        NEVER.save(&mut storage, &2, 2).unwrap();
+
+        assert_eq!(NEVER.load(&storage).unwrap(), 2);
+        assert!(NEVER.may_load_at_height(&storage, 2).is_err());
+    }
+}
@@ -1,8 +1,8 @@
 use {
     grug_mock_account::{Credential, PublicKey, SignDoc},
     grug_types::{
-        Addr, Addressable, ByteArray, GENESIS_SENDER, Hash256, Json, JsonSerExt, Message, NonEmpty,
-        SignData, Signer, StdResult, Tx, UnsignedTx,
+        Addr, Addressable, ByteArray, FeePayer, GENESIS_SENDER, Hash256, Json, JsonSerExt, Message,
+        NonEmpty, SignData, Signer, StdResult, Tx, UnsignedTx,
     },
     identity::Identity256,
     k256::ecdsa::{Signature, SigningKey, signature::DigestSigner},
@@ -58,30 +58,86 @@ impl TestAccount {
         sequence: u32,
         gas_limit: u64,
     ) -> StdResult<Tx> {
-        let sign_doc = SignDoc {
+        let credential = self.create_credential(self.address, None, &msgs, chain_id, sequence)?;
+
+        Ok(Tx {
             sender: self.address,
-            msgs: &msgs,
+            fee_payer: None,
+            gas_limit,
+            msgs,
+            data: Json::null(),
+            credential,
+        })
+    }
+
+    /// Sign a transaction whose fee is to be paid by the given fee payer, and
+    /// have the fee payer sign it as well. The internally tracked sequences of
+    /// both accounts are incremented.
+    pub fn sign_sponsored_transaction(
+        &mut self,
+        fee_payer: &mut TestAccount,
+        msgs: NonEmpty<Vec<Message>>,
+        chain_id: &str,
+        gas_limit: u64,
+    ) -> StdResult<Tx> {
+        let credential = self.create_credential(
+            self.address,
+            Some(fee_payer.address),
+            &msgs,
             chain_id,
-            sequence,
-        };
+            self.sequence,
+        )?;
 
-        let sign_data = Identity256::from_inner(sign_doc.to_sign_data()?);
-        let signature: Signature = self.sk.sign_digest(sign_data);
+        let fee_payer_credential = fee_payer.create_credential(
+            self.address,
+            Some(fee_payer.address),
+            &msgs,
+            chain_id,
+            fee_payer.sequence,
+        )?;
 
-        let credential = Credential {
-            signature: ByteArray::from_inner(signature.to_vec().as_slice().try_into()?),
-            sequence,
-        }
-        .to_json_value()?;
+        self.sequence += 1;
+        fee_payer.sequence += 1;
 
         Ok(Tx {
             sender: self.address,
+            fee_payer: Some(FeePayer {
+                address: fee_payer.address,
+                data: Json::null(),
+                credential: fee_payer_credential,
+            }),
             gas_limit,
             msgs,
             data: Json::null(),
             credential,
         })
     }
+
+    fn create_credential(
+        &self,
+        sender: Addr,
+        fee_payer: Option<Addr>,
+        msgs: &[Message],
+        chain_id: &str,
+        sequence: u32,
+    ) -> StdResult<Json> {
+        let sign_doc = SignDoc {
+            sender,
+            fee_payer,
+            msgs,
+            chain_id,
+            sequence,
+        };
+
+        let sign_data = Identity256::from_inner(sign_doc.to_sign_data()?);
+        let signature: Signature = self.sk.sign_digest(sign_data);
+
+        Credential {
+            signature: ByteArray::from_inner(signature.to_vec().as_slice().try_into()?),
+            sequence,
+        }
+        .to_json_value()
+    }
 }
 
 impl Addressable for TestAccount {
@@ -98,6 +154,7 @@ impl Signer for TestAccount {
     ) -> StdResult<UnsignedTx> {
         Ok(UnsignedTx {
             sender: self.address,
+            fee_payer: None,
             msgs,
             data: Json::null(),
         })
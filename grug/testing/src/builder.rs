@@ -8,7 +8,7 @@ use {
     grug_math::Udec128,
     grug_types::{
         Addr, Binary, BlockInfo, Coins, Config, Defined, Denom, Duration, GENESIS_BLOCK_HASH,
-        GENESIS_BLOCK_HEIGHT, GENESIS_SENDER, GenesisState, HashExt, Json, JsonSerExt,
+        GENESIS_BLOCK_HEIGHT, GENESIS_SENDER, GasCosts, GenesisState, HashExt, Json, JsonSerExt,
         MaybeDefined, Message, Permission, Permissions, StdResult, Storage, Timestamp, Undefined,
     },
     grug_vm_rust::RustVm,
@@ -78,6 +78,7 @@ pub struct TestBuilder<
     fee_denom: Option<Denom>,
     fee_rate: Option<Udec128>,
     max_orphan_age: Option<Duration>,
+    gas_costs: Option<GasCosts>,
 }
 
 // Clippy incorrectly thinks we can derive `Default` here, which we can't.
@@ -168,6 +169,7 @@ where
             fee_denom: None,
             fee_rate: None,
             max_orphan_age: None,
+            gas_costs: None,
         }
     }
 }
@@ -240,6 +242,11 @@ where
         self
     }
 
+    pub fn set_gas_costs(mut self, gas_costs: GasCosts) -> Self {
+        self.gas_costs = Some(gas_costs);
+        self
+    }
+
     pub fn set_fee_rate(mut self, fee_rate: Udec128) -> Self {
         self.fee_rate = Some(fee_rate);
         self
@@ -315,6 +322,7 @@ where
             fee_denom: self.fee_denom,
             fee_rate: self.fee_rate,
             max_orphan_age: self.max_orphan_age,
+            gas_costs: self.gas_costs,
         }
     }
 
@@ -382,6 +390,7 @@ where
             fee_denom: self.fee_denom,
             fee_rate: self.fee_rate,
             max_orphan_age: self.max_orphan_age,
+            gas_costs: self.gas_costs,
         }
     }
 
@@ -431,6 +440,7 @@ where
             fee_denom: self.fee_denom,
             fee_rate: self.fee_rate,
             max_orphan_age: self.max_orphan_age,
+            gas_costs: self.gas_costs,
         }
     }
 }
@@ -511,6 +521,7 @@ where
             fee_denom: self.fee_denom,
             fee_rate: self.fee_rate,
             max_orphan_age: self.max_orphan_age,
+            gas_costs: self.gas_costs,
         }
     }
 }
@@ -549,6 +560,7 @@ impl<DB, VM, PP, ID, M1, M2, M3>
             fee_denom: self.fee_denom,
             fee_rate: self.fee_rate,
             max_orphan_age: self.max_orphan_age,
+            gas_costs: self.gas_costs,
         }
     }
 }
@@ -668,6 +680,8 @@ where
                 instantiate: Permission::Everybody,
             },
             max_orphan_age: self.max_orphan_age.unwrap_or(DEFAULT_MAX_ORPHAN_AGE),
+            gas_costs: Box::new(self.gas_costs.unwrap_or_default()),
+            end_block_limits: Default::default(),
         };
 
         let genesis_state = GenesisState {
@@ -6,8 +6,9 @@ use {
     grug_db_memory::MemDb,
     grug_types::{
         Binary, Block, BlockClient, BlockInfo, BlockOutcome, BorshDeExt, BroadcastClient,
-        BroadcastTxOutcome, Hash256, Query, QueryClient, QueryResponse, SearchTxClient,
-        SearchTxOutcome, Timestamp, Tx, TxOutcome, UnsignedTx,
+        BroadcastTxOutcome, Hash256, Query, QueryClient, QueryResponse, QueryStoreResponse,
+        QueryStoreScanRequest, SearchTxClient, SearchTxOutcome, StateOverrides, Timestamp, Tx,
+        TxOutcome, UnsignedTx,
     },
     grug_vm_rust::RustVm,
     std::{collections::BTreeMap, ops::DerefMut, sync::Arc, thread, time::Duration},
@@ -137,22 +138,40 @@ where
         key: Binary,
         height: Option<u64>,
         prove: bool,
-    ) -> Result<(Option<Binary>, Option<Self::Proof>), Self::Error> {
-        let (value, proof) =
-            self.suite
-                .lock()
-                .await
-                .app
-                .do_query_store(&key, height.unwrap_or(0), prove)?;
-
-        let value = value.map(Binary::from_inner);
-        let proof = proof.map(|p| p.deserialize_borsh()).transpose()?;
+    ) -> Result<QueryStoreResponse<Self::Proof>, Self::Error> {
+        self.suite
+            .lock()
+            .await
+            .app
+            .do_query_store(&key, height.unwrap_or(0), prove)?
+            .try_map_proof(|proof| proof.deserialize_borsh())
+            .map_err(Into::into)
+    }
 
-        Ok((value, proof))
+    async fn query_store_scan(
+        &self,
+        min: Option<Binary>,
+        max: Option<Binary>,
+        limit: Option<u32>,
+        height: Option<u64>,
+    ) -> Result<BTreeMap<Binary, Binary>, Self::Error> {
+        Ok(self.suite.lock().await.app.do_query_store_scan(
+            QueryStoreScanRequest { min, max, limit },
+            height.unwrap_or(0),
+        )?)
     }
 
-    async fn simulate(&self, tx: UnsignedTx) -> Result<TxOutcome, Self::Error> {
-        Ok(self.suite.lock().await.app.do_simulate(tx, 0, false)?)
+    async fn simulate_with_overrides(
+        &self,
+        tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> Result<TxOutcome, Self::Error> {
+        Ok(self
+            .suite
+            .lock()
+            .await
+            .app
+            .do_simulate(tx, overrides, 0, false)?)
     }
 }
 
@@ -2,13 +2,15 @@ mod account;
 mod balance_tracker;
 mod builder;
 mod client;
+mod migration;
 mod outcomes;
 mod suite;
 mod tracing;
 mod vm;
 
 pub use {
-    account::*, balance_tracker::*, builder::*, client::*, outcomes::*, suite::*, tracing::*, vm::*,
+    account::*, balance_tracker::*, builder::*, client::*, migration::*, outcomes::*, suite::*,
+    tracing::*, vm::*,
 };
 
 // Re-export the Rust VM contract builder.
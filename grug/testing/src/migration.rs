@@ -0,0 +1,68 @@
+use {
+    grug_storage::{Codec, Map, MigrationStatus, Migrations, PrimaryKey},
+    grug_types::{Order, Storage},
+    std::{collections::BTreeSet, fmt::Debug},
+};
+
+/// Migrate the storage to the target version, and assert that the records in
+/// the `from` map before the migration have the same keys as the records in
+/// the `to` map afterwards, and that the latter can all be decoded.
+///
+/// Chunked steps are resumed one record at a time, so that resuming from every
+/// cursor is exercised.
+pub fn assert_migration_round_trips<K, A, B, CA, CB>(
+    storage: &mut dyn Storage,
+    migrations: &Migrations,
+    target: u32,
+    from: &Map<K, A, CA>,
+    to: &Map<K, B, CB>,
+) where
+    K: PrimaryKey,
+    K::Output: Debug,
+    CA: Codec<A>,
+    CB: Codec<B>,
+{
+    let keys_before = from
+        .keys_raw(storage, None, None, Order::Ascending)
+        .collect::<BTreeSet<_>>();
+
+    let mut status = migrations
+        .migrate(storage, target, 1)
+        .unwrap_or_else(|err| panic!("failed to start migration: {err}"));
+
+    while let MigrationStatus::InProgress { .. } = status {
+        status = migrations
+            .resume(storage, 1)
+            .unwrap_or_else(|err| panic!("failed to resume migration: {err}"));
+    }
+
+    assert_eq!(status, MigrationStatus::Done { version: target });
+
+    let keys_after = to
+        .range_raw(storage, None, None, Order::Ascending)
+        .map(|(key_raw, value_raw)| {
+            if let Err(err) = CB::decode(&value_raw) {
+                panic!(
+                    "failed to decode migrated record under key {:?}: {err}",
+                    K::from_slice(&key_raw)
+                );
+            }
+
+            key_raw
+        })
+        .collect::<BTreeSet<_>>();
+
+    let decode_keys = |keys: Vec<&Vec<u8>>| {
+        keys.into_iter()
+            .map(|key_raw| K::from_slice(key_raw))
+            .collect::<Vec<_>>()
+    };
+
+    let missing = decode_keys(keys_before.difference(&keys_after).collect());
+    let unexpected = decode_keys(keys_after.difference(&keys_before).collect());
+
+    assert!(
+        missing.is_empty() && unexpected.is_empty(),
+        "migration didn't round-trip all keys! missing: {missing:?}, unexpected: {unexpected:?}"
+    );
+}
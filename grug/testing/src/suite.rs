@@ -3,23 +3,29 @@ use {
         BalanceTracker, InstantiateOutcome, MakeBlockOutcome, UploadAndInstantiateOutcome,
         UploadOutcome,
     },
+    ed25519_dalek::{Signer as _, SigningKey},
     grug_app::{
-        App, AppError, Db, Indexer, NaiveProposalPreparer, NullIndexer, ProposalPreparer,
-        UpgradeHandler, Vm,
+        App, AppError, CommitValidator, Db, Indexer, LastCommit, NaiveProposalPreparer,
+        NullIndexer, ProposalPreparer, UpgradeHandler, Vm, VoteExtension, validator_address,
+        vote_extension_sign_bytes,
     },
     grug_db_memory::MemDb,
     grug_math::Uint128,
     grug_types::{
         Addr, Addressable, Binary, Block, BlockInfo, CheckTxOutcome, Coins, Config, Denom,
-        Duration, GenesisState, Hash256, HashExt, JsonDeExt, JsonSerExt, Message, NonEmpty,
-        Querier, QuerierExt, QuerierWrapper, Query, QueryResponse, QueryStatusResponse, Signer,
-        StdError, StdResult, Tx, TxOutcome, UnsignedTx,
+        Duration, ExecutionTrace, GenesisState, Hash256, HashExt, Inner, JsonDeExt, JsonSerExt,
+        Message, NonEmpty, Querier, QuerierExt, QuerierWrapper, Query, QueryResponse,
+        QueryStatusResponse, Signer, StateOverrides, StdError, StdResult, Tx, TxOutcome,
+        UnsignedTx,
     },
     grug_vm_rust::RustVm,
     serde::ser::Serialize,
     std::collections::BTreeMap,
 };
 
+/// Ed25519 private key of the single validator simulated by the test suite.
+const VALIDATOR_PRIVATE_KEY: [u8; 32] = [1; 32];
+
 pub struct TestSuite<DB = MemDb, VM = RustVm, PP = NaiveProposalPreparer, ID = NullIndexer>
 where
     DB: Db,
@@ -38,6 +44,12 @@ where
     pub block_time: Duration,
     /// Transaction gas limit to use if user doesn't specify one.
     pub default_gas_limit: u64,
+    /// Vote extensions on the last finalized block, to be provided to the
+    /// proposal preparer when making the next block.
+    ///
+    /// The test suite simulates a chain with a single validator, so this
+    /// contains at most one extension.
+    pub vote_extensions: Vec<VoteExtension>,
     pub(crate) balances: BTreeMap<Addr, Coins>,
 }
 
@@ -124,6 +136,70 @@ where
     }
 }
 
+/// A copy of the state of a [`TestSuite`] using `MemDb`, taken with
+/// [`TestSuite::snapshot`], that the suite can be rolled back to with
+/// [`TestSuite::restore`].
+pub struct TestSuiteSnapshot {
+    db: MemDb,
+    block: BlockInfo,
+    vote_extensions: Vec<VoteExtension>,
+    balances: BTreeMap<Addr, Coins>,
+}
+
+impl<VM, PP, ID> TestSuite<MemDb, VM, PP, ID>
+where
+    VM: Vm,
+    PP: ProposalPreparer,
+    ID: Indexer,
+{
+    /// Take a snapshot of the current state of the chain, which can later be
+    /// restored with [`restore`](Self::restore), any number of times.
+    pub fn snapshot(&self) -> TestSuiteSnapshot {
+        TestSuiteSnapshot {
+            db: self.app.db.deep_clone(),
+            block: self.block,
+            vote_extensions: self.vote_extensions.clone(),
+            balances: self.balances.clone(),
+        }
+    }
+
+    /// Roll the chain back to the state at the time the snapshot was taken,
+    /// discarding all blocks made since then.
+    ///
+    /// The indexer isn't rolled back, so this is intended to be used with
+    /// indexers that don't keep their own state, such as `NullIndexer`.
+    pub fn restore(&mut self, snapshot: &TestSuiteSnapshot) {
+        self.app.db = snapshot.db.deep_clone();
+        self.block = snapshot.block;
+        self.vote_extensions = snapshot.vote_extensions.clone();
+        self.balances = snapshot.balances.clone();
+    }
+
+    /// Create an independent test suite with a copy of the current state of
+    /// the chain. Blocks made in either suite don't affect the other.
+    ///
+    /// The forked suite uses `NullIndexer`, regardless of the indexer this one
+    /// uses.
+    pub fn fork(&self) -> TestSuite<MemDb, VM, PP, NullIndexer>
+    where
+        VM: Clone,
+        PP: Clone,
+    {
+        let mut app = self.app.clone_without_indexer();
+        app.db = self.app.db.deep_clone();
+
+        TestSuite {
+            app,
+            chain_id: self.chain_id.clone(),
+            block: self.block,
+            block_time: self.block_time,
+            default_gas_limit: self.default_gas_limit,
+            vote_extensions: self.vote_extensions.clone(),
+            balances: self.balances.clone(),
+        }
+    }
+}
+
 impl<DB, VM, PP, ID> TestSuite<DB, VM, PP, ID>
 where
     DB: Db,
@@ -169,12 +245,21 @@ where
 
         // 2. Creating the app instance
         // Use `u64::MAX` as query gas limit so that there's practically no limit.
-        let app = App::new(db, vm, pp, id, u64::MAX, upgrade_handler);
+        let app = App::new(db, vm, pp, id, u64::MAX, upgrade_handler, None);
 
-        app.do_init_chain(chain_id.clone(), genesis_block, genesis_state)
-            .unwrap_or_else(|err| {
-                panic!("fatal error while initializing chain: {err}");
-            });
+        let validator_pubkey = SigningKey::from_bytes(&VALIDATOR_PRIVATE_KEY)
+            .verifying_key()
+            .to_bytes();
+
+        app.do_init_chain(
+            chain_id.clone(),
+            genesis_block,
+            &[validator_pubkey],
+            genesis_state,
+        )
+        .unwrap_or_else(|err| {
+            panic!("fatal error while initializing chain: {err}");
+        });
 
         Self {
             app,
@@ -182,14 +267,82 @@ where
             block: genesis_block,
             block_time,
             default_gas_limit,
+            vote_extensions: Vec::new(),
+            balances: Default::default(),
+        }
+    }
+
+    /// Create a new test suite with the given DB, which already contains the
+    /// state of a chain, such as one forked from a remote node.
+    ///
+    /// Unlike the other constructors, this doesn't initialize the chain.
+    /// Instead, the chain ID and the last finalized block are loaded from the
+    /// DB, and the next block is made on top of it.
+    pub fn new_with_existing_state(
+        db: DB,
+        vm: VM,
+        pp: PP,
+        mut id: ID,
+        upgrade_handler: Option<UpgradeHandler<VM>>,
+        block_time: Duration,
+        default_gas_limit: u64,
+    ) -> Self {
+        let state_storage = db.state_storage(None).unwrap_or_else(|err| {
+            panic!(
+                "Fatal error while getting the state storage: {}",
+                err.to_string()
+            );
+        });
+
+        id.start(&state_storage).unwrap_or_else(|err| {
+            panic!("fatal error while running indexer start: {err}");
+        });
+
+        // Use `u64::MAX` as query gas limit so that there's practically no limit.
+        let app = App::new(db, vm, pp, id, u64::MAX, upgrade_handler, None);
+
+        let status = app
+            .do_query_app(Query::status(), 0, false)
+            .map(|res| res.as_status())
+            .unwrap_or_else(|err| {
+                panic!("fatal error while querying the chain status: {err}");
+            });
+
+        Self {
+            app,
+            chain_id: status.chain_id,
+            block: status.last_finalized_block,
+            block_time,
+            default_gas_limit,
+            vote_extensions: Vec::new(),
             balances: Default::default(),
         }
     }
 
     /// Simulate the gas cost and event outputs of an unsigned transaction.
     pub fn simulate_tx(&self, unsigned_tx: UnsignedTx) -> TxOutcome {
+        self.simulate_tx_with_overrides(unsigned_tx, StateOverrides::default())
+    }
+
+    /// Simulate an unsigned transaction, with the given changes applied to the
+    /// state beforehand.
+    pub fn simulate_tx_with_overrides(
+        &self,
+        unsigned_tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> TxOutcome {
         self.app
-            .do_simulate(unsigned_tx, 0, false)
+            .do_simulate(unsigned_tx, overrides, 0, false)
+            .unwrap_or_else(|err| {
+                panic!("fatal error while simulating tx: {err}");
+            })
+    }
+
+    /// Simulate a transaction, and return the execution trace along with the
+    /// outcome.
+    pub fn simulate_tx_with_trace(&self, unsigned_tx: UnsignedTx) -> (TxOutcome, ExecutionTrace) {
+        self.app
+            .do_simulate_with_trace(unsigned_tx, StateOverrides::default(), 0, false)
             .unwrap_or_else(|err| {
                 panic!("fatal error while simulating tx: {err}");
             })
@@ -228,11 +381,14 @@ where
             .collect();
         let txs = self
             .app
-            .do_prepare_proposal(raw_txs, usize::MAX)
+            .do_prepare_proposal(raw_txs, &self.vote_extensions, usize::MAX)
             .into_iter()
             .map(|raw_tx| (raw_tx.deserialize_json().unwrap(), raw_tx.hash256()))
             .collect::<Vec<_>>();
 
+        // Call ABCI `ExtendVote` method
+        let vote_extension = self.app.do_extend_vote(self.block.height);
+
         let block = Block {
             info: self.block,
             txs: txs.clone(),
@@ -248,9 +404,34 @@ where
             panic!("fatal error while committing block: {err}");
         });
 
+        let validator = SigningKey::from_bytes(&VALIDATOR_PRIVATE_KEY);
+        let sign_bytes =
+            vote_extension_sign_bytes(&self.chain_id, self.block.height, 0, &vote_extension);
+
+        self.vote_extensions = vec![VoteExtension {
+            validator: validator_address(&validator.verifying_key().to_bytes()).into_inner(),
+            power: 1,
+            data: vote_extension,
+            signature: validator.sign(&sign_bytes).to_vec().into(),
+        }];
+
         MakeBlockOutcome { txs, block_outcome }
     }
 
+    /// Return the commit of the last finalized block, as the consensus engine
+    /// would report it to `ProcessProposal`.
+    pub fn last_commit(&self) -> LastCommit {
+        let validator = SigningKey::from_bytes(&VALIDATOR_PRIVATE_KEY);
+
+        LastCommit {
+            round: 0,
+            validators: vec![CommitValidator {
+                address: validator_address(&validator.verifying_key().to_bytes()).into_inner(),
+                power: 1,
+            }],
+        }
+    }
+
     /// Execute a single transaction.
     pub fn send_transaction(&mut self, tx: Tx) -> TxOutcome {
         let mut block_outcome = self.make_block(vec![tx]);
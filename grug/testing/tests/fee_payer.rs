@@ -0,0 +1,182 @@
+use {
+    grug_math::{NumberConst, Udec128, Uint128},
+    grug_testing::{TestAccount, TestAccounts, TestBuilder, TestSuite},
+    grug_types::{Coins, CommitmentStatus, FeePayer, Message, NonEmpty, ResultExt},
+};
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+const SPONSOR_BALANCE: u128 = 2 * GAS_LIMIT as u128;
+
+/// Set up a test suite, where:
+///
+/// - `larry` is the chain owner, who receives the transferred tokens;
+/// - `jake` is a new user who doesn't have any token to pay fees with;
+/// - `sponsor` pays fees on behalf of `jake`.
+///
+/// The fee rate is 1, meaning the fee equals the amount of gas consumed.
+fn setup() -> (TestSuite, TestAccounts, TestAccount, TestAccount) {
+    let (suite, mut accounts) = TestBuilder::new()
+        .add_account("larry", Coins::new())
+        .add_account("jake", Coins::one("uatom", 100).unwrap())
+        .add_account("sponsor", Coins::one("ugrug", SPONSOR_BALANCE).unwrap())
+        .set_fee_rate(Udec128::ONE)
+        .set_owner("larry")
+        .build();
+
+    // Take the two accounts out, so that we can borrow both of them mutably
+    // when signing sponsored transactions.
+    let jake = accounts.remove("jake").unwrap();
+    let sponsor = accounts.remove("sponsor").unwrap();
+
+    (suite, accounts, jake, sponsor)
+}
+
+fn transfer_msgs(accounts: &TestAccounts) -> NonEmpty<Vec<Message>> {
+    NonEmpty::new_unchecked(vec![
+        Message::transfer(accounts["larry"].address, Coins::one("uatom", 100).unwrap()).unwrap(),
+    ])
+}
+
+#[test]
+fn sponsored_transaction_works() {
+    let (mut suite, accounts, mut jake, mut sponsor) = setup();
+
+    // Without a fee payer, jake's transaction is rejected, because he doesn't
+    // have any token to pay the fee with.
+    let tx = jake
+        .sign_transaction_with_sequence(transfer_msgs(&accounts), &suite.chain_id, 0, GAS_LIMIT)
+        .unwrap();
+
+    suite
+        .check_tx(tx)
+        .should_fail_with_error("subtraction overflow");
+
+    // With the sponsor as fee payer, the transaction succeeds.
+    let tx = jake
+        .sign_sponsored_transaction(
+            &mut sponsor,
+            transfer_msgs(&accounts),
+            &suite.chain_id,
+            GAS_LIMIT,
+        )
+        .unwrap();
+
+    suite.check_tx(tx.clone()).should_succeed();
+
+    let outcome = suite.send_transaction(tx).should_succeed();
+
+    assert!(matches!(
+        outcome.events.authenticate_fee_payer,
+        CommitmentStatus::Committed(_)
+    ));
+
+    // The fee is paid by the sponsor, instead of jake.
+    suite
+        .query_balance(&jake, "ugrug")
+        .should_succeed_and_equal(Uint128::ZERO);
+    suite
+        .query_balance(&sponsor, "ugrug")
+        .should_succeed_and_equal(Uint128::new(SPONSOR_BALANCE - outcome.gas_used as u128));
+
+    // The messages are executed on behalf of jake.
+    suite
+        .query_balance(&jake, "uatom")
+        .should_succeed_and_equal(Uint128::ZERO);
+    suite
+        .query_balance(&accounts["larry"], "uatom")
+        .should_succeed_and_equal(Uint128::new(100));
+}
+
+#[test]
+fn fee_payer_must_authenticate() {
+    let (mut suite, accounts, mut jake, mut sponsor) = setup();
+
+    // Jake names the sponsor as fee payer, but signs in place of the sponsor.
+    let mut tx = jake
+        .sign_sponsored_transaction(
+            &mut sponsor,
+            transfer_msgs(&accounts),
+            &suite.chain_id,
+            GAS_LIMIT,
+        )
+        .unwrap();
+
+    tx.fee_payer.as_mut().unwrap().credential = tx.credential.clone();
+
+    // The transaction is rejected from entering the mempool.
+    let error = suite.check_tx(tx.clone()).should_fail();
+
+    assert!(matches!(
+        error.events.authenticate,
+        CommitmentStatus::Committed(_)
+    ));
+    assert!(matches!(
+        error.events.authenticate_fee_payer,
+        CommitmentStatus::Failed { .. }
+    ));
+
+    // If included in a block anyways, the messages aren't executed.
+    let error = suite.send_transaction(tx).should_fail();
+
+    assert!(matches!(
+        error.events.authenticate_fee_payer,
+        CommitmentStatus::Failed { .. }
+    ));
+    assert!(matches!(
+        error.events.msgs_and_backrun,
+        CommitmentStatus::NotReached
+    ));
+
+    suite
+        .query_balance(&jake, "uatom")
+        .should_succeed_and_equal(Uint128::new(100));
+}
+
+#[test]
+fn fee_payer_cannot_be_the_sender() {
+    let (mut suite, accounts, _, sponsor) = setup();
+
+    // The sponsor names itself as the fee payer of its own transaction.
+    let msgs = NonEmpty::new_unchecked(vec![
+        Message::transfer(accounts["larry"].address, Coins::one("ugrug", 100).unwrap()).unwrap(),
+    ]);
+
+    let mut tx = sponsor
+        .sign_transaction_with_sequence(msgs, &suite.chain_id, 0, GAS_LIMIT)
+        .unwrap();
+
+    tx.fee_payer = Some(FeePayer {
+        address: sponsor.address,
+        data: tx.data.clone(),
+        credential: tx.credential.clone(),
+    });
+
+    // The transaction is rejected from entering the mempool.
+    let error = suite
+        .check_tx(tx.clone())
+        .should_fail_with_error("fee payer can't be the sender");
+
+    assert!(matches!(
+        error.events.authenticate,
+        CommitmentStatus::Failed { .. }
+    ));
+
+    // If included in a block anyways, the messages aren't executed.
+    let error = suite
+        .send_transaction(tx)
+        .should_fail_with_error("fee payer can't be the sender");
+
+    assert!(matches!(
+        error.events.authenticate_fee_payer,
+        CommitmentStatus::NotReached
+    ));
+    assert!(matches!(
+        error.events.msgs_and_backrun,
+        CommitmentStatus::NotReached
+    ));
+
+    suite
+        .query_balance(&accounts["larry"], "ugrug")
+        .should_succeed_and_equal(Uint128::ZERO);
+}
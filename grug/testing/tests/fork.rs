@@ -0,0 +1,139 @@
+use {
+    grug_app::{Db, NaiveProposalPreparer, NullIndexer},
+    grug_db_fork::ForkDb,
+    grug_testing::{BlockCreation, MockClient, TestBuilder, TestSuite},
+    grug_types::{Coins, Hash256, Order, Query, ResultExt, Storage},
+    grug_vm_rust::RustVm,
+};
+
+#[test]
+fn forking_works() {
+    let (mut remote, mut accounts) = TestBuilder::new()
+        .add_account("larry", Coins::one("uusdc", 100).unwrap())
+        .add_account("jake", Coins::one("uusdc", 50).unwrap())
+        .set_owner("larry")
+        .build();
+
+    let jake = accounts["jake"].address;
+    let larry = accounts["larry"].address;
+
+    remote
+        .transfer(
+            &mut accounts["larry"],
+            jake,
+            Coins::one("uusdc", 10).unwrap(),
+        )
+        .should_succeed();
+
+    let (fork_height, root_hash) = remote.app.do_info().unwrap();
+    let contracts = remote
+        .app
+        .do_query_app(Query::contracts(None, None), fork_height, false)
+        .unwrap()
+        .as_contracts();
+    let records = remote
+        .app
+        .db
+        .state_storage(Some(fork_height))
+        .unwrap()
+        .scan(None, None, Order::Ascending)
+        .collect::<Vec<_>>();
+    let block_time = remote.block_time;
+    let default_gas_limit = remote.default_gas_limit;
+
+    let client = MockClient::new(remote, BlockCreation::OnBroadcast);
+    let db = ForkDb::new(client, fork_height, root_hash).unwrap();
+
+    let mut fork = TestSuite::new_with_existing_state(
+        db.clone(),
+        RustVm::new(),
+        NaiveProposalPreparer,
+        NullIndexer,
+        None,
+        block_time,
+        default_gas_limit,
+    );
+
+    // The fork sees the remote state.
+    assert_eq!(fork.block.height, fork_height);
+    fork.query_balances(&larry)
+        .should_succeed_and_equal(Coins::one("uusdc", 90).unwrap());
+    fork.query_balances(&jake)
+        .should_succeed_and_equal(Coins::one("uusdc", 60).unwrap());
+
+    // Ranges outside of contracts' storage, such as the list of contracts in
+    // the app's own state, are fetched from the remote node as well.
+    let fork_contracts = fork
+        .app
+        .do_query_app(Query::contracts(None, None), fork_height, false)
+        .unwrap()
+        .as_contracts();
+    assert!(!fork_contracts.is_empty());
+    assert_eq!(fork_contracts, contracts);
+
+    // The whole remote state can be iterated over, in either order.
+    let storage = db.state_storage(Some(fork_height)).unwrap();
+    assert_eq!(
+        storage
+            .scan(None, None, Order::Ascending)
+            .collect::<Vec<_>>(),
+        records
+    );
+    assert!(
+        storage
+            .scan(None, None, Order::Descending)
+            .eq(records.iter().rev().cloned())
+    );
+
+    // Transactions can be executed on top of it.
+    fork.transfer(
+        &mut accounts["jake"],
+        larry,
+        Coins::one("uusdc", 60).unwrap(),
+    )
+    .should_succeed();
+
+    assert_eq!(db.latest_version(), Some(fork_height + 1));
+
+    // Local writes are overlaid on the remote state when iterating.
+    let storage = db.state_storage(None).unwrap();
+    let ascending = storage
+        .scan(None, None, Order::Ascending)
+        .collect::<Vec<_>>();
+    assert_ne!(ascending, records);
+    assert!(
+        storage
+            .scan(None, None, Order::Descending)
+            .eq(ascending.into_iter().rev())
+    );
+    fork.query_balances(&larry)
+        .should_succeed_and_equal(Coins::one("uusdc", 150).unwrap());
+    fork.query_balances(&jake)
+        .should_succeed_and_equal(Coins::new());
+
+    // The state at the fork height is unchanged.
+    let balances = fork
+        .app
+        .do_query_app(Query::balances(jake, None, None), fork_height, false)
+        .unwrap()
+        .as_balances();
+    assert_eq!(balances, Coins::one("uusdc", 60).unwrap());
+
+    // Versions before the fork aren't available.
+    assert!(db.state_storage(Some(fork_height - 1)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "invalid proof")]
+fn rejecting_data_not_matching_root_hash() {
+    let (remote, _) = TestBuilder::new()
+        .add_account("larry", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    let fork_height = remote.block.height;
+    let client = MockClient::new(remote, BlockCreation::OnBroadcast);
+    let db = ForkDb::new(client, fork_height, Hash256::from_inner([1; 32])).unwrap();
+
+    db.state_storage(None).unwrap().read(b"chain_id");
+}
@@ -0,0 +1,64 @@
+use {
+    grug_testing::TestBuilder,
+    grug_types::{Coins, DEFAULT_GAS_COSTS, Empty, LinearGasCost, QuerierExt, ResultExt},
+    grug_vm_rust::ContractBuilder,
+};
+
+mod tester {
+    use grug_types::{Empty, MutableCtx, Response, StdResult};
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+#[test]
+fn updating_gas_costs_works() {
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .add_account("owner", Coins::new())
+        .add_account("sender", Coins::new())
+        .set_owner("owner")
+        .build();
+
+    // The `RustVm` doesn't meter gas, but the app does when uploading codes,
+    // as it reads and writes the storage. With the default costs, this isn't
+    // free.
+    let code = ContractBuilder::new(Box::new(tester::instantiate)).build();
+
+    let outcome = suite
+        .upload(&mut accounts["sender"], code)
+        .should_succeed()
+        .outcome;
+
+    assert!(outcome.gas_used > 0);
+
+    // Only the owner can update the gas costs.
+    let mut cfg = suite.query_config().should_succeed();
+    assert_eq!(*cfg.gas_costs, DEFAULT_GAS_COSTS);
+
+    cfg.gas_costs.db_read = LinearGasCost::new(0, 0);
+    cfg.gas_costs.db_scan = 0;
+    cfg.gas_costs.db_next = 0;
+    cfg.gas_costs.db_write = LinearGasCost::new(0, 0);
+    cfg.gas_costs.db_remove = 0;
+
+    suite
+        .configure(&mut accounts["sender"], Some(cfg.clone()), None::<Empty>)
+        .should_fail_with_error("is not the owner");
+
+    suite
+        .configure(&mut accounts["owner"], Some(cfg.clone()), None::<Empty>)
+        .should_succeed();
+
+    assert_eq!(suite.query_config().should_succeed(), cfg);
+
+    // Storage access is now free, so uploading costs no gas.
+    let code = ContractBuilder::new(Box::new(tester::instantiate)).build();
+
+    let outcome = suite
+        .upload(&mut accounts["sender"], code)
+        .should_succeed()
+        .outcome;
+
+    assert_eq!(outcome.gas_used, 0);
+}
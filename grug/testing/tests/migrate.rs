@@ -1,6 +1,7 @@
 use {
-    grug_testing::{TestBuilder, UploadAndInstantiateOutcomeSuccess},
-    grug_types::{Coins, Empty, QuerierExt, ResultExt},
+    grug_storage::{STORAGE_VERSION, StorageQuerier},
+    grug_testing::{TestBuilder, UploadAndInstantiateOutcomeSuccess, assert_migration_round_trips},
+    grug_types::{Coins, Duration, Empty, Json, MockStorage, QuerierExt, ResultExt, btree_map},
     grug_vm_rust::ContractBuilder,
     tester::{MigrateMsg, QueryV1, QueryV2RequestV1, QueryV2RequestV2},
 };
@@ -93,6 +94,85 @@ mod tester {
     }
 }
 
+/// A contract that migrates its records to a new format in chunks, continuing
+/// across blocks in `cron_execute`.
+mod chunked_tester {
+    use {
+        grug_storage::{Map, Migrations, STORAGE_VERSION, migrate_records},
+        grug_types::{
+            Empty, ImmutableCtx, Json, JsonSerExt, MutableCtx, Order, QueryRequest, Response,
+            StdResult, Storage, SudoCtx,
+        },
+        serde::{Deserialize, Serialize},
+    };
+
+    /// The number of records to migrate in each block.
+    const CHUNK_SIZE: u32 = 2;
+
+    pub const NAMES_V1: Map<u32, String> = Map::new("names");
+    pub const NAMES_V2: Map<u32, (String, u32)> = Map::new("names_v2");
+
+    fn v1_to_v2(
+        storage: &mut dyn Storage,
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> StdResult<Option<Vec<u8>>> {
+        migrate_records(storage, &NAMES_V1, cursor, limit, |storage, id, name| {
+            NAMES_V1.remove(storage, id);
+            NAMES_V2.save(storage, id, &(name, id * 10))
+        })
+    }
+
+    pub fn migrations() -> Migrations {
+        Migrations::new().add_chunked_step(1, 2, v1_to_v2)
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct QueryNames;
+
+    impl QueryRequest for QueryNames {
+        type Message = Self;
+        type Response = Vec<(u32, (String, u32))>;
+    }
+
+    pub fn save_v1_records(storage: &mut dyn Storage) -> StdResult<()> {
+        STORAGE_VERSION.save(storage, &1)?;
+
+        for id in 1..=5 {
+            NAMES_V1.save(storage, id, &format!("name_{id}"))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn instantiate(ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        save_v1_records(ctx.storage)?;
+
+        Ok(Response::new())
+    }
+
+    pub fn migrate(ctx: SudoCtx, _msg: Empty) -> StdResult<Response> {
+        migrations().migrate(ctx.storage, 2, CHUNK_SIZE)?;
+
+        Ok(Response::new())
+    }
+
+    pub fn cron_execute(ctx: SudoCtx) -> StdResult<Response> {
+        migrations().resume(ctx.storage, CHUNK_SIZE)?;
+
+        Ok(Response::new())
+    }
+
+    pub fn query(ctx: ImmutableCtx, _msg: QueryNames) -> StdResult<Json> {
+        Migrations::ensure_done(ctx.storage)?;
+
+        NAMES_V2
+            .range(ctx.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?
+            .to_json_value()
+    }
+}
+
 #[test]
 fn migrate() {
     let (mut suite, mut accounts) = TestBuilder::new()
@@ -218,3 +298,86 @@ fn migrate_no_admin() {
         .migrate(&mut admin, contract, v2_code_hash, &MigrateMsg::Ok)
         .should_fail_with_error("sender does not have permission to perform this action");
 }
+
+#[test]
+fn migrate_in_chunks() {
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .add_account("owner", Coins::new())
+        .set_owner("owner")
+        .build();
+
+    let v1 = ContractBuilder::new(Box::new(chunked_tester::instantiate)).build();
+
+    let v2 = ContractBuilder::new(Box::new(chunked_tester::instantiate))
+        .with_migrate(Box::new(chunked_tester::migrate))
+        .with_cron_execute(Box::new(chunked_tester::cron_execute))
+        .with_query(Box::new(chunked_tester::query))
+        .build();
+
+    let admin_addr = accounts["owner"].address;
+
+    let contract = suite
+        .upload_and_instantiate(
+            &mut accounts["owner"],
+            v1,
+            &Empty {},
+            "salt",
+            None::<String>,
+            Some(admin_addr),
+            Coins::default(),
+        )
+        .should_succeed()
+        .address;
+
+    let v2_code_hash = suite
+        .upload(&mut accounts["owner"], v2)
+        .should_succeed()
+        .code_hash;
+
+    // The first chunk is migrated in the `migrate` call. Queries are rejected
+    // until the migration is done.
+    suite
+        .migrate(&mut accounts["owner"], contract, v2_code_hash, &Empty {})
+        .should_succeed();
+
+    suite
+        .query_wasm_smart(contract, chunked_tester::QueryNames)
+        .should_fail_with_error("storage migration from version 1 to 2 is in progress");
+
+    // The rest is migrated in cronjobs, in subsequent blocks.
+    let mut cfg = suite.query_config().unwrap();
+    cfg.cronjobs = btree_map! { contract => Duration::from_seconds(0) };
+
+    suite
+        .configure::<Json>(&mut accounts["owner"], Some(cfg), None)
+        .should_succeed();
+
+    suite.make_empty_block();
+
+    suite
+        .query_wasm_smart(contract, chunked_tester::QueryNames)
+        .should_succeed_and_equal(
+            (1..=5)
+                .map(|id| (id, (format!("name_{id}"), id * 10)))
+                .collect::<Vec<_>>(),
+        );
+
+    suite
+        .query_wasm_path(contract, STORAGE_VERSION.path())
+        .should_succeed_and_equal(2);
+}
+
+#[test]
+fn chunked_migration_round_trips() {
+    let mut storage = MockStorage::new();
+
+    chunked_tester::save_v1_records(&mut storage).unwrap();
+
+    assert_migration_round_trips(
+        &mut storage,
+        &chunked_tester::migrations(),
+        2,
+        &chunked_tester::NAMES_V1,
+        &chunked_tester::NAMES_V2,
+    );
+}
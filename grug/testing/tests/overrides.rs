@@ -0,0 +1,252 @@
+use {
+    grug_math::{NumberConst, Uint128},
+    grug_testing::{TestAccounts, TestBuilder, TestSuite},
+    grug_types::{
+        Addr, BorshSerExt, Coins, Empty, Hash256, Json, Message, NonEmpty, QuerierExt, ResultExt,
+        StateOverrides, Timestamp, UnsignedTx,
+    },
+    grug_vm_rust::ContractBuilder,
+    std::collections::BTreeMap,
+};
+
+mod account {
+    use grug_types::{AuthCtx, AuthResponse, Empty, MutableCtx, Response, StdResult, Tx};
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    // The mock account requires a credential, which simulated transactions
+    // don't have, so use one that accepts all transactions instead.
+    pub fn authenticate(_ctx: AuthCtx, _tx: Tx) -> StdResult<AuthResponse> {
+        Ok(AuthResponse::new())
+    }
+
+    pub fn receive(_ctx: MutableCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+/// A contract that only accepts calls once it's unlocked, and only at the block
+/// specified by the caller.
+mod vault {
+    use {
+        grug_storage::Item,
+        grug_types::{Empty, MutableCtx, Response, StdError, StdResult, Timestamp},
+        serde::{Deserialize, Serialize},
+    };
+
+    pub const UNLOCKED: Item<bool> = Item::new("unlocked");
+
+    #[derive(Serialize, Deserialize)]
+    pub struct ExecuteMsg {
+        pub height: u64,
+        pub timestamp: Timestamp,
+    }
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    pub fn execute(ctx: MutableCtx, msg: ExecuteMsg) -> StdResult<Response> {
+        if !UNLOCKED.may_load(ctx.storage)?.unwrap_or(false) {
+            return Err(StdError::host("vault is locked".to_string()));
+        }
+
+        if ctx.block.height != msg.height || ctx.block.timestamp != msg.timestamp {
+            return Err(StdError::host("wrong block".to_string()));
+        }
+
+        Ok(Response::new())
+    }
+}
+
+/// A contract that accepts all calls.
+mod open {
+    use grug_types::{Json, MutableCtx, Response, StdResult};
+
+    pub fn execute(_ctx: MutableCtx, _msg: Json) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn setup() -> (TestSuite, TestAccounts, Addr) {
+    let account_code = ContractBuilder::new(Box::new(account::instantiate))
+        .with_authenticate(Box::new(account::authenticate))
+        .with_receive(Box::new(account::receive))
+        .build();
+
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .set_account_code(account_code, |_| Empty {})
+        .add_account("larry", Coins::new())
+        .add_account("jake", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    let code = ContractBuilder::new(Box::new(vault::instantiate))
+        .with_execute(Box::new(vault::execute))
+        .build();
+
+    let vault = suite
+        .upload_and_instantiate(
+            &mut accounts["larry"],
+            code,
+            &Empty {},
+            "vault",
+            Some("vault"),
+            None,
+            Coins::new(),
+        )
+        .should_succeed()
+        .address;
+
+    (suite, accounts, vault)
+}
+
+fn unsigned_tx(sender: Addr, msg: Message) -> UnsignedTx {
+    UnsignedTx {
+        sender,
+        fee_payer: None,
+        msgs: NonEmpty::new_unchecked(vec![msg]),
+        data: Json::null(),
+    }
+}
+
+#[test]
+fn overriding_balances() {
+    let (mut suite, accounts, _) = setup();
+
+    let tx = unsigned_tx(
+        accounts["larry"].address,
+        Message::transfer(accounts["jake"].address, Coins::one("uatom", 100).unwrap()).unwrap(),
+    );
+
+    let overrides = StateOverrides {
+        balances: BTreeMap::from([(accounts["larry"].address, Coins::one("uatom", 100).unwrap())]),
+        ..Default::default()
+    };
+
+    // Larry doesn't have any token, so the transfer fails.
+    suite
+        .simulate_tx(tx.clone())
+        .should_fail_with_error("subtraction overflow");
+
+    // Balance overrides are applied by writing to the bank contract's storage,
+    // which requires knowing how it stores balances.
+    suite.app.balance_overrider = Some(grug_mock_bank::issue);
+
+    // Give larry the tokens he needs; the transfer succeeds.
+    suite
+        .simulate_tx_with_overrides(tx, overrides)
+        .should_succeed();
+
+    // The tokens only existed during the simulation.
+    suite
+        .query_balance(&accounts["larry"], "uatom")
+        .should_succeed_and_equal(Uint128::ZERO);
+    suite
+        .query_balance(&accounts["jake"], "uatom")
+        .should_succeed_and_equal(Uint128::ZERO);
+}
+
+#[test]
+fn overriding_storage_and_block() {
+    let (suite, accounts, vault) = setup();
+
+    let height = 100;
+    let timestamp = Timestamp::from_seconds(1_000);
+
+    let tx = unsigned_tx(
+        accounts["larry"].address,
+        Message::execute(
+            vault,
+            &vault::ExecuteMsg { height, timestamp },
+            Coins::new(),
+        )
+        .unwrap(),
+    );
+
+    suite
+        .simulate_tx(tx.clone())
+        .should_fail_with_error("vault is locked");
+
+    let unlocked = StateOverrides {
+        storage: BTreeMap::from([(
+            vault,
+            BTreeMap::from([(
+                vault::UNLOCKED.storage_key().to_vec().into(),
+                Some(true.to_borsh_vec().unwrap().into()),
+            )]),
+        )]),
+        ..Default::default()
+    };
+
+    suite
+        .simulate_tx_with_overrides(tx.clone(), unlocked.clone())
+        .should_fail_with_error("wrong block");
+
+    suite
+        .simulate_tx_with_overrides(tx, StateOverrides {
+            block_height: Some(height),
+            block_timestamp: Some(timestamp),
+            ..unlocked
+        })
+        .should_succeed();
+
+    // The storage is untouched.
+    suite
+        .query_wasm_raw(vault, vault::UNLOCKED.storage_key())
+        .should_succeed_and(|value| value.is_none());
+}
+
+#[test]
+fn overriding_code_hashes() {
+    let (mut suite, mut accounts, vault) = setup();
+
+    let open_code = ContractBuilder::new(Box::new(vault::instantiate))
+        .with_execute(Box::new(open::execute))
+        .build();
+
+    let open_code_hash = suite
+        .upload(&mut accounts["larry"], open_code)
+        .should_succeed()
+        .code_hash;
+
+    let tx = unsigned_tx(
+        accounts["larry"].address,
+        Message::execute(
+            vault,
+            &vault::ExecuteMsg {
+                height: 0,
+                timestamp: Timestamp::from_seconds(0),
+            },
+            Coins::new(),
+        )
+        .unwrap(),
+    );
+
+    suite
+        .simulate_tx_with_overrides(tx.clone(), StateOverrides {
+            code_hashes: BTreeMap::from([(vault, open_code_hash)]),
+            ..Default::default()
+        })
+        .should_succeed();
+
+    // The code must have been uploaded.
+    let result = suite.app.do_simulate(
+        tx,
+        StateOverrides {
+            code_hashes: BTreeMap::from([(vault, Hash256::ZERO)]),
+            ..Default::default()
+        },
+        0,
+        false,
+    );
+
+    assert!(result.is_err());
+
+    // The contract still runs its original code.
+    suite
+        .query_contract(vault)
+        .should_succeed_and(|info| info.code_hash != open_code_hash);
+}
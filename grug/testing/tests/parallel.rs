@@ -0,0 +1,268 @@
+use {
+    grug_math::{NumberConst, Udec128, Uint128},
+    grug_testing::{TestBuilder, TestSuite, TestVm},
+    grug_types::{Addr, Binary, Coins, Empty, Json, Message, NonEmpty, ResultExt, Timestamp, Tx},
+    grug_vm_rust::{ContractBuilder, RustVm},
+    proptest::{collection::vec, prelude::*},
+};
+
+mod account {
+    use grug_types::{AuthCtx, AuthResponse, Empty, MutableCtx, Response, StdResult, Tx};
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    // Accept all transactions, so that the two test suites, whose accounts
+    // have different keys, end up with the same state.
+    pub fn authenticate(_ctx: AuthCtx, _tx: Tx) -> StdResult<AuthResponse> {
+        Ok(AuthResponse::new())
+    }
+}
+
+mod tester {
+    use {
+        grug_storage::{Item, Map},
+        grug_types::{Empty, MutableCtx, Order, Response, StdResult},
+        serde::{Deserialize, Serialize},
+    };
+
+    const COUNTERS: Map<u8, u64> = Map::new("counter");
+
+    const TOTAL: Item<u64> = Item::new("total");
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ExecuteMsg {
+        /// Increment a counter. Reads and writes a single key.
+        Increment { key: u8 },
+        /// Sum up all the counters. Reads a range.
+        Sum {},
+    }
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    pub fn execute(ctx: MutableCtx, msg: ExecuteMsg) -> StdResult<Response> {
+        match msg {
+            ExecuteMsg::Increment { key } => {
+                let counter = COUNTERS.may_load(ctx.storage, key)?.unwrap_or(0);
+                COUNTERS.save(ctx.storage, key, &(counter + 1))?;
+            },
+            ExecuteMsg::Sum {} => {
+                let total = COUNTERS
+                    .values(ctx.storage, None, None, Order::Ascending)
+                    .sum::<StdResult<u64>>()?;
+                TOTAL.save(ctx.storage, &total)?;
+            },
+        }
+
+        Ok(Response::new())
+    }
+}
+
+const NUM_ACCOUNTS: usize = 4;
+
+const NUM_COUNTERS: u8 = 4;
+
+#[derive(Debug, Clone)]
+enum Action {
+    Transfer {
+        from: usize,
+        to: usize,
+        amount: u128,
+    },
+    Increment {
+        from: usize,
+        key: u8,
+    },
+    Sum {
+        from: usize,
+    },
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        // The amount may exceed the balance, in which case the tx fails.
+        (0..NUM_ACCOUNTS, 0..NUM_ACCOUNTS, 1..60_u128)
+            .prop_map(|(from, to, amount)| Action::Transfer { from, to, amount }),
+        (0..NUM_ACCOUNTS, 0..NUM_COUNTERS).prop_map(|(from, key)| Action::Increment { from, key }),
+        (0..NUM_ACCOUNTS).prop_map(|from| Action::Sum { from }),
+    ]
+}
+
+/// The contract codes, built once and shared by both test suites.
+///
+/// The Rust VM identifies a contract by the order in which it's built, so
+/// building them separately for each suite would result in different code
+/// hashes, and thus different contract addresses and app hashes.
+struct Codes {
+    account: Binary,
+    bank: Binary,
+    taxman: Binary,
+    tester: Binary,
+}
+
+impl Codes {
+    fn new() -> Self {
+        Self {
+            account: ContractBuilder::new(Box::new(account::instantiate))
+                .with_authenticate(Box::new(account::authenticate))
+                .build()
+                .into(),
+            bank: RustVm::default_bank_code(),
+            taxman: RustVm::default_taxman_code(),
+            tester: ContractBuilder::new(Box::new(tester::instantiate))
+                .with_execute(Box::new(tester::execute))
+                .build()
+                .into(),
+        }
+    }
+}
+
+struct Setup {
+    suite: TestSuite,
+    accounts: Vec<Addr>,
+    tester: Addr,
+}
+
+fn setup(codes: &Codes) -> Setup {
+    let mut builder = TestBuilder::new()
+        .set_account_code(codes.account.clone(), |_| Empty {})
+        .set_bank_code(codes.bank.clone(), |initial_balances| {
+            grug_mock_bank::InstantiateMsg { initial_balances }
+        })
+        .set_taxman_code(codes.taxman.clone(), |fee_denom, fee_rate| {
+            grug_mock_taxman::InstantiateMsg {
+                config: grug_mock_taxman::Config {
+                    fee_denom,
+                    fee_rate,
+                },
+            }
+        })
+        .set_genesis_time(Timestamp::from_nanos(0))
+        // Without fees, txs from different senders don't necessarily conflict.
+        .set_fee_rate(Udec128::ZERO)
+        .add_account("owner", Coins::new());
+
+    for name in ["0", "1", "2", "3"] {
+        builder = builder.add_account(name, Coins::one("uusdc", 100).unwrap());
+    }
+
+    let (mut suite, mut accounts) = builder.set_owner("owner").build();
+
+    let tester = suite
+        .upload_and_instantiate(
+            &mut accounts["owner"],
+            codes.tester.clone(),
+            &Empty {},
+            "tester",
+            Some("tester"),
+            None,
+            Coins::new(),
+        )
+        .should_succeed()
+        .address;
+
+    let accounts = ["0", "1", "2", "3"]
+        .into_iter()
+        .map(|name| accounts[name].address)
+        .collect();
+
+    Setup {
+        suite,
+        accounts,
+        tester,
+    }
+}
+
+fn make_tx(setup: &Setup, action: &Action) -> Tx {
+    let (from, msg) = match action {
+        Action::Transfer { from, to, amount } => (
+            *from,
+            Message::transfer(
+                setup.accounts[*to],
+                Coins::one("uusdc", Uint128::new(*amount)).unwrap(),
+            )
+            .unwrap(),
+        ),
+        Action::Increment { from, key } => (
+            *from,
+            Message::execute(
+                setup.tester,
+                &tester::ExecuteMsg::Increment { key: *key },
+                Coins::new(),
+            )
+            .unwrap(),
+        ),
+        Action::Sum { from } => (
+            *from,
+            Message::execute(setup.tester, &tester::ExecuteMsg::Sum {}, Coins::new()).unwrap(),
+        ),
+    };
+
+    Tx {
+        sender: setup.accounts[from],
+        fee_payer: None,
+        gas_limit: setup.suite.default_gas_limit,
+        msgs: NonEmpty::new_unchecked(vec![msg]),
+        data: Json::null(),
+        credential: Json::null(),
+    }
+}
+
+/// Process the same blocks with both setups, and assert that they produce the
+/// exact same block outcomes, including the app hashes.
+fn assert_same_outcomes(
+    mut expected: Setup,
+    mut actual: Setup,
+    blocks: Vec<Vec<Action>>,
+) -> Result<(), TestCaseError> {
+    prop_assert_eq!(
+        expected.suite.app.do_info().unwrap(),
+        actual.suite.app.do_info().unwrap()
+    );
+
+    for actions in blocks {
+        let txs = actions
+            .iter()
+            .map(|action| make_tx(&expected, action))
+            .collect::<Vec<_>>();
+
+        let expected_outcome = expected.suite.make_block(txs.clone()).block_outcome;
+        let actual_outcome = actual.suite.make_block(txs).block_outcome;
+
+        prop_assert_eq!(actual_outcome, expected_outcome);
+    }
+
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    /// Processing blocks sequentially must be deterministic.
+    #[test]
+    fn sequential_execution_is_deterministic(
+        blocks in vec(vec(action(), 0..20), 1..5),
+    ) {
+        let codes = Codes::new();
+
+        assert_same_outcomes(setup(&codes), setup(&codes), blocks)?;
+    }
+
+    /// Processing blocks in parallel must produce the exact same block outcomes,
+    /// including the app hashes, as processing them sequentially.
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_execution_matches_sequential(
+        blocks in vec(vec(action(), 0..20), 1..5),
+    ) {
+        let codes = Codes::new();
+        let mut parallel = setup(&codes);
+        parallel.suite.app.parallel_execution = true;
+
+        assert_same_outcomes(setup(&codes), parallel, blocks)?;
+    }
+}
@@ -1,11 +1,15 @@
 use {
-    grug_app::{AppError, NaiveProposalPreparer, ProposalPreparer},
-    grug_testing::TestBuilder,
+    grug_app::{
+        AppError, Db, NaiveProposalPreparer, ProcessProposalOutcome, ProposalPreparer,
+        VoteExtension, VoteExtensionVerifier,
+    },
+    grug_db_memory::MemDb,
+    grug_testing::{TestAccounts, TestBuilder, TestSuite},
     grug_types::{
-        Addr, Coins, Empty, Json, JsonSerExt, Message, NonEmpty, QuerierExt, QuerierWrapper,
-        ResultExt, StdError, Tx, btree_map,
+        Addr, Coins, Empty, Json, JsonDeExt, JsonSerExt, Message, MsgExecute, NonEmpty, QuerierExt,
+        QuerierWrapper, ResultExt, Shared, StdError, StdResult, Tx, btree_map,
     },
-    grug_vm_rust::ContractBuilder,
+    grug_vm_rust::{ContractBuilder, RustVm},
     prost::bytes::Bytes,
     std::str::FromStr,
     thiserror::Error,
@@ -87,6 +91,12 @@ enum CoingeckoPriceFeederError {
 
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
+
+    #[error("oracle tx must be the first tx in the proposal, found at index {index}")]
+    OracleTxNotFirst { index: usize },
+
+    #[error("oracle tx must contain exactly one `FeedPrices` message")]
+    MalformedOracleTx,
 }
 
 impl From<CoingeckoPriceFeederError> for AppError {
@@ -104,6 +114,7 @@ impl ProposalPreparer for CoingeckoPriceFeeder {
         &self,
         querier: QuerierWrapper,
         mut txs: Vec<Bytes>,
+        vote_extensions: &[VoteExtension],
         max_tx_bytes: usize,
     ) -> Result<Vec<Bytes>, Self::Error> {
         // Check whether the oracle address in app config has been set.
@@ -142,6 +153,7 @@ impl ProposalPreparer for CoingeckoPriceFeeder {
             // Compose an oracle update transaction.
             let tx = Tx {
                 sender: oracle,
+                fee_payer: None,
                 gas_limit: 1_000_000,
                 msgs: NonEmpty::new_unchecked(vec![Message::execute(
                     oracle,
@@ -160,13 +172,75 @@ impl ProposalPreparer for CoingeckoPriceFeeder {
 
         // Use the naive preparer to trim the txs to under the max bytes.
         Ok(NaiveProposalPreparer
-            .prepare_proposal(querier, txs, max_tx_bytes)
+            .prepare_proposal(querier, txs, vote_extensions, max_tx_bytes)
             .unwrap())
     }
+
+    fn process_proposal(
+        &self,
+        querier: QuerierWrapper,
+        txs: &[Bytes],
+        _verifier: &VoteExtensionVerifier,
+    ) -> Result<ProcessProposalOutcome, Self::Error> {
+        let Some(oracle) = querier
+            .query_app_config::<Json>()?
+            .as_str()
+            .map(Addr::from_str)
+        else {
+            return Ok(ProcessProposalOutcome::Accept);
+        };
+
+        match validate_oracle_tx(oracle?, txs) {
+            Ok(()) => Ok(ProcessProposalOutcome::Accept),
+            Err(err) => Ok(ProcessProposalOutcome::Reject {
+                reason: err.to_string(),
+            }),
+        }
+    }
 }
 
-#[test]
-fn prepare_proposal_works() {
+fn validate_oracle_tx(oracle: Addr, txs: &[Bytes]) -> Result<(), CoingeckoPriceFeederError> {
+    for (index, raw_tx) in txs.iter().enumerate() {
+        // Ignore txs that can't be deserialized. They will fail during
+        // `FinalizeBlock` anyway.
+        let Ok(tx) = raw_tx.deserialize_json::<Tx>() else {
+            continue;
+        };
+
+        if tx.sender != oracle {
+            continue;
+        }
+
+        // There can only be one oracle tx, which is the first one in the
+        // block, as inserted by the preparer.
+        if index != 0 {
+            return Err(CoingeckoPriceFeederError::OracleTxNotFirst { index });
+        }
+
+        let (Some(Message::Execute(MsgExecute { contract, msg, .. })), 1) =
+            (tx.msgs.first(), tx.msgs.len())
+        else {
+            return Err(CoingeckoPriceFeederError::MalformedOracleTx);
+        };
+
+        if *contract != oracle
+            || msg
+                .clone()
+                .deserialize_json::<mock_oracle::ExecuteMsg>()
+                .is_err()
+        {
+            return Err(CoingeckoPriceFeederError::MalformedOracleTx);
+        }
+    }
+
+    Ok(())
+}
+
+fn setup_oracle() -> (
+    TestSuite<MemDb, RustVm, CoingeckoPriceFeeder>,
+    TestAccounts,
+    Addr,
+) {
     let (mut suite, mut accounts) = TestBuilder::new_with_pp(CoingeckoPriceFeeder)
         .add_account("larry", Coins::new())
         .set_owner("larry")
@@ -192,6 +266,13 @@ fn prepare_proposal_works() {
         .should_succeed()
         .address;
 
+    (suite, accounts, oracle)
+}
+
+#[test]
+fn prepare_proposal_works() {
+    let (mut suite, mut accounts, oracle) = setup_oracle();
+
     // Set oracle contract address as app config.
     suite
         .configure(&mut accounts["larry"], None, Some(oracle))
@@ -220,3 +301,219 @@ fn prepare_proposal_works() {
                 .eq(["bitcoin", "ethereum", "harrypotterobamasonic10in"])
         });
 }
+
+#[test]
+fn process_proposal_works() {
+    let (mut suite, mut accounts, oracle) = setup_oracle();
+
+    // Before the oracle address is set in app config, any proposal is accepted.
+    let oracle_tx: Bytes = Tx {
+        sender: oracle,
+        fee_payer: None,
+        gas_limit: 1_000_000,
+        msgs: NonEmpty::new_unchecked(vec![
+            Message::execute(
+                oracle,
+                &mock_oracle::ExecuteMsg::FeedPrices {
+                    prices: btree_map! { "bitcoin".to_string() => 1. },
+                },
+                Coins::new(),
+            )
+            .unwrap(),
+        ]),
+        data: Json::null(),
+        credential: Json::null(),
+    }
+    .to_json_vec()
+    .unwrap()
+    .into();
+    let user_tx = Bytes::from_static(b"not a valid tx");
+
+    assert!(
+        suite
+            .app
+            .do_process_proposal(&[user_tx.clone(), oracle_tx.clone()], &suite.last_commit())
+            .unwrap()
+    );
+
+    suite
+        .configure(&mut accounts["larry"], None, Some(oracle))
+        .should_succeed();
+
+    // A proposal prepared by an honest proposer should be accepted.
+    let txs = suite
+        .app
+        .do_prepare_proposal(vec![user_tx.clone()], &[], usize::MAX);
+    assert_eq!(txs.len(), 2);
+    assert!(
+        suite
+            .app
+            .do_process_proposal(&txs, &suite.last_commit())
+            .unwrap()
+    );
+
+    // An empty proposal should be accepted.
+    assert!(
+        suite
+            .app
+            .do_process_proposal(&[], &suite.last_commit())
+            .unwrap()
+    );
+
+    // The oracle tx not being the first should be rejected.
+    assert!(
+        !suite
+            .app
+            .do_process_proposal(&[user_tx.clone(), oracle_tx.clone()], &suite.last_commit())
+            .unwrap()
+    );
+
+    // Duplicate oracle txs should be rejected.
+    assert!(
+        !suite
+            .app
+            .do_process_proposal(
+                &[oracle_tx.clone(), oracle_tx.clone()],
+                &suite.last_commit()
+            )
+            .unwrap()
+    );
+
+    // A malformed oracle tx should be rejected.
+    let malformed_tx: Bytes = Tx {
+        sender: oracle,
+        fee_payer: None,
+        gas_limit: 1_000_000,
+        msgs: NonEmpty::new_unchecked(vec![Message::transfer(oracle, Coins::new()).unwrap()]),
+        data: Json::null(),
+        credential: Json::null(),
+    }
+    .to_json_vec()
+    .unwrap()
+    .into();
+    assert!(
+        !suite
+            .app
+            .do_process_proposal(&[malformed_tx, user_tx.clone()], &suite.last_commit())
+            .unwrap()
+    );
+
+    // Failing to evaluate the proposal locally is an error, not a rejection.
+    // Here, the app config can't be read as the preparer expects.
+    suite
+        .configure(&mut accounts["larry"], None, Some("not an address"))
+        .should_succeed();
+    assert!(
+        suite
+            .app
+            .do_process_proposal(&[user_tx], &suite.last_commit())
+            .is_err()
+    );
+}
+
+#[test]
+fn naive_preparer_accepts_all_proposals() {
+    let (suite, _) = TestBuilder::new()
+        .add_account("larry", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    assert!(
+        suite
+            .app
+            .do_process_proposal(&[Bytes::from_static(b"whatever")], &suite.last_commit())
+            .unwrap()
+    );
+}
+
+/// A proposal preparer that extends each vote with the block height, and
+/// records the vote extensions it receives when preparing proposals.
+#[derive(Default)]
+struct VoteExtensionRecorder {
+    received: Shared<Vec<Vec<VoteExtension>>>,
+}
+
+impl ProposalPreparer for VoteExtensionRecorder {
+    type Error = StdError;
+
+    fn prepare_proposal(
+        &self,
+        _querier: QuerierWrapper,
+        txs: Vec<Bytes>,
+        vote_extensions: &[VoteExtension],
+        _max_tx_bytes: usize,
+    ) -> StdResult<Vec<Bytes>> {
+        self.received
+            .write_with(|mut received| received.push(vote_extensions.to_vec()));
+
+        Ok(txs)
+    }
+
+    fn extend_vote(&self, _querier: QuerierWrapper, height: u64) -> StdResult<Bytes> {
+        Ok(height.to_be_bytes().to_vec().into())
+    }
+
+    fn verify_vote_extension(&self, _querier: QuerierWrapper, data: &[u8]) -> StdResult<()> {
+        <[u8; 8]>::try_from(data)?;
+
+        Ok(())
+    }
+}
+
+#[test]
+fn vote_extensions_work() {
+    let pp = VoteExtensionRecorder::default();
+    let received = pp.received.clone();
+
+    let (mut suite, _) = TestBuilder::new_with_pp(pp)
+        .add_account("larry", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    // Make a block, so that there's a vote extension from the previous block,
+    // then forget about the proposals prepared so far.
+    suite.make_empty_block();
+    received.replace(vec![]);
+
+    let height = suite.block.height;
+
+    for _ in 0..2 {
+        suite.make_empty_block();
+    }
+
+    // When preparing each block, the preparer should receive the extension of
+    // the vote on the previous block.
+    received.read_with(|received| {
+        let data = received
+            .iter()
+            .map(|votes| {
+                assert_eq!(votes.len(), 1);
+                assert_eq!(votes[0].power, 1);
+                votes[0].data.clone()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(data, [
+            Bytes::from(height.to_be_bytes().to_vec()),
+            Bytes::from((height + 1).to_be_bytes().to_vec()),
+        ]);
+    });
+
+    // The extension on the last block should be signed by the validator.
+    let verifier = VoteExtensionVerifier::load(
+        &suite.app.db.state_storage(None).unwrap(),
+        suite.chain_id.clone(),
+        suite.block.height,
+        &suite.last_commit(),
+    )
+    .unwrap();
+    assert!(verifier.verify(&suite.vote_extensions[0]));
+
+    // Tampering with the extension should invalidate the signature.
+    let mut tampered = suite.vote_extensions[0].clone();
+    tampered.data = Bytes::from_static(b"tampered");
+    assert!(!verifier.verify(&tampered));
+
+    assert!(suite.app.do_verify_vote_extension(&height.to_be_bytes()));
+    assert!(!suite.app.do_verify_vote_extension(b"garbage"));
+}
@@ -1,6 +1,8 @@
 use {
-    grug_testing::TestBuilder,
-    grug_types::{Coins, Empty, ResultExt},
+    grug_app::LAST_FINALIZED_BLOCK,
+    grug_jmt::verify_proof,
+    grug_testing::{BlockCreation, MockClient, TestBuilder},
+    grug_types::{Binary, BlockInfo, BorshDeExt, Coins, Empty, HashExt, QueryClient, ResultExt},
     grug_vm_rust::ContractBuilder,
 };
 
@@ -48,3 +50,69 @@ fn handling_multi_query() {
         )
         .should_succeed();
 }
+
+#[tokio::test]
+async fn querying_store_at_height_with_proof() {
+    let (mut suite, _) = TestBuilder::new()
+        .add_account("larry", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    // Height zero means the latest height, so query a later one.
+    suite.make_empty_block();
+
+    let height = suite.block.height;
+
+    for _ in 0..3 {
+        suite.make_empty_block();
+    }
+
+    let client = MockClient::new(suite, BlockCreation::OnBroadcast);
+
+    // Query the last finalized block as of an earlier height, and verify the
+    // response against the root hash at that height.
+    let key = Binary::from(LAST_FINALIZED_BLOCK.storage_key().to_vec());
+    let res = client
+        .query_store(key.clone(), Some(height), true)
+        .await
+        .unwrap();
+
+    assert_eq!(res.height, height);
+
+    let value = res.value.unwrap();
+    let block: BlockInfo = value.deserialize_borsh().unwrap();
+
+    assert_eq!(block.height, height);
+
+    verify_proof(
+        res.root_hash.unwrap(),
+        key.hash256(),
+        Some(value.hash256()),
+        &res.proof.unwrap(),
+    )
+    .unwrap();
+
+    // If the height isn't specified, the latest one is used, and reported.
+    let res = client.query_store(key, None, false).await.unwrap();
+
+    assert_eq!(res.height, height + 3);
+    assert!(res.proof.is_none());
+    assert!(res.root_hash.is_none());
+
+    // Non-existence of a key can be proved as well.
+    let key = Binary::from(b"nonexistent".to_vec());
+    let res = client
+        .query_store(key.clone(), Some(height), true)
+        .await
+        .unwrap();
+
+    assert!(res.value.is_none());
+
+    verify_proof(
+        res.root_hash.unwrap(),
+        key.hash256(),
+        None,
+        &res.proof.unwrap(),
+    )
+    .unwrap();
+}
@@ -0,0 +1,468 @@
+use {
+    grug_testing::{TestAccounts, TestBuilder, TestSuite},
+    grug_types::{
+        Addr, BorshDeExt, Coins, CommitmentStatus, Config, DEFAULT_END_BLOCK_LIMITS, Due, Duration,
+        Empty, EndBlockLimits, EventStatus, EvtSchedule, Message, NonEmpty, QuerierExt, ResultExt,
+        ScheduledOutcome, SearchEvent, Timestamp,
+    },
+    grug_vm_rust::ContractBuilder,
+};
+
+/// A contract that counts how many times it has been executed. Used as the
+/// target of scheduled messages.
+///
+/// It can also schedule executions of itself, and cancel them.
+mod counter {
+    use {
+        grug_storage::Item,
+        grug_types::{
+            Coins, Due, Empty, Message, MutableCtx, Response, StdError, StdResult, SubMessage,
+            SubMsgResult, SudoCtx,
+        },
+        serde::{Deserialize, Serialize},
+    };
+
+    pub const COUNT: Item<u32> = Item::new("count");
+
+    const SCHEDULED_ID: Item<u64> = Item::new("scheduled_id");
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ExecuteMsg {
+        Increment {},
+        Fail {},
+        ScheduleIncrement { due: Due },
+        CancelIncrement {},
+    }
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    pub fn execute(ctx: MutableCtx, msg: ExecuteMsg) -> StdResult<Response> {
+        match msg {
+            ExecuteMsg::Increment {} => {
+                COUNT.may_update(ctx.storage, |count| -> StdResult<_> {
+                    Ok(count.unwrap_or(0) + 1)
+                })?;
+
+                Ok(Response::new())
+            },
+            ExecuteMsg::Fail {} => Err(StdError::host("intentional failure".to_string())),
+            ExecuteMsg::ScheduleIncrement { due } => {
+                let msg = Message::schedule(
+                    due,
+                    1_000_000,
+                    ctx.contract,
+                    &ExecuteMsg::Increment {},
+                    Coins::new(),
+                )?;
+
+                // Request a reply, so that we can learn the ID assigned to the
+                // scheduled message, for canceling it later.
+                Ok(Response::new().add_submessage(SubMessage::reply_on_success(msg, &Empty {})?))
+            },
+            ExecuteMsg::CancelIncrement {} => {
+                let id = SCHEDULED_ID.load(ctx.storage)?;
+
+                Ok(Response::new().add_message(Message::unschedule(id)))
+            },
+        }
+    }
+
+    pub fn reply(ctx: SudoCtx, _msg: Empty, res: SubMsgResult) -> StdResult<Response> {
+        let id = res
+            .map_err(StdError::host)?
+            .as_schedule()
+            .id
+            .expect("scheduled message doesn't have an ID");
+
+        SCHEDULED_ID.save(ctx.storage, &id)?;
+
+        Ok(Response::new())
+    }
+}
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+/// The gas limit of transactions, which must cover that of the messages they
+/// schedule.
+const TX_GAS_LIMIT: u64 = 10_000_000;
+
+fn setup() -> (TestSuite, TestAccounts, Addr) {
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .add_account("larry", Coins::new())
+        .add_account("jake", Coins::new())
+        .set_genesis_time(Timestamp::from_nanos(0))
+        .set_block_time(Duration::from_seconds(1))
+        .set_default_gas_limit(TX_GAS_LIMIT)
+        .set_owner("larry")
+        .build();
+
+    let code = ContractBuilder::new(Box::new(counter::instantiate))
+        .with_execute(Box::new(counter::execute))
+        .with_reply(Box::new(counter::reply))
+        .build();
+
+    let counter = suite
+        .upload_and_instantiate(
+            &mut accounts["larry"],
+            code,
+            &Empty {},
+            "counter",
+            Some("counter"),
+            None,
+            Coins::new(),
+        )
+        .should_succeed()
+        .address;
+
+    (suite, accounts, counter)
+}
+
+fn query_count(suite: &TestSuite, counter: Addr) -> u32 {
+    suite
+        .query_wasm_raw(counter, counter::COUNT.storage_key())
+        .unwrap()
+        .map(|raw| raw.deserialize_borsh().unwrap())
+        .unwrap_or(0)
+}
+
+fn schedule_increment(counter: Addr, due: Due, gas_limit: u64) -> Message {
+    Message::schedule(
+        due,
+        gas_limit,
+        counter,
+        &counter::ExecuteMsg::Increment {},
+        Coins::new(),
+    )
+    .unwrap()
+}
+
+fn assert_succeeded(outcome: &ScheduledOutcome, id: u64) {
+    assert_eq!(outcome.id, id);
+    assert!(matches!(
+        outcome.execute_event,
+        CommitmentStatus::Committed(EventStatus::Ok(_))
+    ));
+}
+
+#[test]
+fn performing_scheduled_messages() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    let height = suite.block.height;
+    let time = suite.block.timestamp;
+
+    // Block time: time + 1
+    //
+    // Schedule three increments:
+    // - ID 0, at two blocks later;
+    // - ID 1, at two seconds later, which is the same block as the above;
+    // - ID 2, at three blocks later.
+    let ids = suite
+        .send_messages(
+            &mut accounts["larry"],
+            NonEmpty::new_unchecked(vec![
+                schedule_increment(counter, Due::Height(height + 2), GAS_LIMIT),
+                schedule_increment(
+                    counter,
+                    Due::Time(time + Duration::from_seconds(2)),
+                    GAS_LIMIT,
+                ),
+                schedule_increment(counter, Due::Height(height + 3), GAS_LIMIT),
+            ]),
+        )
+        .should_succeed()
+        .events
+        .search_event::<EvtSchedule>()
+        .take()
+        .all()
+        .into_iter()
+        .map(|evt| evt.event.id.unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(ids, [0, 1, 2]);
+    assert_eq!(query_count(&suite, counter), 0);
+
+    // Block time: time + 2
+    //
+    // The first two are performed, in the order they were scheduled.
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert_eq!(outcome.scheduled_outcomes.len(), 2);
+    assert_succeeded(&outcome.scheduled_outcomes[0], 0);
+    assert_succeeded(&outcome.scheduled_outcomes[1], 1);
+    assert_eq!(query_count(&suite, counter), 2);
+
+    // Block time: time + 3
+    //
+    // The last one is performed.
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert_eq!(outcome.scheduled_outcomes.len(), 1);
+    assert_succeeded(&outcome.scheduled_outcomes[0], 2);
+    assert_eq!(query_count(&suite, counter), 3);
+
+    // Block time: time + 4
+    //
+    // Each scheduled message is only performed once.
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert!(outcome.scheduled_outcomes.is_empty());
+    assert_eq!(query_count(&suite, counter), 3);
+}
+
+#[test]
+fn failing_scheduled_messages() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    let height = suite.block.height;
+
+    // Schedule a message that fails, followed by one that succeeds.
+    suite
+        .send_messages(
+            &mut accounts["larry"],
+            NonEmpty::new_unchecked(vec![
+                Message::schedule(
+                    Due::Height(height + 2),
+                    GAS_LIMIT,
+                    counter,
+                    &counter::ExecuteMsg::Fail {},
+                    Coins::new(),
+                )
+                .unwrap(),
+                schedule_increment(counter, Due::Height(height + 2), GAS_LIMIT),
+            ]),
+        )
+        .should_succeed();
+
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert_eq!(outcome.scheduled_outcomes.len(), 2);
+    assert_eq!(outcome.scheduled_outcomes[0].id, 0);
+    assert!(matches!(
+        outcome.scheduled_outcomes[0].execute_event,
+        CommitmentStatus::Failed { .. }
+    ));
+
+    // The failure of one scheduled message doesn't affect the others.
+    assert_succeeded(&outcome.scheduled_outcomes[1], 1);
+    assert_eq!(query_count(&suite, counter), 1);
+}
+
+#[test]
+fn unscheduling_messages() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    let height = suite.block.height;
+
+    // Block height: height + 1
+    suite
+        .send_message(
+            &mut accounts["larry"],
+            schedule_increment(counter, Due::Height(height + 4), GAS_LIMIT),
+        )
+        .should_succeed();
+
+    // Block height: height + 2
+    //
+    // Only the sender of the scheduled message can cancel it.
+    suite
+        .send_message(&mut accounts["jake"], Message::unschedule(0))
+        .should_fail_with_error("sender does not have permission to perform this action");
+
+    // Block height: height + 3
+    suite
+        .send_message(&mut accounts["larry"], Message::unschedule(0))
+        .should_succeed();
+
+    // Block height: height + 4
+    //
+    // The canceled message isn't performed.
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert!(outcome.scheduled_outcomes.is_empty());
+    assert_eq!(query_count(&suite, counter), 0);
+
+    // A message can't be canceled twice.
+    suite
+        .send_message(&mut accounts["larry"], Message::unschedule(0))
+        .should_fail_with_error("scheduled message with id `0` not found");
+}
+
+#[test]
+fn contract_scheduling_and_unscheduling_messages() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    let due = Due::Height(suite.block.height + 4);
+
+    // Block height: height + 1, height + 2
+    //
+    // The contract schedules two executions of itself, both due at the same
+    // block height.
+    for _ in 0..2 {
+        suite
+            .execute(
+                &mut accounts["larry"],
+                counter,
+                &counter::ExecuteMsg::ScheduleIncrement { due },
+                Coins::new(),
+            )
+            .should_succeed();
+    }
+
+    // Block height: height + 3
+    //
+    // The contract cancels the latter one.
+    suite
+        .execute(
+            &mut accounts["larry"],
+            counter,
+            &counter::ExecuteMsg::CancelIncrement {},
+            Coins::new(),
+        )
+        .should_succeed();
+
+    // Block height: height + 4
+    //
+    // Only the former one is performed.
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert_eq!(outcome.scheduled_outcomes.len(), 1);
+    assert_succeeded(&outcome.scheduled_outcomes[0], 0);
+    assert_eq!(query_count(&suite, counter), 1);
+
+    // Block height: height + 5
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert!(outcome.scheduled_outcomes.is_empty());
+    assert_eq!(query_count(&suite, counter), 1);
+}
+
+#[test]
+fn scheduling_in_the_past_fails() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    // The tx is included in the next block, so a message due at the next block
+    // height or earlier is rejected.
+    for due in [
+        Due::Height(suite.block.height),
+        Due::Height(suite.block.height + 1),
+        Due::Time(suite.block.timestamp),
+    ] {
+        suite
+            .send_message(
+                &mut accounts["larry"],
+                schedule_increment(counter, due, GAS_LIMIT),
+            )
+            .should_fail_with_error("scheduled message must be due in the future");
+    }
+}
+
+#[test]
+fn scheduling_consumes_the_gas_limit() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    let due = Due::Height(suite.block.height + 10);
+
+    let gas_used_1 = suite
+        .send_message(
+            &mut accounts["larry"],
+            schedule_increment(counter, due, GAS_LIMIT),
+        )
+        .should_succeed()
+        .gas_used;
+
+    let gas_used_2 = suite
+        .send_message(
+            &mut accounts["larry"],
+            schedule_increment(counter, due, 2 * GAS_LIMIT),
+        )
+        .should_succeed()
+        .gas_used;
+
+    // The sender pays for the gas limit of the scheduled message up front.
+    assert_eq!(gas_used_2 - gas_used_1, GAS_LIMIT);
+
+    // The transaction must have enough gas to cover it.
+    suite
+        .send_message_with_gas(
+            &mut accounts["larry"],
+            GAS_LIMIT,
+            schedule_increment(counter, due, GAS_LIMIT),
+        )
+        .should_fail_with_error("out of gas");
+}
+
+#[test]
+fn scheduling_with_too_high_gas_limit_fails() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    suite
+        .send_message(
+            &mut accounts["larry"],
+            schedule_increment(
+                counter,
+                Due::Height(suite.block.height + 10),
+                DEFAULT_END_BLOCK_LIMITS.max_scheduled_gas_limit + 1,
+            ),
+        )
+        .should_fail_with_error("gas limit of scheduled message exceeds the maximum");
+}
+
+#[test]
+fn scheduled_gas_per_block_is_bounded() {
+    let (mut suite, mut accounts, counter) = setup();
+
+    // Only allow two messages' worth of gas per block.
+    let cfg = suite.query_config().should_succeed();
+    suite
+        .configure(
+            &mut accounts["larry"],
+            Some(Config {
+                end_block_limits: EndBlockLimits {
+                    scheduled_gas_per_block: 2 * GAS_LIMIT,
+                    ..cfg.end_block_limits
+                },
+                ..cfg
+            }),
+            None::<Empty>,
+        )
+        .should_succeed();
+
+    let height = suite.block.height;
+
+    // Block height: height + 1
+    //
+    // Schedule three increments, all due at the same block height.
+    suite
+        .send_messages(
+            &mut accounts["larry"],
+            NonEmpty::new_unchecked(vec![
+                schedule_increment(counter, Due::Height(height + 2), GAS_LIMIT),
+                schedule_increment(counter, Due::Height(height + 2), GAS_LIMIT),
+                schedule_increment(counter, Due::Height(height + 2), GAS_LIMIT),
+            ]),
+        )
+        .should_succeed();
+
+    // Block height: height + 2
+    //
+    // Only the first two fit in the budget.
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert_eq!(outcome.scheduled_outcomes.len(), 2);
+    assert_succeeded(&outcome.scheduled_outcomes[0], 0);
+    assert_succeeded(&outcome.scheduled_outcomes[1], 1);
+    assert_eq!(query_count(&suite, counter), 2);
+
+    // Block height: height + 3
+    //
+    // The last one is deferred to the next block.
+    let outcome = suite.make_empty_block().block_outcome;
+
+    assert_eq!(outcome.scheduled_outcomes.len(), 1);
+    assert_succeeded(&outcome.scheduled_outcomes[0], 2);
+    assert_eq!(query_count(&suite, counter), 3);
+}
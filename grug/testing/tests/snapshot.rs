@@ -0,0 +1,90 @@
+use {
+    grug_testing::TestBuilder,
+    grug_types::{Coins, ResultExt},
+};
+
+#[test]
+fn snapshot_and_restore_works() {
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .add_account("larry", Coins::one("uusdc", 100).unwrap())
+        .add_account("jake", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    let jake = accounts["jake"].address;
+    let larry = accounts["larry"].address;
+
+    let snapshot = suite.snapshot();
+    let height = suite.block.height;
+    let sequence = accounts["larry"].sequence;
+
+    // Try out two alternative scenarios, starting from the same state.
+    for amount in [30, 70] {
+        suite
+            .transfer(
+                &mut accounts["larry"],
+                jake,
+                Coins::one("uusdc", amount).unwrap(),
+            )
+            .should_succeed();
+
+        suite
+            .query_balances(&jake)
+            .should_succeed_and_equal(Coins::one("uusdc", amount).unwrap());
+
+        suite.restore(&snapshot);
+
+        assert_eq!(suite.block.height, height);
+        suite
+            .query_balances(&larry)
+            .should_succeed_and_equal(Coins::one("uusdc", 100).unwrap());
+        suite
+            .query_balances(&jake)
+            .should_succeed_and_equal(Coins::new());
+
+        // The account's sequence is rolled back on chain as well, so the signer
+        // has to be rolled back to sign transactions in the next scenario.
+        accounts["larry"].sequence = sequence;
+    }
+}
+
+#[test]
+fn forking_works() {
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .add_account("larry", Coins::one("uusdc", 100).unwrap())
+        .add_account("owen", Coins::one("uusdc", 100).unwrap())
+        .add_account("jake", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    let jake = accounts["jake"].address;
+
+    let mut fork = suite.fork();
+
+    fork.transfer(
+        &mut accounts["larry"],
+        jake,
+        Coins::one("uusdc", 40).unwrap(),
+    )
+    .should_succeed();
+
+    // Blocks made in the fork don't affect the original suite, and vice versa.
+    assert_eq!(fork.block.height, suite.block.height + 1);
+    suite
+        .query_balances(&jake)
+        .should_succeed_and_equal(Coins::new());
+
+    suite
+        .transfer(
+            &mut accounts["owen"],
+            jake,
+            Coins::one("uusdc", 10).unwrap(),
+        )
+        .should_succeed();
+
+    fork.query_balances(&jake)
+        .should_succeed_and_equal(Coins::one("uusdc", 40).unwrap());
+    suite
+        .query_balances(&jake)
+        .should_succeed_and_equal(Coins::one("uusdc", 10).unwrap());
+}
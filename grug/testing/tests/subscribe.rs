@@ -0,0 +1,637 @@
+use {
+    grug_testing::{TestAccounts, TestBuilder, TestSuite},
+    grug_types::{
+        Addr, BorshDeExt, CheckedContractEvent, Coins, CommitmentStatus, Config, Empty,
+        EndBlockLimits, EventStatus, FlatEvtReceiveEvent, JsonSerExt, Message, NonEmpty,
+        QuerierExt, ReceiveEventOutcome, ResultExt, SearchEvent, TxOutcome,
+    },
+    grug_vm_rust::ContractBuilder,
+};
+
+/// A contract that emits events on demand.
+mod emitter {
+    use {
+        grug_types::{Empty, EventName, MutableCtx, Response, StdError, StdResult},
+        serde::{Deserialize, Serialize},
+    };
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Ping {
+        pub n: u32,
+    }
+
+    impl EventName for Ping {
+        const EVENT_NAME: &'static str = "ping";
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Pong {
+        pub n: u32,
+    }
+
+    impl EventName for Pong {
+        const EVENT_NAME: &'static str = "pong";
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ExecuteMsg {
+        Ping {
+            n: u32,
+        },
+        Pong {
+            n: u32,
+        },
+        /// Emit a ping event, then fail.
+        PingAndFail {
+            n: u32,
+        },
+    }
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    pub fn execute(_ctx: MutableCtx, msg: ExecuteMsg) -> StdResult<Response> {
+        match msg {
+            ExecuteMsg::Ping { n } => Response::new().add_event(Ping { n }),
+            ExecuteMsg::Pong { n } => Response::new().add_event(Pong { n }),
+            ExecuteMsg::PingAndFail { n } => {
+                Response::new().add_event(Ping { n })?;
+                Err(StdError::host("intentional failure".to_string()))
+            },
+        }
+    }
+}
+
+/// A contract that subscribes to events, and records the ones it's notified of.
+///
+/// It fails to receive ping events with the number specified at instantiation,
+/// and emits a ping event itself for each event it receives.
+mod subscriber {
+    use {
+        grug_storage::Item,
+        grug_types::{
+            Addr, CheckedContractEvent, JsonDeExt, Message, MutableCtx, Response, StdError,
+            StdResult, SudoCtx,
+        },
+        serde::{Deserialize, Serialize},
+    };
+
+    pub const RECEIVED: Item<Vec<CheckedContractEvent>> = Item::new("received");
+
+    const FAIL_ON: Item<u32> = Item::new("fail_on");
+
+    #[derive(Serialize, Deserialize)]
+    pub struct InstantiateMsg {
+        pub fail_on: u32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ExecuteMsg {
+        Subscribe {
+            emitter: Addr,
+            event_type: String,
+            gas_limit: u64,
+            gas_budget: u64,
+        },
+        Unsubscribe {
+            emitter: Addr,
+            event_type: String,
+        },
+    }
+
+    pub fn instantiate(ctx: MutableCtx, msg: InstantiateMsg) -> StdResult<Response> {
+        FAIL_ON.save(ctx.storage, &msg.fail_on)?;
+
+        Ok(Response::new())
+    }
+
+    pub fn execute(_ctx: MutableCtx, msg: ExecuteMsg) -> StdResult<Response> {
+        let msg = match msg {
+            ExecuteMsg::Subscribe {
+                emitter,
+                event_type,
+                gas_limit,
+                gas_budget,
+            } => Message::subscribe(emitter, event_type, gas_limit, gas_budget),
+            ExecuteMsg::Unsubscribe {
+                emitter,
+                event_type,
+            } => Message::unsubscribe(emitter, event_type),
+        };
+
+        Ok(Response::new().add_message(msg))
+    }
+
+    pub fn receive_event(ctx: SudoCtx, event: CheckedContractEvent) -> StdResult<Response> {
+        // Ping and pong events have the same data format.
+        let n = event
+            .data
+            .clone()
+            .deserialize_json::<super::emitter::Ping>()?
+            .n;
+
+        RECEIVED.may_update(ctx.storage, |received| -> StdResult<_> {
+            let mut received = received.unwrap_or_default();
+            received.push(event.clone());
+            Ok(received)
+        })?;
+
+        if event.ty == "ping" && n == FAIL_ON.load(ctx.storage)? {
+            return Err(StdError::host("intentional failure".to_string()));
+        }
+
+        Response::new().add_event(super::emitter::Ping { n })
+    }
+}
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+const GAS_BUDGET: u64 = 10 * GAS_LIMIT;
+
+const TX_GAS_LIMIT: u64 = 50_000_000;
+
+fn setup() -> (TestSuite, TestAccounts, Addr, Addr, Addr) {
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .add_account("larry", Coins::new())
+        .add_account("jake", Coins::new())
+        .set_owner("larry")
+        .set_default_gas_limit(TX_GAS_LIMIT)
+        .build();
+
+    let emitter_code = ContractBuilder::new(Box::new(emitter::instantiate))
+        .with_execute(Box::new(emitter::execute))
+        .build();
+
+    let emitter = suite
+        .upload_and_instantiate(
+            &mut accounts["larry"],
+            emitter_code,
+            &Empty {},
+            "emitter",
+            Some("emitter"),
+            None,
+            Coins::new(),
+        )
+        .should_succeed()
+        .address;
+
+    let subscriber_code = ContractBuilder::new(Box::new(subscriber::instantiate))
+        .with_execute(Box::new(subscriber::execute))
+        .with_receive_event(Box::new(subscriber::receive_event))
+        .build();
+
+    let subscriber_code_hash = suite
+        .upload(&mut accounts["larry"], subscriber_code)
+        .should_succeed()
+        .code_hash;
+
+    let mut instantiate_subscriber = |salt: &str, fail_on: u32| {
+        suite
+            .instantiate(
+                &mut accounts["larry"],
+                subscriber_code_hash,
+                &subscriber::InstantiateMsg { fail_on },
+                salt,
+                Some(salt),
+                None,
+                Coins::new(),
+            )
+            .should_succeed()
+            .address
+    };
+
+    let alice = instantiate_subscriber("alice", 1);
+    let bob = instantiate_subscriber("bob", 2);
+
+    (suite, accounts, emitter, alice, bob)
+}
+
+fn subscribe(
+    suite: &mut TestSuite,
+    accounts: &mut TestAccounts,
+    subscriber: Addr,
+    emitter: Addr,
+    event_type: &str,
+) {
+    subscribe_with_gas(
+        suite, accounts, subscriber, emitter, event_type, GAS_LIMIT, GAS_BUDGET,
+    )
+    .should_succeed();
+}
+
+fn subscribe_with_gas(
+    suite: &mut TestSuite,
+    accounts: &mut TestAccounts,
+    subscriber: Addr,
+    emitter: Addr,
+    event_type: &str,
+    gas_limit: u64,
+    gas_budget: u64,
+) -> TxOutcome {
+    suite.execute(
+        &mut accounts["larry"],
+        subscriber,
+        &subscriber::ExecuteMsg::Subscribe {
+            emitter,
+            event_type: event_type.to_string(),
+            gas_limit,
+            gas_budget,
+        },
+        Coins::new(),
+    )
+}
+
+fn emit(
+    suite: &mut TestSuite,
+    accounts: &mut TestAccounts,
+    emitter: Addr,
+    msg: emitter::ExecuteMsg,
+) -> Vec<ReceiveEventOutcome> {
+    let tx = suite.sign_transaction(
+        &mut accounts["jake"],
+        NonEmpty::new_unchecked(vec![Message::execute(emitter, &msg, Coins::new()).unwrap()]),
+    );
+
+    suite
+        .make_block(vec![tx])
+        .block_outcome
+        .receive_event_outcomes
+}
+
+fn query_received(suite: &TestSuite, subscriber: Addr) -> Vec<CheckedContractEvent> {
+    suite
+        .query_wasm_raw(subscriber, subscriber::RECEIVED.storage_key())
+        .unwrap()
+        .map(|raw| raw.deserialize_borsh().unwrap())
+        .unwrap_or_default()
+}
+
+fn ping(emitter: Addr, n: u32) -> CheckedContractEvent {
+    CheckedContractEvent {
+        contract: emitter,
+        ty: "ping".to_string(),
+        data: emitter::Ping { n }.to_json_value().unwrap(),
+    }
+}
+
+fn subscriber_of(outcome: &ReceiveEventOutcome) -> Addr {
+    outcome
+        .receive_event
+        .clone()
+        .search_event::<FlatEvtReceiveEvent>()
+        .take()
+        .one()
+        .event
+        .contract
+}
+
+fn assert_received(outcome: &ReceiveEventOutcome, subscriber: Addr, event: &CheckedContractEvent) {
+    assert_eq!(outcome.gas_limit, GAS_LIMIT);
+    assert!(matches!(
+        &outcome.receive_event,
+        CommitmentStatus::Committed(EventStatus::Ok(evt))
+            if evt.contract == subscriber && evt.event == *event
+    ));
+}
+
+#[test]
+fn receiving_events() {
+    let (mut suite, mut accounts, emitter, alice, bob) = setup();
+
+    subscribe(&mut suite, &mut accounts, alice, emitter, "ping");
+    subscribe(&mut suite, &mut accounts, bob, emitter, "ping");
+    subscribe(&mut suite, &mut accounts, bob, emitter, "pong");
+
+    // Bob also subscribes to the events Alice emits when she is notified.
+    subscribe(&mut suite, &mut accounts, bob, alice, "ping");
+
+    // Both subscribers are notified of the ping event, in the order of their
+    // addresses.
+    let outcomes = emit(
+        &mut suite,
+        &mut accounts,
+        emitter,
+        emitter::ExecuteMsg::Ping { n: 0 },
+    );
+
+    let (first, second) = if alice < bob {
+        (alice, bob)
+    } else {
+        (bob, alice)
+    };
+
+    assert_eq!(outcomes.len(), 2);
+    assert_received(&outcomes[0], first, &ping(emitter, 0));
+    assert_received(&outcomes[1], second, &ping(emitter, 0));
+
+    // Only Bob is notified of the pong event.
+    let outcomes = emit(
+        &mut suite,
+        &mut accounts,
+        emitter,
+        emitter::ExecuteMsg::Pong { n: 0 },
+    );
+
+    assert_eq!(outcomes.len(), 1);
+    assert_eq!(subscriber_of(&outcomes[0]), bob);
+
+    // Events emitted by a failed transaction aren't notified.
+    let outcomes = emit(
+        &mut suite,
+        &mut accounts,
+        emitter,
+        emitter::ExecuteMsg::PingAndFail { n: 0 },
+    );
+
+    assert!(outcomes.is_empty());
+
+    // Alice emitted a ping event when notified, but events emitted during
+    // notifications don't trigger further notifications, so Bob never received
+    // it.
+    assert_eq!(query_received(&suite, alice), [ping(emitter, 0)]);
+    assert_eq!(query_received(&suite, bob).len(), 2);
+}
+
+#[test]
+fn failing_to_receive_events() {
+    let (mut suite, mut accounts, emitter, alice, bob) = setup();
+
+    subscribe(&mut suite, &mut accounts, alice, emitter, "ping");
+    subscribe(&mut suite, &mut accounts, bob, emitter, "ping");
+
+    // Alice fails to receive the event. Her state changes are discarded, while
+    // Bob isn't affected.
+    let outcomes = emit(
+        &mut suite,
+        &mut accounts,
+        emitter,
+        emitter::ExecuteMsg::Ping { n: 1 },
+    );
+
+    assert_eq!(outcomes.len(), 2);
+
+    for outcome in outcomes {
+        if subscriber_of(&outcome) == alice {
+            assert!(matches!(
+                outcome.receive_event,
+                CommitmentStatus::Failed { .. }
+            ));
+        } else {
+            assert_received(&outcome, bob, &ping(emitter, 1));
+        }
+    }
+
+    assert!(query_received(&suite, alice).is_empty());
+    assert_eq!(query_received(&suite, bob), [ping(emitter, 1)]);
+}
+
+#[test]
+fn unsubscribing() {
+    let (mut suite, mut accounts, emitter, alice, _) = setup();
+
+    subscribe(&mut suite, &mut accounts, alice, emitter, "ping");
+
+    suite
+        .execute(
+            &mut accounts["larry"],
+            alice,
+            &subscriber::ExecuteMsg::Unsubscribe {
+                emitter,
+                event_type: "ping".to_string(),
+            },
+            Coins::new(),
+        )
+        .should_succeed();
+
+    let outcomes = emit(
+        &mut suite,
+        &mut accounts,
+        emitter,
+        emitter::ExecuteMsg::Ping { n: 0 },
+    );
+
+    assert!(outcomes.is_empty());
+    assert!(query_received(&suite, alice).is_empty());
+
+    // A subscription can't be canceled twice.
+    suite
+        .execute(
+            &mut accounts["larry"],
+            alice,
+            &subscriber::ExecuteMsg::Unsubscribe {
+                emitter,
+                event_type: "ping".to_string(),
+            },
+            Coins::new(),
+        )
+        .should_fail_with_error(format!(
+            "subscription to event `ping` emitted by `{emitter}` not found"
+        ));
+}
+
+#[test]
+fn subscribing_to_non_contract_fails() {
+    let (mut suite, mut accounts, ..) = setup();
+
+    let not_contract = Addr::mock(123);
+
+    suite
+        .send_message(
+            &mut accounts["larry"],
+            Message::subscribe(not_contract, "ping", GAS_LIMIT, GAS_BUDGET),
+        )
+        .should_fail_with_error(format!("contract with address `{not_contract}` not found"));
+}
+
+#[test]
+fn subscribing_consumes_the_gas_budget() {
+    let (mut suite, mut accounts, emitter, alice, bob) = setup();
+
+    let gas_used_1 = subscribe_with_gas(
+        &mut suite,
+        &mut accounts,
+        alice,
+        emitter,
+        "ping",
+        GAS_LIMIT,
+        GAS_BUDGET,
+    )
+    .should_succeed()
+    .gas_used;
+
+    let gas_used_2 = subscribe_with_gas(
+        &mut suite,
+        &mut accounts,
+        bob,
+        emitter,
+        "ping",
+        GAS_LIMIT,
+        2 * GAS_BUDGET,
+    )
+    .should_succeed()
+    .gas_used;
+
+    // The sender pays for the gas budget of the subscription up front.
+    assert_eq!(gas_used_2 - gas_used_1, GAS_BUDGET);
+
+    // The budget must cover at least one notification.
+    subscribe_with_gas(
+        &mut suite,
+        &mut accounts,
+        alice,
+        emitter,
+        "pong",
+        GAS_LIMIT,
+        GAS_LIMIT - 1,
+    )
+    .should_fail_with_error("gas budget of subscription can't cover its gas limit");
+}
+
+#[test]
+fn subscribing_with_too_high_gas_limit_fails() {
+    let (mut suite, mut accounts, emitter, alice, _) = setup();
+
+    let max = suite
+        .query_config()
+        .should_succeed()
+        .end_block_limits
+        .max_subscription_gas_limit;
+
+    subscribe_with_gas(
+        &mut suite,
+        &mut accounts,
+        alice,
+        emitter,
+        "ping",
+        max + 1,
+        max + 1,
+    )
+    .should_fail_with_error("gas limit of subscription exceeds the maximum");
+}
+
+#[test]
+fn subscribing_with_too_low_gas_limit_fails() {
+    let (mut suite, mut accounts, emitter, alice, _) = setup();
+
+    let min = suite
+        .query_config()
+        .should_succeed()
+        .end_block_limits
+        .min_subscription_gas_limit;
+
+    subscribe_with_gas(
+        &mut suite,
+        &mut accounts,
+        alice,
+        emitter,
+        "ping",
+        min - 1,
+        GAS_BUDGET,
+    )
+    .should_fail_with_error("gas limit of subscription is below the minimum");
+
+    // A zero gas limit would never draw from the budget, so it's rejected even
+    // if the minimum is configured to be zero.
+    let cfg = suite.query_config().should_succeed();
+    suite
+        .configure(
+            &mut accounts["larry"],
+            Some(Config {
+                end_block_limits: EndBlockLimits {
+                    min_subscription_gas_limit: 0,
+                    ..cfg.end_block_limits
+                },
+                ..cfg
+            }),
+            None::<Empty>,
+        )
+        .should_succeed();
+
+    subscribe_with_gas(&mut suite, &mut accounts, alice, emitter, "ping", 0, 0)
+        .should_fail_with_error("gas limit of subscription is below the minimum");
+}
+
+#[test]
+fn subscription_ends_when_gas_budget_runs_out() {
+    let (mut suite, mut accounts, emitter, alice, _) = setup();
+
+    // Only pay for a single notification.
+    subscribe_with_gas(
+        &mut suite,
+        &mut accounts,
+        alice,
+        emitter,
+        "ping",
+        GAS_LIMIT,
+        GAS_LIMIT,
+    )
+    .should_succeed();
+
+    // Emit two events in the same block. Only the first one is notified, after
+    // which the budget is used up.
+    let tx = suite.sign_transaction(
+        &mut accounts["jake"],
+        NonEmpty::new_unchecked(vec![
+            Message::execute(emitter, &emitter::ExecuteMsg::Ping { n: 0 }, Coins::new()).unwrap(),
+            Message::execute(emitter, &emitter::ExecuteMsg::Ping { n: 3 }, Coins::new()).unwrap(),
+        ]),
+    );
+
+    let outcomes = suite
+        .make_block(vec![tx])
+        .block_outcome
+        .receive_event_outcomes;
+
+    assert_eq!(outcomes.len(), 1);
+    assert_received(&outcomes[0], alice, &ping(emitter, 0));
+
+    // The subscription has been removed.
+    let outcomes = emit(
+        &mut suite,
+        &mut accounts,
+        emitter,
+        emitter::ExecuteMsg::Ping { n: 0 },
+    );
+
+    assert!(outcomes.is_empty());
+    assert_eq!(query_received(&suite, alice), [ping(emitter, 0)]);
+}
+
+#[test]
+fn notification_gas_per_block_is_bounded() {
+    let (mut suite, mut accounts, emitter, alice, bob) = setup();
+
+    subscribe(&mut suite, &mut accounts, alice, emitter, "ping");
+    subscribe(&mut suite, &mut accounts, bob, emitter, "ping");
+
+    // Only allow one notification's worth of gas per block.
+    let cfg = suite.query_config().should_succeed();
+    suite
+        .configure(
+            &mut accounts["larry"],
+            Some(Config {
+                end_block_limits: EndBlockLimits {
+                    notification_gas_per_block: GAS_LIMIT,
+                    ..cfg.end_block_limits
+                },
+                ..cfg
+            }),
+            None::<Empty>,
+        )
+        .should_succeed();
+
+    // Only the first subscriber, by address, is notified. The notification of
+    // the second one is dropped.
+    let outcomes = emit(
+        &mut suite,
+        &mut accounts,
+        emitter,
+        emitter::ExecuteMsg::Ping { n: 0 },
+    );
+
+    assert_eq!(outcomes.len(), 1);
+    assert_received(&outcomes[0], alice.min(bob), &ping(emitter, 0));
+}
@@ -0,0 +1,222 @@
+use {
+    grug_testing::{TestAccounts, TestBuilder, TestSuite},
+    grug_types::{
+        Addr, Binary, Coins, Empty, Json, Message, NonEmpty, QuerierExt, ResultExt, UnsignedTx,
+    },
+    grug_vm_rust::ContractBuilder,
+    std::collections::BTreeSet,
+};
+
+mod account {
+    use grug_types::{AuthCtx, AuthResponse, Empty, MutableCtx, Response, StdResult, Tx};
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    // The mock account requires a credential, which simulated transactions
+    // don't have, so use one that accepts all transactions instead.
+    pub fn authenticate(_ctx: AuthCtx, _tx: Tx) -> StdResult<AuthResponse> {
+        Ok(AuthResponse::new())
+    }
+}
+
+/// A contract that counts how many times it has been called, either directly
+/// or by another instance of itself.
+mod counter {
+    use {
+        grug_storage::Item,
+        grug_types::{
+            Addr, Coins, Empty, ImmutableCtx, Json, JsonSerExt, Message, MutableCtx, Querier,
+            Query, Response, StdResult,
+        },
+        serde::{Deserialize, Serialize},
+    };
+
+    pub const COUNT: Item<u32> = Item::new("count");
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ExecuteMsg {
+        Increment {},
+        /// Increment the count; then, query the count of the other contract,
+        /// and ask it to increment its count as well.
+        Call {
+            other: Addr,
+        },
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum QueryMsg {
+        Count {},
+    }
+
+    pub fn instantiate(_ctx: MutableCtx, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    pub fn execute(ctx: MutableCtx, msg: ExecuteMsg) -> StdResult<Response> {
+        let count = COUNT.may_load(ctx.storage)?.unwrap_or(0);
+        COUNT.save(ctx.storage, &(count + 1))?;
+
+        match msg {
+            ExecuteMsg::Increment {} => Ok(Response::new()),
+            ExecuteMsg::Call { other } => {
+                ctx.querier
+                    .query_chain(Query::wasm_smart(other, &QueryMsg::Count {})?)?;
+
+                Ok(Response::new().add_message(Message::execute(
+                    other,
+                    &ExecuteMsg::Increment {},
+                    Coins::new(),
+                )?))
+            },
+        }
+    }
+
+    pub fn query(ctx: ImmutableCtx, msg: QueryMsg) -> StdResult<Json> {
+        match msg {
+            QueryMsg::Count {} => COUNT.may_load(ctx.storage)?.unwrap_or(0).to_json_value(),
+        }
+    }
+}
+
+fn setup() -> (TestSuite, TestAccounts, Addr, Addr) {
+    let account_code = ContractBuilder::new(Box::new(account::instantiate))
+        .with_authenticate(Box::new(account::authenticate))
+        .build();
+
+    let (mut suite, mut accounts) = TestBuilder::new()
+        .set_account_code(account_code, |_| Empty {})
+        .add_account("larry", Coins::new())
+        .set_owner("larry")
+        .build();
+
+    let code = ContractBuilder::new(Box::new(counter::instantiate))
+        .with_execute(Box::new(counter::execute))
+        .with_query(Box::new(counter::query))
+        .build();
+
+    let caller = suite
+        .upload_and_instantiate(
+            &mut accounts["larry"],
+            code,
+            &Empty {},
+            "caller",
+            Some("caller"),
+            None,
+            Coins::new(),
+        )
+        .should_succeed();
+
+    let callee = suite
+        .instantiate(
+            &mut accounts["larry"],
+            caller.code_hash,
+            &Empty {},
+            "callee",
+            Some("callee"),
+            None,
+            Coins::new(),
+        )
+        .should_succeed()
+        .address;
+
+    (suite, accounts, caller.address, callee)
+}
+
+fn keys(keys: &[&[u8]]) -> BTreeSet<Binary> {
+    keys.iter().map(|key| key.to_vec().into()).collect()
+}
+
+#[test]
+fn tracing_contract_calls() {
+    let (suite, accounts, caller, callee) = setup();
+
+    let (outcome, trace) = suite.simulate_tx_with_trace(UnsignedTx {
+        sender: accounts["larry"].address,
+        fee_payer: None,
+        msgs: NonEmpty::new_unchecked(vec![
+            Message::execute(
+                caller,
+                &counter::ExecuteMsg::Call { other: callee },
+                Coins::new(),
+            )
+            .unwrap(),
+        ]),
+        data: Json::null(),
+    });
+
+    outcome.should_succeed();
+
+    // The calls to the taxman and the sender account are also recorded.
+    assert_eq!(
+        trace
+            .calls
+            .iter()
+            .map(|call| call.entry_point.as_str())
+            .collect::<Vec<_>>(),
+        ["withhold_fee", "authenticate", "execute", "finalize_fee"]
+    );
+
+    let call = &trace.calls[2];
+
+    assert_eq!(call.contract, caller);
+    assert_eq!(call.reads, keys(&[counter::COUNT.storage_key()]));
+    assert_eq!(call.writes, keys(&[counter::COUNT.storage_key()]));
+
+    // Both the query and the submessage are recorded as children of the call
+    // that made them, with their own storage keys.
+    assert_eq!(call.children.len(), 2);
+
+    let query = &call.children[0];
+
+    assert_eq!(query.contract, callee);
+    assert_eq!(query.entry_point, "query");
+    assert_eq!(query.reads, keys(&[counter::COUNT.storage_key()]));
+    assert!(query.writes.is_empty());
+    assert!(query.children.is_empty());
+
+    let execute = &call.children[1];
+
+    assert_eq!(execute.contract, callee);
+    assert_eq!(execute.entry_point, "execute");
+    assert_eq!(execute.reads, keys(&[counter::COUNT.storage_key()]));
+    assert_eq!(execute.writes, keys(&[counter::COUNT.storage_key()]));
+    assert!(execute.children.is_empty());
+
+    // Simulation doesn't change the state.
+    suite
+        .query_wasm_raw(callee, counter::COUNT.storage_key())
+        .should_succeed_and(|count| count.is_none());
+}
+
+#[test]
+fn tracing_gas_outside_contract_calls() {
+    let (suite, accounts, ..) = setup();
+
+    let code = ContractBuilder::new(Box::new(counter::instantiate)).build();
+
+    let (outcome, trace) = suite.simulate_tx_with_trace(UnsignedTx {
+        sender: accounts["larry"].address,
+        fee_payer: None,
+        msgs: NonEmpty::new_unchecked(vec![Message::upload(code)]),
+        data: Json::null(),
+    });
+
+    let outcome = outcome.should_succeed();
+
+    // The Rust VM doesn't meter gas, so all gas is consumed by the app itself
+    // for storing the code.
+    assert!(trace.gas_by_category.contains_key("db_write"));
+    assert_eq!(trace.gas_used(), outcome.gas_used);
+
+    let folded_stacks = trace.to_folded_stacks();
+
+    assert!(
+        folded_stacks
+            .lines()
+            .any(|line| line.starts_with("db_write "))
+    );
+}
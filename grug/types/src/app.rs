@@ -1,5 +1,5 @@
 use {
-    crate::{Addr, Duration, Hash256, Json, Label, Message, Timestamp, Tx},
+    crate::{Addr, Duration, GasCosts, Hash256, Json, Label, Message, Timestamp, Tx},
     borsh::{BorshDeserialize, BorshSerialize},
     hex_literal::hex,
     serde::{Deserialize, Serialize},
@@ -51,6 +51,7 @@ pub struct GenesisState {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Config {
     /// The account that can update this config.
     pub owner: Addr,
@@ -66,10 +67,69 @@ pub struct Config {
     /// A code is deleted if it remains orphaned (not used by any contract) for
     /// longer than this duration.
     pub max_orphan_age: Duration,
+    /// The amount of gas charged for host operations.
+    ///
+    /// Defaults to [`DEFAULT_GAS_COSTS`](crate::DEFAULT_GAS_COSTS) if omitted
+    /// from the genesis file. Boxed, as it's much bigger than the other fields.
+    #[serde(default)]
+    pub gas_costs: Box<GasCosts>,
+    /// Limits on the work performed at the end of each block on behalf of
+    /// contracts.
+    ///
+    /// Defaults to [`DEFAULT_END_BLOCK_LIMITS`] if omitted from the genesis
+    /// file.
+    #[serde(default)]
+    pub end_block_limits: EndBlockLimits,
+}
+
+/// The default limits on the work performed at the end of each block.
+pub const DEFAULT_END_BLOCK_LIMITS: EndBlockLimits = EndBlockLimits {
+    max_scheduled_gas_limit: 10_000_000,
+    scheduled_gas_per_block: 100_000_000,
+    min_subscription_gas_limit: 100_000,
+    max_subscription_gas_limit: 10_000_000,
+    notification_gas_per_block: 100_000_000,
+};
+
+/// Limits on the work performed at the end of each block on behalf of
+/// contracts, which isn't otherwise bounded by any transaction's gas limit.
+#[derive(
+    Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EndBlockLimits {
+    /// Maximum gas limit of a scheduled message.
+    pub max_scheduled_gas_limit: u64,
+    /// Maximum sum of the gas limits of the scheduled messages performed in a
+    /// block. Messages that are due but don't fit are deferred to the following
+    /// blocks, in the order they were scheduled.
+    pub scheduled_gas_per_block: u64,
+    /// Minimum gas limit of an event subscription.
+    ///
+    /// Finding the subscribed events and calling the subscriber costs work
+    /// even if the call runs out of gas right away. This minimum ensures every
+    /// notification draws from the subscription's gas budget. It also counts
+    /// towards `notification_gas_per_block` for subscriptions made with a lower
+    /// gas limit, before the minimum was raised.
+    pub min_subscription_gas_limit: u64,
+    /// Maximum gas limit of an event subscription, i.e. of each call to the
+    /// subscriber's `receive_event` entry point.
+    pub max_subscription_gas_limit: u64,
+    /// Maximum sum of the gas limits of the event notifications performed in a
+    /// block. Notifications that don't fit are dropped.
+    pub notification_gas_per_block: u64,
+}
+
+impl Default for EndBlockLimits {
+    fn default() -> Self {
+        DEFAULT_END_BLOCK_LIMITS
+    }
 }
 
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Permissions {
     pub upload: Permission,
     pub instantiate: Permission,
@@ -77,6 +137,7 @@ pub struct Permissions {
 
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Permission {
     /// Only the owner can perform the action. Note, the owner is always able to
     /// upload code or instantiate contracts.
@@ -91,6 +152,7 @@ pub enum Permission {
     Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq,
 )]
 #[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BlockInfo {
     pub height: u64,
     pub timestamp: Timestamp,
@@ -107,6 +169,7 @@ pub struct Block {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ContractInfo {
     pub code_hash: Hash256,
     pub label: Option<Label>,
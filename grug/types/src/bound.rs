@@ -140,6 +140,25 @@ where
     }
 }
 
+#[cfg(feature = "schemars")]
+impl<T, B> schemars::JsonSchema for Bounded<T, B>
+where
+    T: PartialOrd + ToString + schemars::JsonSchema,
+    B: Bounds<T>,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        T::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        generator.subschema_for::<T>()
+    }
+}
+
 impl<T, B> BorshSerialize for Bounded<T, B>
 where
     T: PartialOrd + ToString + BorshSerialize,
@@ -142,7 +142,11 @@ where
     }
 }
 
-struct Merged<'a, B, P>
+/// An iterator that merges records from a base storage with pending ops on top
+/// of it. Records in the base are overridden by ops of the same keys.
+///
+/// Both iterators must be sorted in the given order.
+pub struct Merged<'a, B, P>
 where
     B: Iterator<Item = Record>,
     P: Iterator<Item = (&'a Vec<u8>, &'a Op)>,
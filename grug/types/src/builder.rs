@@ -23,6 +23,7 @@ use {
     PartialOrd,
     Ord,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Undefined<T = ()>(PhantomData<T>);
 
 impl<T> Undefined<T> {
@@ -51,6 +52,7 @@ impl<T> Default for Undefined<T> {
     PartialOrd,
     Ord,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Defined<T>(T);
 
 impl<T> Defined<T> {
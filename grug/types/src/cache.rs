@@ -34,3 +34,27 @@ where
         Ok(self.data.get(k).unwrap())
     }
 }
+
+/// Statistics about the usage of a cache instance.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self { hits: 0, misses: 0 }
+    }
+
+    pub fn increment_hits(&mut self) {
+        // In practice, it's unlikely a cache is hit `usize::MAX` times, but we
+        // still use saturating add to avoid panicking on overflow.
+        self.hits = self.hits.saturating_add(1);
+    }
+
+    pub fn increment_misses(&mut self) {
+        // Same as above, use saturating add to avoid panicking on overflow.
+        self.misses = self.misses.saturating_add(1);
+    }
+}
@@ -16,6 +16,7 @@ use {
 /// This struct implements a custom deserialization method that ensures there's
 /// no intersection between the keys to be added and those to be removed.
 #[derive(Serialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ChangeSet<K, V> {
     /// For adding new key-value pairs, or updating the values associated with
     /// existing keys.
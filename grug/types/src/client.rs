@@ -9,10 +9,10 @@ pub use {block::*, broadcast::*, options::*, query::*, search_tx::*};
 use {
     crate::{
         Binary, Block, BlockOutcome, BroadcastTxOutcome, Hash256, Proof, Query, QueryResponse,
-        SearchTxOutcome, StdError, Tx, TxOutcome, UnsignedTx,
+        QueryStoreResponse, SearchTxOutcome, StateOverrides, StdError, Tx, TxOutcome, UnsignedTx,
     },
     async_trait::async_trait,
-    std::sync::Arc,
+    std::{collections::BTreeMap, sync::Arc},
 };
 
 pub trait Client<E, P>:
@@ -70,12 +70,26 @@ where
         key: Binary,
         height: Option<u64>,
         prove: bool,
-    ) -> Result<(Option<Binary>, Option<Self::Proof>), Self::Error> {
+    ) -> Result<QueryStoreResponse<Self::Proof>, Self::Error> {
         self.client.query_store(key, height, prove).await
     }
 
-    async fn simulate(&self, tx: UnsignedTx) -> Result<TxOutcome, Self::Error> {
-        self.client.simulate(tx).await
+    async fn query_store_scan(
+        &self,
+        min: Option<Binary>,
+        max: Option<Binary>,
+        limit: Option<u32>,
+        height: Option<u64>,
+    ) -> Result<BTreeMap<Binary, Binary>, Self::Error> {
+        self.client.query_store_scan(min, max, limit, height).await
+    }
+
+    async fn simulate_with_overrides(
+        &self,
+        tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> Result<TxOutcome, Self::Error> {
+        self.client.simulate_with_overrides(tx, overrides).await
     }
 }
 
@@ -1,7 +1,8 @@
 use {
     crate::{
         Addr, Binary, Code, Coins, Config, ContractInfo, Denom, Hash256, JsonDeExt, Query,
-        QueryRequest, QueryResponse, QueryStatusResponse, StdError, TxOutcome, UnsignedTx,
+        QueryRequest, QueryResponse, QueryStatusResponse, QueryStoreResponse, StateOverrides,
+        StdError, TxOutcome, UnsignedTx,
     },
     async_trait::async_trait,
     grug_math::Uint128,
@@ -20,14 +21,38 @@ pub trait QueryClient: Send + Sync {
         height: Option<u64>,
     ) -> Result<QueryResponse, Self::Error>;
 
+    /// Query a raw key in the chain's key-value store at the given height, or
+    /// the latest height if unspecified, optionally with a Merkle proof.
     async fn query_store(
         &self,
         key: Binary,
         height: Option<u64>,
         prove: bool,
-    ) -> Result<(Option<Binary>, Option<Self::Proof>), Self::Error>;
+    ) -> Result<QueryStoreResponse<Self::Proof>, Self::Error>;
 
-    async fn simulate(&self, tx: UnsignedTx) -> Result<TxOutcome, Self::Error>;
+    /// Enumerate raw key-value pairs in the chain's key-value store, including
+    /// both the app's own state and contracts' internal states, at the given
+    /// height, or the latest height if unspecified.
+    async fn query_store_scan(
+        &self,
+        min: Option<Binary>,
+        max: Option<Binary>,
+        limit: Option<u32>,
+        height: Option<u64>,
+    ) -> Result<BTreeMap<Binary, Binary>, Self::Error>;
+
+    async fn simulate(&self, tx: UnsignedTx) -> Result<TxOutcome, Self::Error> {
+        self.simulate_with_overrides(tx, StateOverrides::default())
+            .await
+    }
+
+    /// Simulate a transaction, with the given changes applied to the chain's
+    /// state beforehand.
+    async fn simulate_with_overrides(
+        &self,
+        tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> Result<TxOutcome, Self::Error>;
 }
 
 #[async_trait]
@@ -25,6 +25,7 @@ pub struct CoinRefMut<'a> {
 /// A coin, defined by a denomincation ("denom") and an amount.
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Clone, PartialEq, Eq)]
 #[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Coin {
     pub denom: Denom,
     pub amount: Uint128,
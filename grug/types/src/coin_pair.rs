@@ -22,6 +22,7 @@ macro_rules! coin_pair {
 
 /// A _sorted_ pair of coins of distinct denoms and possibly zero amounts.
 #[derive(Serialize, BorshSerialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CoinPair([Coin; 2]);
 
 impl CoinPair {
@@ -24,6 +24,7 @@ macro_rules! coins {
 #[derive(
     Serialize, Deserialize, BorshSerialize, BorshDeserialize, Default, Clone, PartialEq, Eq,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Coins(BTreeMap<Denom, Uint128>);
 
 impl Coins {
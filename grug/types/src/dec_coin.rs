@@ -15,6 +15,7 @@ use {
 
 /// Like `Coin` but the amount is a decimal.
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DecCoin<const S: u32> {
     pub denom: Denom,
     pub amount: Dec<u128, S>,
@@ -29,6 +30,7 @@ impl<const S: u32> From<(Denom, Dec<u128, S>)> for DecCoin<S> {
 #[derive(
     Serialize, Deserialize, BorshSerialize, BorshDeserialize, Default, Debug, Clone, PartialEq, Eq,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DecCoins<const S: u32>(BTreeMap<Denom, Dec<u128, S>>);
 
 impl<const S: u32> DecCoins<S> {
@@ -18,6 +18,7 @@ use {
 
 /// A non-empty, alphanumeric string; makes up coin denoms.
 #[derive(Serialize, BorshSerialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Part(String);
 
 impl Part {
@@ -280,6 +281,19 @@ impl<'de> de::Deserialize<'de> for Denom {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Denom {
+    fn schema_name() -> String {
+        "Denom".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::schema::string_schema(
+            "Denomination of a coin, consisting of one or more alphanumeric parts separated by `/`.",
+        )
+    }
+}
+
 impl BorshSerialize for Denom {
     fn serialize<W>(&self, writer: &mut W) -> io::Result<()>
     where
@@ -306,6 +306,25 @@ where
     }
 }
 
+#[cfg(feature = "schemars")]
+impl<B, E> schemars::JsonSchema for EncodedBytes<B, E>
+where
+    B: Bytes,
+    E: Encoder,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        E::NAME.to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::schema::string_schema(&format!("A byte slice in {} encoding.", E::NAME))
+    }
+}
+
 impl<B, E> BorshSerialize for EncodedBytes<B, E>
 where
     B: Bytes,
@@ -16,6 +16,9 @@ pub enum VerificationError {
 
     #[error("signature is unauthentic")]
     Unauthentic,
+
+    #[error("invalid elliptic curve point")]
+    InvalidPoint,
 }
 
 impl VerificationError {
@@ -26,7 +29,8 @@ impl VerificationError {
             1 => Self::IncorrectLength,
             2 => Self::InvalidRecoveryId,
             3 => Self::Unauthentic,
-            _ => unreachable!("unknown verification error code: {error_code}, must be 1-3"),
+            4 => Self::InvalidPoint,
+            _ => unreachable!("unknown verification error code: {error_code}, must be 1-4"),
         }
     }
 }
@@ -78,6 +82,15 @@ pub enum StdError {
     #[error("invalid change set: the add and remove sets must be disjoint")]
     InvalidChangeSet,
 
+    #[error("height {height} is not checkpointed")]
+    NotCheckpointed { height: u64 },
+
+    #[error("no migration path from storage version {from} to {to}")]
+    NoMigrationPath { from: u32, to: u32 },
+
+    #[error("storage migration from version {version} to {target} is in progress")]
+    MigrationInProgress { version: u32, target: u32 },
+
     #[error("value out of range: {value} {comparator} {bound}")]
     OutOfRange {
         value: String,
@@ -175,6 +188,18 @@ impl StdError {
         }
     }
 
+    pub fn not_checkpointed(height: u64) -> Self {
+        Self::NotCheckpointed { height }
+    }
+
+    pub fn no_migration_path(from: u32, to: u32) -> Self {
+        Self::NoMigrationPath { from, to }
+    }
+
+    pub fn migration_in_progress(version: u32, target: u32) -> Self {
+        Self::MigrationInProgress { version, target }
+    }
+
     pub fn out_of_range<T>(value: T, comparator: &'static str, bound: T) -> Self
     where
         T: ToString,
@@ -1,6 +1,7 @@
 use crate::{
-    CheckedContractEvent, EvtConfigure, EvtUpload, FlatEvent, FlatEvtAuthenticate, FlatEvtBackrun,
-    FlatEvtCron, FlatEvtExecute, FlatEvtFinalize, FlatEvtGuest, FlatEvtInstantiate, FlatEvtMigrate,
+    CheckedContractEvent, EvtConfigure, EvtSchedule, EvtSubscribe, EvtUnschedule, EvtUnsubscribe,
+    EvtUpload, FlatEvent, FlatEvtAuthenticate, FlatEvtBackrun, FlatEvtCron, FlatEvtExecute,
+    FlatEvtFinalize, FlatEvtGuest, FlatEvtInstantiate, FlatEvtMigrate, FlatEvtReceiveEvent,
     FlatEvtReply, FlatEvtTransfer, FlatEvtWithhold,
 };
 
@@ -44,4 +45,9 @@ impl_as_variant! {
     Cron          => FlatEvtCron,
     Guest         => FlatEvtGuest,
     ContractEvent => CheckedContractEvent,
+    Schedule      => EvtSchedule,
+    Unschedule    => EvtUnschedule,
+    Subscribe     => EvtSubscribe,
+    Unsubscribe   => EvtUnsubscribe,
+    ReceiveEvent  => FlatEvtReceiveEvent,
 }
@@ -5,8 +5,9 @@ use sea_orm::entity::prelude::*;
 use {
     super::FlattenStatus,
     crate::{
-        Addr, CheckedContractEvent, Coins, CommitmentStatus, EvtConfigure, EvtUpload, Hash256,
-        Json, Label, ReplyOn, Timestamp, TxEvents,
+        Addr, CheckedContractEvent, Coins, CommitmentStatus, EvtConfigure, EvtSchedule,
+        EvtSubscribe, EvtUnschedule, EvtUnsubscribe, EvtUpload, Hash256, Json, Label, ReplyOn,
+        Timestamp, TxEvents,
     },
     borsh::{BorshDeserialize, BorshSerialize},
     serde::{Deserialize, Serialize},
@@ -209,6 +210,16 @@ pub enum FlatEvent {
     Guest(FlatEvtGuest),
     /// A contract event was emitted.
     ContractEvent(CheckedContractEvent),
+    /// A contract execution was scheduled.
+    Schedule(EvtSchedule),
+    /// A scheduled contract execution was canceled.
+    Unschedule(EvtUnschedule),
+    /// A contract subscribed to a type of event emitted by another contract.
+    Subscribe(EvtSubscribe),
+    /// An event subscription was canceled.
+    Unsubscribe(EvtUnsubscribe),
+    /// A contract was notified of an event it subscribed to.
+    ReceiveEvent(FlatEvtReceiveEvent),
 }
 
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
@@ -285,6 +296,13 @@ pub struct FlatEvtCron {
     pub next: Timestamp,
 }
 
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FlatEvtReceiveEvent {
+    pub contract: Addr,
+    /// The event the contract was notified of.
+    pub event: CheckedContractEvent,
+}
+
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct FlatEvtGuest {
     pub contract: Addr,
@@ -327,6 +345,9 @@ pub fn flatten_tx_events(tx_events: TxEvents, block_id: u64, tx_id: u32) -> Vec<
     let events = flatten_commitment_status(&mut next_id, tx_events.authenticate);
     flat_events.extend(events);
 
+    let events = flatten_commitment_status(&mut next_id, tx_events.authenticate_fee_payer);
+    flat_events.extend(events);
+
     let events = flatten_commitment_status(&mut next_id, tx_events.msgs_and_backrun);
     flat_events.extend(events);
 
@@ -1,10 +1,11 @@
 use crate::{
     Event, EventId, EventStatus, EvtAuthenticate, EvtBackrun, EvtConfigure, EvtCron, EvtExecute,
-    EvtFinalize, EvtGuest, EvtInstantiate, EvtMigrate, EvtReply, EvtTransfer, EvtUpload,
-    EvtWithhold, FlatCategory, FlatCommitmentStatus, FlatEvent, FlatEventInfo, FlatEventStatus,
-    FlatEvtAuthenticate, FlatEvtBackrun, FlatEvtCron, FlatEvtExecute, FlatEvtFinalize,
-    FlatEvtGuest, FlatEvtInstantiate, FlatEvtMigrate, FlatEvtReply, FlatEvtTransfer,
-    FlatEvtWithhold, MsgsAndBackrunEvents, SubEvent, SubEventStatus,
+    EvtFinalize, EvtGuest, EvtInstantiate, EvtMigrate, EvtReceiveEvent, EvtReply, EvtSchedule,
+    EvtSubscribe, EvtTransfer, EvtUnschedule, EvtUnsubscribe, EvtUpload, EvtWithhold, FlatCategory,
+    FlatCommitmentStatus, FlatEvent, FlatEventInfo, FlatEventStatus, FlatEvtAuthenticate,
+    FlatEvtBackrun, FlatEvtCron, FlatEvtExecute, FlatEvtFinalize, FlatEvtGuest, FlatEvtInstantiate,
+    FlatEvtMigrate, FlatEvtReceiveEvent, FlatEvtReply, FlatEvtTransfer, FlatEvtWithhold,
+    MsgsAndBackrunEvents, SubEvent, SubEventStatus,
 };
 
 pub trait Flatten {
@@ -171,6 +172,18 @@ impl Flatten for Event {
             Event::Migrate(evt_migrate) => {
                 evt_migrate.flatten(parent_id, next_id, commitment, status)
             },
+            Event::Schedule(evt_schedule) => {
+                evt_schedule.flatten(parent_id, next_id, commitment, status)
+            },
+            Event::Unschedule(evt_unschedule) => {
+                evt_unschedule.flatten(parent_id, next_id, commitment, status)
+            },
+            Event::Subscribe(evt_subscribe) => {
+                evt_subscribe.flatten(parent_id, next_id, commitment, status)
+            },
+            Event::Unsubscribe(evt_unsubscribe) => {
+                evt_unsubscribe.flatten(parent_id, next_id, commitment, status)
+            },
             Event::Reply(evt_reply) => evt_reply.flatten(parent_id, next_id, commitment, status),
             Event::Authenticate(evt_authenticate) => {
                 evt_authenticate.flatten(parent_id, next_id, commitment, status)
@@ -185,6 +198,9 @@ impl Flatten for Event {
                 evt_finalize.flatten(parent_id, next_id, commitment, status)
             },
             Event::Cron(evt_cron) => evt_cron.flatten(parent_id, next_id, commitment, status),
+            Event::ReceiveEvent(evt_receive_event) => {
+                evt_receive_event.flatten(parent_id, next_id, commitment, status)
+            },
         }
     }
 }
@@ -382,6 +398,78 @@ impl Flatten for EvtMigrate {
     }
 }
 
+impl Flatten for EvtSchedule {
+    fn flatten(
+        self,
+        parent_id: &EventId,
+        next_id: &mut EventId,
+        commitment: FlatCommitmentStatus,
+        status: FlatEventStatus,
+    ) -> Vec<FlatEventInfo> {
+        vec![FlatEventInfo {
+            id: next_id.clone(),
+            parent_id: parent_id.clone(),
+            commitment_status: commitment,
+            event_status: status,
+            event: FlatEvent::Schedule(self),
+        }]
+    }
+}
+
+impl Flatten for EvtUnschedule {
+    fn flatten(
+        self,
+        parent_id: &EventId,
+        next_id: &mut EventId,
+        commitment: FlatCommitmentStatus,
+        status: FlatEventStatus,
+    ) -> Vec<FlatEventInfo> {
+        vec![FlatEventInfo {
+            id: next_id.clone(),
+            parent_id: parent_id.clone(),
+            commitment_status: commitment,
+            event_status: status,
+            event: FlatEvent::Unschedule(self),
+        }]
+    }
+}
+
+impl Flatten for EvtSubscribe {
+    fn flatten(
+        self,
+        parent_id: &EventId,
+        next_id: &mut EventId,
+        commitment: FlatCommitmentStatus,
+        status: FlatEventStatus,
+    ) -> Vec<FlatEventInfo> {
+        vec![FlatEventInfo {
+            id: next_id.clone(),
+            parent_id: parent_id.clone(),
+            commitment_status: commitment,
+            event_status: status,
+            event: FlatEvent::Subscribe(self),
+        }]
+    }
+}
+
+impl Flatten for EvtUnsubscribe {
+    fn flatten(
+        self,
+        parent_id: &EventId,
+        next_id: &mut EventId,
+        commitment: FlatCommitmentStatus,
+        status: FlatEventStatus,
+    ) -> Vec<FlatEventInfo> {
+        vec![FlatEventInfo {
+            id: next_id.clone(),
+            parent_id: parent_id.clone(),
+            commitment_status: commitment,
+            event_status: status,
+            event: FlatEvent::Unsubscribe(self),
+        }]
+    }
+}
+
 impl Flatten for EvtBackrun {
     fn flatten(
         self,
@@ -509,6 +597,37 @@ impl Flatten for EvtCron {
     }
 }
 
+impl Flatten for EvtReceiveEvent {
+    fn flatten(
+        self,
+        parent_id: &EventId,
+        next_id: &mut EventId,
+        commitment: FlatCommitmentStatus,
+        status: FlatEventStatus,
+    ) -> Vec<FlatEventInfo> {
+        let mut events = vec![FlatEventInfo {
+            id: next_id.clone(),
+            parent_id: parent_id.clone(),
+            commitment_status: commitment,
+            event_status: status,
+            event: FlatEvent::ReceiveEvent(FlatEvtReceiveEvent {
+                contract: self.contract,
+                event: self.event,
+            }),
+        }];
+
+        let parent_id = next_id.clone();
+        next_id.event_index += 1;
+
+        let guest = self
+            .guest_event
+            .flatten_status(&parent_id, next_id, commitment);
+
+        events.extend(guest);
+        events
+    }
+}
+
 impl Flatten for EvtAuthenticate {
     fn flatten(
         self,
@@ -1,6 +1,7 @@
 use {
     crate::{
-        Addr, CheckedContractEvent, Coins, EventStatus, Hash256, Json, Label, ReplyOn, Timestamp,
+        Addr, CheckedContractEvent, Coins, Due, EventStatus, Hash256, Json, Label, MsgSchedule,
+        ReplyOn, Timestamp,
     },
     borsh::{BorshDeserialize, BorshSerialize},
     paste::paste,
@@ -36,6 +37,16 @@ pub enum Event {
     Finalize(EvtFinalize),
     /// A cronjob was executed.
     Cron(EvtCron),
+    /// A contract execution was scheduled.
+    Schedule(EvtSchedule),
+    /// A scheduled contract execution was canceled.
+    Unschedule(EvtUnschedule),
+    /// A contract subscribed to a type of event emitted by another contract.
+    Subscribe(EvtSubscribe),
+    /// An event subscription was canceled.
+    Unsubscribe(EvtUnsubscribe),
+    /// A contract was notified of an event it subscribed to.
+    ReceiveEvent(EvtReceiveEvent),
     // TODO: IBC events
 }
 
@@ -75,6 +86,11 @@ impl Event {
         Withhold     => EvtWithhold,
         Finalize     => EvtFinalize,
         Cron         => EvtCron,
+        Schedule     => EvtSchedule,
+        Unschedule   => EvtUnschedule,
+        Subscribe    => EvtSubscribe,
+        Unsubscribe  => EvtUnsubscribe,
+        ReceiveEvent => EvtReceiveEvent,
     }
 
     pub fn reply(contract: Addr, reply_on: ReplyOn, guest_event: EventStatus<EvtGuest>) -> Self {
@@ -214,6 +230,61 @@ impl EvtMigrate {
     }
 }
 
+/// An event indicating that a contract execution was scheduled.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EvtSchedule {
+    pub sender: Addr,
+    /// The ID assigned to the scheduled execution, which can be used to cancel
+    /// it. `None` if scheduling failed.
+    pub id: Option<u64>,
+    pub due: Due,
+    pub gas_limit: u64,
+    pub contract: Addr,
+    pub funds: Coins,
+    pub execute_msg: Json,
+}
+
+impl EvtSchedule {
+    pub fn base(sender: Addr, msg: &MsgSchedule) -> Self {
+        Self {
+            sender,
+            id: None,
+            due: msg.due,
+            gas_limit: msg.gas_limit,
+            contract: msg.msg.contract,
+            funds: msg.msg.funds.clone(),
+            execute_msg: msg.msg.msg.clone(),
+        }
+    }
+}
+
+/// An event indicating that a scheduled contract execution was canceled.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EvtUnschedule {
+    pub sender: Addr,
+    pub id: u64,
+}
+
+/// An event indicating that a contract subscribed to a type of event emitted
+/// by another contract.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EvtSubscribe {
+    pub subscriber: Addr,
+    pub emitter: Addr,
+    pub event_type: String,
+    pub gas_limit: u64,
+    pub gas_budget: u64,
+}
+
+/// An event indicating that an event subscription was canceled.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EvtUnsubscribe {
+    pub subscriber: Addr,
+    pub emitter: Addr,
+    pub event_type: String,
+}
+
 /// An event indicating that a contract was replied the outcome of its submessage.
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct EvtReply {
@@ -330,6 +401,26 @@ impl EvtCron {
     }
 }
 
+/// An event indicating that a contract was notified of an event it subscribed to.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EvtReceiveEvent {
+    /// The subscriber.
+    pub contract: Addr,
+    /// The event the subscriber was notified of.
+    pub event: CheckedContractEvent,
+    pub guest_event: EventStatus<EvtGuest>,
+}
+
+impl EvtReceiveEvent {
+    pub fn base(contract: Addr, event: CheckedContractEvent) -> Self {
+        Self {
+            contract,
+            event,
+            guest_event: EventStatus::NotReached,
+        }
+    }
+}
+
 /// An event indicating that a contract emitted a custom event.
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct EvtGuest {
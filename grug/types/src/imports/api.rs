@@ -40,6 +40,21 @@ pub trait Api {
         compressed: bool,
     ) -> StdResult<Vec<u8>>;
 
+    /// Verify a batch of Secp256k1 signatures with the given hashed messages
+    /// and public keys.
+    ///
+    /// Note: this function takes the hashes of the messages, not the prehashes.
+    fn secp256k1_batch_verify(
+        &self,
+        msg_hashes: &[&[u8]],
+        sigs: &[&[u8]],
+        pks: &[&[u8]],
+    ) -> StdResult<()>;
+
+    /// Verify a BIP-340 Schnorr signature, as used by Bitcoin Taproot, with
+    /// the given 32-byte message and x-only public key.
+    fn secp256k1_schnorr_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()>;
+
     /// Verify an ED25519 signature with the given hashed message and public
     /// key.
     ///
@@ -56,6 +71,31 @@ pub trait Api {
         pks: &[&[u8]],
     ) -> StdResult<()>;
 
+    /// Verify a BLS12-381 signature with the given hashed message and public
+    /// key. The public key is a compressed G1 point, and the signature a
+    /// compressed G2 point.
+    ///
+    /// NOTE: This function takes the hash of the message, not the prehash.
+    fn bls12_381_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()>;
+
+    /// Verify an aggregate BLS12-381 signature, produced by the given public
+    /// keys all signing the same hashed message.
+    ///
+    /// The caller must make sure the public keys have been verified for proof
+    /// of possession, otherwise this is vulnerable to rogue key attacks.
+    ///
+    /// NOTE: This function takes the hash of the message, not the prehash.
+    fn bls12_381_aggregate_verify(
+        &self,
+        msg_hash: &[u8],
+        sig: &[u8],
+        pks: &[&[u8]],
+    ) -> StdResult<()>;
+
+    /// Check whether the product of the pairings of the given compressed G1
+    /// and G2 points equals the identity.
+    fn bls12_381_pairing_check(&self, g1s: &[&[u8]], g2s: &[&[u8]]) -> StdResult<()>;
+
     /// Perform the SHA2-256 hash.
     fn sha2_256(&self, data: &[u8]) -> [u8; 32];
 
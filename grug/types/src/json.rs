@@ -33,6 +33,7 @@ macro_rules! json {
 /// A wrapper over [`serde_json::Value`](serde_json::Value) that implements
 /// [Borsh](https://github.com/near/borsh-rs) traits.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Json(JsonValue);
 
 impl Json {
@@ -113,6 +113,24 @@ where
     }
 }
 
+#[cfg(feature = "schemars")]
+impl<T, const MIN: usize, const MAX: usize> schemars::JsonSchema for LengthBounded<T, MIN, MAX>
+where
+    T: Lengthy + schemars::JsonSchema,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        T::schema_name()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        generator.subschema_for::<T>()
+    }
+}
+
 impl<T, const MIN: usize, const MAX: usize> BorshSerialize for LengthBounded<T, MIN, MAX>
 where
     T: Lengthy + BorshSerialize,
@@ -22,6 +22,7 @@ mod encoders;
 mod error;
 mod events;
 mod ffi;
+mod gas;
 mod git_info;
 mod hash;
 mod hashers;
@@ -35,14 +36,18 @@ mod lengthy;
 mod macros;
 mod non_zero;
 mod outcome;
+mod overrides;
 mod query;
 mod response;
 mod result;
+#[cfg(feature = "schemars")]
+mod schema;
 mod serializers;
 mod shared;
 mod signer;
 mod status;
 mod time;
+mod trace;
 mod transfer;
 mod tx;
 mod unique_vec;
@@ -51,13 +56,16 @@ mod utils;
 pub use {
     address::*, app::*, bank::*, binary::*, bound::*, buffer::*, builder::*, bytes::*, cache::*,
     changeset::*, code::*, coin::*, coin_pair::*, coins::*, context::*, db::*, dec_coin::*,
-    denom::*, empty::*, encoded_bytes::*, encoders::*, error::*, events::*, ffi::*, git_info::*,
-    hash::*, hashers::*, imports::*, indexer::*, inner::*, jellyfish_merkle::*, json::*,
-    length_bounded::*, lengthy::*, non_zero::*, outcome::*, query::*, response::*, result::*,
-    serializers::*, shared::*, signer::*, status::*, time::*, transfer::*, tx::*, unique_vec::*,
-    utils::*,
+    denom::*, empty::*, encoded_bytes::*, encoders::*, error::*, events::*, ffi::*, gas::*,
+    git_info::*, hash::*, hashers::*, imports::*, indexer::*, inner::*, jellyfish_merkle::*,
+    json::*, length_bounded::*, lengthy::*, non_zero::*, outcome::*, overrides::*, query::*,
+    response::*, result::*, serializers::*, shared::*, signer::*, status::*, time::*, trace::*,
+    transfer::*, tx::*, unique_vec::*, utils::*,
 };
 
+#[cfg(feature = "schemars")]
+pub use schema::*;
+
 // ---------------------------------- testing ----------------------------------
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -74,4 +82,7 @@ pub use {client::*, testing::*};
 #[doc(hidden)]
 pub mod __private {
     pub use {::borsh, ::hex_literal, ::serde, ::serde_json, ::serde_with};
+
+    #[cfg(feature = "schemars")]
+    pub use ::schemars;
 }
@@ -15,6 +15,7 @@ use {
 
 /// A wrapper over a number that ensures it is non-zero.
 #[derive(Serialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct NonZero<T>(pub(crate) T)
 where
     T: IsZero;
@@ -4,8 +4,8 @@ use crate::serializers::JsonDeExt;
 use {crate::StdResult, data_encoding::BASE64};
 use {
     crate::{
-        CommitmentStatus, Event, EventStatus, EvtAuthenticate, EvtBackrun, EvtCron, EvtFinalize,
-        EvtWithhold, GenericResult, Hash256, ResultExt, Tx,
+        CommitmentStatus, Event, EventStatus, EvtAuthenticate, EvtBackrun, EvtCron, EvtExecute,
+        EvtFinalize, EvtReceiveEvent, EvtWithhold, GenericResult, Hash256, ResultExt, Tx,
     },
     borsh::{BorshDeserialize, BorshSerialize},
     serde::{Deserialize, Serialize},
@@ -98,6 +98,83 @@ impl CronOutcome {
     }
 }
 
+/// Outcome of performing a scheduled contract execution.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+#[must_use = "`Outcome` must be checked for success or error with `should_succeed`, `should_fail`, or similar methods."]
+pub struct ScheduledOutcome {
+    /// The ID that was assigned to the execution when it was scheduled.
+    pub id: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub execute_event: CommitmentStatus<EventStatus<EvtExecute>>,
+}
+
+impl ScheduledOutcome {
+    pub fn new(
+        id: u64,
+        gas_limit: u64,
+        gas_used: u64,
+        execute_event: CommitmentStatus<EventStatus<EvtExecute>>,
+    ) -> Self {
+        Self {
+            id,
+            gas_limit,
+            gas_used,
+            execute_event,
+        }
+    }
+}
+
+#[cfg(feature = "tendermint")]
+impl ScheduledOutcome {
+    pub fn from_tm_event(tm_event: tendermint::abci::Event) -> StdResult<Self> {
+        tm_event
+            .attributes
+            .first()
+            .unwrap()
+            .value_bytes()
+            .deserialize_json()
+    }
+}
+
+/// Outcome of notifying a contract of an event it subscribed to.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+#[must_use = "`Outcome` must be checked for success or error with `should_succeed`, `should_fail`, or similar methods."]
+pub struct ReceiveEventOutcome {
+    /// The gas limit that was specified when the subscription was made.
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub receive_event: CommitmentStatus<EventStatus<EvtReceiveEvent>>,
+}
+
+impl ReceiveEventOutcome {
+    pub fn new(
+        gas_limit: u64,
+        gas_used: u64,
+        receive_event: CommitmentStatus<EventStatus<EvtReceiveEvent>>,
+    ) -> Self {
+        Self {
+            gas_limit,
+            gas_used,
+            receive_event,
+        }
+    }
+}
+
+#[cfg(feature = "tendermint")]
+impl ReceiveEventOutcome {
+    pub fn from_tm_event(tm_event: tendermint::abci::Event) -> StdResult<Self> {
+        tm_event
+            .attributes
+            .first()
+            .unwrap()
+            .value_bytes()
+            .deserialize_json()
+    }
+}
+
 /// Outcome of processing a transaction.
 ///
 /// Different from `Outcome`, which can either succeed or fail, a transaction
@@ -186,6 +263,9 @@ impl Display for TxError {
 pub struct TxEvents {
     pub withhold: CommitmentStatus<EventStatus<EvtWithhold>>,
     pub authenticate: CommitmentStatus<EventStatus<EvtAuthenticate>>,
+    /// Authentication of the fee payer. `NotReached` if the transaction doesn't
+    /// have a fee payer.
+    pub authenticate_fee_payer: CommitmentStatus<EventStatus<EvtAuthenticate>>,
     pub msgs_and_backrun: CommitmentStatus<MsgsAndBackrunEvents>,
     pub finalize: CommitmentStatus<EventStatus<EvtFinalize>>,
 }
@@ -195,6 +275,7 @@ impl TxEvents {
         Self {
             withhold,
             authenticate: CommitmentStatus::NotReached,
+            authenticate_fee_payer: CommitmentStatus::NotReached,
             msgs_and_backrun: CommitmentStatus::NotReached,
             finalize: CommitmentStatus::NotReached,
         }
@@ -219,6 +300,7 @@ impl TxEvents {
         TxEvents {
             withhold: update(self.withhold, cause),
             authenticate: update(self.authenticate, cause),
+            authenticate_fee_payer: update(self.authenticate_fee_payer, cause),
             msgs_and_backrun: update(self.msgs_and_backrun, cause),
             finalize,
         }
@@ -229,6 +311,7 @@ impl TxEvents {
 pub struct CheckTxEvents {
     pub withhold: CommitmentStatus<EventStatus<EvtWithhold>>,
     pub authenticate: CommitmentStatus<EventStatus<EvtAuthenticate>>,
+    pub authenticate_fee_payer: CommitmentStatus<EventStatus<EvtAuthenticate>>,
 }
 
 impl CheckTxEvents {
@@ -236,6 +319,7 @@ impl CheckTxEvents {
         Self {
             withhold,
             authenticate: CommitmentStatus::NotReached,
+            authenticate_fee_payer: CommitmentStatus::NotReached,
         }
     }
 }
@@ -259,6 +343,7 @@ impl Default for TxEvents {
         Self {
             withhold: CommitmentStatus::NotReached,
             authenticate: CommitmentStatus::NotReached,
+            authenticate_fee_payer: CommitmentStatus::NotReached,
             msgs_and_backrun: CommitmentStatus::NotReached,
             finalize: CommitmentStatus::NotReached,
         }
@@ -276,6 +361,10 @@ pub struct BlockOutcome {
     pub cron_outcomes: Vec<CronOutcome>,
     /// Results of executing the transactions.
     pub tx_outcomes: Vec<TxOutcome>,
+    /// Results of performing the scheduled contract executions that were due.
+    pub scheduled_outcomes: Vec<ScheduledOutcome>,
+    /// Results of notifying contracts of the events they subscribed to.
+    pub receive_event_outcomes: Vec<ReceiveEventOutcome>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
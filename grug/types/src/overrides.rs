@@ -0,0 +1,68 @@
+#[cfg(feature = "async-graphql")]
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+use {
+    crate::{Addr, Binary, Coins, Hash256, Timestamp},
+    borsh::{BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    serde_with::skip_serializing_none,
+    std::collections::BTreeMap,
+};
+
+/// Changes to the chain's state to be applied before simulating a transaction.
+///
+/// The changes only live for the duration of the simulation, and are never
+/// persisted.
+#[skip_serializing_none]
+#[derive(
+    Serialize, Deserialize, BorshSerialize, BorshDeserialize, Default, Debug, Clone, PartialEq, Eq,
+)]
+#[serde(default, deny_unknown_fields)]
+pub struct StateOverrides {
+    /// Coins to be given to accounts, in addition to their existing balances.
+    ///
+    /// These are written directly to the bank contract's storage, which is only
+    /// supported by nodes that know how the bank contract stores balances.
+    pub balances: BTreeMap<Addr, Coins>,
+    /// Raw key-value pairs to be written to contracts' storages. A value of
+    /// `None` means the key is to be removed.
+    pub storage: BTreeMap<Addr, BTreeMap<Binary, Option<Binary>>>,
+    /// Code hashes to substitute for those of the contracts. The codes must
+    /// have been uploaded.
+    pub code_hashes: BTreeMap<Addr, Hash256>,
+    /// Block height to simulate at, instead of that of the last finalized block.
+    pub block_height: Option<u64>,
+    /// Block time to simulate at, instead of that of the last finalized block.
+    pub block_timestamp: Option<Timestamp>,
+}
+
+impl StateOverrides {
+    /// Return whether there isn't any change to be applied.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Changes to the chain's state to be applied before simulating a transaction.
+///
+/// See [StateOverrides](https://github.com/left-curve/left-curve/blob/main/grug/types/src/overrides.rs).
+#[cfg(feature = "async-graphql")]
+#[Scalar(name = "StateOverrides")]
+impl ScalarType for StateOverrides {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        value
+            .into_json()
+            .and_then(serde_json::from_value)
+            .map_err(|err| {
+                InputValueError::custom(format!(
+                    "failed to parse `StateOverrides` from GraphQL value: {err}"
+                ))
+            })
+    }
+
+    fn to_value(&self) -> Value {
+        serde_json::to_value(self)
+            .and_then(serde_json::from_value)
+            .map(Value::Object)
+            .unwrap_or(Value::Null)
+    }
+}
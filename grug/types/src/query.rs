@@ -298,6 +298,18 @@ pub struct QueryWasmSmartRequest {
     pub msg: Json,
 }
 
+/// Request of a raw scan of the chain's key-value store, including both the
+/// app's own state and contracts' internal states.
+///
+/// Unlike the [`Query`] variants, this isn't available to contracts. It's only
+/// served to clients of the node, the same way as raw store queries.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QueryStoreScanRequest {
+    pub min: Option<Binary>, // inclusive
+    pub max: Option<Binary>, // exclusive
+    pub limit: Option<u32>,
+}
+
 macro_rules! impl_into_query {
     ($variant:ident => $req:ty => $res:ty) => {
         impl From<$req> for Query {
@@ -412,3 +424,40 @@ impl QueryResponse {
         Multi      => Vec<GenericResult<QueryResponse>>,
     }
 }
+
+/// Response of a raw query of the chain's key-value store.
+///
+/// The proof, if requested, can be verified against the root hash using
+/// `grug_jmt::verify_proof`. Note that the root hash is reported by the node
+/// that served the query; for the verification to be meaningful, it must be
+/// checked against one from a trusted source, e.g. the app hash in a block
+/// header signed by the validators.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QueryStoreResponse<P> {
+    /// The block height at which the query was performed.
+    pub height: u64,
+    /// The value corresponding to the key; `None` if the key doesn't exist.
+    pub value: Option<Binary>,
+    /// Merkle proof of the value's membership, or the key's non-membership;
+    /// `None` if not requested.
+    pub proof: Option<P>,
+    /// Merkle root hash of the state at the height; `None` if the proof isn't
+    /// requested, or if the state is empty.
+    pub root_hash: Option<Hash256>,
+}
+
+impl<P> QueryStoreResponse<P> {
+    /// Convert the proof into another type, e.g. deserialize it from bytes.
+    pub fn try_map_proof<F, Q, E>(self, f: F) -> Result<QueryStoreResponse<Q>, E>
+    where
+        F: FnOnce(P) -> Result<Q, E>,
+    {
+        Ok(QueryStoreResponse {
+            height: self.height,
+            value: self.value,
+            proof: self.proof.map(f).transpose()?,
+            root_hash: self.root_hash,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use {
+    schemars::{
+        JsonSchema,
+        schema::{InstanceType, Metadata, RootSchema, Schema, SchemaObject},
+        schema_for,
+    },
+    serde::Serialize,
+    std::collections::BTreeMap,
+};
+
+/// A query message whose variants each correspond to a response type.
+///
+/// Don't implement this trait manually. Instead, derive it together with the
+/// query request types using `#[grug::derive(Serde, Schema, QueryRequest)]`.
+pub trait QueryResponses: JsonSchema {
+    /// Return the JSON schemas of the response types, indexed by the names of
+    /// the query message variants, as they appear in the JSON encoding.
+    fn response_schemas() -> BTreeMap<String, RootSchema>;
+}
+
+/// JSON schemas of the messages a contract accepts and the responses it
+/// returns, for generating client code in other languages.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ContractSchema {
+    pub contract_name: String,
+    pub instantiate: Option<RootSchema>,
+    pub execute: Option<RootSchema>,
+    pub query: Option<RootSchema>,
+    /// Query message variant name => schema of the response type.
+    pub responses: BTreeMap<String, RootSchema>,
+}
+
+impl ContractSchema {
+    pub fn new<T>(contract_name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            contract_name: contract_name.into(),
+            instantiate: None,
+            execute: None,
+            query: None,
+            responses: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_instantiate<M>(mut self) -> Self
+    where
+        M: JsonSchema,
+    {
+        self.instantiate = Some(schema_for!(M));
+        self
+    }
+
+    pub fn with_execute<M>(mut self) -> Self
+    where
+        M: JsonSchema,
+    {
+        self.execute = Some(schema_for!(M));
+        self
+    }
+
+    pub fn with_query<M>(mut self) -> Self
+    where
+        M: QueryResponses,
+    {
+        self.query = Some(schema_for!(M));
+        self.responses = M::response_schemas();
+        self
+    }
+}
+
+/// Schema of a type that is serialized to JSON as a string, such as addresses,
+/// hashes, and denoms.
+pub(crate) fn string_schema(description: &str) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_string()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
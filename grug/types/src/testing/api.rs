@@ -29,6 +29,21 @@ impl Api for MockApi {
             .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
     }
 
+    fn secp256k1_batch_verify(
+        &self,
+        msg_hashes: &[&[u8]],
+        sigs: &[&[u8]],
+        pks: &[&[u8]],
+    ) -> StdResult<()> {
+        grug_crypto::secp256k1_batch_verify(msg_hashes, sigs, pks)
+            .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
+    }
+
+    fn secp256k1_schnorr_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()> {
+        grug_crypto::secp256k1_schnorr_verify(msg_hash, sig, pk)
+            .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
+    }
+
     fn ed25519_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()> {
         grug_crypto::ed25519_verify(msg_hash, sig, pk)
             .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
@@ -44,6 +59,26 @@ impl Api for MockApi {
             .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
     }
 
+    fn bls12_381_verify(&self, msg_hash: &[u8], sig: &[u8], pk: &[u8]) -> StdResult<()> {
+        grug_crypto::bls12_381_verify(msg_hash, sig, pk)
+            .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
+    }
+
+    fn bls12_381_aggregate_verify(
+        &self,
+        msg_hash: &[u8],
+        sig: &[u8],
+        pks: &[&[u8]],
+    ) -> StdResult<()> {
+        grug_crypto::bls12_381_aggregate_verify(msg_hash, sig, pks)
+            .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
+    }
+
+    fn bls12_381_pairing_check(&self, g1s: &[&[u8]], g2s: &[&[u8]]) -> StdResult<()> {
+        grug_crypto::bls12_381_pairing_check(g1s, g2s)
+            .map_err(|err| VerificationError::from_error_code(err.into_error_code()).into())
+    }
+
     fn sha2_256(&self, data: &[u8]) -> [u8; 32] {
         grug_crypto::sha2_256(data)
     }
@@ -48,6 +48,7 @@ pub type Timestamp = Duration;
     PartialOrd,
     Ord,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Duration(Dec<u128, 9>);
 
 impl Duration {
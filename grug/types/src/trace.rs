@@ -0,0 +1,151 @@
+use {
+    crate::{Addr, Binary},
+    borsh::{BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        fmt::Write,
+    },
+};
+
+/// A record of the contract calls made during the execution of a transaction,
+/// and the gas consumed by each of them.
+#[derive(
+    Serialize, Deserialize, BorshSerialize, BorshDeserialize, Default, Debug, Clone, PartialEq, Eq,
+)]
+#[serde(deny_unknown_fields)]
+pub struct ExecutionTrace {
+    /// The top-level contract calls, in the order they were made.
+    pub calls: Vec<CallFrame>,
+    /// Gas consumed outside of any contract call, such as for uploading code,
+    /// grouped by category.
+    pub gas_by_category: BTreeMap<String, u64>,
+}
+
+impl ExecutionTrace {
+    /// Return the total amount of gas recorded in the trace.
+    ///
+    /// Note that this may differ from the `gas_used` in the transaction's
+    /// outcome, as the trace also includes the calls to the taxman, which are
+    /// not charged gas for.
+    pub fn gas_used(&self) -> u64 {
+        self.gas_by_category.values().sum::<u64>()
+            + self.calls.iter().map(|call| call.gas_used).sum::<u64>()
+    }
+
+    /// Render the trace in the "folded stacks" format, which can be turned
+    /// into a flamegraph by tools such as [`inferno`](https://github.com/jonhoo/inferno).
+    ///
+    /// Each line consists of a call stack, where each frame is denoted as
+    /// `{contract}:{entry_point}`, followed by a gas category, and the amount
+    /// of gas consumed. For example:
+    ///
+    /// ```plain
+    /// 0x1234...abcd:execute;0x5678...ef01:query;db_read 1176
+    /// ```
+    pub fn to_folded_stacks(&self) -> String {
+        let mut output = String::new();
+
+        for (category, gas) in &self.gas_by_category {
+            writeln!(output, "{category} {gas}").unwrap();
+        }
+
+        for call in &self.calls {
+            call.write_folded_stacks("", &mut output);
+        }
+
+        output
+    }
+}
+
+/// A call to a contract's entry point, such as `execute` or `query`.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct CallFrame {
+    pub contract: Addr,
+    pub entry_point: String,
+    /// Gas consumed by this call, including that of the calls made from it.
+    pub gas_used: u64,
+    /// Gas consumed by this call, excluding that of the calls made from it,
+    /// grouped by category.
+    pub gas_by_category: BTreeMap<String, u64>,
+    /// Keys in the contract's storage that were read during this call.
+    pub reads: BTreeSet<Binary>,
+    /// Keys in the contract's storage that were written to or removed during
+    /// this call.
+    pub writes: BTreeSet<Binary>,
+    /// Calls made from this call, including queries and submessages, in the
+    /// order they were made.
+    pub children: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    pub fn new(contract: Addr, entry_point: &str) -> Self {
+        Self {
+            contract,
+            entry_point: entry_point.to_string(),
+            gas_used: 0,
+            gas_by_category: BTreeMap::new(),
+            reads: BTreeSet::new(),
+            writes: BTreeSet::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn write_folded_stacks(&self, parent: &str, output: &mut String) {
+        let stack = if parent.is_empty() {
+            format!("{}:{}", self.contract, self.entry_point)
+        } else {
+            format!("{parent};{}:{}", self.contract, self.entry_point)
+        };
+
+        for (category, gas) in &self.gas_by_category {
+            writeln!(output, "{stack};{category} {gas}").unwrap();
+        }
+
+        for child in &self.children {
+            child.write_folded_stacks(&stack, output);
+        }
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rendering_folded_stacks() {
+        let account = Addr::mock(1);
+        let bank = Addr::mock(2);
+
+        let trace = ExecutionTrace {
+            calls: vec![CallFrame {
+                gas_used: 350,
+                gas_by_category: BTreeMap::from([
+                    ("db_read".to_string(), 100),
+                    ("secp256k1_verify".to_string(), 200),
+                ]),
+                children: vec![CallFrame {
+                    gas_used: 50,
+                    gas_by_category: BTreeMap::from([("db_read".to_string(), 50)]),
+                    ..CallFrame::new(bank, "query")
+                }],
+                ..CallFrame::new(account, "authenticate")
+            }],
+            gas_by_category: BTreeMap::from([("db_write".to_string(), 10)]),
+        };
+
+        assert_eq!(trace.gas_used(), 360);
+        assert_eq!(
+            trace.to_folded_stacks(),
+            format!(
+                "db_write 10\n\
+                 {account}:authenticate;db_read 100\n\
+                 {account}:authenticate;secp256k1_verify 200\n\
+                 {account}:authenticate;{bank}:query;db_read 50\n"
+            )
+        );
+    }
+}
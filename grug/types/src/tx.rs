@@ -2,8 +2,8 @@
 use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
 use {
     crate::{
-        Addr, Binary, Coins, Config, Hash256, HashExt, Json, JsonSerExt, LengthBounded, MaxLength,
-        NonEmpty, StdError, StdResult, btree_map,
+        Addr, Binary, BlockInfo, Coins, Config, Hash256, HashExt, Json, JsonSerExt, LengthBounded,
+        MaxLength, NonEmpty, StdError, StdResult, Timestamp, btree_map,
     },
     borsh::{BorshDeserialize, BorshSerialize},
     serde::{Deserialize, Serialize},
@@ -24,9 +24,13 @@ pub type Salt = MaxLength<Binary, 82>;
 pub type Label = LengthBounded<String, 1, 128>;
 
 /// A transaction.
+#[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Tx {
     pub sender: Addr,
+    /// An account other than the sender that pays the fee for this transaction,
+    /// if any.
+    pub fee_payer: Option<FeePayer>,
     pub gas_limit: u64,
     pub msgs: NonEmpty<Vec<Message>>,
     pub data: Json,
@@ -37,6 +41,28 @@ impl Tx {
     pub fn tx_hash(&self) -> StdResult<Hash256> {
         Ok(self.to_json_vec()?.hash256())
     }
+
+    /// Return the address of the account that pays the fee for this
+    /// transaction, which is the fee payer if there is one, or otherwise the
+    /// sender.
+    pub fn payer(&self) -> Addr {
+        self.fee_payer
+            .as_ref()
+            .map_or(self.sender, |fee_payer| fee_payer.address)
+    }
+}
+
+/// An account that sponsors a transaction by paying its fee on behalf of the
+/// sender.
+///
+/// The fee payer is authenticated separately from the sender: its account's
+/// `authenticate` method is called with the same transaction, and is expected
+/// to verify the `data` and `credential` here, instead of those of the sender.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FeePayer {
+    pub address: Addr,
+    pub data: Json,
+    pub credential: Json,
 }
 
 // NOTE: implementing `InputType` doesn't work for complex enums, `Message` in this case
@@ -72,9 +98,12 @@ impl ScalarType for Tx {
 /// A transaction but without a gas limit or credential.
 ///
 /// This is for using in gas simulation.
+#[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct UnsignedTx {
     pub sender: Addr,
+    /// The fee payer's credential is ignored in simulations.
+    pub fee_payer: Option<FeePayer>,
     pub msgs: NonEmpty<Vec<Message>>,
     pub data: Json,
 }
@@ -113,6 +142,7 @@ impl ScalarType for UnsignedTx {
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Message {
     /// Update the chain- and app-level configurations.
     Configure(MsgConfigure),
@@ -126,6 +156,15 @@ pub enum Message {
     Execute(MsgExecute),
     /// Update the code hash associated with a contract.
     Migrate(MsgMigrate),
+    /// Schedule a contract execution to be performed at a later time or block
+    /// height, on behalf of the sender.
+    Schedule(MsgSchedule),
+    /// Cancel a previously scheduled contract execution.
+    Unschedule(MsgUnschedule),
+    /// Subscribe the sender to a type of event emitted by a contract.
+    Subscribe(MsgSubscribe),
+    /// Cancel a previously made event subscription.
+    Unsubscribe(MsgUnsubscribe),
 }
 
 impl Message {
@@ -222,10 +261,63 @@ impl Message {
         }
         .into())
     }
+
+    pub fn schedule<M, C>(
+        due: Due,
+        gas_limit: u64,
+        contract: Addr,
+        msg: &M,
+        funds: C,
+    ) -> StdResult<Self>
+    where
+        M: Serialize,
+        C: TryInto<Coins>,
+        StdError: From<C::Error>,
+    {
+        Ok(MsgSchedule {
+            due,
+            gas_limit,
+            msg: MsgExecute {
+                contract,
+                msg: msg.to_json_value()?,
+                funds: funds.try_into()?,
+            },
+        }
+        .into())
+    }
+
+    pub fn unschedule(id: u64) -> Self {
+        MsgUnschedule { id }.into()
+    }
+
+    pub fn subscribe<T>(emitter: Addr, event_type: T, gas_limit: u64, gas_budget: u64) -> Self
+    where
+        T: Into<String>,
+    {
+        MsgSubscribe {
+            emitter,
+            event_type: event_type.into(),
+            gas_limit,
+            gas_budget,
+        }
+        .into()
+    }
+
+    pub fn unsubscribe<T>(emitter: Addr, event_type: T) -> Self
+    where
+        T: Into<String>,
+    {
+        MsgUnsubscribe {
+            emitter,
+            event_type: event_type.into(),
+        }
+        .into()
+    }
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MsgConfigure {
     pub new_cfg: Option<Config>,
     pub new_app_cfg: Option<Json>,
@@ -236,12 +328,14 @@ pub type MsgTransfer = BTreeMap<Addr, Coins>;
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MsgUpload {
     pub code: Binary,
 }
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MsgInstantiate {
     pub code_hash: Hash256,
     pub msg: Json,
@@ -253,6 +347,7 @@ pub struct MsgInstantiate {
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MsgExecute {
     pub contract: Addr,
     pub msg: Json,
@@ -261,12 +356,107 @@ pub struct MsgExecute {
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MsgMigrate {
     pub contract: Addr,
     pub new_code_hash: Hash256,
     pub msg: Json,
 }
 
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MsgSchedule {
+    /// When the execution is to be performed.
+    pub due: Due,
+    /// The gas limit for performing the execution.
+    ///
+    /// Must not exceed the chain's `max_scheduled_gas_limit`. The sender pays
+    /// for it in full when scheduling, as it's consumed from the gas of the
+    /// transaction that schedules the execution.
+    pub gas_limit: u64,
+    pub msg: MsgExecute,
+}
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MsgUnschedule {
+    pub id: u64,
+}
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MsgSubscribe {
+    /// The contract that emits the events.
+    pub emitter: Addr,
+    /// The type of the events, as emitted in
+    /// [`ContractEvent`](crate::ContractEvent)s.
+    pub event_type: String,
+    /// The gas limit for each call to the subscriber's `receive_event` entry
+    /// point.
+    ///
+    /// Must be at least the chain's `min_subscription_gas_limit`, and must not
+    /// exceed its `max_subscription_gas_limit`.
+    pub gas_limit: u64,
+    /// Gas to pay for up front, to cover the calls to the subscriber's
+    /// `receive_event` entry point.
+    ///
+    /// It's consumed from the gas of the transaction that subscribes, such
+    /// that the sender pays for it as part of the transaction fee. Each call
+    /// draws its full gas limit from the budget, regardless of the gas it
+    /// actually uses. Subscribing again adds to the remaining budget.
+    pub gas_budget: u64,
+}
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MsgUnsubscribe {
+    pub emitter: Addr,
+    pub event_type: String,
+}
+
+/// The time or block height at which a scheduled message is to be performed.
+///
+/// The message is performed at the end of the first block whose timestamp or
+/// height is equal to or greater than this.
+#[derive(
+    Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Due {
+    Time(Timestamp),
+    Height(u64),
+}
+
+impl Due {
+    /// Return whether the due has been reached in the given block.
+    pub fn is_reached(&self, block: &BlockInfo) -> bool {
+        match self {
+            Due::Time(time) => *time <= block.timestamp,
+            Due::Height(height) => *height <= block.height,
+        }
+    }
+}
+
+/// An event subscription made by a [`MsgSubscribe`](crate::MsgSubscribe).
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Subscription {
+    pub gas_limit: u64,
+    /// The prepaid gas that hasn't been drawn yet. The subscription is removed
+    /// once this can't cover another call.
+    pub gas_budget: u64,
+}
+
+/// A contract execution scheduled by a [`MsgSchedule`](crate::MsgSchedule),
+/// waiting to be performed.
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledMsg {
+    pub sender: Addr,
+    pub due: Due,
+    pub gas_limit: u64,
+    pub msg: MsgExecute,
+}
+
 macro_rules! impl_into_message {
     ($variant:ident, $msg:ty) => {
         impl From<$msg> for Message {
@@ -290,4 +480,8 @@ impl_into_message! {
     Instantiate => MsgInstantiate,
     Execute     => MsgExecute,
     Migrate     => MsgMigrate,
+    Schedule    => MsgSchedule,
+    Unschedule  => MsgUnschedule,
+    Subscribe   => MsgSubscribe,
+    Unsubscribe => MsgUnsubscribe,
 }
@@ -10,6 +10,7 @@ use {
 /// This is useful if you want to ensure a collection of items is unique, and
 /// also _ordered_ (in which case `BTreeSet` isn't suitable).
 #[derive(Serialize, BorshSerialize, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UniqueVec<T>(Vec<T>)
 where
     T: Eq + Hash;
@@ -1,15 +1,15 @@
 use {
     crate::{
         AuthenticateFn, BackrunFn, BankExecuteFn, BankQueryFn, Contract, CronExecuteFn, ExecuteFn,
-        FinalizeFeeFn, InstantiateFn, MigrateFn, QueryFn, ReceiveFn, ReplyFn, VmError, VmResult,
-        WithholdFeeFn,
+        FinalizeFeeFn, InstantiateFn, MigrateFn, QueryFn, ReceiveEventFn, ReceiveFn, ReplyFn,
+        VmError, VmResult, WithholdFeeFn,
     },
     elsa::sync::FrozenVec,
     grug_types::{
         Api, AuthCtx, AuthResponse, BankMsg, BankQuery, BankQueryResponse, Binary, BorshDeExt,
-        Context, Empty, GenericResult, GenericResultExt, ImmutableCtx, Json, JsonDeExt, MutableCtx,
-        Querier, QuerierWrapper, Response, StdError, Storage, SubMsgResult, SudoCtx, Tx, TxOutcome,
-        make_auth_ctx, make_immutable_ctx, make_mutable_ctx, make_sudo_ctx,
+        CheckedContractEvent, Context, Empty, GenericResult, GenericResultExt, ImmutableCtx, Json,
+        JsonDeExt, MutableCtx, Querier, QuerierWrapper, Response, StdError, Storage, SubMsgResult,
+        SudoCtx, Tx, TxOutcome, make_auth_ctx, make_immutable_ctx, make_mutable_ctx, make_sudo_ctx,
     },
     serde::de::DeserializeOwned,
     std::sync::OnceLock,
@@ -72,6 +72,7 @@ pub struct ContractBuilder<
     E11 = StdError,
     E12 = StdError,
     E13 = StdError,
+    E14 = StdError,
 > {
     instantiate_fn: InstantiateFn<M1, E1>,
     execute_fn: Option<ExecuteFn<M2, E2>>,
@@ -86,6 +87,7 @@ pub struct ContractBuilder<
     withhold_fee_fn: Option<WithholdFeeFn<E11>>,
     finalize_fee_fn: Option<FinalizeFeeFn<E12>>,
     cron_execute_fn: Option<CronExecuteFn<E13>>,
+    receive_event_fn: Option<ReceiveEventFn<E14>>,
 }
 
 impl<M1, E1> ContractBuilder<M1, E1>
@@ -108,12 +110,13 @@ where
             withhold_fee_fn: None,
             finalize_fee_fn: None,
             cron_execute_fn: None,
+            receive_event_fn: None,
         }
     }
 }
 
-impl<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13>
-    ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13>
+impl<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13, E14>
+    ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13, E14>
 where
     M1: DeserializeOwned + 'static,
     M2: DeserializeOwned + 'static,
@@ -133,11 +136,32 @@ where
     E11: ToString + 'static,
     E12: ToString + 'static,
     E13: ToString + 'static,
+    E14: ToString + 'static,
 {
     pub fn with_execute<M2A, E2A>(
         self,
         execute_fn: ExecuteFn<M2A, E2A>,
-    ) -> ContractBuilder<M1, E1, M2A, M3, M5, M6, E2A, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13>
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2A,
+        M3,
+        M5,
+        M6,
+        E2A,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    >
     where
         M2A: DeserializeOwned + 'static,
         E2A: ToString + 'static,
@@ -156,13 +180,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_migrate<M3A, E3A>(
         self,
         migrate_fn: MigrateFn<M3A, E3A>,
-    ) -> ContractBuilder<M1, E1, M2, M3A, M5, M6, E2, E3A, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13>
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3A,
+        M5,
+        M6,
+        E2,
+        E3A,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    >
     where
         M3A: DeserializeOwned + 'static,
         E3A: ToString + 'static,
@@ -181,13 +226,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_receive<E4A>(
         self,
         receive_fn: ReceiveFn<E4A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4A, E5, E6, E7, E8, E9, E10, E11, E12, E13>
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4A,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    >
     where
         E4A: ToString + 'static,
     {
@@ -205,13 +271,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_reply<M5A, E5A>(
         self,
         reply_fn: ReplyFn<M5A, E5A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5A, M6, E2, E3, E4, E5A, E6, E7, E8, E9, E10, E11, E12, E13>
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5A,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5A,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    >
     where
         M5A: DeserializeOwned + 'static,
         E5A: ToString + 'static,
@@ -230,13 +317,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_query<M6A, E6A>(
         self,
         query_fn: QueryFn<M6A, E6A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6A, E2, E3, E4, E5, E6A, E7, E8, E9, E10, E11, E12, E13>
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6A,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6A,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    >
     where
         M6A: DeserializeOwned + 'static,
         E6A: ToString + 'static,
@@ -255,14 +363,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_authenticate<E7A>(
         self,
         authenticate_fn: AuthenticateFn<E7A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7A, E8, E9, E10, E11, E12, E13>
-    {
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7A,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    > {
         ContractBuilder {
             instantiate_fn: self.instantiate_fn,
             execute_fn: self.execute_fn,
@@ -277,14 +405,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_backrun<E8A>(
         self,
         backrun_fn: BackrunFn<E8A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8A, E9, E10, E11, E12, E13>
-    {
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8A,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    > {
         ContractBuilder {
             instantiate_fn: self.instantiate_fn,
             execute_fn: self.execute_fn,
@@ -299,14 +447,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_bank_execute<E9A>(
         self,
         bank_execute_fn: BankExecuteFn<E9A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9A, E10, E11, E12, E13>
-    {
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9A,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    > {
         ContractBuilder {
             instantiate_fn: self.instantiate_fn,
             execute_fn: self.execute_fn,
@@ -321,14 +489,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_bank_query<E10A>(
         self,
         bank_query_fn: BankQueryFn<E10A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10A, E11, E12, E13>
-    {
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10A,
+        E11,
+        E12,
+        E13,
+        E14,
+    > {
         ContractBuilder {
             instantiate_fn: self.instantiate_fn,
             execute_fn: self.execute_fn,
@@ -343,14 +531,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_withhold_fee<E11A>(
         self,
         withhold_fee_fn: WithholdFeeFn<E11A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11A, E12, E13>
-    {
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11A,
+        E12,
+        E13,
+        E14,
+    > {
         ContractBuilder {
             instantiate_fn: self.instantiate_fn,
             execute_fn: self.execute_fn,
@@ -365,14 +573,34 @@ where
             withhold_fee_fn: Some(withhold_fee_fn),
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_finalize_fee<E12A>(
         self,
         finalize_fee_fn: FinalizeFeeFn<E12A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12A, E13>
-    {
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12A,
+        E13,
+        E14,
+    > {
         ContractBuilder {
             instantiate_fn: self.instantiate_fn,
             execute_fn: self.execute_fn,
@@ -387,14 +615,34 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: Some(finalize_fee_fn),
             cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: self.receive_event_fn,
         }
     }
 
     pub fn with_cron_execute<E13A>(
         self,
         cron_execute_fn: CronExecuteFn<E13A>,
-    ) -> ContractBuilder<M1, E1, M2, M3, M5, M6, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13A>
-    {
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13A,
+        E14,
+    > {
         ContractBuilder {
             instantiate_fn: self.instantiate_fn,
             execute_fn: self.execute_fn,
@@ -409,6 +657,49 @@ where
             withhold_fee_fn: self.withhold_fee_fn,
             finalize_fee_fn: self.finalize_fee_fn,
             cron_execute_fn: Some(cron_execute_fn),
+            receive_event_fn: self.receive_event_fn,
+        }
+    }
+
+    pub fn with_receive_event<E14A>(
+        self,
+        receive_event_fn: ReceiveEventFn<E14A>,
+    ) -> ContractBuilder<
+        M1,
+        E1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14A,
+    > {
+        ContractBuilder {
+            instantiate_fn: self.instantiate_fn,
+            execute_fn: self.execute_fn,
+            migrate_fn: self.migrate_fn,
+            receive_fn: self.receive_fn,
+            reply_fn: self.reply_fn,
+            query_fn: self.query_fn,
+            authenticate_fn: self.authenticate_fn,
+            backrun_fn: self.backrun_fn,
+            bank_execute_fn: self.bank_execute_fn,
+            bank_query_fn: self.bank_query_fn,
+            withhold_fee_fn: self.withhold_fee_fn,
+            finalize_fee_fn: self.finalize_fee_fn,
+            cron_execute_fn: self.cron_execute_fn,
+            receive_event_fn: Some(receive_event_fn),
         }
     }
 
@@ -429,6 +720,7 @@ where
                 withhold_fee_fn: self.withhold_fee_fn,
                 finalize_fee_fn: self.finalize_fee_fn,
                 cron_execute_fn: self.cron_execute_fn,
+                receive_event_fn: self.receive_event_fn,
             }));
 
         ContractWrapper { index }
@@ -437,7 +729,8 @@ where
 
 // ----------------------------------- impl ------------------------------------
 
-struct ContractImpl<M1, M2, M3, M5, M6, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13> {
+struct ContractImpl<M1, M2, M3, M5, M6, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13, E14>
+{
     instantiate_fn: InstantiateFn<M1, E1>,
     execute_fn: Option<ExecuteFn<M2, E2>>,
     migrate_fn: Option<MigrateFn<M3, E3>>,
@@ -451,10 +744,31 @@ struct ContractImpl<M1, M2, M3, M5, M6, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10,
     withhold_fee_fn: Option<WithholdFeeFn<E11>>,
     finalize_fee_fn: Option<FinalizeFeeFn<E12>>,
     cron_execute_fn: Option<CronExecuteFn<E13>>,
+    receive_event_fn: Option<ReceiveEventFn<E14>>,
 }
 
-impl<M1, M2, M3, M5, M6, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13> Contract
-    for ContractImpl<M1, M2, M3, M5, M6, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13>
+impl<M1, M2, M3, M5, M6, E1, E2, E3, E4, E5, E6, E7, E8, E9, E10, E11, E12, E13, E14> Contract
+    for ContractImpl<
+        M1,
+        M2,
+        M3,
+        M5,
+        M6,
+        E1,
+        E2,
+        E3,
+        E4,
+        E5,
+        E6,
+        E7,
+        E8,
+        E9,
+        E10,
+        E11,
+        E12,
+        E13,
+        E14,
+    >
 where
     M1: DeserializeOwned,
     M2: DeserializeOwned,
@@ -474,6 +788,7 @@ where
     E11: ToString,
     E12: ToString,
     E13: ToString,
+    E14: ToString,
 {
     fn instantiate(
         &self,
@@ -709,4 +1024,22 @@ where
 
         Ok(res.into_generic_result())
     }
+
+    fn receive_event(
+        &self,
+        ctx: Context,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        querier: &dyn Querier,
+        event: CheckedContractEvent,
+    ) -> VmResult<GenericResult<Response>> {
+        let Some(receive_event_fn) = &self.receive_event_fn else {
+            return Err(VmError::function_not_found("receive_event"));
+        };
+
+        let sudo_ctx = make_sudo_ctx!(ctx, storage, api, querier);
+        let res = receive_event_fn(sudo_ctx, event);
+
+        Ok(res.into_generic_result())
+    }
 }
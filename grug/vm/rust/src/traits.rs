@@ -5,9 +5,9 @@
 use {
     crate::VmResult,
     grug_types::{
-        Api, AuthCtx, AuthResponse, BankMsg, BankQuery, BankQueryResponse, Context, GenericResult,
-        ImmutableCtx, Json, MutableCtx, Querier, Response, Storage, SubMsgResult, SudoCtx, Tx,
-        TxOutcome,
+        Api, AuthCtx, AuthResponse, BankMsg, BankQuery, BankQueryResponse, CheckedContractEvent,
+        Context, GenericResult, ImmutableCtx, Json, MutableCtx, Querier, Response, Storage,
+        SubMsgResult, SudoCtx, Tx, TxOutcome,
     },
 };
 
@@ -128,6 +128,15 @@ pub trait Contract {
         api: &dyn Api,
         querier: &dyn Querier,
     ) -> VmResult<GenericResult<Response>>;
+
+    fn receive_event(
+        &self,
+        ctx: Context,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        querier: &dyn Querier,
+        event: CheckedContractEvent,
+    ) -> VmResult<GenericResult<Response>>;
 }
 
 // Trait alias is unstable:
@@ -159,3 +168,5 @@ pub type WithholdFeeFn<E> = Box<dyn Fn(AuthCtx, Tx) -> Result<Response, E> + Sen
 pub type FinalizeFeeFn<E> = Box<dyn Fn(AuthCtx, Tx, TxOutcome) -> Result<Response, E> + Send + Sync>;
 
 pub type CronExecuteFn<E> = Box<dyn Fn(SudoCtx) -> Result<Response, E> + Send + Sync>;
+
+pub type ReceiveEventFn<E> = Box<dyn Fn(SudoCtx, CheckedContractEvent) -> Result<Response, E> + Send + Sync>;
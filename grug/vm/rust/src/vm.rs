@@ -8,7 +8,7 @@ use {
 ///
 /// This doesn't include `allocate` and `deallocate`, which are only relevant
 /// for the `WasmVm`.
-pub const KNOWN_FUNCTIONS: [&str; 13] = [
+pub const KNOWN_FUNCTIONS: [&str; 14] = [
     "instantate",
     "execute",
     "migrate",
@@ -22,6 +22,7 @@ pub const KNOWN_FUNCTIONS: [&str; 13] = [
     "withhold_fee",
     "finalize_fee",
     "cron_execute",
+    "receive_event",
 ];
 
 #[derive(Default, Clone)]
@@ -192,6 +193,17 @@ impl Instance for RustInstance {
                 )?;
                 res.to_borsh_vec()
             },
+            "receive_event" => {
+                let event = param.deserialize_borsh()?;
+                let res = contract.receive_event(
+                    ctx.clone(),
+                    &mut self.storage,
+                    &MockApi,
+                    &self.querier,
+                    event,
+                )?;
+                res.to_borsh_vec()
+            },
             "withhold_fee" => {
                 let tx = param.deserialize_borsh()?;
                 let res = contract.withhold_fee(
@@ -4,11 +4,19 @@ use {
     grug_crypto::sha2_256,
     grug_tester::QueryMsg,
     grug_types::{
-        Addr, BlockInfo, BorshSerExt, Context, GenericResult, Hash, JsonSerExt, MockStorage,
-        Timestamp,
+        Addr, BlockInfo, BorshSerExt, Context, DEFAULT_GAS_COSTS, GasCosts, GenericResult, Hash,
+        JsonSerExt, MockStorage, Timestamp, WasmMetering,
+    },
+    grug_vm_wasm::{OperatorCosts, WasmVm},
+    std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+    wasmer::{CompilerConfig, Engine, Module, Singlepass, Store, TypedFunction, imports},
+    wasmer_middlewares::{
+        Metering,
+        metering::{MeteringPoints, get_remaining_points, set_remaining_points},
     },
-    grug_vm_wasm::WasmVm,
-    std::time::Duration,
 };
 
 const MOCK_CHAIN_ID: &str = "dev-1";
@@ -23,20 +31,91 @@ const MOCK_BLOCK: BlockInfo = BlockInfo {
 
 static BENCHMARKER_CODE: &[u8] = include_bytes!("../testdata/grug_tester.wasm");
 
+/// Ways of metering to compare: charging every operator the same, versus
+/// charging by operator class.
+const METERINGS: [(&str, WasmMetering); 2] = [
+    ("flat", WasmMetering::Flat),
+    ("by_class", WasmMetering::ByClass),
+];
+
+/// Gas consumed and time spent, averaged over the runs of one benchmark.
+#[derive(Default)]
+struct DataPoint {
+    gas: u64,
+    nanos: u128,
+    repeats: u64,
+}
+
+impl DataPoint {
+    fn record(&mut self, gas: u64, elapsed: Duration) {
+        self.gas += gas;
+        self.nanos += elapsed.as_nanos();
+        self.repeats += 1;
+    }
+
+    fn gas(&self) -> f64 {
+        self.gas as f64 / self.repeats as f64
+    }
+
+    fn nanos(&self) -> f64 {
+        self.nanos as f64 / self.repeats as f64
+    }
+}
+
+/// Fit `nanos = slope * gas + intercept` over the data points by least squares,
+/// and print the fit together with Pearson's correlation coefficient.
+///
+/// The closer `r²` is to 1, the better gas consumption predicts run time.
+fn report_fit(label: &str, points: &[DataPoint]) {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(DataPoint::gas).sum::<f64>() / n;
+    let mean_y = points.iter().map(DataPoint::nanos).sum::<f64>() / n;
+
+    let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+    for point in points {
+        let dx = point.gas() - mean_x;
+        let dy = point.nanos() - mean_y;
+        sxx += dx * dx;
+        syy += dy * dy;
+        sxy += dx * dy;
+    }
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let r = sxy / (sxx * syy).sqrt();
+
+    println!(
+        "{label}: nanos = {slope:.4} * gas + {intercept:.0}; r = {r:.4}; r² = {:.4}\n",
+        r * r
+    );
+}
+
 fn looping(c: &mut Criterion) {
-    // Share one `WasmVm` across all benches, which caches the module, so we
-    // don't need to rebuild it every time.
-    let mut vm = WasmVm::new(100);
+    for (name, metering) in METERINGS {
+        // Share one `WasmVm` across all benches of the same metering, which
+        // caches the module, so we don't need to rebuild it every time.
+        let points = looping_with_vm(c, name, metering, WasmVm::new(100));
+
+        report_fit(&format!("looping/{name}"), &points);
+    }
+}
+
+fn looping_with_vm(
+    c: &mut Criterion,
+    name: &str,
+    metering: WasmMetering,
+    mut vm: WasmVm,
+) -> Vec<DataPoint> {
+    let mut points = Vec::new();
 
     for iterations in [200_000, 400_000, 600_000, 800_000, 1_000_000] {
         // The `criterion` library only benchmarks the time consumption, however
-        // we additinally want to know the gas used, so that we can compute the
-        // gas per second. So we record it separately here.
-        let mut sum = 0;
-        let mut repeats = 0;
+        // we additinally want to know the gas used, so that we can fit the time
+        // against it. So we record both separately here.
+        let mut point = DataPoint::default();
 
         c.bench_with_input(
-            BenchmarkId::new("looping", iterations),
+            BenchmarkId::new(format!("looping/{name}"), iterations),
             &iterations,
             |b, iterations| {
                 // `Bencher::iter_with_setup` has been deprecated, in favor of
@@ -45,7 +124,10 @@ fn looping(c: &mut Criterion) {
                 b.iter_batched(
                     || {
                         let storage = Box::new(MockStorage::new());
-                        let gas_tracker = GasTracker::new_limitless();
+                        let gas_tracker = GasTracker::new_limitless().with_costs(GasCosts {
+                            wasm_metering: metering,
+                            ..DEFAULT_GAS_COSTS
+                        });
 
                         let querier = QuerierProviderImpl::new_boxed(
                             vm.clone(),
@@ -94,14 +176,15 @@ fn looping(c: &mut Criterion) {
                         let (instance, ctx, msg, ok, gas_tracker) = suite;
 
                         // Call the `loop` query method
+                        let start = Instant::now();
                         let output = instance.call_in_1_out_1("query", &ctx, &msg).unwrap();
+                        let elapsed = start.elapsed();
 
                         // Make sure the contract didn't error
                         assert_eq!(output, ok);
 
-                        // Record the gas consumed
-                        sum += gas_tracker.used();
-                        repeats += 1;
+                        // Record the gas consumed and the time spent
+                        point.record(gas_tracker.used(), elapsed);
                     },
                     BatchSize::SmallInput,
                 )
@@ -109,17 +192,153 @@ fn looping(c: &mut Criterion) {
         );
 
         println!(
-            "Iterations per run = {}; points per run = {}\n",
+            "Metering = {}; iterations per run = {}; points per run = {:.0}; nanos per run = {:.0}\n",
+            name,
             iterations,
-            sum / repeats
+            point.gas(),
+            point.nanos()
         );
+
+        points.push(point);
+    }
+
+    points
+}
+
+/// Minimal modules that each run one class of operators in a loop of `n`
+/// iterations.
+///
+/// If operators are priced realistically, the gas consumed per second of run
+/// time should be roughly the same across all classes.
+const OPERATOR_CLASSES: [(&str, &str, i64); 6] = [
+    (
+        "simple",
+        "(local.set $acc (i64.add (local.get $acc) (i64.const 3)))",
+        1_000_000,
+    ),
+    (
+        "memory_access",
+        "(i64.store (i32.const 0) (i64.add (i64.load (i32.const 8)) (local.get $i)))",
+        1_000_000,
+    ),
+    ("call", "(call $noop)", 1_000_000),
+    (
+        "call_indirect",
+        "(call_indirect (type $void) (i32.const 0))",
+        1_000_000,
+    ),
+    (
+        "division",
+        "(local.set $acc (i64.div_u (i64.const -1) (i64.or (local.get $i) (i64.const 1))))",
+        1_000_000,
+    ),
+    // Each iteration allocates a new page, so use fewer iterations.
+    ("memory_grow", "(drop (memory.grow (i32.const 1)))", 1_000),
+];
+
+fn operator_class_wat(body: &str) -> String {
+    format!(
+        r#"(module
+            (type $void (func))
+            (memory (export "memory") 1)
+            (table 1 funcref)
+            (elem (i32.const 0) $noop)
+            (func $noop)
+            (func (export "run") (param $n i64)
+                (local $i i64)
+                (local $acc i64)
+                (loop $loop
+                    {body}
+                    (local.set $i (i64.add (local.get $i) (i64.const 1)))
+                    (br_if $loop (i64.lt_u (local.get $i) (local.get $n))))))"#
+    )
+}
+
+fn operator_classes(c: &mut Criterion) {
+    for (name, metering) in METERINGS {
+        let costs = OperatorCosts::from(metering);
+        let mut points = Vec::new();
+
+        for (class, body, iterations) in OPERATOR_CLASSES {
+            let mut compiler = Singlepass::new();
+            compiler.push_middleware(Arc::new(Metering::new(0, move |operator| {
+                costs.cost(operator)
+            })));
+
+            let engine = Engine::from(compiler);
+            let module = Module::new(&engine, operator_class_wat(body)).unwrap();
+
+            let mut point = DataPoint::default();
+
+            c.bench_with_input(
+                BenchmarkId::new(format!("operator_classes/{name}"), class),
+                &iterations,
+                |b, iterations| {
+                    b.iter_batched(
+                        || {
+                            let mut store = Store::new(engine.clone());
+                            let instance =
+                                wasmer::Instance::new(&mut store, &module, &imports! {}).unwrap();
+                            set_remaining_points(&mut store, &instance, u64::MAX);
+
+                            let run: TypedFunction<i64, ()> =
+                                instance.exports.get_typed_function(&store, "run").unwrap();
+
+                            (store, instance, run)
+                        },
+                        |(mut store, instance, run)| {
+                            let start = Instant::now();
+                            run.call(&mut store, *iterations).unwrap();
+                            let elapsed = start.elapsed();
+
+                            let MeteringPoints::Remaining(remaining) =
+                                get_remaining_points(&mut store, &instance)
+                            else {
+                                panic!("ran out of gas");
+                            };
+
+                            point.record(u64::MAX - remaining, elapsed);
+                        },
+                        BatchSize::SmallInput,
+                    )
+                },
+            );
+
+            println!(
+                "Metering = {}; operator class = {}; points per run = {:.0}; gas per second = {:.0}\n",
+                name,
+                class,
+                point.gas(),
+                point.gas() / point.nanos() * 1e9
+            );
+
+            points.push(point);
+        }
+
+        // If operators are priced realistically, the gas per second is about
+        // the same across classes, i.e. the coefficient of variation is small,
+        // and gas correlates with time.
+        let rates = points
+            .iter()
+            .map(|point| point.gas() / point.nanos())
+            .collect::<Vec<_>>();
+        let mean = rates.iter().sum::<f64>() / rates.len() as f64;
+        let variance =
+            rates.iter().map(|rate| (rate - mean).powi(2)).sum::<f64>() / rates.len() as f64;
+
+        println!(
+            "operator_classes/{name}: coefficient of variation of gas per second = {:.4}\n",
+            variance.sqrt() / mean
+        );
+
+        report_fit(&format!("operator_classes/{name}"), &points);
     }
 }
 
 criterion_group! {
     name = wasmer_metering;
     config = Criterion::default().measurement_time(Duration::from_secs(40)).sample_size(200);
-    targets = looping
+    targets = looping, operator_classes
 }
 
 criterion_main!(wasmer_metering);
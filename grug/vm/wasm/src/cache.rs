@@ -1,7 +1,7 @@
 use {
     crate::VmResult,
     clru::CLruCache,
-    grug_types::{Hash256, Shared},
+    grug_types::{Hash256, Metrics, Shared, WasmMetering},
     std::num::NonZeroUsize,
     wasmer::{Engine, Module},
 };
@@ -13,30 +13,6 @@ use {
 /// engine from that was used to build the module.
 type Data = (Module, Engine);
 
-/// Statistics about the usage of the cache instance.
-#[derive(Default, Debug, Clone, Copy)]
-pub struct Metrics {
-    pub hits: usize,
-    pub misses: usize,
-}
-
-impl Metrics {
-    pub fn new() -> Self {
-        Self { hits: 0, misses: 0 }
-    }
-
-    pub fn increment_hits(&mut self) {
-        // In practice, it's unlikely a cache is hit `usize::MAX` times, but we
-        // still use saturating add to avoid panicking on overflow.
-        self.hits = self.hits.saturating_add(1);
-    }
-
-    pub fn increment_misses(&mut self) {
-        // Same as above, use saturating add to avoid panicking on overflow.
-        self.misses = self.misses.saturating_add(1);
-    }
-}
-
 /// An in-memory cache for wasm modules, so that they don't need to be re-built
 /// every time the same contract is called.
 #[derive(Clone)]
@@ -45,7 +21,7 @@ pub struct Cache {
 }
 
 struct CacheInner {
-    lru_cache: CLruCache<Hash256, Data>,
+    lru_cache: CLruCache<(Hash256, WasmMetering), Data>,
     metrics: Metrics,
 }
 
@@ -60,15 +36,20 @@ impl Cache {
         }
     }
 
-    /// Attempt to get a cached module by hash. If not found, build the module
-    /// using the given method, insert the built module into the cache, and
-    /// return the module.
-    pub fn get_or_build_with<B>(&self, code_hash: Hash256, builder: B) -> VmResult<Data>
+    /// Attempt to get a cached module by hash and the way it's metered. If not
+    /// found, build the module using the given method, insert the built module
+    /// into the cache, and return the module.
+    pub fn get_or_build_with<B>(
+        &self,
+        code_hash: Hash256,
+        metering: WasmMetering,
+        builder: B,
+    ) -> VmResult<Data>
     where
         B: FnOnce() -> VmResult<Data>,
     {
         self.inner.write_with(|mut inner| {
-            match inner.lru_cache.get(&code_hash).cloned() {
+            match inner.lru_cache.get(&(code_hash, metering)).cloned() {
                 // Cache hit - simply clone the cached data and return.
                 Some(data) => {
                     inner.metrics.increment_hits();
@@ -80,7 +61,7 @@ impl Cache {
                 None => {
                     let data = builder()?;
 
-                    inner.lru_cache.put(code_hash, data.clone());
+                    inner.lru_cache.put((code_hash, metering), data.clone());
                     inner.metrics.increment_misses();
 
                     Ok(data)
@@ -96,7 +77,7 @@ impl Cache {
 mod tests {
     use {
         crate::{Cache, VmResult},
-        grug_types::HashExt,
+        grug_types::{HashExt, WasmMetering},
         std::num::NonZeroUsize,
         wasmer::{Engine, Module, Singlepass},
     };
@@ -116,18 +97,22 @@ mod tests {
         // Build the 1st contract. Should be a cache miss, and the data is
         // inserted into the cache.
         let hash1 = CONTRACT.hash256();
-        cache.get_or_build_with(hash1, builder).unwrap();
+        cache
+            .get_or_build_with(hash1, WasmMetering::Flat, builder)
+            .unwrap();
 
         // Build the 2nd contract. Should also be a cache miss, and the data is
         // inserted. Data of the previous build should have been removed,
         // because the cache only has a capacity of 1.
         let hash2 = b"jake".hash256();
-        cache.get_or_build_with(hash2, builder).unwrap();
+        cache
+            .get_or_build_with(hash2, WasmMetering::Flat, builder)
+            .unwrap();
 
         // Cache should have had 2 misses, with hash2 cached but hash1 not.
         cache.inner.read_with(|inner| {
-            assert!(!inner.lru_cache.contains(&hash1));
-            assert!(inner.lru_cache.contains(&hash2));
+            assert!(!inner.lru_cache.contains(&(hash1, WasmMetering::Flat)));
+            assert!(inner.lru_cache.contains(&(hash2, WasmMetering::Flat)));
             assert_eq!(inner.lru_cache.len(), 1);
             assert_eq!(inner.metrics.hits, 0);
             assert_eq!(inner.metrics.misses, 2);
@@ -141,14 +126,39 @@ mod tests {
         // Build the same contract twice. 1st time should be a cache miss, 2nd
         // time should be a cache hit.
         let hash = CONTRACT.hash256();
-        cache.get_or_build_with(hash, builder).unwrap();
-        cache.get_or_build_with(hash, builder).unwrap();
+        cache
+            .get_or_build_with(hash, WasmMetering::Flat, builder)
+            .unwrap();
+        cache
+            .get_or_build_with(hash, WasmMetering::Flat, builder)
+            .unwrap();
 
         cache.inner.read_with(|inner| {
-            assert!(inner.lru_cache.contains(&hash));
+            assert!(inner.lru_cache.contains(&(hash, WasmMetering::Flat)));
             assert_eq!(inner.lru_cache.len(), 1);
             assert_eq!(inner.metrics.hits, 1);
             assert_eq!(inner.metrics.misses, 1);
         });
     }
+
+    #[test]
+    fn cached_separately_per_metering() {
+        let cache = Cache::new(NonZeroUsize::new(2).unwrap());
+
+        // Build the same contract with different ways of metering. Both should
+        // be cache misses, as the operator costs are baked into the module.
+        let hash = CONTRACT.hash256();
+        cache
+            .get_or_build_with(hash, WasmMetering::Flat, builder)
+            .unwrap();
+        cache
+            .get_or_build_with(hash, WasmMetering::ByClass, builder)
+            .unwrap();
+
+        cache.inner.read_with(|inner| {
+            assert_eq!(inner.lru_cache.len(), 2);
+            assert_eq!(inner.metrics.hits, 0);
+            assert_eq!(inner.metrics.misses, 2);
+        });
+    }
 }
@@ -283,7 +283,7 @@ impl Environment {
 #[cfg(test)]
 mod test {
     use {
-        crate::{Environment, GAS_PER_OPERATION, Iterator, VmError, VmResult, WasmVm},
+        crate::{Environment, Iterator, OperatorCosts, VmError, VmResult, WasmVm},
         grug_app::{GasTracker, QuerierProviderImpl, StorageProvider},
         grug_types::{
             BlockInfo, Hash256, MockStorage, Order, Shared, StdError, Storage, Timestamp,
@@ -315,7 +315,10 @@ mod test {
         // Compile the contract; create Wasmer store and instance.
         let (store, instance) = {
             let mut compiler = Singlepass::new();
-            compiler.push_middleware(Arc::new(Metering::new(0, |_| GAS_PER_OPERATION)));
+            let operator_costs = OperatorCosts::from(gas_tracker.costs().wasm_metering);
+            compiler.push_middleware(Arc::new(Metering::new(0, move |operator| {
+                operator_costs.cost(operator)
+            })));
 
             let engine = Engine::from(compiler);
             let module = Module::new(&engine, wat).unwrap();
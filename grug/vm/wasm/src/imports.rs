@@ -1,6 +1,5 @@
 use {
     crate::{Environment, Iterator, VmError, VmResult, read_from_memory, write_to_memory},
-    grug_app::GAS_COSTS,
     grug_types::{Addr, BorshDeExt, BorshSerExt, Query, Record, Storage, decode_sections},
     tracing::info,
     wasmer::FunctionEnvMut,
@@ -15,13 +14,17 @@ pub fn db_read(mut fe: FunctionEnvMut<Environment>, key_ptr: u32) -> VmResult<u3
         Some(value) => {
             env.consume_external_gas(
                 &mut store,
-                GAS_COSTS.db_read.cost(value.len()),
+                env.gas_tracker.costs().db_read.cost(value.len()),
                 "db_read/found",
             )?;
             write_to_memory(env, &mut store, &value)
         },
         None => {
-            env.consume_external_gas(&mut store, GAS_COSTS.db_read.cost(0), "db_read/not_found")?;
+            env.consume_external_gas(
+                &mut store,
+                env.gas_tracker.costs().db_read.cost(0),
+                "db_read/not_found",
+            )?;
             // If the record doesn't exist, return a zero pointer.
             Ok(0)
         },
@@ -50,7 +53,7 @@ pub fn db_scan(
     let order = order.try_into()?;
     let iterator = Iterator::new(min, max, order);
 
-    env.consume_external_gas(&mut store, GAS_COSTS.db_scan, "db_scan")?;
+    env.consume_external_gas(&mut store, env.gas_tracker.costs().db_scan, "db_scan")?;
 
     Ok(env.add_iterator(iterator))
 }
@@ -60,16 +63,19 @@ pub fn db_next(mut fe: FunctionEnvMut<Environment>, iterator_id: i32) -> VmResul
 
     match env.advance_iterator(iterator_id)? {
         Some((key, value)) => {
-            env.consume_external_gas(
-                &mut store,
-                GAS_COSTS.db_next + GAS_COSTS.db_read.cost(key.len() + value.len()),
-                "db_next/found",
-            )?;
+            let costs = env.gas_tracker.costs();
+            let cost = costs.db_next + costs.db_read.cost(key.len() + value.len());
+
+            env.consume_external_gas(&mut store, cost, "db_next/found")?;
 
             write_to_memory(env, &mut store, &encode_record((key, value)))
         },
         None => {
-            env.consume_external_gas(&mut store, GAS_COSTS.db_next, "db_next/not_found")?;
+            env.consume_external_gas(
+                &mut store,
+                env.gas_tracker.costs().db_next,
+                "db_next/not_found",
+            )?;
 
             Ok(0)
         },
@@ -81,16 +87,19 @@ pub fn db_next_key(mut fe: FunctionEnvMut<Environment>, iterator_id: i32) -> VmR
 
     match env.advance_iterator(iterator_id)? {
         Some((key, _)) => {
-            env.consume_external_gas(
-                &mut store,
-                GAS_COSTS.db_next + GAS_COSTS.db_read.cost(key.len()),
-                "db_next_key/found",
-            )?;
+            let costs = env.gas_tracker.costs();
+            let cost = costs.db_next + costs.db_read.cost(key.len());
+
+            env.consume_external_gas(&mut store, cost, "db_next_key/found")?;
 
             write_to_memory(env, &mut store, &key)
         },
         None => {
-            env.consume_external_gas(&mut store, GAS_COSTS.db_next, "db_next_key/not_found")?;
+            env.consume_external_gas(
+                &mut store,
+                env.gas_tracker.costs().db_next,
+                "db_next_key/not_found",
+            )?;
 
             Ok(0)
         },
@@ -102,16 +111,19 @@ pub fn db_next_value(mut fe: FunctionEnvMut<Environment>, iterator_id: i32) -> V
 
     match env.advance_iterator(iterator_id)? {
         Some((_, value)) => {
-            env.consume_external_gas(
-                &mut store,
-                GAS_COSTS.db_next + GAS_COSTS.db_read.cost(value.len()),
-                "db_next_value/found",
-            )?;
+            let costs = env.gas_tracker.costs();
+            let cost = costs.db_next + costs.db_read.cost(value.len());
+
+            env.consume_external_gas(&mut store, cost, "db_next_value/found")?;
 
             write_to_memory(env, &mut store, &value)
         },
         None => {
-            env.consume_external_gas(&mut store, GAS_COSTS.db_next, "db_next_value/not_found")?;
+            env.consume_external_gas(
+                &mut store,
+                env.gas_tracker.costs().db_next,
+                "db_next_value/not_found",
+            )?;
 
             Ok(0)
         },
@@ -133,7 +145,9 @@ pub fn db_write(mut fe: FunctionEnvMut<Environment>, key_ptr: u32, value_ptr: u3
     let key = read_from_memory(env, &store, key_ptr)?;
     let value = read_from_memory(env, &store, value_ptr)?;
 
-    let gas_cost = GAS_COSTS
+    let gas_cost = env
+        .gas_tracker
+        .costs()
         .db_write
         .cost(env.storage.namespace().len() + key.len() + value.len());
 
@@ -177,7 +191,11 @@ pub fn db_remove(mut fe: FunctionEnvMut<Environment>, key_ptr: u32) -> VmResult<
 
     env.storage.remove(&key);
     env.clear_iterators();
-    env.consume_external_gas(&mut store, GAS_COSTS.db_remove, "storage_remove")
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker.costs().db_remove,
+        "storage_remove",
+    )
 }
 
 pub fn db_remove_range(
@@ -204,7 +222,11 @@ pub fn db_remove_range(
 
     env.storage.remove_range(min.as_deref(), max.as_deref());
     env.clear_iterators();
-    env.consume_external_gas(&mut store, GAS_COSTS.db_remove, "storage_remove_range")
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker.costs().db_remove,
+        "storage_remove_range",
+    )
 }
 
 pub fn debug(mut fe: FunctionEnvMut<Environment>, addr_ptr: u32, msg_ptr: u32) -> VmResult<()> {
@@ -250,7 +272,11 @@ pub fn secp256k1_verify(
     let sig = read_from_memory(env, &store, sig_ptr)?;
     let pk = read_from_memory(env, &store, pk_ptr)?;
 
-    env.consume_external_gas(&mut store, GAS_COSTS.secp256k1_verify, "secp256k1_verify")?;
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker.costs().secp256k1_verify,
+        "secp256k1_verify",
+    )?;
 
     match grug_crypto::secp256k1_verify(&msg_hash, &sig, &pk) {
         Ok(()) => Ok(0),
@@ -270,7 +296,11 @@ pub fn secp256r1_verify(
     let sig = read_from_memory(env, &store, sig_ptr)?;
     let pk = read_from_memory(env, &store, pk_ptr)?;
 
-    env.consume_external_gas(&mut store, GAS_COSTS.secp256k1_verify, "secp256r1_verify")?;
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker.costs().secp256k1_verify,
+        "secp256r1_verify",
+    )?;
 
     match grug_crypto::secp256r1_verify(&msg_hash, &sig, &pk) {
         Ok(()) => Ok(0),
@@ -298,7 +328,7 @@ pub fn secp256k1_pubkey_recover(
 
     env.consume_external_gas(
         &mut store,
-        GAS_COSTS.secp256k1_pubkey_recover,
+        env.gas_tracker.costs().secp256k1_pubkey_recover,
         "secp256k1_pubkey_recover",
     )?;
 
@@ -316,6 +346,61 @@ pub fn secp256k1_pubkey_recover(
     Ok(((error_code as u64) << 32) | (ptr as u64))
 }
 
+pub fn secp256k1_batch_verify(
+    mut fe: FunctionEnvMut<Environment>,
+    msg_hashes_ptr: u32,
+    sigs_ptr: u32,
+    pks_ptr: u32,
+) -> VmResult<u32> {
+    let (env, mut store) = fe.data_and_store_mut();
+
+    let msg_hashes = read_from_memory(env, &store, msg_hashes_ptr)?;
+    let sigs = read_from_memory(env, &store, sigs_ptr)?;
+    let pks = read_from_memory(env, &store, pks_ptr)?;
+
+    let msg_hashes = decode_sections(&msg_hashes);
+    let sigs = decode_sections(&sigs);
+    let pks = decode_sections(&pks);
+
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker
+            .costs()
+            .secp256k1_batch_verify
+            .cost(msg_hashes.len()),
+        "secp256k1_batch_verify",
+    )?;
+
+    match grug_crypto::secp256k1_batch_verify(&msg_hashes, &sigs, &pks) {
+        Ok(()) => Ok(0),
+        Err(err) => Ok(err.into_error_code()),
+    }
+}
+
+pub fn secp256k1_schnorr_verify(
+    mut fe: FunctionEnvMut<Environment>,
+    msg_hash_ptr: u32,
+    sig_ptr: u32,
+    pk_ptr: u32,
+) -> VmResult<u32> {
+    let (env, mut store) = fe.data_and_store_mut();
+
+    let msg_hash = read_from_memory(env, &store, msg_hash_ptr)?;
+    let sig = read_from_memory(env, &store, sig_ptr)?;
+    let pk = read_from_memory(env, &store, pk_ptr)?;
+
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker.costs().secp256k1_schnorr_verify,
+        "secp256k1_schnorr_verify",
+    )?;
+
+    match grug_crypto::secp256k1_schnorr_verify(&msg_hash, &sig, &pk) {
+        Ok(()) => Ok(0),
+        Err(err) => Ok(err.into_error_code()),
+    }
+}
+
 pub fn ed25519_verify(
     mut fe: FunctionEnvMut<Environment>,
     msg_hash_ptr: u32,
@@ -328,7 +413,11 @@ pub fn ed25519_verify(
     let sig = read_from_memory(env, &store, sig_ptr)?;
     let pk = read_from_memory(env, &store, pk_ptr)?;
 
-    env.consume_external_gas(&mut store, GAS_COSTS.ed25519_verify, "ed25519_verify")?;
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker.costs().ed25519_verify,
+        "ed25519_verify",
+    )?;
 
     match grug_crypto::ed25519_verify(&msg_hash, &sig, &pk) {
         Ok(()) => Ok(0),
@@ -354,7 +443,10 @@ pub fn ed25519_batch_verify(
 
     env.consume_external_gas(
         &mut store,
-        GAS_COSTS.ed25519_batch_verify.cost(prehash_msgs.len()),
+        env.gas_tracker
+            .costs()
+            .ed25519_batch_verify
+            .cost(prehash_msgs.len()),
         "ed25519_batch_verify",
     )?;
 
@@ -364,6 +456,88 @@ pub fn ed25519_batch_verify(
     }
 }
 
+pub fn bls12_381_verify(
+    mut fe: FunctionEnvMut<Environment>,
+    msg_hash_ptr: u32,
+    sig_ptr: u32,
+    pk_ptr: u32,
+) -> VmResult<u32> {
+    let (env, mut store) = fe.data_and_store_mut();
+
+    let msg_hash = read_from_memory(env, &store, msg_hash_ptr)?;
+    let sig = read_from_memory(env, &store, sig_ptr)?;
+    let pk = read_from_memory(env, &store, pk_ptr)?;
+
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker.costs().bls12_381_verify,
+        "bls12_381_verify",
+    )?;
+
+    match grug_crypto::bls12_381_verify(&msg_hash, &sig, &pk) {
+        Ok(()) => Ok(0),
+        Err(err) => Ok(err.into_error_code()),
+    }
+}
+
+pub fn bls12_381_aggregate_verify(
+    mut fe: FunctionEnvMut<Environment>,
+    msg_hash_ptr: u32,
+    sig_ptr: u32,
+    pks_ptr: u32,
+) -> VmResult<u32> {
+    let (env, mut store) = fe.data_and_store_mut();
+
+    let msg_hash = read_from_memory(env, &store, msg_hash_ptr)?;
+    let sig = read_from_memory(env, &store, sig_ptr)?;
+    let pks = read_from_memory(env, &store, pks_ptr)?;
+
+    let pks = decode_sections(&pks);
+
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker
+            .costs()
+            .bls12_381_aggregate_verify
+            .cost(pks.len()),
+        "bls12_381_aggregate_verify",
+    )?;
+
+    match grug_crypto::bls12_381_aggregate_verify(&msg_hash, &sig, &pks) {
+        Ok(()) => Ok(0),
+        Err(err) => Ok(err.into_error_code()),
+    }
+}
+
+pub fn bls12_381_pairing_check(
+    mut fe: FunctionEnvMut<Environment>,
+    g1s_ptr: u32,
+    g2s_ptr: u32,
+) -> VmResult<u32> {
+    let (env, mut store) = fe.data_and_store_mut();
+
+    let g1s = read_from_memory(env, &store, g1s_ptr)?;
+    let g2s = read_from_memory(env, &store, g2s_ptr)?;
+
+    let g1s = decode_sections(&g1s);
+    let g2s = decode_sections(&g2s);
+
+    // Charge by the longer of the two lists, in case they mismatch.
+    env.consume_external_gas(
+        &mut store,
+        env.gas_tracker
+            .costs()
+            .bls12_381_pairing_check
+            .cost(g1s.len().max(g2s.len())),
+        "bls12_381_pairing_check",
+    )?;
+
+    match grug_crypto::bls12_381_pairing_check(&g1s, &g2s) {
+        Ok(()) => Ok(0),
+        Err(err) => Ok(err.into_error_code()),
+    }
+}
+
 macro_rules! impl_hash_method {
     ($hasher:ident, $name:literal) => {
         pub fn $hasher(mut fe: FunctionEnvMut<Environment>, data_ptr: u32) -> VmResult<u32> {
@@ -372,7 +546,7 @@ macro_rules! impl_hash_method {
             let data = read_from_memory(env, &store, data_ptr)?;
             let hash = grug_crypto::$hasher(&data);
 
-            env.consume_external_gas(&mut store, GAS_COSTS.$hasher.cost(data.len()), $name)?;
+            env.consume_external_gas(&mut store, env.gas_tracker.costs().$hasher.cost(data.len()), $name)?;
 
             write_to_memory(env, &mut store, &hash)
         }
@@ -411,13 +585,14 @@ fn encode_record((mut k, v): Record) -> Vec<u8> {
 mod tests {
     use {
         crate::{
-            Environment, GAS_PER_OPERATION, VmResult, WasmVm, db_read, db_remove, db_remove_range,
+            Environment, OperatorCosts, VmResult, WasmVm, db_read, db_remove, db_remove_range,
             db_scan, db_write, debug, read_from_memory, write_to_memory,
         },
-        grug_app::{APP_CONFIG, GAS_COSTS, GasTracker, QuerierProviderImpl, StorageProvider},
+        grug_app::{APP_CONFIG, GasTracker, QuerierProviderImpl, StorageProvider},
         grug_types::{
-            Addr, BlockInfo, BorshDeExt, BorshSerExt, GenericResult, Hash256, MockStorage, Order,
-            Query, QueryResponse, ResultExt, Shared, Storage, Timestamp, encode_sections, json,
+            Addr, BlockInfo, BorshDeExt, BorshSerExt, DEFAULT_GAS_COSTS, GenericResult, Hash256,
+            MockStorage, Order, Query, QueryResponse, ResultExt, Shared, Storage, Timestamp,
+            encode_sections, json,
         },
         identity::{Identity256, Identity512},
         rand::rngs::OsRng,
@@ -477,7 +652,10 @@ mod tests {
         // Compile the contract; create Wasmer store and instance.
         let (mut store, instance) = {
             let mut compiler = Singlepass::new();
-            compiler.push_middleware(Arc::new(Metering::new(0, |_| GAS_PER_OPERATION)));
+            let operator_costs = OperatorCosts::from(gas_tracker.costs().wasm_metering);
+            compiler.push_middleware(Arc::new(Metering::new(0, move |operator| {
+                operator_costs.cost(operator)
+            })));
 
             let engine = Engine::from(compiler);
             let module = Module::new(&engine, TESTER_CONTRACT).unwrap();
@@ -487,31 +665,36 @@ mod tests {
             // Import functions are not used but need to be defined.
             let import_obj = imports! {
                 "env" => {
-                    "db_read"                  => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "db_scan"                  => Function::new_typed(&mut store, |_: u32, _: u32, _: i32|       -> u32 { 0 }),
-                    "db_next"                  => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "db_next_key"              => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "db_next_value"            => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "db_write"                 => Function::new_typed(&mut store, |_: u32, _: u32|                      {   }),
-                    "db_remove"                => Function::new_typed(&mut store, |_: u32|                              {   }),
-                    "db_remove_range"          => Function::new_typed(&mut store, |_: u32, _: u32|                      {   }),
-                    "secp256k1_verify"         => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
-                    "secp256r1_verify"         => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
-                    "secp256k1_pubkey_recover" => Function::new_typed(&mut store, |_: u32, _: u32, _: u8, _: u8| -> u64 { 0 }),
-                    "ed25519_verify"           => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
-                    "ed25519_batch_verify"     => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
-                    "sha2_256"                 => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "sha2_512"                 => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "sha2_512_truncated"       => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "sha3_256"                 => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "sha3_512"                 => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "sha3_512_truncated"       => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "keccak256"                => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "blake2s_256"              => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "blake2b_512"              => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "blake3"                   => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
-                    "debug"                    => Function::new_typed(&mut store, |_: u32, _: u32|                      {   }),
-                    "query_chain"              => Function::new_typed(&mut store, |_: u32,|                      -> u32 { 0 }),
+                    "db_read"                    => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "db_scan"                    => Function::new_typed(&mut store, |_: u32, _: u32, _: i32|       -> u32 { 0 }),
+                    "db_next"                    => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "db_next_key"                => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "db_next_value"              => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "db_write"                   => Function::new_typed(&mut store, |_: u32, _: u32|                      {   }),
+                    "db_remove"                  => Function::new_typed(&mut store, |_: u32|                              {   }),
+                    "db_remove_range"            => Function::new_typed(&mut store, |_: u32, _: u32|                      {   }),
+                    "secp256k1_verify"           => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "secp256r1_verify"           => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "secp256k1_pubkey_recover"   => Function::new_typed(&mut store, |_: u32, _: u32, _: u8, _: u8| -> u64 { 0 }),
+                    "secp256k1_batch_verify"     => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "secp256k1_schnorr_verify"   => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "ed25519_verify"             => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "ed25519_batch_verify"       => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "bls12_381_verify"           => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "bls12_381_aggregate_verify" => Function::new_typed(&mut store, |_: u32, _: u32, _: u32|       -> u32 { 0 }),
+                    "bls12_381_pairing_check"    => Function::new_typed(&mut store, |_: u32, _: u32|              -> u32 { 0 }),
+                    "sha2_256"                   => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "sha2_512"                   => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "sha2_512_truncated"         => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "sha3_256"                   => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "sha3_512"                   => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "sha3_512_truncated"         => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "keccak256"                  => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "blake2s_256"                => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "blake2b_512"                => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "blake3"                     => Function::new_typed(&mut store, |_: u32|                       -> u32 { 0 }),
+                    "debug"                      => Function::new_typed(&mut store, |_: u32, _: u32|                      {   }),
+                    "query_chain"                => Function::new_typed(&mut store, |_: u32,|                      -> u32 { 0 }),
                 },
             };
 
@@ -754,7 +937,7 @@ mod tests {
 
         let gas_consumed = suite.env_mut().gas_tracker.used() - gas_pre;
 
-        let cost = GAS_COSTS
+        let cost = DEFAULT_GAS_COSTS
             .db_write
             .cost(NAMESPACE_CONTRACT.len() + k.len() + v.len());
 
@@ -785,7 +968,7 @@ mod tests {
 
         let gas_consumed = suite.env_mut().gas_tracker.used() - gas_pre;
 
-        assert_eq!(gas_consumed, GAS_COSTS.db_remove);
+        assert_eq!(gas_consumed, DEFAULT_GAS_COSTS.db_remove);
     }
 
     // ---------------------------- db_remove_range ----------------------------
@@ -847,7 +1030,7 @@ mod tests {
 
         let gas_consumed = suite.env_mut().gas_tracker.used() - gas_pre;
 
-        assert_eq!(gas_consumed, GAS_COSTS.db_remove);
+        assert_eq!(gas_consumed, DEFAULT_GAS_COSTS.db_remove);
     }
 
     // -------------------------------- debug ----------------------------------
@@ -938,6 +1121,21 @@ mod tests {
         }
     }
 
+    fn generate_secp256k1_schnorr_verify_request() -> VerifyTest {
+        use k256::schnorr::SigningKey;
+
+        let sk = SigningKey::random(&mut OsRng);
+        let msg_hash = grug_crypto::sha2_256(MSG);
+        let sig = sk.sign_raw(&msg_hash, &[0; 32]).unwrap();
+
+        VerifyTest {
+            pk: sk.verifying_key().to_bytes().to_vec(),
+            sig: sig.to_bytes().to_vec(),
+            msg_hash: msg_hash.to_vec(),
+            wrong_msg: grug_crypto::sha2_256(WRONG_MSG).to_vec(),
+        }
+    }
+
     fn generate_ed25519_verify_request() -> VerifyTest {
         use ed25519_dalek::{DigestSigner, SigningKey, VerifyingKey};
 
@@ -964,6 +1162,11 @@ mod tests {
         generate_secp256r1_verify_request;
         "secp256kr_verify"
     )]
+    #[test_case(
+        crate::secp256k1_schnorr_verify,
+        generate_secp256k1_schnorr_verify_request;
+        "secp256k1_schnorr_verify"
+    )]
     #[test_case(
         crate::ed25519_verify,
         generate_ed25519_verify_request;
@@ -1134,6 +1337,65 @@ mod tests {
         }
     }
 
+    // ---------------------- bls12_381_aggregate_verify -----------------------
+    #[test]
+    fn bls12_381_aggregate_verify_works() {
+        use {
+            blst::min_pk::{AggregateSignature, SecretKey},
+            rand::RngCore,
+        };
+
+        let mut suite = setup_test();
+
+        let msg_hash = grug_crypto::sha2_256(b"msg");
+        let sks = (0..3)
+            .map(|_| {
+                let mut ikm = [0; 32];
+                OsRng.fill_bytes(&mut ikm);
+                SecretKey::key_gen(&ikm, &[]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let sigs = sks
+            .iter()
+            .map(|sk| sk.sign(&msg_hash, grug_crypto::BLS12_381_DST, &[]))
+            .collect::<Vec<_>>();
+        let sig = AggregateSignature::aggregate(&sigs.iter().collect::<Vec<_>>(), true)
+            .unwrap()
+            .to_signature()
+            .compress();
+        let pks = sks
+            .iter()
+            .map(|sk| sk.sk_to_pk().compress())
+            .collect::<Vec<_>>();
+        let pks = pks.iter().map(|pk| pk.as_slice()).collect::<Vec<_>>();
+
+        // Ok
+        {
+            let ptr_msg_hash = suite.write(&msg_hash).unwrap();
+            let ptr_sig = suite.write(&sig).unwrap();
+            let ptr_pks = suite.write(&encode_sections(&pks).unwrap()).unwrap();
+
+            let result =
+                crate::bls12_381_aggregate_verify(suite.fe_mut(), ptr_msg_hash, ptr_sig, ptr_pks)
+                    .unwrap();
+
+            assert_eq!(result, 0);
+        }
+
+        // Fail: one of the signers is missing
+        {
+            let ptr_msg_hash = suite.write(&msg_hash).unwrap();
+            let ptr_sig = suite.write(&sig).unwrap();
+            let ptr_pks = suite.write(&encode_sections(&pks[..2]).unwrap()).unwrap();
+
+            let result =
+                crate::bls12_381_aggregate_verify(suite.fe_mut(), ptr_msg_hash, ptr_sig, ptr_pks)
+                    .unwrap();
+
+            assert_eq!(result, 3);
+        }
+    }
+
     // --------------------------------- Hash ----------------------------------
 
     #[test_case(
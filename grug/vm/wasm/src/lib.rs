@@ -5,6 +5,7 @@ mod gatekeeper;
 mod imports;
 mod iterator;
 mod memory;
+mod metering;
 mod region;
 #[cfg(feature = "testing")]
 mod testing;
@@ -13,5 +14,5 @@ mod vm;
 
 pub use {
     cache::*, environment::*, error::*, gatekeeper::*, imports::*, iterator::*, memory::*,
-    region::*, tunables::*, vm::*,
+    metering::*, region::*, tunables::*, vm::*,
 };
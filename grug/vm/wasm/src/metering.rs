@@ -0,0 +1,213 @@
+use {crate::GAS_PER_OPERATION, grug_types::WasmMetering, wasmer::wasmparser::Operator};
+
+/// Gas costs of Wasm operators when charged by class, i.e. with
+/// [`WasmMetering::ByClass`].
+///
+/// The costs are multiples of that of a simple operation, such as an integer
+/// addition or a local variable access, which costs [`GAS_PER_OPERATION`].
+/// They are chosen such that, for each class, the gas consumed roughly tracks
+/// the CPU time spent. See the `looping` and `operator_classes` benchmarks.
+pub const OPERATOR_COSTS_BY_CLASS: OperatorCosts = OperatorCosts {
+    simple: GAS_PER_OPERATION,
+    memory_access: GAS_PER_OPERATION,
+    call: 2 * GAS_PER_OPERATION,
+    call_indirect: 4 * GAS_PER_OPERATION,
+    division: 4 * GAS_PER_OPERATION,
+    // `memory.grow` may allocate and zero up to 64 KiB per page. The number of
+    // pages is only known at run time, so we charge a flat cost that's high
+    // enough to make growing the memory in a loop prohibitively expensive.
+    memory_grow: 1_000 * GAS_PER_OPERATION,
+    // Similarly, the amount of memory copied or filled by bulk memory operations
+    // is only known at run time.
+    //
+    // Note that bulk memory operations are currently rejected by the
+    // `Gatekeeper`, so this cost only matters if that is changed.
+    bulk_memory: 100 * GAS_PER_OPERATION,
+};
+
+/// Classes of Wasm operators that are priced differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorClass {
+    /// Operators not covered by any of the other classes, such as arithmetics,
+    /// comparisons, constants, local and global variable access, and control flow.
+    Simple,
+    /// Loading from or storing to the linear memory.
+    MemoryAccess,
+    /// Calling a function directly.
+    Call,
+    /// Calling a function through a table, which involves a bounds check and a
+    /// signature check at run time.
+    CallIndirect,
+    /// Integer division and remainder, as well as float division and square
+    /// root, which are an order of magnitude slower than other arithmetics.
+    Division,
+    /// Growing the linear memory.
+    MemoryGrow,
+    /// Bulk memory operations, such as `memory.copy` and `memory.fill`.
+    BulkMemory,
+}
+
+impl OperatorClass {
+    pub fn of(operator: &Operator) -> Self {
+        match operator {
+            Operator::I32Load { .. }
+            | Operator::I64Load { .. }
+            | Operator::F32Load { .. }
+            | Operator::F64Load { .. }
+            | Operator::I32Load8S { .. }
+            | Operator::I32Load8U { .. }
+            | Operator::I32Load16S { .. }
+            | Operator::I32Load16U { .. }
+            | Operator::I64Load8S { .. }
+            | Operator::I64Load8U { .. }
+            | Operator::I64Load16S { .. }
+            | Operator::I64Load16U { .. }
+            | Operator::I64Load32S { .. }
+            | Operator::I64Load32U { .. }
+            | Operator::I32Store { .. }
+            | Operator::I64Store { .. }
+            | Operator::F32Store { .. }
+            | Operator::F64Store { .. }
+            | Operator::I32Store8 { .. }
+            | Operator::I32Store16 { .. }
+            | Operator::I64Store8 { .. }
+            | Operator::I64Store16 { .. }
+            | Operator::I64Store32 { .. } => OperatorClass::MemoryAccess,
+            Operator::Call { .. } | Operator::ReturnCall { .. } => OperatorClass::Call,
+            Operator::CallIndirect { .. } | Operator::ReturnCallIndirect { .. } => {
+                OperatorClass::CallIndirect
+            },
+            Operator::I32DivS
+            | Operator::I32DivU
+            | Operator::I32RemS
+            | Operator::I32RemU
+            | Operator::I64DivS
+            | Operator::I64DivU
+            | Operator::I64RemS
+            | Operator::I64RemU
+            | Operator::F32Div
+            | Operator::F64Div
+            | Operator::F32Sqrt
+            | Operator::F64Sqrt => OperatorClass::Division,
+            Operator::MemoryGrow { .. } => OperatorClass::MemoryGrow,
+            Operator::MemoryInit { .. }
+            | Operator::DataDrop { .. }
+            | Operator::MemoryCopy { .. }
+            | Operator::MemoryFill { .. }
+            | Operator::TableInit { .. }
+            | Operator::ElemDrop { .. }
+            | Operator::TableCopy { .. } => OperatorClass::BulkMemory,
+            _ => OperatorClass::Simple,
+        }
+    }
+}
+
+/// Gas costs of Wasm operators, by class.
+///
+/// The costs are baked into the Wasm modules at compile time. Which costs to
+/// use is decided by the chain config, so that all nodes use the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorCosts {
+    pub simple: u64,
+    pub memory_access: u64,
+    pub call: u64,
+    pub call_indirect: u64,
+    pub division: u64,
+    pub memory_grow: u64,
+    pub bulk_memory: u64,
+}
+
+impl OperatorCosts {
+    /// Costs that charge every operator the same, regardless of its class.
+    pub const fn flat(cost: u64) -> Self {
+        Self {
+            simple: cost,
+            memory_access: cost,
+            call: cost,
+            call_indirect: cost,
+            division: cost,
+            memory_grow: cost,
+            bulk_memory: cost,
+        }
+    }
+
+    /// Return the gas cost of the given operator.
+    pub fn cost(&self, operator: &Operator) -> u64 {
+        match OperatorClass::of(operator) {
+            OperatorClass::Simple => self.simple,
+            OperatorClass::MemoryAccess => self.memory_access,
+            OperatorClass::Call => self.call,
+            OperatorClass::CallIndirect => self.call_indirect,
+            OperatorClass::Division => self.division,
+            OperatorClass::MemoryGrow => self.memory_grow,
+            OperatorClass::BulkMemory => self.bulk_memory,
+        }
+    }
+}
+
+impl From<WasmMetering> for OperatorCosts {
+    fn from(metering: WasmMetering) -> Self {
+        match metering {
+            WasmMetering::Flat => OperatorCosts::flat(GAS_PER_OPERATION),
+            WasmMetering::ByClass => OPERATOR_COSTS_BY_CLASS,
+        }
+    }
+}
+
+// ----------------------------------- tests -----------------------------------
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        test_case::test_case,
+        wasmer::wasmparser::{BlockType, MemArg},
+    };
+
+    const MEM_ARG: MemArg = MemArg {
+        align: 0,
+        max_align: 0,
+        offset: 0,
+        memory: 0,
+    };
+
+    #[test_case(Operator::I32Add, OperatorClass::Simple; "add")]
+    #[test_case(Operator::LocalGet { local_index: 0 }, OperatorClass::Simple; "local get")]
+    #[test_case(Operator::Loop { blockty: BlockType::Empty }, OperatorClass::Simple; "loop")]
+    #[test_case(Operator::I64Load { memarg: MEM_ARG }, OperatorClass::MemoryAccess; "load")]
+    #[test_case(Operator::I32Store8 { memarg: MEM_ARG }, OperatorClass::MemoryAccess; "store")]
+    #[test_case(Operator::Call { function_index: 0 }, OperatorClass::Call; "call")]
+    #[test_case(Operator::CallIndirect { type_index: 0, table_index: 0 }, OperatorClass::CallIndirect; "call indirect")]
+    #[test_case(Operator::I64DivU, OperatorClass::Division; "div")]
+    #[test_case(Operator::I32RemS, OperatorClass::Division; "rem")]
+    #[test_case(Operator::MemoryGrow { mem: 0 }, OperatorClass::MemoryGrow; "memory grow")]
+    #[test_case(Operator::MemoryFill { mem: 0 }, OperatorClass::BulkMemory; "memory fill")]
+    fn classifying_operators_works(operator: Operator, class: OperatorClass) {
+        assert_eq!(OperatorClass::of(&operator), class);
+    }
+
+    #[test]
+    fn flat_costs_ignore_classes() {
+        let costs = OperatorCosts::flat(7);
+
+        assert_eq!(costs.cost(&Operator::I32Add), 7);
+        assert_eq!(costs.cost(&Operator::I64DivS), 7);
+        assert_eq!(costs.cost(&Operator::MemoryGrow { mem: 0 }), 7);
+    }
+
+    #[test]
+    fn flat_metering_is_the_default() {
+        let costs = OperatorCosts::from(WasmMetering::default());
+
+        assert_eq!(costs, OperatorCosts::flat(GAS_PER_OPERATION));
+    }
+
+    #[test]
+    fn costs_by_class_price_expensive_operators_higher() {
+        let costs = OperatorCosts::from(WasmMetering::ByClass);
+
+        assert_eq!(costs.cost(&Operator::I32Add), GAS_PER_OPERATION);
+        assert!(costs.cost(&Operator::I64DivS) > costs.cost(&Operator::I64Mul));
+        assert!(costs.cost(&Operator::MemoryGrow { mem: 0 }) > costs.cost(&Operator::I32Add));
+    }
+}
@@ -1,11 +1,12 @@
 use {
     crate::{
-        Cache, Environment, Gatekeeper, LimitingTunables, VmError, VmResult, blake2b_512,
-        blake2s_256, blake3, db_next, db_next_key, db_next_value, db_read, db_remove,
-        db_remove_range, db_scan, db_write, debug, ed25519_batch_verify, ed25519_verify, keccak256,
-        query_chain, read_then_wipe, secp256k1_pubkey_recover, secp256k1_verify, secp256r1_verify,
-        sha2_256, sha2_512, sha2_512_truncated, sha3_256, sha3_512, sha3_512_truncated,
-        write_to_memory,
+        Cache, Environment, Gatekeeper, LimitingTunables, OperatorCosts, VmError, VmResult,
+        blake2b_512, blake2s_256, blake3, bls12_381_aggregate_verify, bls12_381_pairing_check,
+        bls12_381_verify, db_next, db_next_key, db_next_value, db_read, db_remove, db_remove_range,
+        db_scan, db_write, debug, ed25519_batch_verify, ed25519_verify, keccak256, query_chain,
+        read_then_wipe, secp256k1_batch_verify, secp256k1_pubkey_recover, secp256k1_schnorr_verify,
+        secp256k1_verify, secp256r1_verify, sha2_256, sha2_512, sha2_512_truncated, sha3_256,
+        sha3_512, sha3_512_truncated, write_to_memory,
     },
     grug_app::{GasTracker, Instance, QuerierProvider, StorageProvider, Vm},
     grug_types::{BorshSerExt, Context, Hash256},
@@ -17,7 +18,11 @@ use {
     wasmer_middlewares::{Metering, metering::set_remaining_points},
 };
 
-/// Gas cost per Wasmer operation.
+/// Gas cost per simple Wasmer operation.
+///
+/// By default, every operation costs this. If the chain is configured to meter
+/// by operator class, more expensive operations cost a multiple of this. See
+/// [`OperatorCosts`].
 pub const GAS_PER_OPERATION: u64 = 1;
 
 /// Maximum number of chained queries.
@@ -84,12 +89,18 @@ impl Vm for WasmVm {
             return Err(VmError::ExceedMaxQueryDepth);
         }
 
+        // The operator costs are baked into the module when it's built, so the
+        // module is cached separately for each way of metering.
+        let metering = gas_tracker.costs().wasm_metering;
+
         let (module, engine) = if let Some(cache) = &self.cache {
             // Attempt to fetch a pre-built Wasmer module from the cache.
             // If not found, build it and insert it into the cache.
-            cache.get_or_build_with(code_hash, || compile_wasmer(code))?
+            cache.get_or_build_with(code_hash, metering, || {
+                compile_wasmer(code, metering.into())
+            })?
         } else {
-            compile_wasmer(code)?
+            compile_wasmer(code, metering.into())?
         };
 
         // Compute the amount of gas left for this call. This will be used as
@@ -121,31 +132,36 @@ impl Vm for WasmVm {
         );
         let import_obj = imports! {
             "env" => {
-                "db_read"                  => Function::new_typed_with_env(&mut store, &fe, db_read),
-                "db_scan"                  => Function::new_typed_with_env(&mut store, &fe, db_scan),
-                "db_next"                  => Function::new_typed_with_env(&mut store, &fe, db_next),
-                "db_next_key"              => Function::new_typed_with_env(&mut store, &fe, db_next_key),
-                "db_next_value"            => Function::new_typed_with_env(&mut store, &fe, db_next_value),
-                "db_write"                 => Function::new_typed_with_env(&mut store, &fe, db_write),
-                "db_remove"                => Function::new_typed_with_env(&mut store, &fe, db_remove),
-                "db_remove_range"          => Function::new_typed_with_env(&mut store, &fe, db_remove_range),
-                "secp256k1_verify"         => Function::new_typed_with_env(&mut store, &fe, secp256k1_verify),
-                "secp256r1_verify"         => Function::new_typed_with_env(&mut store, &fe, secp256r1_verify),
-                "secp256k1_pubkey_recover" => Function::new_typed_with_env(&mut store, &fe, secp256k1_pubkey_recover),
-                "ed25519_verify"           => Function::new_typed_with_env(&mut store, &fe, ed25519_verify),
-                "ed25519_batch_verify"     => Function::new_typed_with_env(&mut store, &fe, ed25519_batch_verify),
-                "sha2_256"                 => Function::new_typed_with_env(&mut store, &fe, sha2_256),
-                "sha2_512"                 => Function::new_typed_with_env(&mut store, &fe, sha2_512),
-                "sha2_512_truncated"       => Function::new_typed_with_env(&mut store, &fe, sha2_512_truncated),
-                "sha3_256"                 => Function::new_typed_with_env(&mut store, &fe, sha3_256),
-                "sha3_512"                 => Function::new_typed_with_env(&mut store, &fe, sha3_512),
-                "sha3_512_truncated"       => Function::new_typed_with_env(&mut store, &fe, sha3_512_truncated),
-                "keccak256"                => Function::new_typed_with_env(&mut store, &fe, keccak256),
-                "blake2s_256"              => Function::new_typed_with_env(&mut store, &fe, blake2s_256),
-                "blake2b_512"              => Function::new_typed_with_env(&mut store, &fe, blake2b_512),
-                "blake3"                   => Function::new_typed_with_env(&mut store, &fe, blake3),
-                "debug"                    => Function::new_typed_with_env(&mut store, &fe, debug),
-                "query_chain"              => Function::new_typed_with_env(&mut store, &fe, query_chain),
+                "db_read"                    => Function::new_typed_with_env(&mut store, &fe, db_read),
+                "db_scan"                    => Function::new_typed_with_env(&mut store, &fe, db_scan),
+                "db_next"                    => Function::new_typed_with_env(&mut store, &fe, db_next),
+                "db_next_key"                => Function::new_typed_with_env(&mut store, &fe, db_next_key),
+                "db_next_value"              => Function::new_typed_with_env(&mut store, &fe, db_next_value),
+                "db_write"                   => Function::new_typed_with_env(&mut store, &fe, db_write),
+                "db_remove"                  => Function::new_typed_with_env(&mut store, &fe, db_remove),
+                "db_remove_range"            => Function::new_typed_with_env(&mut store, &fe, db_remove_range),
+                "secp256k1_verify"           => Function::new_typed_with_env(&mut store, &fe, secp256k1_verify),
+                "secp256r1_verify"           => Function::new_typed_with_env(&mut store, &fe, secp256r1_verify),
+                "secp256k1_pubkey_recover"   => Function::new_typed_with_env(&mut store, &fe, secp256k1_pubkey_recover),
+                "secp256k1_batch_verify"     => Function::new_typed_with_env(&mut store, &fe, secp256k1_batch_verify),
+                "secp256k1_schnorr_verify"   => Function::new_typed_with_env(&mut store, &fe, secp256k1_schnorr_verify),
+                "ed25519_verify"             => Function::new_typed_with_env(&mut store, &fe, ed25519_verify),
+                "ed25519_batch_verify"       => Function::new_typed_with_env(&mut store, &fe, ed25519_batch_verify),
+                "bls12_381_verify"           => Function::new_typed_with_env(&mut store, &fe, bls12_381_verify),
+                "bls12_381_aggregate_verify" => Function::new_typed_with_env(&mut store, &fe, bls12_381_aggregate_verify),
+                "bls12_381_pairing_check"    => Function::new_typed_with_env(&mut store, &fe, bls12_381_pairing_check),
+                "sha2_256"                   => Function::new_typed_with_env(&mut store, &fe, sha2_256),
+                "sha2_512"                   => Function::new_typed_with_env(&mut store, &fe, sha2_512),
+                "sha2_512_truncated"         => Function::new_typed_with_env(&mut store, &fe, sha2_512_truncated),
+                "sha3_256"                   => Function::new_typed_with_env(&mut store, &fe, sha3_256),
+                "sha3_512"                   => Function::new_typed_with_env(&mut store, &fe, sha3_512),
+                "sha3_512_truncated"         => Function::new_typed_with_env(&mut store, &fe, sha3_512_truncated),
+                "keccak256"                  => Function::new_typed_with_env(&mut store, &fe, keccak256),
+                "blake2s_256"                => Function::new_typed_with_env(&mut store, &fe, blake2s_256),
+                "blake2b_512"                => Function::new_typed_with_env(&mut store, &fe, blake2b_512),
+                "blake3"                     => Function::new_typed_with_env(&mut store, &fe, blake3),
+                "debug"                      => Function::new_typed_with_env(&mut store, &fe, debug),
+                "query_chain"                => Function::new_typed_with_env(&mut store, &fe, query_chain),
             }
         };
 
@@ -169,7 +185,7 @@ impl Vm for WasmVm {
     }
 }
 
-fn compile_wasmer(code: &[u8]) -> VmResult<(Module, Engine)> {
+fn compile_wasmer(code: &[u8], operator_costs: OperatorCosts) -> VmResult<(Module, Engine)> {
     let mut compiler = Singlepass::new();
 
     // Set up the gas metering middleware.
@@ -183,7 +199,7 @@ fn compile_wasmer(code: &[u8]) -> VmResult<(Module, Engine)> {
     //
     // Also, compiling the module doesn't cost gas, so setting the limit
     // to zero won't raise out of gas errors.
-    let metering = Metering::new(0, |_| GAS_PER_OPERATION);
+    let metering = Metering::new(0, move |operator| operator_costs.cost(operator));
     compiler.push_middleware(Arc::new(metering));
 
     // Set up the `Gatekeeper`. This rejects certain Wasm operators that
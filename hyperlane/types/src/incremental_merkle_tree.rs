@@ -45,7 +45,7 @@ pub const ZERO_HASHES: [Hash256; TREE_DEPTH] = [
 
 /// Reference:
 /// <https://medium.com/@josephdelong/ethereum-2-0-deposit-merkle-tree-13ec8404ca4f>
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct IncrementalMerkleTree {
     pub branch: [Hash256; TREE_DEPTH],
     pub count: u128,
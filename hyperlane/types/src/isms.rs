@@ -6,7 +6,7 @@ pub const HYPERLANE_DOMAIN_KEY: &str = "HYPERLANE";
 
 // ----------------------------------- types -----------------------------------
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum IsmQuery {
     /// Verify a message.
     /// Return nothing is succeeds; throw error if fails.
@@ -16,7 +16,7 @@ pub enum IsmQuery {
     },
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum IsmQueryResponse {
     Verify(()),
 }
@@ -31,7 +31,7 @@ impl IsmQueryResponse {
 
 // --------------------------------- messages ----------------------------------
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     #[returns(IsmQueryResponse)]
     Ism(IsmQuery),
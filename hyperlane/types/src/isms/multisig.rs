@@ -6,7 +6,7 @@ use {
     std::collections::{BTreeMap, BTreeSet},
 };
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct ValidatorSet {
     pub threshold: u32,
     // A validator is identified by an Ethereum address. However we avoid using
@@ -15,7 +15,7 @@ pub struct ValidatorSet {
     pub validators: BTreeSet<HexByteArray<20>>,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct Metadata {
     pub origin_merkle_tree: Addr32,
     pub merkle_root: Hash256,
@@ -58,12 +58,12 @@ impl Metadata {
 
 // --------------------------------- messages ----------------------------------
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub validator_sets: BTreeMap<Domain, ValidatorSet>,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Set validators for a domain.
     SetValidators {
@@ -73,7 +73,7 @@ pub enum ExecuteMsg {
     },
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the validator set for a domain.
     #[returns(ValidatorSet)]
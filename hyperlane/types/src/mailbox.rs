@@ -10,7 +10,7 @@ pub type Domain = u32;
 
 // ----------------------------------- types -----------------------------------
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct Message {
     pub version: u8,
     pub nonce: u32,
@@ -53,7 +53,7 @@ impl Message {
     }
 }
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct Config {
     // Domain registry: https://github.com/hyperlane-xyz/hyperlane-registry
     pub local_domain: Domain,
@@ -63,12 +63,12 @@ pub struct Config {
 
 // --------------------------------- messages ----------------------------------
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub config: Config,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     /// Send a message.
     Dispatch {
@@ -83,7 +83,7 @@ pub enum ExecuteMsg {
     },
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the mailbox configuration.
     #[returns(Config)]
@@ -101,17 +101,17 @@ pub enum QueryMsg {
 
 // ---------------------------------- events -----------------------------------
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("mailbox_dispatch")]
 pub struct Dispatch(pub Message);
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("mailbox_dispatch_id")]
 pub struct DispatchId {
     pub message_id: Hash256,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("mailbox_process")]
 pub struct Process {
     pub origin_domain: Domain,
@@ -119,20 +119,20 @@ pub struct Process {
     pub recipient: Addr32,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("mailbox_process_id")]
 pub struct ProcessId {
     pub message_id: Hash256,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("post_dispatch")]
 pub struct PostDispatch {
     pub message_id: Hash256,
     pub index: u128,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("inserted_into_tree")]
 pub struct InsertedIntoTree {
     pub index: u128,
@@ -3,12 +3,12 @@ use {
     grug::{Addr, HexBinary},
 };
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     Recipient(RecipientMsg),
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum RecipientMsg {
     Handle {
         origin_domain: Domain,
@@ -17,13 +17,13 @@ pub enum RecipientMsg {
     },
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     #[returns(RecipientQueryResponse)]
     Recipient(RecipientQuery),
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum RecipientQuery {
     /// Return the ISM this recipient would like to use for verifying incoming
     /// messages.
@@ -31,7 +31,7 @@ pub enum RecipientQuery {
     InterchainSecurityModule {},
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum RecipientQueryResponse {
     InterchainSecurityModule(Option<Addr>),
 }
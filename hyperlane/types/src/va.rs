@@ -8,13 +8,13 @@ pub const VA_DOMAIN_KEY: &str = "HYPERLANE_ANNOUNCEMENT";
 
 // --------------------------------- messages ----------------------------------
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct InstantiateMsg {
     pub mailbox: Addr,
     pub announce_fee_per_byte: Coin,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub enum ExecuteMsg {
     Announce {
         validator: HexByteArray<20>,
@@ -23,7 +23,7 @@ pub enum ExecuteMsg {
     },
 }
 
-#[grug::derive(Serde, QueryRequest)]
+#[grug::derive(Serde, Schema, QueryRequest)]
 pub enum QueryMsg {
     /// Query the mailbox contract address.
     #[returns(Addr)]
@@ -46,7 +46,7 @@ pub enum QueryMsg {
 
 // ---------------------------------- events -----------------------------------
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("init_validator_announce")]
 pub struct Initialize {
     pub creator: Addr,
@@ -54,7 +54,7 @@ pub struct Initialize {
     pub announce_fee_per_byte: Coin,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 #[grug::event("validator_announcement")]
 pub struct Announce {
     pub sender: Addr,
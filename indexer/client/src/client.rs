@@ -6,12 +6,12 @@ use {
     grug_types::{
         Addr, Binary, Block, BlockClient, BlockOutcome, BorshDeExt, BroadcastClient,
         BroadcastTxOutcome, GenericResult, Hash256, Inner, Json, JsonDeExt, JsonSerExt, NonEmpty,
-        Query, QueryClient, QueryResponse, SearchTxClient, SearchTxOutcome, Tx, TxOutcome,
-        UnsignedTx,
+        Query, QueryClient, QueryResponse, QueryStoreResponse, SearchTxClient, SearchTxOutcome,
+        StateOverrides, Tx, TxOutcome, UnsignedTx,
     },
     reqwest::IntoUrl,
     serde::Serialize,
-    std::str::FromStr,
+    std::{collections::BTreeMap, str::FromStr},
     url::Url,
 };
 
@@ -101,27 +101,58 @@ impl QueryClient for HttpClient {
         key: Binary,
         height: Option<u64>,
         prove: bool,
-    ) -> Result<(Option<Binary>, Option<Self::Proof>), Self::Error> {
+    ) -> Result<QueryStoreResponse<Self::Proof>, Self::Error> {
         let response = self
             .post_graphql(query_store::Variables {
                 key: key.to_string(),
                 height: height.map(|h| h as i64),
                 prove,
             })
-            .await?;
+            .await?
+            .query_store;
 
-        let proof = match response.query_store.proof {
-            Some(proof) => Binary::from_str(&proof)?.into_inner().deserialize_borsh()?,
-            None => None,
-        };
+        Ok(QueryStoreResponse {
+            height: response.height as u64,
+            value: response
+                .value
+                .map(|value| Binary::from_str(&value))
+                .transpose()?,
+            proof: response
+                .proof
+                .map(|proof| Binary::from_str(&proof)?.deserialize_borsh())
+                .transpose()?,
+            root_hash: response
+                .root_hash
+                .map(|root_hash| Hash256::from_str(&root_hash))
+                .transpose()?,
+        })
+    }
 
-        Ok((Some(Binary::from_str(&response.query_store.value)?), proof))
+    async fn query_store_scan(
+        &self,
+        _min: Option<Binary>,
+        _max: Option<Binary>,
+        _limit: Option<u32>,
+        _height: Option<u64>,
+    ) -> Result<BTreeMap<Binary, Binary>, Self::Error> {
+        bail!("store scan isn't served by the indexer; query the node directly instead")
     }
 
-    async fn simulate(&self, tx: UnsignedTx) -> Result<TxOutcome, Self::Error> {
+    async fn simulate_with_overrides(
+        &self,
+        tx: UnsignedTx,
+        overrides: StateOverrides,
+    ) -> Result<TxOutcome, Self::Error> {
+        let overrides = if overrides.is_empty() {
+            None
+        } else {
+            Some(overrides.to_json_value()?.into_inner())
+        };
+
         let response = self
             .post_graphql(simulate::Variables {
                 tx: tx.to_json_value()?.into_inner(),
+                overrides,
             })
             .await?;
 
@@ -191,6 +222,8 @@ impl SearchTxClient for HttpClient {
 
         let tx = Tx {
             sender: Addr::from_str(&res.sender)?,
+            // TODO: the indexer doesn't record the fee payer yet.
+            fee_payer: None,
             gas_limit: res.gas_wanted as u64,
             msgs: NonEmpty::new(msgs)?,
             data: Json::from_inner(res.data.clone()),
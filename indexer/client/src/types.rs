@@ -99,6 +99,7 @@ macro_rules! generate_types {
 type JSON = serde_json::Value;
 type GrugQueryInput = serde_json::Value;
 type UnsignedTx = serde_json::Value;
+type StateOverrides = serde_json::Value;
 type Tx = serde_json::Value;
 
 generate_types! {
@@ -140,6 +141,7 @@ generate_types! {
               ],
               "sender": "0x33361de42571d6aa20c37daa6da4b5ab67bfaad9"
             }),
+            overrides: None
         }
     },
     {
@@ -451,6 +451,8 @@ mod tests {
             height: 1,
             app_hash: grug_types::Hash256::ZERO,
             cron_outcomes: vec![],
+            scheduled_outcomes: vec![],
+            receive_event_outcomes: vec![],
             tx_outcomes: vec![],
         };
 
@@ -601,6 +603,8 @@ mod tests {
             height: 42,
             app_hash: grug_types::Hash256::ZERO,
             cron_outcomes: vec![],
+            scheduled_outcomes: vec![],
+            receive_event_outcomes: vec![],
             tx_outcomes: vec![],
         };
 
@@ -123,6 +123,18 @@ impl Models {
 
                 events.extend(active_models);
 
+                let active_models = flatten_events(
+                    block,
+                    &mut events_by_address,
+                    &mut event_id,
+                    tx_outcome.events.authenticate_fee_payer.clone(),
+                    Some(transaction_id),
+                    &[],
+                    created_at,
+                )?;
+
+                events.extend(active_models);
+
                 let mut message_ids = vec![];
 
                 // 3. Storing messages
@@ -1,3 +1,5 @@
+mod legacy;
+
 #[cfg(feature = "metrics")]
 use {metrics::counter, std::time::Instant};
 
@@ -7,6 +9,7 @@ use {
     grug_types::{Block, BlockOutcome, HttpRequestDetails},
     indexer_disk_saver::persistence::DiskPersistence,
     itertools::Itertools,
+    legacy::LegacyBlockToIndex,
     sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, TransactionTrait},
     serde::{Deserialize, Serialize},
     std::{collections::HashMap, path::PathBuf},
@@ -208,8 +211,25 @@ impl BlockToIndex {
         Ok(())
     }
 
+    /// Load a block from disk, whether it was saved in the current layout or
+    /// the legacy one.
     pub fn load_from_disk(file_path: PathBuf) -> error::Result<Self> {
-        let mut block_to_index: Self = DiskPersistence::new(file_path.clone(), false).load()?;
+        let mut file = DiskPersistence::new(file_path.clone(), false);
+
+        let mut block_to_index: Self = match file.load() {
+            Ok(block_to_index) => block_to_index,
+            Err(err) => match file.load::<LegacyBlockToIndex>() {
+                Ok(legacy) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(?file.file_path, "Loaded block file in the legacy layout");
+
+                    legacy.into()
+                },
+                // Report the error of decoding in the current layout, which is
+                // the more likely one to be relevant.
+                Err(_) => return Err(err.into()),
+            },
+        };
         block_to_index.filename = file_path;
         Ok(block_to_index)
     }
@@ -234,10 +254,19 @@ mod tests {
     use {
         super::*,
         assertor::*,
-        grug_types::{BlockInfo, Hash},
+        grug_types::{
+            Addr, BlockInfo, Coins, CommitmentStatus, Config, Duration, Hash, Json, Message,
+            MsgConfigure, NonEmpty, Permission, Permissions, Timestamp, Tx, TxEvents, TxOutcome,
+            json,
+        },
+        std::{collections::BTreeMap, str::FromStr},
         tempfile::NamedTempFile,
     };
 
+    /// A block with a transaction, archived before transactions could have a
+    /// fee payer.
+    const LEGACY_BLOCK: &[u8] = include_bytes!("../testdata/legacy_block.borsh");
+
     #[test]
     fn test_save_and_load_and_delete_tmp_file() {
         let block_info = BlockInfo {
@@ -256,6 +285,8 @@ mod tests {
             app_hash: Hash::ZERO,
             cron_outcomes: vec![],
             tx_outcomes: vec![],
+            scheduled_outcomes: vec![],
+            receive_event_outcomes: vec![],
         };
 
         let temp_file = NamedTempFile::new().expect("Failed to create a temp file");
@@ -271,4 +302,126 @@ mod tests {
         assert_that!(saved_block_to_index).is_equal_to(block_to_index);
         assert_that!(BlockToIndex::delete_from_disk(temp_filename)).is_ok();
     }
+
+    #[test]
+    fn test_load_legacy_tmp_file() {
+        let block_info = BlockInfo {
+            height: 10,
+            timestamp: Default::default(),
+            hash: Hash::ZERO,
+        };
+
+        let legacy = LegacyBlockToIndex {
+            block: legacy::LegacyBlock {
+                info: block_info,
+                txs: vec![],
+            },
+            block_outcome: legacy::LegacyBlockOutcome {
+                height: 10,
+                app_hash: Hash::ZERO,
+                cron_outcomes: vec![],
+                tx_outcomes: vec![],
+            },
+            http_request_details: HashMap::new(),
+        };
+
+        let temp_file = NamedTempFile::new().expect("Failed to create a temp file");
+        let temp_filename = temp_file.path().to_path_buf();
+
+        assert_that!(DiskPersistence::new(temp_filename.clone(), false).save(&legacy)).is_ok();
+
+        let loaded_block_to_index =
+            BlockToIndex::load_from_disk(temp_filename.clone()).expect("Can't load tmp file");
+
+        assert_that!(loaded_block_to_index).is_equal_to(BlockToIndex::new(
+            temp_filename.clone(),
+            Block {
+                info: block_info,
+                txs: vec![],
+            },
+            BlockOutcome {
+                height: 10,
+                app_hash: Hash::ZERO,
+                cron_outcomes: vec![],
+                tx_outcomes: vec![],
+                scheduled_outcomes: vec![],
+                receive_event_outcomes: vec![],
+            },
+        ));
+        assert_that!(BlockToIndex::delete_from_disk(temp_filename)).is_ok();
+    }
+
+    #[test]
+    fn test_load_legacy_file_with_txs() {
+        let temp_file = NamedTempFile::new().expect("Failed to create a temp file");
+        let temp_filename = temp_file.path().to_path_buf();
+
+        std::fs::write(temp_filename.with_extension("borsh"), LEGACY_BLOCK)
+            .expect("Failed to write the legacy block file");
+
+        let loaded_block_to_index =
+            BlockToIndex::load_from_disk(temp_filename.clone()).expect("Can't load tmp file");
+
+        let tx = Tx {
+            sender: Addr::mock(1),
+            fee_payer: None,
+            gas_limit: 100_000,
+            msgs: NonEmpty::new_unchecked(vec![
+                Message::Configure(MsgConfigure {
+                    new_cfg: Some(Config {
+                        owner: Addr::mock(1),
+                        bank: Addr::mock(2),
+                        taxman: Addr::mock(3),
+                        cronjobs: BTreeMap::new(),
+                        permissions: Permissions {
+                            upload: Permission::Everybody,
+                            instantiate: Permission::Everybody,
+                        },
+                        max_orphan_age: Duration::from_seconds(86400),
+                        gas_costs: Default::default(),
+                        end_block_limits: Default::default(),
+                    }),
+                    new_app_cfg: None,
+                }),
+                Message::execute(Addr::mock(4), &json!({ "foo": "bar" }), Coins::new()).unwrap(),
+            ]),
+            data: Json::null(),
+            credential: Json::null(),
+        };
+        let tx_hash =
+            Hash::from_str("9F68DBFA8CB4C9970D32ABDD57B932752FD963C05FDB9B897B391FC523625CBA")
+                .unwrap();
+
+        assert_that!(loaded_block_to_index).is_equal_to(BlockToIndex::new(
+            temp_filename.clone(),
+            Block {
+                info: BlockInfo {
+                    height: 10,
+                    timestamp: Timestamp::from_seconds(1),
+                    hash: Hash::ZERO,
+                },
+                txs: vec![(tx, tx_hash)],
+            },
+            BlockOutcome {
+                height: 10,
+                app_hash: Hash::ZERO,
+                cron_outcomes: vec![],
+                tx_outcomes: vec![TxOutcome {
+                    gas_limit: 100_000,
+                    gas_used: 50_000,
+                    result: Ok(()),
+                    events: TxEvents {
+                        withhold: CommitmentStatus::NotReached,
+                        authenticate: CommitmentStatus::NotReached,
+                        authenticate_fee_payer: CommitmentStatus::NotReached,
+                        msgs_and_backrun: CommitmentStatus::NotReached,
+                        finalize: CommitmentStatus::NotReached,
+                    },
+                }],
+                scheduled_outcomes: vec![],
+                receive_event_outcomes: vec![],
+            },
+        ));
+        assert_that!(BlockToIndex::delete_from_disk(temp_filename)).is_ok();
+    }
 }
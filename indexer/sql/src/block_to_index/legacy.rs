@@ -0,0 +1,185 @@
+//! Layout of the block archives written before transactions could have a fee
+//! payer, and before the chain config included the gas costs and the end of
+//! block limits, and block outcomes included scheduled executions and event
+//! notifications.
+//!
+//! Archives in this layout can't be decoded as [`BlockToIndex`], so they are
+//! decoded with the types here and converted to the current layout instead.
+
+use {
+    super::BlockToIndex,
+    borsh::{BorshDeserialize, BorshSerialize},
+    grug_types::{
+        Addr, Block, BlockInfo, BlockOutcome, CommitmentStatus, Config, CronOutcome, Duration,
+        EventStatus, EvtAuthenticate, EvtFinalize, EvtWithhold, GenericResult, Hash256,
+        HttpRequestDetails, Inner, Json, Message, MsgConfigure, MsgExecute, MsgInstantiate,
+        MsgMigrate, MsgTransfer, MsgUpload, MsgsAndBackrunEvents, NonEmpty, Permissions, Tx,
+        TxEvents, TxOutcome,
+    },
+    std::collections::{BTreeMap, HashMap},
+};
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyBlockToIndex {
+    pub block: LegacyBlock,
+    pub block_outcome: LegacyBlockOutcome,
+    pub http_request_details: HashMap<String, HttpRequestDetails>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyBlock {
+    pub info: BlockInfo,
+    pub txs: Vec<(LegacyTx, Hash256)>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyTx {
+    pub sender: Addr,
+    pub gas_limit: u64,
+    pub msgs: NonEmpty<Vec<LegacyMessage>>,
+    pub data: Json,
+    pub credential: Json,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) enum LegacyMessage {
+    Configure(LegacyMsgConfigure),
+    Transfer(MsgTransfer),
+    Upload(MsgUpload),
+    Instantiate(MsgInstantiate),
+    Execute(MsgExecute),
+    Migrate(MsgMigrate),
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyMsgConfigure {
+    pub new_cfg: Option<LegacyConfig>,
+    pub new_app_cfg: Option<Json>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyConfig {
+    pub owner: Addr,
+    pub bank: Addr,
+    pub taxman: Addr,
+    pub cronjobs: BTreeMap<Addr, Duration>,
+    pub permissions: Permissions,
+    pub max_orphan_age: Duration,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyBlockOutcome {
+    pub height: u64,
+    pub app_hash: Hash256,
+    pub cron_outcomes: Vec<CronOutcome>,
+    pub tx_outcomes: Vec<LegacyTxOutcome>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyTxOutcome {
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub result: GenericResult<()>,
+    pub events: LegacyTxEvents,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(super) struct LegacyTxEvents {
+    pub withhold: CommitmentStatus<EventStatus<EvtWithhold>>,
+    pub authenticate: CommitmentStatus<EventStatus<EvtAuthenticate>>,
+    pub msgs_and_backrun: CommitmentStatus<MsgsAndBackrunEvents>,
+    pub finalize: CommitmentStatus<EventStatus<EvtFinalize>>,
+}
+
+impl From<LegacyBlockToIndex> for BlockToIndex {
+    fn from(legacy: LegacyBlockToIndex) -> Self {
+        Self {
+            block: Block {
+                info: legacy.block.info,
+                txs: legacy
+                    .block
+                    .txs
+                    .into_iter()
+                    .map(|(tx, tx_hash)| (tx.into(), tx_hash))
+                    .collect(),
+            },
+            block_outcome: BlockOutcome {
+                height: legacy.block_outcome.height,
+                app_hash: legacy.block_outcome.app_hash,
+                cron_outcomes: legacy.block_outcome.cron_outcomes,
+                tx_outcomes: legacy
+                    .block_outcome
+                    .tx_outcomes
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                scheduled_outcomes: vec![],
+                receive_event_outcomes: vec![],
+            },
+            http_request_details: legacy.http_request_details,
+            filename: Default::default(),
+        }
+    }
+}
+
+impl From<LegacyTx> for Tx {
+    fn from(legacy: LegacyTx) -> Self {
+        Self {
+            sender: legacy.sender,
+            fee_payer: None,
+            gas_limit: legacy.gas_limit,
+            msgs: NonEmpty::new_unchecked(
+                legacy
+                    .msgs
+                    .into_inner()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            ),
+            data: legacy.data,
+            credential: legacy.credential,
+        }
+    }
+}
+
+impl From<LegacyMessage> for Message {
+    fn from(legacy: LegacyMessage) -> Self {
+        match legacy {
+            LegacyMessage::Configure(msg) => Message::Configure(MsgConfigure {
+                new_cfg: msg.new_cfg.map(|cfg| Config {
+                    owner: cfg.owner,
+                    bank: cfg.bank,
+                    taxman: cfg.taxman,
+                    cronjobs: cfg.cronjobs,
+                    permissions: cfg.permissions,
+                    max_orphan_age: cfg.max_orphan_age,
+                    gas_costs: Default::default(),
+                    end_block_limits: Default::default(),
+                }),
+                new_app_cfg: msg.new_app_cfg,
+            }),
+            LegacyMessage::Transfer(msg) => Message::Transfer(msg),
+            LegacyMessage::Upload(msg) => Message::Upload(msg),
+            LegacyMessage::Instantiate(msg) => Message::Instantiate(msg),
+            LegacyMessage::Execute(msg) => Message::Execute(msg),
+            LegacyMessage::Migrate(msg) => Message::Migrate(msg),
+        }
+    }
+}
+
+impl From<LegacyTxOutcome> for TxOutcome {
+    fn from(legacy: LegacyTxOutcome) -> Self {
+        Self {
+            gas_limit: legacy.gas_limit,
+            gas_used: legacy.gas_used,
+            result: legacy.result,
+            events: TxEvents {
+                withhold: legacy.events.withhold,
+                authenticate: legacy.events.authenticate,
+                authenticate_fee_payer: CommitmentStatus::NotReached,
+                msgs_and_backrun: legacy.events.msgs_and_backrun,
+                finalize: legacy.events.finalize,
+            },
+        }
+    }
+}
@@ -230,6 +230,8 @@ async fn no_sql_index_error_after_restart() {
         height: 1,
         app_hash: Hash::ZERO,
         cron_outcomes: vec![],
+        scheduled_outcomes: vec![],
+        receive_event_outcomes: vec![],
         tx_outcomes: vec![],
     };
     let block = Block {
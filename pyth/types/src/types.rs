@@ -2,12 +2,12 @@ use grug::{AddrEncoder, Binary, EncodedBytes};
 
 pub type PythId = EncodedBytes<[u8; 32], AddrEncoder>;
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct LatestVaaResponse {
     pub binary: LatestVaaBinaryResponse,
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct LatestVaaBinaryResponse {
     pub data: Vec<Binary>,
 }
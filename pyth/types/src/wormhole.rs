@@ -8,7 +8,7 @@ use {
 
 pub type GuardianSetIndex = u32;
 
-#[grug::derive(Serde, Borsh)]
+#[grug::derive(Serde, Borsh, Schema)]
 pub struct GuardianSet {
     pub addresses: Vec<Hash160>,
     pub expiration_time: Option<NonZero<Timestamp>>,
@@ -20,7 +20,7 @@ impl GuardianSet {
     }
 }
 
-#[grug::derive(Serde)]
+#[grug::derive(Serde, Schema)]
 pub struct GuardianSignature {
     pub id_recover: u8,
     pub signature: ByteArray<{ WormholeVaa::SIGNATURE_LEN - 1 }>,
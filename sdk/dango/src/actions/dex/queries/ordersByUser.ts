@@ -4,20 +4,25 @@ import type { Client, Prettify, Transport } from "@left-curve/sdk/types";
 import { getAction, getAppConfig } from "@left-curve/sdk/actions";
 import type { Chain, Signer } from "@left-curve/sdk/types";
 import type { AppConfig } from "../../../types/app.js";
-import type { GetDexQueryMsg, OrderId, OrdersByUserResponse } from "../../../types/dex.js";
+import type {
+  GetDexQueryMsg,
+  OrderCursor,
+  OrdersByUserResponse,
+  OrdersPage,
+} from "../../../types/dex.js";
 
 type ActionMsg = GetDexQueryMsg<"ordersByUser">;
 
 export type OrdersByUserParameters = Prettify<ActionMsg["ordersByUser"] & { height?: number }>;
 
-export type OrdersByUserReturnType = Promise<Record<OrderId, OrdersByUserResponse>>;
+export type OrdersByUserReturnType = Promise<OrdersPage<OrderCursor, OrdersByUserResponse>>;
 
 /**
  * Query orders by user.
  * This function retrieves orders placed by a specific user on the Dango DEX.
  * @param parameters
  * @param parameters.user The user address to query orders for.
- * @param parameters.startAfter The ID of the order to start after.
+ * @param parameters.startAfter The cursor returned by the previous page, to start after.
  * @param parameters.limit The maximum number of orders to return.
  * @param parameters.height The height at which to query the pairs
  * @returns The orders by user response.
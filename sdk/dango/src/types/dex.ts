@@ -120,8 +120,8 @@ export type DexQueryMsg =
         baseDenom: Denom;
         /** The quote denomination of the trading pair. */
         quoteDenom: Denom;
-        /** The ID of the order to start after. */
-        startAfter: Option<OrderId>;
+        /** The cursor returned by the previous page, to start after. */
+        startAfter: Option<OrderCursor>;
         /** The maximum number of orders to return. */
         limit: Option<number>;
       };
@@ -131,8 +131,8 @@ export type DexQueryMsg =
       ordersByUser: {
         /** The user address to query. */
         user: Address;
-        /** The ID of the order to start after. */
-        startAfter?: Option<OrderId>;
+        /** The cursor returned by the previous page, to start after. */
+        startAfter?: Option<OrderCursor>;
         /** The maximum number of orders to return. */
         limit?: Option<number>;
       };
@@ -240,6 +240,26 @@ export type OrderResponse = {
   remaining: string;
 };
 
+/**
+ * A position to continue enumerating orders from, in the `ordersByPair` and
+ * `ordersByUser` queries. Consists of the order's full storage key, so it
+ * remains valid after the order has been filled or canceled.
+ */
+export type OrderCursor = {
+  baseDenom: string;
+  quoteDenom: string;
+  direction: Directions;
+  price: string;
+  orderId: OrderId;
+};
+
+/** A page of orders, and the cursor to get the next page with. */
+export type OrdersPage<C, T> = {
+  orders: Record<OrderId, T>;
+  /** `null` if this is the last page. */
+  next: Option<C>;
+};
+
 export type OrdersByPairResponse = {
   user: Address;
   direction: Directions;
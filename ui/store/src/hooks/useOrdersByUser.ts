@@ -36,8 +36,8 @@ export function useOrdersByUser(
     queryKey: ["ordersByUser", address, ...queryKey],
     queryFn: async () => {
       if (!account) return [];
-      const response = await publicClient.ordersByUser({ user: account.address });
-      return Object.entries(response).map(([id, order]) => ({
+      const { orders } = await publicClient.ordersByUser({ user: account.address });
+      return Object.entries(orders).map(([id, order]) => ({
         ...order,
         id,
       }));
@@ -120,8 +120,8 @@ export function useProTradeState(parameters: UseProTradeStateParameters) {
     queryKey: ["ordersByUser", account?.address],
     queryFn: async () => {
       if (!account) return [];
-      const response = await publicClient.ordersByUser({ user: account.address });
-      return Object.entries(response).map(([id, order]) => ({
+      const { orders } = await publicClient.ordersByUser({ user: account.address });
+      return Object.entries(orders).map(([id, order]) => ({
         ...order,
         id,
       }));